@@ -2,7 +2,7 @@ use ahash::HashSetExt;
 use alloy_primitives::Address;
 use brontes_database::{clickhouse::cex_config::CexDownloadConfig, libmdbx::LibmdbxReader};
 use brontes_types::{
-    constants::USDT_ADDRESS,
+    chain::chain_config,
     db::cex::{trades::CexTrades, CexExchange},
     init_thread_pools,
     pair::Pair,
@@ -55,7 +55,7 @@ impl CexDB {
 
         let libmdbx = static_object(load_libmdbx(&task_executor, brontes_db_path)?);
 
-        let metadata = libmdbx.get_metadata(self.block_number, USDT_ADDRESS)?;
+        let metadata = libmdbx.get_metadata(self.block_number, chain_config().usdt_address())?;
 
         let clickhouse: ClickhouseClient<NullDBMS> = get_clickhouse_env();
 
@@ -66,7 +66,10 @@ impl Clear {
                 AddressMeta,
                 SearcherEOAs,
                 SearcherContracts,
-                TxTraces
+                TxTraces,
+                ExpressLaneRounds,
+                ExpressLaneReports,
+                ExpressLaneBids
             )
         });
 
@@ -77,7 +77,10 @@ impl DatabaseQuery {
                     AddressMeta,
                     SearcherEOAs,
                     SearcherContracts,
-                    TxTraces
+                    TxTraces,
+                    ExpressLaneRounds,
+                    ExpressLaneReports,
+                    ExpressLaneBids
                 );
             } else {
                 match_table!(
@@ -97,6 +100,9 @@ impl DatabaseQuery {
                     SearcherEOAs,
                     SearcherContracts,
                     TxTraces,
+                    ExpressLaneRounds,
+                    ExpressLaneReports,
+                    ExpressLaneBids,
                     PoolCreationBlocks = &self.key
                 );
             }
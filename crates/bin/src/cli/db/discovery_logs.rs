@@ -6,14 +6,15 @@ use alloy_sol_types::SolEvent;
 use brontes_classifier::{FluidVaultFactory, PendleMarketV3Factory, PendleYieldContractFactory};
 use brontes_core::decoding::LogParser as DLogParser;
 use brontes_types::{
+    chain::{chain_config, SupportedChain},
     constants::arbitrum::{
         BALANCER_V2_VAULT_ADDRESS, CAMELOT_V2_FACTORY_ADDRESS, CAMELOT_V3_FACTORY_ADDRESS,
         FLUID_DEX_FACTORY_ADDRESS, FLUID_VAULT_FACTORY_ADDRESS, LFJ_V2_1_DEX_FACTORY_ADDRESS,
         LFJ_V2_2_DEX_FACTORY_ADDRESS, PANCAKESWAP_V2_FACTORY_ADDRESS,
         PANCAKESWAP_V3_FACTORY_ADDRESS, PENDLE_MARKET_V3_FACTORY_ADDRESS,
-        PENDLE_YIELD_CONTRACT_FACTORY_ADDRESS, SUSHISWAP_V2_FACTORY_ADDRESS,
-        SUSHISWAP_V3_FACTORY_ADDRESS, UNISWAP_V2_FACTORY_ADDRESS, UNISWAP_V3_FACTORY_ADDRESS,
-        UNISWAP_V4_FACTORY_ADDRESS,
+        PENDLE_YIELD_CONTRACT_FACTORY_ADDRESS, RAMSES_V1_FACTORY_ADDRESS,
+        RAMSES_V2_FACTORY_ADDRESS, SUSHISWAP_V2_FACTORY_ADDRESS, SUSHISWAP_V3_FACTORY_ADDRESS,
+        UNISWAP_V2_FACTORY_ADDRESS, UNISWAP_V3_FACTORY_ADDRESS, UNISWAP_V4_FACTORY_ADDRESS,
     },
     init_thread_pools, Protocol,
 };
@@ -67,6 +68,11 @@ sol!(
     FluidDEX,
     "../brontes-classifier/classifier-abis/fluid/FluidDexFactory.json"
 );
+sol!(
+    #![sol(all_derives)]
+    RamsesV1,
+    "../brontes-classifier/classifier-abis/ramses/RamsesPairFactory.json"
+);
 
 #[derive(Debug, Parser)]
 pub struct DiscoveryLogsFill {
@@ -131,6 +137,14 @@ impl DiscoveryLogsFill {
             Protocol::CamelotV3,
             vec![(CAMELOT_V3_FACTORY_ADDRESS, CamelotV3::Pool::SIGNATURE_HASH)],
         );
+        protocol_to_address.insert(
+            Protocol::RamsesV1,
+            vec![(RAMSES_V1_FACTORY_ADDRESS, RamsesV1::PairCreated::SIGNATURE_HASH)],
+        );
+        protocol_to_address.insert(
+            Protocol::RamsesV2,
+            vec![(RAMSES_V2_FACTORY_ADDRESS, UniswapV3::PoolCreated::SIGNATURE_HASH)],
+        );
         protocol_to_address.insert(
             Protocol::FluidDEX,
             vec![(FLUID_DEX_FACTORY_ADDRESS, FluidDEX::DexT1Deployed::SIGNATURE_HASH)],
@@ -166,6 +180,10 @@ impl DiscoveryLogsFill {
     }
 
     pub async fn execute(self, brontes_db_path: String, ctx: CliContext) -> eyre::Result<()> {
+        // the factories below are the arbitrum deployments
+        if chain_config().chain() != SupportedChain::Arbitrum {
+            eyre::bail!("log based discovery only knows the arbitrum factories")
+        }
         let db_path = get_env_vars()?;
 
         let max_tasks = self.max_tasks.unwrap_or(num_cpus::get_physical());
@@ -0,0 +1,86 @@
+use std::collections::BTreeMap;
+
+use brontes_types::{
+    db::{
+        express_lane::ExpressLaneRoundReport,
+        mev_block::MevBlockWithClassified,
+        traits::{DBWriter, LibmdbxReader},
+    },
+    init_thread_pools,
+};
+use clap::Parser;
+
+use crate::{
+    cli::{load_database, static_object},
+    runner::CliContext,
+};
+
+#[derive(Debug, Parser)]
+pub struct ExpressLaneReportArgs {
+    /// Start Block
+    #[arg(long, short)]
+    pub start_block: u64,
+    /// End Block
+    #[arg(long, short)]
+    pub end_block:   u64,
+    /// Blocks of mev data to load at once
+    #[arg(long, short = 'b', default_value_t = 10_000)]
+    pub batch_size:  u64,
+}
+
+impl ExpressLaneReportArgs {
+    pub async fn execute(self, brontes_db_path: String, ctx: CliContext) -> eyre::Result<()> {
+        init_thread_pools(10);
+        let db =
+            static_object(load_database(&ctx.task_executor, brontes_db_path, None, None).await?);
+
+        let mut rounds: BTreeMap<u64, Vec<MevBlockWithClassified>> = BTreeMap::new();
+        let mut written = 0usize;
+        for start in (self.start_block..=self.end_block).step_by(self.batch_size as usize) {
+            let end = (start + self.batch_size - 1).min(self.end_block);
+            for mev_block in db.try_fetch_mev_blocks(Some(start), end)? {
+                let Some(round) = mev_block.block.express_lane_round else { continue };
+                rounds.entry(round).or_default().push(mev_block);
+            }
+
+            // the last round we have seen can continue into the next batch
+            let Some(&last_round) = rounds.keys().last() else { continue };
+            let pending = rounds.split_off(&last_round);
+            let finished = std::mem::replace(&mut rounds, pending);
+
+            written += Self::write_reports(db, finished).await?;
+            tracing::info!(block = end, reports = written, "stored express lane reports");
+        }
+
+        written += Self::write_reports(db, rounds).await?;
+        tracing::info!(reports = written, "finished storing express lane reports");
+
+        Ok(())
+    }
+
+    async fn write_reports<DB>(
+        db: &DB,
+        rounds: BTreeMap<u64, Vec<MevBlockWithClassified>>,
+    ) -> eyre::Result<usize>
+    where
+        DB: LibmdbxReader + DBWriter,
+    {
+        let mut reports = Vec::with_capacity(rounds.len());
+        for (round, blocks) in rounds {
+            let Some(express_lane_round) = db.try_fetch_express_lane_round(round)? else {
+                tracing::warn!(round, "express lane round missing, run `db express-lane-rounds`");
+                continue
+            };
+            reports.push(ExpressLaneRoundReport::new(&express_lane_round, &blocks));
+        }
+
+        if reports.is_empty() {
+            return Ok(0)
+        }
+
+        let written = reports.len();
+        db.write_express_lane_reports(reports).await?;
+
+        Ok(written)
+    }
+}
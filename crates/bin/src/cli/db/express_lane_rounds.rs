@@ -0,0 +1,132 @@
+use std::{collections::BTreeMap, path::Path, sync::Arc};
+
+use brontes_timeboost::{
+    auction::ExpressLaneAuctionProvider,
+    bids::ExpressLaneBidStore,
+    config::{ExpressLaneAuctionConfig, CHAIN_CONFIG_FILE},
+};
+use brontes_types::{
+    chain::chain_config,
+    db::{express_lane::ExpressLaneRound, traits::DBWriter},
+    init_thread_pools,
+    traits::TracingProvider,
+};
+use clap::Parser;
+
+use crate::{
+    cli::{get_env_vars, get_tracing_provider, load_libmdbx},
+    runner::CliContext,
+};
+
+#[derive(Debug, Parser)]
+pub struct ExpressLaneRoundsArgs {
+    /// Start Block, defaults to the activation block of the auction
+    #[arg(long, short)]
+    pub start_block:  Option<u64>,
+    /// End Block
+    #[arg(long, short)]
+    pub end_block:    u64,
+    /// Block range per log request
+    #[arg(long, short = 'b', default_value_t = 10_000)]
+    pub batch_size:   u64,
+    /// Path to a dump of the auctioneer's bid feed (.json or .csv) to
+    /// store the losing bids of each round
+    #[arg(long)]
+    pub bids:         Option<String>,
+    /// Entry of the chain config to use, defaults to the entry of the chain
+    /// selected with `--chain`
+    #[arg(long)]
+    pub chain_entry:  Option<String>,
+    /// Path to the chain config
+    #[arg(long, default_value = CHAIN_CONFIG_FILE)]
+    pub chain_config: String,
+}
+
+impl ExpressLaneRoundsArgs {
+    pub async fn execute(self, brontes_db_path: String, ctx: CliContext) -> eyre::Result<()> {
+        let db_path = get_env_vars()?;
+        init_thread_pools(10);
+
+        let libmdbx = load_libmdbx(&ctx.task_executor, brontes_db_path)?;
+        let tracer = Arc::new(get_tracing_provider(
+            Path::new(&db_path),
+            10,
+            ctx.task_executor.clone(),
+            None,
+        ));
+
+        if !chain_config().has_sequencer() {
+            eyre::bail!("{} has no express lane auction", chain_config().chain())
+        }
+        let chain_entry = self
+            .chain_entry
+            .as_deref()
+            .unwrap_or(chain_config().chain_config_entry());
+        let config = ExpressLaneAuctionConfig::load(&self.chain_config, chain_entry)?;
+        let mut provider = ExpressLaneAuctionProvider::new(tracer, config.clone());
+        let activation_block = provider
+            .activation_block(self.end_block)
+            .await?
+            .ok_or_else(|| {
+                eyre::eyre!("express lane auction isn't live as of block {}", self.end_block)
+            })?;
+        let start_block = self
+            .start_block
+            .unwrap_or(activation_block)
+            .max(activation_block);
+
+        if let Some(bids) = self.bids {
+            let bids = ExpressLaneBidStore::load(bids, config.address)?;
+            libmdbx.write_express_lane_bids(bids.round_bids()).await?;
+            provider = provider.with_bid_store(Arc::new(bids));
+        }
+
+        let mut rounds = BTreeMap::new();
+        let mut written = 0usize;
+        for start in (start_block..=self.end_block).step_by(self.batch_size as usize) {
+            let end = (start + self.batch_size - 1).min(self.end_block);
+            for log in provider.fetch_auction_events_range(start, end).await? {
+                log.apply_to_rounds(&mut rounds);
+            }
+
+            // an auction is resolved during the previous round and transfers only happen
+            // during the round itself, so anything before the second to last round we
+            // have seen won't receive any more events.
+            let Some(&last_round) = rounds.keys().last() else { continue };
+            let pending = rounds.split_off(&last_round.saturating_sub(1));
+            let finished = std::mem::replace(&mut rounds, pending);
+
+            written += finished.len();
+            Self::write_rounds(&provider, &libmdbx, finished).await?;
+            tracing::info!(block = end, rounds = written, "stored express lane rounds");
+        }
+
+        written += rounds.len();
+        Self::write_rounds(&provider, &libmdbx, rounds).await?;
+        tracing::info!(rounds = written, "finished storing express lane rounds");
+
+        Ok(())
+    }
+
+    async fn write_rounds<T, DB>(
+        provider: &ExpressLaneAuctionProvider<T>,
+        db: &DB,
+        rounds: BTreeMap<u64, ExpressLaneRound>,
+    ) -> eyre::Result<()>
+    where
+        T: TracingProvider,
+        DB: DBWriter,
+    {
+        if rounds.is_empty() {
+            return Ok(())
+        }
+
+        let mut res = Vec::with_capacity(rounds.len());
+        for (_, mut round) in rounds {
+            provider.fill_round(&mut round).await;
+            res.push(round);
+        }
+
+        db.write_express_lane_rounds(res).await
+    }
+}
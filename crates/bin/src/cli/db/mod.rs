@@ -15,6 +15,8 @@ mod discovery_logs;
 #[cfg(feature = "local-clickhouse")]
 mod ensure_test_traces;
 mod export;
+mod express_lane_report;
+mod express_lane_rounds;
 mod init;
 mod pendle_pools;
 mod table_stats;
@@ -44,6 +46,14 @@ pub enum DatabaseCommands {
     /// --feature local-clickhouse)
     #[command(name = "generate-traces")]
     TraceRange(trace_range::TraceArgs),
+    /// Builds the express lane auction history from the auction contract's
+    /// logs and stores it in libmdbx
+    #[command(name = "express-lane-rounds")]
+    ExpressLaneRounds(express_lane_rounds::ExpressLaneRoundsArgs),
+    /// Rolls up the timeboosted mev of each express lane round into a
+    /// profitability report for its controller
+    #[command(name = "express-lane-report")]
+    ExpressLaneReport(express_lane_report::ExpressLaneReportArgs),
     /// Fetches Cex data from the Sorella DB
     #[command(name = "cex-query")]
     CexData(cex_data::CexDB),
@@ -84,7 +94,7 @@ pub enum DatabaseCommands {
     #[command(name = "run-discovery")]
     Discovery(discovery::DiscoveryFill),
     /// Only runs discovery and inserts discovered protocols into clickhouse
-    #[cfg(all(feature = "local-clickhouse", feature = "arbitrum"))]
+    #[cfg(feature = "local-clickhouse")]
     #[command(name = "run-discovery-log")]
     DiscoveryLogs(discovery_logs::DiscoveryLogsFill),
     /// Insert Pendle V2 SY pools into ClickHouse
@@ -98,6 +108,8 @@ impl Database {
             DatabaseCommands::DbInserts(cmd) => cmd.execute(brontes_db_path).await,
             DatabaseCommands::DbQuery(cmd) => cmd.execute(brontes_db_path).await,
             DatabaseCommands::TraceRange(cmd) => cmd.execute(brontes_db_path, ctx).await,
+            DatabaseCommands::ExpressLaneRounds(cmd) => cmd.execute(brontes_db_path, ctx).await,
+            DatabaseCommands::ExpressLaneReport(cmd) => cmd.execute(brontes_db_path, ctx).await,
             DatabaseCommands::Init(cmd) => cmd.execute(brontes_db_path, ctx).await,
             DatabaseCommands::DbClear(cmd) => cmd.execute(brontes_db_path).await,
             DatabaseCommands::UploadSnapshot(cmd) => cmd.execute(brontes_db_path, ctx).await,
@@ -113,9 +125,9 @@ impl Database {
             DatabaseCommands::TestTracesInit(cmd) => cmd.execute(brontes_db_path, ctx).await,
             #[cfg(feature = "local-clickhouse")]
             DatabaseCommands::TraceAtTip(cmd) => cmd.execute(brontes_db_path, ctx).await,
-            #[cfg(all(feature = "local-clickhouse", feature = "arbitrum"))]
+            #[cfg(feature = "local-clickhouse")]
             DatabaseCommands::DiscoveryLogs(cmd) => cmd.execute(brontes_db_path, ctx).await,
-            #[cfg(all(feature = "local-clickhouse", feature = "arbitrum"))]
+            #[cfg(feature = "local-clickhouse")]
             DatabaseCommands::PendlePools(cmd) => cmd.execute().await,
         }
     }
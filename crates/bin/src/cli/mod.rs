@@ -1,3 +1,9 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use brontes_types::chain::SupportedChain;
 use clap::{Parser, Subcommand};
 
 mod db;
@@ -20,6 +26,10 @@ pub struct Args {
     /// path to the brontes libmdbx db
     #[arg(long = "brontes-db-path", global = true)]
     pub brontes_db_path: Option<String>,
+    /// The chain to run against. Each chain keeps its own libmdbx database in
+    /// a sub directory of the brontes db path
+    #[arg(long, value_enum, default_value_t = SupportedChain::default(), global = true)]
+    pub chain:           SupportedChain,
     /// The verbosity level of the logs
     #[clap(flatten)]
     pub verbosity:       Verbosity,
@@ -29,6 +39,40 @@ pub struct Args {
     pub skip_prometheus: bool,
 }
 
+impl Args {
+    /// The libmdbx db of the selected chain, see [`chain_db_path`]
+    pub fn chain_db_path(&self) -> PathBuf {
+        let root = self
+            .brontes_db_path
+            .clone()
+            .unwrap_or_else(|| env::var("BRONTES_DB_PATH").expect("No BRONTES_DB_PATH in .env"));
+
+        chain_db_path(Path::new(&root), self.chain)
+    }
+}
+
+/// Data file libmdbx creates inside the db directory
+const LIBMDBX_DATA_FILE: &str = "mdbx.dat";
+
+/// Each chain keeps its db in a sub directory of the root. Databases created
+/// before that only ever held arbitrum data and live in the root itself, so
+/// for arbitrum they are kept in use until they get moved into the
+/// `arbitrum` sub directory.
+pub fn chain_db_path(root: &Path, chain: SupportedChain) -> PathBuf {
+    let path = root.join(chain.to_string());
+    if chain == SupportedChain::Arbitrum && !path.exists() && root.join(LIBMDBX_DATA_FILE).exists()
+    {
+        tracing::warn!(
+            root = %root.display(),
+            "found a libmdbx db from before the per chain layout, move it into {} to silence this",
+            path.display()
+        );
+        return root.to_path_buf()
+    }
+
+    path
+}
+
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Subcommand)]
 pub enum Commands {
@@ -39,3 +83,72 @@ pub enum Commands {
     #[command(name = "db")]
     Database(db::Database),
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_express_lane_rounds_args_dont_clash_with_chain() {
+        let args = Args::try_parse_from([
+            "brontes",
+            "--chain",
+            "arbitrum",
+            "db",
+            "express-lane-rounds",
+            "--end-block",
+            "100",
+            "--chain-entry",
+            "arbitrum-sepolia",
+        ])
+        .unwrap();
+
+        assert_eq!(args.chain, SupportedChain::Arbitrum);
+        let Commands::Database(db::Database {
+            command: db::DatabaseCommands::ExpressLaneRounds(rounds),
+        }) = args.command
+        else {
+            panic!("expected the express lane rounds command")
+        };
+        assert_eq!(rounds.chain_entry.as_deref(), Some("arbitrum-sepolia"));
+        assert_eq!(rounds.end_block, 100);
+    }
+
+    #[test]
+    fn test_chain_defaults_to_arbitrum() {
+        let args =
+            Args::try_parse_from(["brontes", "db", "express-lane-rounds", "-e", "1"]).unwrap();
+        assert_eq!(args.chain, SupportedChain::Arbitrum);
+
+        let args = Args::try_parse_from([
+            "brontes",
+            "db",
+            "express-lane-rounds",
+            "-e",
+            "1",
+            "--chain",
+            "mainnet",
+        ])
+        .unwrap();
+        assert_eq!(args.chain, SupportedChain::Mainnet);
+    }
+
+    #[test]
+    fn test_chain_db_path_falls_back_to_old_layout() {
+        let root = env::temp_dir().join(format!("brontes-chain-db-path-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+
+        assert_eq!(chain_db_path(&root, SupportedChain::Arbitrum), root.join("arbitrum"));
+
+        fs::write(root.join(LIBMDBX_DATA_FILE), b"").unwrap();
+        assert_eq!(chain_db_path(&root, SupportedChain::Arbitrum), root);
+        assert_eq!(chain_db_path(&root, SupportedChain::Mainnet), root.join("mainnet"));
+
+        fs::create_dir_all(root.join("arbitrum")).unwrap();
+        assert_eq!(chain_db_path(&root, SupportedChain::Arbitrum), root.join("arbitrum"));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -4,9 +4,16 @@ use brontes_core::decoding::Parser as DParser;
 use brontes_database::clickhouse::cex_config::CexDownloadConfig;
 use brontes_inspect::Inspectors;
 use brontes_metrics::ParserMetricsListener;
+use brontes_timeboost::{
+    bids::ExpressLaneBidStore,
+    config::{ExpressLaneAuctionConfig, CHAIN_CONFIG_FILE},
+};
 use brontes_types::{
-    constants::USDT_ADDRESS_STRING,
-    db::cex::{trades::CexDexTradeConfig, CexExchange},
+    chain::chain_config,
+    db::{
+        cex::{trades::CexDexTradeConfig, CexExchange},
+        traits::DBWriter,
+    },
     db_write_trigger::{backup_server_heartbeat, start_hr_monitor, HeartRateMonitor},
     init_thread_pools, UnboundedYapperReceiver,
 };
@@ -57,9 +64,10 @@ pub struct RunArgs {
     /// Optional minimum batch size
     #[arg(long, default_value = "500")]
     pub min_batch_size:       u64,
-    /// Optional quote asset, if omitted it will default to USDT
-    #[arg(long, short, default_value = USDT_ADDRESS_STRING)]
-    pub quote_asset:          String,
+    /// Optional quote asset, if omitted it will default to USDT on the
+    /// selected chain
+    #[arg(long, short)]
+    pub quote_asset:          Option<String>,
     /// Inspectors to run. If omitted it defaults to running all inspectors
     #[arg(long, short, value_delimiter = ',')]
     pub inspectors:           Option<Vec<Inspectors>>,
@@ -110,6 +118,20 @@ pub struct RunArgs {
     #[arg(long)]
     pub fallback_server:      Option<String>,
 
+    /// Optional path to a dump (.csv or .json) of the express lane
+    /// auctioneer's bid feed. Attaches the full sealed-bid history, including
+    /// the losing bids, to every auction round.
+    #[arg(long)]
+    pub express_lane_bids:    Option<String>,
+    /// Entry of the chain config to use, defaults to the entry of the chain
+    /// selected with `--chain`
+    #[arg(long)]
+    pub chain_entry:          Option<String>,
+    /// Path to the chain config holding the per chain express lane auction
+    /// deployment
+    #[arg(long, default_value = CHAIN_CONFIG_FILE)]
+    pub chain_config:         String,
+
     // Rate limit (req/s) for RPC provider
     #[arg(long)]
     pub rate_limit: Option<u32>,
@@ -140,7 +162,11 @@ impl RunArgs {
         // Fetch required environment variables.
         let reth_db_path = get_env_vars()?;
         tracing::info!(target: "brontes", "got env vars");
-        let quote_asset = self.quote_asset.parse()?;
+        let quote_asset = self
+            .quote_asset
+            .map(|quote_asset| quote_asset.parse::<Address>())
+            .transpose()?
+            .unwrap_or_else(|| chain_config().usdt_address());
         tracing::info!(target: "brontes", "parsed quote asset");
         let task_executor = ctx.task_executor;
 
@@ -211,6 +237,25 @@ impl RunArgs {
             Arc::new(RateLimiter::direct(Quota::per_second(NonZeroU32::new(rate_limit).unwrap())))
         });
 
+        let express_lane_auction = if chain_config().has_sequencer() {
+            let chain_entry = self
+                .chain_entry
+                .as_deref()
+                .unwrap_or(chain_config().chain_config_entry());
+            ExpressLaneAuctionConfig::load(&self.chain_config, chain_entry)?
+        } else {
+            ExpressLaneAuctionConfig::disabled()
+        };
+        let express_lane_bids = self
+            .express_lane_bids
+            .as_ref()
+            .map(|path| ExpressLaneBidStore::load(path, express_lane_auction.address))
+            .transpose()?;
+        // persist the bids so that later runs have them without the dump
+        if let Some(bids) = express_lane_bids.as_ref() {
+            libmdbx.write_express_lane_bids(bids.round_bids()).await?;
+        }
+
         let tracer =
             get_tracing_provider(Path::new(&reth_db_path), max_tasks, task_executor.clone(), limiter);
         let parser = static_object(DParser::new(metrics_tx, libmdbx, tracer.clone()).await);
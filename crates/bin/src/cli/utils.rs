@@ -21,6 +21,7 @@ use brontes_metrics::inspectors::{OutlierMetrics, ProfitMetrics};
 #[cfg(feature = "local-clickhouse")]
 use brontes_types::UnboundedYapperReceiver;
 use brontes_types::{
+    chain::chain_config,
     db::{
         cex::{trades::CexDexTradeConfig, CexExchange},
         traits::LibmdbxReader,
@@ -172,9 +173,21 @@ pub fn init_inspectors<DB: LibmdbxReader>(
     let profit_metrics = metrics.then(ProfitMetrics::new);
     let metrics = metrics.then(OutlierMetrics::new);
 
+    let requested = inspectors.is_some();
     for inspector in inspectors
         .map(|i| i.into_iter())
         .unwrap_or_else(|| Inspectors::iter().collect_vec().into_iter())
+        .filter(|inspector| {
+            let supported = chain_config().supports_mev_type(inspector.mev_type());
+            if !supported && requested {
+                tracing::warn!(
+                    %inspector,
+                    chain = %chain_config().chain(),
+                    "inspector doesn't apply to the chain, skipping it"
+                );
+            }
+            supported
+        })
     {
         res.push(inspector.init_mev_inspector(
             quote_token,
@@ -8,7 +8,7 @@ use std::{
 use brontes_core::decoding::TracingProvider;
 use brontes_pricing::BrontesBatchPricer;
 use brontes_types::{
-    constants::START_OF_CHAINBOUND_MEMPOOL_DATA,
+    chain::chain_config,
     db::{dex::DexQuotes, metadata::Metadata},
     normalized_actions::Action,
     tree::BlockTree,
@@ -121,7 +121,7 @@ impl<T: TracingProvider> WaitingForPricerFuture<T> {
             true
         });
 
-        if tree.header.number >= START_OF_CHAINBOUND_MEMPOOL_DATA {
+        if tree.header.number >= chain_config().mempool_data_start_block() {
             tree.label_private_txes(&meta);
         }
 
@@ -3,7 +3,7 @@ use std::{
     pin::Pin,
     sync::{
         atomic::{AtomicBool, Ordering},
-        Arc,
+        Arc, Once,
     },
     task::Poll,
     time::Duration,
@@ -11,15 +11,15 @@ use std::{
 
 use alloy_primitives::Address;
 use brontes_database::clickhouse::ClickhouseHandle;
-use brontes_timeboost::auction::ExpressLaneAuctionProvider;
 use brontes_types::{
-    constants::BLOCK_TIME_MILLIS,
+    chain::chain_config,
     db::{
         cex::trades::{window_loader::CexWindow, CexTradeMap},
         dex::DexQuotes,
         metadata::Metadata,
         traits::{DBWriter, LibmdbxReader},
     },
+    express_lane::ExpressLaneMetaData,
     normalized_actions::Action,
     traits::TracingProvider,
     BlockData, BlockTree,
@@ -31,21 +31,23 @@ use tracing::error;
 
 use super::dex_pricing::WaitingForPricerFuture;
 
+/// Warns once per process when a block has no express lane round in libmdbx
+static MISSING_EXPRESS_LANE_ROUNDS: Once = Once::new();
+
 pub type ClickhouseMetadataFuture =
     FuturesOrdered<Pin<Box<dyn Future<Output = (u64, BlockTree<Action>, Metadata)> + Send>>>;
 
 /// deals with all cases on how we get and finalize our metadata
 pub struct MetadataLoader<T: TracingProvider, CH: ClickhouseHandle> {
-    clickhouse:                    Option<&'static CH>,
-    dex_pricer_stream:             WaitingForPricerFuture<T>,
-    clickhouse_futures:            ClickhouseMetadataFuture,
-    result_buf:                    VecDeque<BlockData>,
-    needs_more_data:               Arc<AtomicBool>,
-    cex_window_data:               CexWindow,
-    always_generate_price:         bool,
-    force_no_dex_pricing:          bool,
-    max_pending:                   usize,
-    express_lane_auction_provider: ExpressLaneAuctionProvider<T>,
+    clickhouse:            Option<&'static CH>,
+    dex_pricer_stream:     WaitingForPricerFuture<T>,
+    clickhouse_futures:    ClickhouseMetadataFuture,
+    result_buf:            VecDeque<BlockData>,
+    needs_more_data:       Arc<AtomicBool>,
+    cex_window_data:       CexWindow,
+    always_generate_price: bool,
+    force_no_dex_pricing:  bool,
+    max_pending:           usize,
 }
 
 impl<T: TracingProvider, CH: ClickhouseHandle> MetadataLoader<T, CH> {
@@ -57,7 +59,6 @@ impl<T: TracingProvider, CH: ClickhouseHandle> MetadataLoader<T, CH> {
         needs_more_data: Arc<AtomicBool>,
         #[allow(unused)] cex_window_sec: usize,
         max_pending: usize,
-        express_lane_auction_provider: ExpressLaneAuctionProvider<T>,
     ) -> Self {
         Self {
             cex_window_data: CexWindow::new(cex_window_sec),
@@ -69,7 +70,6 @@ impl<T: TracingProvider, CH: ClickhouseHandle> MetadataLoader<T, CH> {
             always_generate_price,
             force_no_dex_pricing,
             max_pending,
-            express_lane_auction_provider,
         }
     }
 
@@ -149,7 +149,7 @@ impl<T: TracingProvider, CH: ClickhouseHandle> MetadataLoader<T, CH> {
             let window = self.cex_window_data.get_window_lookahead();
             // given every download is -6 + 6 around the block
             // we calculate the offset from the current block that we need
-            let offsets = (window * 1000 / BLOCK_TIME_MILLIS) as u64;
+            let offsets = (window * 1000 / chain_config().block_time_millis()) as u64;
             let mut trades = Vec::new();
             for block in block - offsets..=block + offsets {
                 if let Ok(res) = libmdbx.get_cex_trades(block) {
@@ -196,6 +196,8 @@ impl<T: TracingProvider, CH: ClickhouseHandle> MetadataLoader<T, CH> {
             .expect("failed to fetch builder info table in libmdbx");
 
         meta.cex_trades = self.load_cex_trades(libmdbx, block);
+        meta.express_lane_auction =
+            Self::load_express_lane_meta_data(libmdbx, tree.header.timestamp);
 
         tracing::debug!(?block, "waiting for dex price");
 
@@ -228,6 +230,8 @@ impl<T: TracingProvider, CH: ClickhouseHandle> MetadataLoader<T, CH> {
 
         let mut meta = meta.into_full_metadata(DexQuotes(vec![]));
         meta.cex_trades = self.load_cex_trades(libmdbx, block);
+        meta.express_lane_auction =
+            Self::load_express_lane_meta_data(libmdbx, tree.header.timestamp);
 
         self.result_buf
             .push_back(BlockData { metadata: meta.into(), tree: tree.into() });
@@ -254,6 +258,8 @@ impl<T: TracingProvider, CH: ClickhouseHandle> MetadataLoader<T, CH> {
             .expect("failed to fetch builder info table in libmdbx");
 
         meta.cex_trades = self.load_cex_trades(libmdbx, block);
+        meta.express_lane_auction =
+            Self::load_express_lane_meta_data(libmdbx, tree.header.timestamp);
 
         tracing::debug!(?block, "caching result buf");
         self.result_buf
@@ -273,8 +279,7 @@ impl<T: TracingProvider, CH: ClickhouseHandle> MetadataLoader<T, CH> {
         let window = self.cex_window_data.get_window_lookahead();
         // given every download is -6 + 6 around the block
         // we calculate the offset from the current block that we need
-        let offsets = (window * 1000 / BLOCK_TIME_MILLIS) as u64;
-        let express_lane_auction_provider = self.express_lane_auction_provider.clone();
+        let offsets = (window * 1000 / chain_config().block_time_millis()) as u64;
         let future = Box::pin(async move {
             let builder_info = libmdbx
                 .try_fetch_builder_info(tree.header.beneficiary)
@@ -339,15 +344,53 @@ impl<T: TracingProvider, CH: ClickhouseHandle> MetadataLoader<T, CH> {
 
             meta.cex_trades = Some(trades);
             meta.builder_info = builder_info;
-            // TODO(jinmel): express lane auction metadata is only available when using clickhouse. 
-            meta.express_lane_auction = express_lane_auction_provider.get_express_lane_meta_data(block).await.inspect_err(|e| {
-                tracing::warn!(?e, "failed to load express lane auction meta data");
-            }).ok();
+            meta.express_lane_auction =
+                Self::load_express_lane_meta_data(libmdbx, tree.header.timestamp);
             (block, tree, meta)
         });
 
         self.clickhouse_futures.push_back(future);
     }
+
+    /// loads the express lane round active at the block timestamp from libmdbx,
+    /// along with its sealed bids if the round was stored without them
+    fn load_express_lane_meta_data<DB: LibmdbxReader>(
+        libmdbx: &DB,
+        timestamp: u64,
+    ) -> Option<ExpressLaneMetaData> {
+        let round = libmdbx
+            .try_fetch_express_lane_round_at(timestamp)
+            .inspect_err(|e| tracing::warn!(?e, "failed to load express lane round from libmdbx"))
+            .ok()
+            .flatten();
+        let Some(mut round) = round else {
+            if chain_config().has_sequencer() {
+                MISSING_EXPRESS_LANE_ROUNDS.call_once(|| {
+                    tracing::warn!(
+                        timestamp,
+                        "no express lane round stored for the block. Blocks before the auction \
+                         went live have none, otherwise fill the table with `brontes db \
+                         express-lane-rounds`"
+                    )
+                });
+            }
+            return None
+        };
+
+        if round.bids.is_empty() {
+            round.bids = libmdbx
+                .try_fetch_express_lane_bids(round.round)
+                .inspect_err(|e| {
+                    tracing::warn!(?e, "failed to load express lane bids from libmdbx")
+                })
+                .ok()
+                .flatten()
+                .map(|bids| bids.bids)
+                .unwrap_or_default();
+        }
+
+        Some(round.into_meta_data(timestamp))
+    }
 }
 
 impl<T: TracingProvider, CH: ClickhouseHandle> Stream for MetadataLoader<T, CH> {
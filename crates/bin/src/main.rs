@@ -1,6 +1,7 @@
-use std::{env, error::Error, time::Duration};
+use std::{error::Error, time::Duration};
 
 use brontes_tracing::BoxedLayer;
+use brontes_types::chain::init_chain_config;
 use log_report_layer::TelegramConfig;
 use tracing::Level;
 use tracing_subscriber::{Layer, Registry};
@@ -50,12 +51,12 @@ fn main() -> eyre::Result<()> {
 
 fn run() -> eyre::Result<()> {
     let opt = Args::parse();
-    let brontes_db_path = opt
-        .brontes_db_path
-        .unwrap_or(env::var("BRONTES_DB_PATH").expect("No BRONTES_DB_PATH in .env"));
+    init_chain_config(opt.chain)?;
 
     init_tracing(opt.verbosity.directive());
 
+    let brontes_db_path = opt.chain_db_path().to_string_lossy().into_owned();
+
     let metrics_port = if opt.skip_prometheus { None } else { Some(opt.metrics_port) };
 
     match opt.command {
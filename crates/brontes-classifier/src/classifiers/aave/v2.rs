@@ -1,9 +1,12 @@
 use brontes_macros::action_impl;
 use brontes_types::{
-    normalized_actions::{NormalizedFlashLoan, NormalizedLiquidation},
+    normalized_actions::{
+        NormalizedFlashLoan, NormalizedLiquidation, NormalizedLoan, NormalizedRepayment,
+        NormalizedSupply, NormalizedWithdraw,
+    },
     structured_trace::CallInfo,
     utils::ToScaledRational,
-    Protocol,
+    FastHashMap, Protocol,
 };
 use malachite::{num::basic::traits::Zero, Rational};
 
@@ -74,3 +77,115 @@ action_impl!(
 
     }
 );
+
+action_impl!(
+    Protocol::AaveV2,
+    crate::AaveV2::depositCall,
+    Supply,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: depositCall,
+    db_tx: &DB| {
+        let token = db_tx.try_fetch_token_info(call_data.asset)?;
+        let amount = call_data.amount.to_scaled_rational(token.decimals);
+
+        Ok(NormalizedSupply {
+            protocol: Protocol::AaveV2,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            supplier: info.msg_sender,
+            on_behalf_of: call_data.onBehalfOf,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV2,
+    crate::AaveV2::withdrawCall,
+    Withdraw,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: withdrawCall,
+    return_data: withdrawReturn,
+    db_tx: &DB| {
+        let token = db_tx.try_fetch_token_info(call_data.asset)?;
+        // the requested amount can be `type(uint256).max` to withdraw everything
+        let amount = return_data._0.to_scaled_rational(token.decimals);
+
+        Ok(NormalizedWithdraw {
+            protocol: Protocol::AaveV2,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            owner: info.msg_sender,
+            recipient: call_data.to,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV2,
+    crate::AaveV2::borrowCall,
+    Loan,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: borrowCall,
+    db_tx: &DB| {
+        let loaned_token = db_tx.try_fetch_token_info(call_data.asset)?;
+        let loan_amount = call_data.amount.to_scaled_rational(loaned_token.decimals);
+
+        Ok(NormalizedLoan {
+            protocol: Protocol::AaveV2,
+            trace_index: info.trace_idx,
+            lender: info.target_address,
+            borrower: call_data.onBehalfOf,
+            recipient: info.msg_sender,
+            loaned_token,
+            loan_amount,
+            collateral: FastHashMap::default(),
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV2,
+    crate::AaveV2::repayCall,
+    Repayment,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: repayCall,
+    return_data: repayReturn,
+    db_tx: &DB| {
+        let repayed_token = db_tx.try_fetch_token_info(call_data.asset)?;
+        // the requested amount can be `type(uint256).max` to repay the whole debt
+        let repayment_amount = return_data._0.to_scaled_rational(repayed_token.decimals);
+
+        Ok(NormalizedRepayment {
+            protocol: Protocol::AaveV2,
+            trace_index: info.trace_idx,
+            lender: info.target_address,
+            borrower: call_data.onBehalfOf,
+            payer: info.msg_sender,
+            repayed_token,
+            repayment_amount,
+            collateral: FastHashMap::default(),
+            msg_value: info.msg_value,
+        })
+    }
+);
@@ -1,9 +1,12 @@
 use brontes_macros::action_impl;
 use brontes_types::{
-    normalized_actions::{NormalizedFlashLoan, NormalizedLiquidation},
+    normalized_actions::{
+        NormalizedFlashLoan, NormalizedLiquidation, NormalizedLoan, NormalizedRepayment,
+        NormalizedSupply, NormalizedWithdraw,
+    },
     structured_trace::CallInfo,
     utils::ToScaledRational,
-    Protocol,
+    FastHashMap, Protocol,
 };
 use malachite::{num::basic::traits::Zero, Rational};
 
@@ -113,6 +116,144 @@ action_impl!(
     }
 );
 
+action_impl!(
+    Protocol::AaveV3,
+    crate::AaveV3::supplyCall,
+    Supply,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: supplyCall,
+    db_tx: &DB| {
+        let token = db_tx.try_fetch_token_info(call_data.asset)?;
+        let amount = call_data.amount.to_scaled_rational(token.decimals);
+
+        Ok(NormalizedSupply {
+            protocol: Protocol::AaveV3,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            supplier: info.msg_sender,
+            on_behalf_of: call_data.onBehalfOf,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV3,
+    crate::AaveV3::depositCall,
+    Supply,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: depositCall,
+    db_tx: &DB| {
+        let token = db_tx.try_fetch_token_info(call_data.asset)?;
+        let amount = call_data.amount.to_scaled_rational(token.decimals);
+
+        Ok(NormalizedSupply {
+            protocol: Protocol::AaveV3,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            supplier: info.msg_sender,
+            on_behalf_of: call_data.onBehalfOf,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV3,
+    crate::AaveV3::withdrawCall,
+    Withdraw,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: withdrawCall,
+    return_data: withdrawReturn,
+    db_tx: &DB| {
+        let token = db_tx.try_fetch_token_info(call_data.asset)?;
+        // the requested amount can be `type(uint256).max` to withdraw everything
+        let amount = return_data._0.to_scaled_rational(token.decimals);
+
+        Ok(NormalizedWithdraw {
+            protocol: Protocol::AaveV3,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            owner: info.msg_sender,
+            recipient: call_data.to,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV3,
+    crate::AaveV3::borrowCall,
+    Loan,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: borrowCall,
+    db_tx: &DB| {
+        let loaned_token = db_tx.try_fetch_token_info(call_data.asset)?;
+        let loan_amount = call_data.amount.to_scaled_rational(loaned_token.decimals);
+
+        Ok(NormalizedLoan {
+            protocol: Protocol::AaveV3,
+            trace_index: info.trace_idx,
+            lender: info.target_address,
+            borrower: call_data.onBehalfOf,
+            recipient: info.msg_sender,
+            loaned_token,
+            loan_amount,
+            collateral: FastHashMap::default(),
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV3,
+    crate::AaveV3::repayCall,
+    Repayment,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: repayCall,
+    return_data: repayReturn,
+    db_tx: &DB| {
+        let repayed_token = db_tx.try_fetch_token_info(call_data.asset)?;
+        // the requested amount can be `type(uint256).max` to repay the whole debt
+        let repayment_amount = return_data._0.to_scaled_rational(repayed_token.decimals);
+
+        Ok(NormalizedRepayment {
+            protocol: Protocol::AaveV3,
+            trace_index: info.trace_idx,
+            lender: info.target_address,
+            borrower: call_data.onBehalfOf,
+            payer: info.msg_sender,
+            repayed_token,
+            repayment_amount,
+            collateral: FastHashMap::default(),
+            msg_value: info.msg_value,
+        })
+    }
+);
+
 #[cfg(test)]
 mod tests {
     use alloy_primitives::{hex, Address, B256, U256};
@@ -0,0 +1,319 @@
+use alloy_primitives::B256;
+use alloy_sol_types::{SolCall, SolEvent};
+use brontes_types::{
+    constants::arbitrum::{ARB_RETRYABLE_TX_ADDRESS, ARB_SYS_ADDRESS},
+    normalized_actions::{
+        Action, NormalizedL1Deposit, NormalizedL2ToL1Tx, NormalizedRetryTx,
+        NormalizedRetryableRedeem, NormalizedRetryableTicket,
+    },
+    structured_trace::{TraceActions, TransactionTraceWithLogs},
+};
+
+alloy_sol_macro::sol!(
+    function submitRetryable(
+        bytes32 requestId,
+        uint256 l1BaseFee,
+        uint256 deposit,
+        uint256 callvalue,
+        uint256 gasFeeCap,
+        uint64 gasLimit,
+        uint256 maxSubmissionFee,
+        address feeRefundAddress,
+        address beneficiary,
+        address retryTo,
+        bytes retryData
+    );
+    function redeem(bytes32 ticketId) returns (bytes32 retryTxHash);
+    function withdrawEth(address destination) returns (uint256 position);
+    function sendTxToL1(address destination, bytes data) returns (uint256 position);
+
+    event RedeemScheduled(
+        bytes32 indexed ticketId,
+        bytes32 indexed retryTxHash,
+        uint64 indexed sequenceNum,
+        uint64 donatedGas,
+        address gasDonor,
+        uint256 maxRefund,
+        uint256 submissionFeeRefund
+    );
+);
+
+/// The root call of a deposit transaction, eth minted by ArbOS to the
+/// recipient
+pub fn classify_l1_deposit(trace: &TransactionTraceWithLogs, trace_index: u64) -> Action {
+    Action::L1Deposit(NormalizedL1Deposit {
+        trace_index,
+        from: trace.get_from_addr(),
+        to: trace.get_to_address(),
+        value: trace.get_msg_value(),
+    })
+}
+
+/// The root call of a retry transaction. It is classified like any other call,
+/// this marks the escrowed call value it spends.
+pub fn classify_retry_tx(trace: &TransactionTraceWithLogs, trace_index: u64) -> Action {
+    Action::RetryTx(NormalizedRetryTx {
+        trace_index,
+        from: trace.get_from_addr(),
+        to: trace.get_to_address(),
+        value: trace.get_msg_value(),
+    })
+}
+
+/// The root call of a submit retryable transaction. The ticket id is the hash
+/// of the transaction that submitted it.
+pub fn try_classify_submit_retryable(
+    trace: &TransactionTraceWithLogs,
+    trace_index: u64,
+    tx_hash: B256,
+) -> Option<Action> {
+    if trace.get_to_address() != ARB_RETRYABLE_TX_ADDRESS {
+        return None
+    }
+    let call = submitRetryableCall::abi_decode(&trace.get_calldata(), false).ok()?;
+
+    let auto_redeemed = trace
+        .logs
+        .iter()
+        .any(|log| log.topics().first() == Some(&RedeemScheduled::SIGNATURE_HASH));
+
+    Some(Action::RetryableTicket(NormalizedRetryableTicket {
+        trace_index,
+        ticket_id: tx_hash,
+        from: trace.get_from_addr(),
+        to: call.retryTo,
+        deposit: call.deposit,
+        call_value: call.callvalue,
+        max_submission_fee: call.maxSubmissionFee,
+        fee_refund_address: call.feeRefundAddress,
+        beneficiary: call.beneficiary,
+        auto_redeemed,
+    }))
+}
+
+/// Calls into the `ArbSys` and `ArbRetryableTx` precompiles that redeem a
+/// ticket or send a message to L1
+pub fn try_classify_arbos_call(
+    trace: &TransactionTraceWithLogs,
+    trace_index: u64,
+) -> Option<Action> {
+    let to = trace.get_to_address();
+    let calldata = trace.get_calldata();
+
+    if to == ARB_RETRYABLE_TX_ADDRESS {
+        let call = redeemCall::abi_decode(&calldata, false).ok()?;
+        let retry_tx_hash = redeemCall::abi_decode_returns(&trace.get_return_calldata(), false)
+            .map(|r| r.retryTxHash)
+            .unwrap_or_default();
+
+        return Some(Action::RetryableRedeem(NormalizedRetryableRedeem {
+            trace_index,
+            from: trace.get_from_addr(),
+            ticket_id: call.ticketId,
+            retry_tx_hash,
+        }))
+    }
+
+    if to != ARB_SYS_ADDRESS {
+        return None
+    }
+
+    let return_data = trace.get_return_calldata();
+    let (destination, position) = if let Ok(call) = withdrawEthCall::abi_decode(&calldata, false) {
+        let position = withdrawEthCall::abi_decode_returns(&return_data, false)
+            .map(|r| r.position)
+            .unwrap_or_default();
+        (call.destination, position)
+    } else if let Ok(call) = sendTxToL1Call::abi_decode(&calldata, false) {
+        let position = sendTxToL1Call::abi_decode_returns(&return_data, false)
+            .map(|r| r.position)
+            .unwrap_or_default();
+        (call.destination, position)
+    } else {
+        return None
+    };
+
+    Some(Action::L2ToL1Tx(NormalizedL2ToL1Tx {
+        trace_index,
+        from: trace.get_from_addr(),
+        destination,
+        value: trace.get_msg_value(),
+        position,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Address, Bytes, Log, U256};
+    use brontes_types::{
+        constants::ETH_ADDRESS, normalized_actions::accounting::TokenAccounting, FastHashMap,
+        ToScaledRational,
+    };
+    use reth_rpc_types::trace::parity::{
+        Action as TraceAction, CallAction, CallOutput, CallType, TraceOutput, TransactionTrace,
+    };
+
+    use super::*;
+
+    fn call_trace(
+        from: Address,
+        to: Address,
+        value: U256,
+        input: Vec<u8>,
+        output: Vec<u8>,
+        logs: Vec<Log>,
+    ) -> TransactionTraceWithLogs {
+        TransactionTraceWithLogs {
+            trace: TransactionTrace {
+                action:        TraceAction::Call(CallAction {
+                    from,
+                    to,
+                    value,
+                    gas: Default::default(),
+                    input: Bytes::from(input),
+                    call_type: CallType::Call,
+                }),
+                error:         None,
+                result:        Some(TraceOutput::Call(CallOutput {
+                    gas_used: Default::default(),
+                    output:   Bytes::from(output),
+                })),
+                trace_address: vec![],
+                subtraces:     0,
+            },
+            logs,
+            msg_sender: from,
+            trace_idx: 0,
+            decoded_data: None,
+        }
+    }
+
+    fn submit_retryable(deposit: u64, call_value: u64, max_submission_fee: u64) -> Vec<u8> {
+        submitRetryableCall {
+            requestId:        B256::repeat_byte(1),
+            l1BaseFee:        U256::ZERO,
+            deposit:          U256::from(deposit),
+            callvalue:        U256::from(call_value),
+            gasFeeCap:        U256::ZERO,
+            gasLimit:         0,
+            maxSubmissionFee: U256::from(max_submission_fee),
+            feeRefundAddress: Address::repeat_byte(3),
+            beneficiary:      Address::repeat_byte(4),
+            retryTo:          Address::repeat_byte(5),
+            retryData:        Bytes::new(),
+        }
+        .abi_encode()
+    }
+
+    #[test]
+    fn test_submit_retryable_refunds_the_excess_deposit() {
+        let redeem_scheduled = Log::new_unchecked(
+            ARB_RETRYABLE_TX_ADDRESS,
+            vec![RedeemScheduled::SIGNATURE_HASH],
+            Bytes::new(),
+        );
+        let trace = call_trace(
+            Address::repeat_byte(2),
+            ARB_RETRYABLE_TX_ADDRESS,
+            U256::ZERO,
+            submit_retryable(1_000, 600, 100),
+            vec![],
+            vec![redeem_scheduled],
+        );
+
+        let tx_hash = B256::repeat_byte(9);
+        let Some(Action::RetryableTicket(ticket)) =
+            try_classify_submit_retryable(&trace, 0, tx_hash)
+        else {
+            panic!("expected a retryable ticket")
+        };
+        assert_eq!(ticket.ticket_id, tx_hash);
+        assert_eq!(ticket.to, Address::repeat_byte(5));
+        assert!(ticket.auto_redeemed);
+
+        let mut deltas = FastHashMap::default();
+        ticket.apply_token_deltas(&mut deltas);
+        assert_eq!(deltas.len(), 1);
+        assert_eq!(
+            deltas[&Address::repeat_byte(3)][&ETH_ADDRESS],
+            U256::from(300).to_scaled_rational(18)
+        );
+    }
+
+    #[test]
+    fn test_submit_retryable_to_other_contract_is_skipped() {
+        let trace = call_trace(
+            Address::repeat_byte(2),
+            Address::repeat_byte(7),
+            U256::ZERO,
+            submit_retryable(1_000, 600, 100),
+            vec![],
+            vec![],
+        );
+
+        assert!(try_classify_submit_retryable(&trace, 0, B256::ZERO).is_none());
+    }
+
+    #[test]
+    fn test_retry_tx_credits_the_escrowed_value() {
+        let trace = call_trace(
+            Address::repeat_byte(2),
+            Address::repeat_byte(5),
+            U256::from(600),
+            vec![],
+            vec![],
+            vec![],
+        );
+
+        let action = classify_retry_tx(&trace, 0);
+        assert!(action.is_retry_tx());
+        assert_eq!(action.get_to_address(), Address::repeat_byte(5));
+
+        let mut deltas = FastHashMap::default();
+        action.apply_token_deltas(&mut deltas);
+        assert_eq!(
+            deltas[&Address::repeat_byte(2)][&ETH_ADDRESS],
+            U256::from(600).to_scaled_rational(18)
+        );
+    }
+
+    #[test]
+    fn test_redeem_and_withdraw_eth() {
+        let ticket_id = B256::repeat_byte(6);
+        let retry_tx_hash = B256::repeat_byte(8);
+        let trace = call_trace(
+            Address::repeat_byte(2),
+            ARB_RETRYABLE_TX_ADDRESS,
+            U256::ZERO,
+            redeemCall { ticketId: ticket_id }.abi_encode(),
+            redeemCall::abi_encode_returns(&(retry_tx_hash,)),
+            vec![],
+        );
+        let Some(Action::RetryableRedeem(redeem)) = try_classify_arbos_call(&trace, 0) else {
+            panic!("expected a redeem")
+        };
+        assert_eq!(redeem.ticket_id, ticket_id);
+        assert_eq!(redeem.retry_tx_hash, retry_tx_hash);
+
+        let trace = call_trace(
+            Address::repeat_byte(2),
+            ARB_SYS_ADDRESS,
+            U256::from(500),
+            withdrawEthCall { destination: Address::repeat_byte(4) }.abi_encode(),
+            withdrawEthCall::abi_encode_returns(&(U256::from(12),)),
+            vec![],
+        );
+        let Some(Action::L2ToL1Tx(withdraw)) = try_classify_arbos_call(&trace, 0) else {
+            panic!("expected an l2 to l1 tx")
+        };
+        assert_eq!(withdraw.destination, Address::repeat_byte(4));
+        assert_eq!(withdraw.position, U256::from(12));
+
+        let mut deltas = FastHashMap::default();
+        withdraw.apply_token_deltas(&mut deltas);
+        assert_eq!(
+            deltas[&Address::repeat_byte(2)][&ETH_ADDRESS],
+            -U256::from(500).to_scaled_rational(18)
+        );
+    }
+}
@@ -210,7 +210,8 @@ action_impl!(
             trace_index: info.trace_idx,
             protocol: Protocol::BalancerV2,
             pool_address,
-            tokens: logs.tokens
+            tokens: logs.tokens,
+            uniswap_v4_pool_key: None,
         })
     }
 );
@@ -232,7 +233,7 @@ mod tests {
     use alloy_primitives::{hex, B256};
     use brontes_classifier::test_utils::ClassifierTestUtils;
     use brontes_types::{
-        constants::WETH_ADDRESS, db::token_info::TokenInfo, normalized_actions::Action,
+        constants::arbitrum::WETH_ADDRESS, db::token_info::TokenInfo, normalized_actions::Action,
         Protocol::BalancerV2, TreeSearchBuilder,
     };
 
@@ -0,0 +1,67 @@
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    normalized_actions::NormalizedBridge, structured_trace::CallInfo, ToScaledRational,
+};
+
+use super::local_chain_id;
+
+action_impl!(
+    Protocol::AcrossV3,
+    crate::AcrossSpokePool::depositV3Call,
+    Bridge,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: depositV3Call,
+    db_tx: &DB| {
+        let token = db_tx.try_fetch_token_info(call_data.inputToken)?;
+
+        Ok(NormalizedBridge {
+            protocol: Protocol::AcrossV3,
+            trace_index: info.trace_idx,
+            from: info.msg_sender,
+            recipient: call_data.recipient,
+            bridge: info.target_address,
+            relayer: call_data.exclusiveRelayer,
+            amount: call_data.inputAmount.to_scaled_rational(token.decimals),
+            token,
+            origin_chain: local_chain_id(),
+            destination_chain: call_data.destinationChainId.saturating_to(),
+            is_fill: false,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AcrossV3,
+    crate::AcrossSpokePool::fillV3RelayCall,
+    Bridge,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: fillV3RelayCall,
+    db_tx: &DB| {
+        let relay = call_data.relayData;
+        let token = db_tx.try_fetch_token_info(relay.outputToken)?;
+
+        // the relayer fronts the output amount and is repaid on `repaymentChainId`
+        Ok(NormalizedBridge {
+            protocol: Protocol::AcrossV3,
+            trace_index: info.trace_idx,
+            from: info.msg_sender,
+            recipient: relay.recipient,
+            bridge: info.target_address,
+            relayer: info.msg_sender,
+            amount: relay.outputAmount.to_scaled_rational(token.decimals),
+            token,
+            origin_chain: relay.originChainId.saturating_to(),
+            destination_chain: local_chain_id(),
+            is_fill: true,
+            msg_value: info.msg_value,
+        })
+    }
+);
@@ -0,0 +1,115 @@
+use alloy_primitives::Address;
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    normalized_actions::NormalizedBridge, structured_trace::CallInfo, ToScaledRational,
+};
+
+use super::local_chain_id;
+
+action_impl!(
+    Protocol::CCTP,
+    crate::CCTPTokenMessenger::depositForBurnCall,
+    Bridge,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: depositForBurnCall,
+    db_tx: &DB| {
+        let token = db_tx.try_fetch_token_info(call_data.burnToken)?;
+
+        Ok(NormalizedBridge {
+            protocol: Protocol::CCTP,
+            trace_index: info.trace_idx,
+            from: info.msg_sender,
+            // evm recipients are left padded into a word so non-evm domains fit
+            recipient: Address::from_word(call_data.mintRecipient),
+            bridge: info.target_address,
+            relayer: Address::ZERO,
+            amount: call_data.amount.to_scaled_rational(token.decimals),
+            token,
+            origin_chain: local_chain_id(),
+            destination_chain: domain_chain_id(call_data.destinationDomain),
+            is_fill: false,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::CCTP,
+    crate::CCTPTokenMessenger::depositForBurnWithCallerCall,
+    Bridge,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: depositForBurnWithCallerCall,
+    db_tx: &DB| {
+        let token = db_tx.try_fetch_token_info(call_data.burnToken)?;
+
+        Ok(NormalizedBridge {
+            protocol: Protocol::CCTP,
+            trace_index: info.trace_idx,
+            from: info.msg_sender,
+            recipient: Address::from_word(call_data.mintRecipient),
+            bridge: info.target_address,
+            relayer: Address::from_word(call_data.destinationCaller),
+            amount: call_data.amount.to_scaled_rational(token.decimals),
+            token,
+            origin_chain: local_chain_id(),
+            destination_chain: domain_chain_id(call_data.destinationDomain),
+            is_fill: false,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::CCTP,
+    crate::CCTPTokenMessenger::handleReceiveMessageCall,
+    Bridge,
+    [MintAndWithdraw],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: handleReceiveMessageCall,
+    log_data: CCTPHandleReceiveMessageCallLogs,
+    db_tx: &DB| {
+        let logs = log_data.mint_and_withdraw_field?;
+        let token = db_tx.try_fetch_token_info(logs.mintToken)?;
+
+        // the message transmitter hands the attested message over, whoever submitted the
+        // attestation relayed it
+        Ok(NormalizedBridge {
+            protocol: Protocol::CCTP,
+            trace_index: info.trace_idx,
+            from: info.target_address,
+            recipient: logs.mintRecipient,
+            bridge: info.target_address,
+            relayer: info.from_address,
+            amount: logs.amount.to_scaled_rational(token.decimals),
+            token,
+            origin_chain: domain_chain_id(call_data.remoteDomain),
+            destination_chain: local_chain_id(),
+            is_fill: true,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+/// Maps Circle's domain ids to evm chain ids
+const fn domain_chain_id(domain: u32) -> u64 {
+    match domain {
+        0 => 1,
+        1 => 43114,
+        2 => 10,
+        3 => 42161,
+        6 => 8453,
+        7 => 137,
+        10 => 130,
+        _ => 0,
+    }
+}
@@ -0,0 +1,102 @@
+use alloy_primitives::Address;
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    normalized_actions::NormalizedBridge, structured_trace::CallInfo, ToScaledRational,
+};
+
+use super::{bridge_token, local_chain_id};
+
+action_impl!(
+    Protocol::Hop,
+    crate::HopL2AmmWrapper::swapAndSendCall,
+    Bridge,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: swapAndSendCall,
+    db_tx: &DB| {
+        let token = bridge_token(db_tx, info.target_address)?;
+
+        Ok(NormalizedBridge {
+            protocol: Protocol::Hop,
+            trace_index: info.trace_idx,
+            from: info.msg_sender,
+            recipient: call_data.recipient,
+            bridge: info.target_address,
+            relayer: Address::ZERO,
+            amount: call_data.amount.to_scaled_rational(token.decimals),
+            token,
+            origin_chain: local_chain_id(),
+            destination_chain: call_data.chainId.saturating_to(),
+            is_fill: false,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::Hop,
+    crate::HopL2Bridge::bondWithdrawalAndDistributeCall,
+    Bridge,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: bondWithdrawalAndDistributeCall,
+    db_tx: &DB| {
+        let token = bridge_token(db_tx, info.target_address)?;
+        let amount = call_data.amount.saturating_sub(call_data.bonderFee);
+
+        // bonded withdrawals come from the other l2s, l1 transfers go through `distribute`.
+        // The origin chain is only hashed into the transfer nonce and isn't emitted on this
+        // side, so it's left unknown. The minted h tokens are swapped into `token` through
+        // the bridge's amm afterwards
+        Ok(NormalizedBridge {
+            protocol: Protocol::Hop,
+            trace_index: info.trace_idx,
+            from: info.target_address,
+            recipient: call_data.recipient,
+            bridge: info.target_address,
+            relayer: info.msg_sender,
+            amount: amount.to_scaled_rational(token.decimals),
+            token,
+            origin_chain: 0,
+            destination_chain: local_chain_id(),
+            is_fill: true,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::Hop,
+    crate::HopL2Bridge::distributeCall,
+    Bridge,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: distributeCall,
+    db_tx: &DB| {
+        let token = bridge_token(db_tx, info.target_address)?;
+        let amount = call_data.amount.saturating_sub(call_data.relayerFee);
+
+        // only the l1 bridge can distribute, through the rollup's messenger
+        Ok(NormalizedBridge {
+            protocol: Protocol::Hop,
+            trace_index: info.trace_idx,
+            from: info.target_address,
+            recipient: call_data.recipient,
+            bridge: info.target_address,
+            relayer: call_data.relayer,
+            amount: amount.to_scaled_rational(token.decimals),
+            token,
+            origin_chain: 1,
+            destination_chain: local_chain_id(),
+            is_fill: true,
+            msg_value: info.msg_value,
+        })
+    }
+);
@@ -0,0 +1,30 @@
+mod across;
+mod cctp;
+mod hop;
+mod stargate;
+
+pub use across::*;
+use alloy_primitives::Address;
+use brontes_database::libmdbx::{DBWriter, LibmdbxReader};
+use brontes_types::{chain::chain_config, db::token_info::TokenInfoWithAddress};
+pub use cctp::*;
+pub use hop::*;
+pub use stargate::*;
+
+/// Evm chain id of the chain being inspected
+fn local_chain_id() -> u64 {
+    chain_config().named_chain() as u64
+}
+
+/// Bridges holding a single token have it registered as `token0`
+fn bridge_token<DB: LibmdbxReader + DBWriter>(
+    db_tx: &DB,
+    bridge: Address,
+) -> eyre::Result<TokenInfoWithAddress> {
+    let token = db_tx.get_protocol_details(bridge)?.token0;
+    if token == Address::ZERO {
+        eyre::bail!("bridge {bridge:?} was registered without its token")
+    }
+
+    db_tx.try_fetch_token_info(token)
+}
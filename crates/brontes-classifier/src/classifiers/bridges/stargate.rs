@@ -0,0 +1,102 @@
+use alloy_primitives::{hex, Address};
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    normalized_actions::NormalizedBridge, structured_trace::CallInfo, ToScaledRational,
+};
+
+use super::{bridge_token, local_chain_id};
+
+action_impl!(
+    Protocol::StargateV1,
+    crate::StargateRouter::swapCall,
+    Bridge,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: swapCall,
+    db_tx: &DB| {
+        let Some(pool) = stargate_pool(call_data._srcPoolId.saturating_to()) else {
+            eyre::bail!("unknown stargate pool id {}", call_data._srcPoolId)
+        };
+        let token = bridge_token(db_tx, pool)?;
+
+        // the router pulls the tokens from the caller into the pool, the receiver on the
+        // destination chain is the packed address in `_to`
+        Ok(NormalizedBridge {
+            protocol: Protocol::StargateV1,
+            trace_index: info.trace_idx,
+            from: info.msg_sender,
+            recipient: Address::try_from(&call_data._to[..])?,
+            bridge: pool,
+            relayer: Address::ZERO,
+            amount: call_data._amountLD.to_scaled_rational(token.decimals),
+            token,
+            origin_chain: local_chain_id(),
+            destination_chain: layer_zero_chain_id(call_data._dstChainId),
+            is_fill: false,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::StargateV1,
+    crate::StargatePool::swapRemoteCall,
+    Bridge,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: swapRemoteCall,
+    return_data: swapRemoteReturn,
+    db_tx: &DB| {
+        let token = bridge_token(db_tx, info.target_address)?;
+
+        Ok(NormalizedBridge {
+            protocol: Protocol::StargateV1,
+            trace_index: info.trace_idx,
+            from: info.target_address,
+            recipient: call_data._to,
+            bridge: info.target_address,
+            relayer: info.from_address,
+            amount: return_data.amountLD.to_scaled_rational(token.decimals),
+            token,
+            origin_chain: layer_zero_chain_id(call_data._srcChainId),
+            destination_chain: local_chain_id(),
+            is_fill: true,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+/// The pools behind the router's pool ids on arbitrum
+const fn stargate_pool(pool_id: u64) -> Option<Address> {
+    match pool_id {
+        1 => Some(Address::new(hex!("892785f33CdeE22A30AEF750F285E18c18040c3e"))),
+        2 => Some(Address::new(hex!("B6CfcF89a7B22988bfC96632aC2A9D6daB60d641"))),
+        13 => Some(Address::new(hex!("915A55e36A01285A14f05dE6e81ED9cE89772f8e"))),
+        _ => None,
+    }
+}
+
+/// Maps the LayerZero v1 endpoint ids Stargate uses to evm chain ids
+const fn layer_zero_chain_id(id: u16) -> u64 {
+    match id {
+        101 => 1,
+        102 => 56,
+        106 => 43114,
+        109 => 137,
+        110 => 42161,
+        111 => 10,
+        112 => 250,
+        151 => 1088,
+        177 => 2222,
+        181 => 5000,
+        183 => 59144,
+        184 => 8453,
+        _ => 0,
+    }
+}
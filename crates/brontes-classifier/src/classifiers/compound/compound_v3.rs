@@ -1,8 +1,19 @@
+use alloy_primitives::{Address, Log, U256};
+use alloy_sol_types::SolEvent;
+use brontes_database::libmdbx::{DBWriter, LibmdbxReader};
 use brontes_macros::action_impl;
 use brontes_pricing::Protocol;
 use brontes_types::{
-    normalized_actions::NormalizedLiquidation, structured_trace::CallInfo, utils::ToScaledRational,
+    normalized_actions::{
+        Action, NormalizedLiquidation, NormalizedLoan, NormalizedRepayment, NormalizedSupply,
+        NormalizedWithdraw,
+    },
+    structured_trace::{CallFrameInfo, CallInfo},
+    utils::ToScaledRational,
+    FastHashMap,
 };
+
+use crate::Comet::Transfer;
 // TODO related to OEV(oracle based trading action is needed to implement)
 action_impl!(
     Protocol::CompoundV3,
@@ -40,17 +51,491 @@ action_impl!(
     }
 );
 
+action_impl!(
+    Protocol::CompoundV3,
+    crate::Comet::supplyCall,
+    Action,
+    [(Supply | SupplyCollateral), Transfer*],
+    logs: true,
+    call_data: true,
+    include_delegated_logs: true,
+    extra_actions: comet_base_balance_change,
+    |
+    info: CallInfo,
+    call_data: supplyCall,
+    log_data: CompoundV3SupplyCallLogs,
+    db_tx: &DB| {
+        comet_supply(
+            &info,
+            db_tx,
+            info.msg_sender,
+            info.msg_sender,
+            call_data.asset,
+            log_data
+                .supply_field
+                .map(|log| log.amount)
+                .or_else(|_| log_data.supply_collateral_field.map(|log| log.amount))
+                .unwrap_or(call_data.amount),
+            log_data.transfer_field.unwrap_or_default(),
+        )
+    }
+);
+
+action_impl!(
+    Protocol::CompoundV3,
+    crate::Comet::supplyToCall,
+    Action,
+    [(Supply | SupplyCollateral), Transfer*],
+    logs: true,
+    call_data: true,
+    include_delegated_logs: true,
+    extra_actions: comet_base_balance_change,
+    |
+    info: CallInfo,
+    call_data: supplyToCall,
+    log_data: CompoundV3SupplyToCallLogs,
+    db_tx: &DB| {
+        comet_supply(
+            &info,
+            db_tx,
+            info.msg_sender,
+            call_data.dst,
+            call_data.asset,
+            log_data
+                .supply_field
+                .map(|log| log.amount)
+                .or_else(|_| log_data.supply_collateral_field.map(|log| log.amount))
+                .unwrap_or(call_data.amount),
+            log_data.transfer_field.unwrap_or_default(),
+        )
+    }
+);
+
+action_impl!(
+    Protocol::CompoundV3,
+    crate::Comet::supplyFromCall,
+    Action,
+    [(Supply | SupplyCollateral), Transfer*],
+    logs: true,
+    call_data: true,
+    include_delegated_logs: true,
+    extra_actions: comet_base_balance_change,
+    |
+    info: CallInfo,
+    call_data: supplyFromCall,
+    log_data: CompoundV3SupplyFromCallLogs,
+    db_tx: &DB| {
+        comet_supply(
+            &info,
+            db_tx,
+            call_data.from,
+            call_data.dst,
+            call_data.asset,
+            log_data
+                .supply_field
+                .map(|log| log.amount)
+                .or_else(|_| log_data.supply_collateral_field.map(|log| log.amount))
+                .unwrap_or(call_data.amount),
+            log_data.transfer_field.unwrap_or_default(),
+        )
+    }
+);
+
+action_impl!(
+    Protocol::CompoundV3,
+    crate::Comet::withdrawCall,
+    Action,
+    [(Withdraw | WithdrawCollateral), Transfer*],
+    logs: true,
+    call_data: true,
+    include_delegated_logs: true,
+    extra_actions: comet_base_balance_change,
+    |
+    info: CallInfo,
+    call_data: withdrawCall,
+    log_data: CompoundV3WithdrawCallLogs,
+    db_tx: &DB| {
+        comet_withdraw(
+            &info,
+            db_tx,
+            info.msg_sender,
+            info.msg_sender,
+            call_data.asset,
+            log_data
+                .withdraw_field
+                .map(|log| log.amount)
+                .or_else(|_| log_data.withdraw_collateral_field.map(|log| log.amount))
+                .unwrap_or(call_data.amount),
+            log_data.transfer_field.unwrap_or_default(),
+        )
+    }
+);
+
+action_impl!(
+    Protocol::CompoundV3,
+    crate::Comet::withdrawToCall,
+    Action,
+    [(Withdraw | WithdrawCollateral), Transfer*],
+    logs: true,
+    call_data: true,
+    include_delegated_logs: true,
+    extra_actions: comet_base_balance_change,
+    |
+    info: CallInfo,
+    call_data: withdrawToCall,
+    log_data: CompoundV3WithdrawToCallLogs,
+    db_tx: &DB| {
+        comet_withdraw(
+            &info,
+            db_tx,
+            info.msg_sender,
+            call_data.to,
+            call_data.asset,
+            log_data
+                .withdraw_field
+                .map(|log| log.amount)
+                .or_else(|_| log_data.withdraw_collateral_field.map(|log| log.amount))
+                .unwrap_or(call_data.amount),
+            log_data.transfer_field.unwrap_or_default(),
+        )
+    }
+);
+
+action_impl!(
+    Protocol::CompoundV3,
+    crate::Comet::withdrawFromCall,
+    Action,
+    [(Withdraw | WithdrawCollateral), Transfer*],
+    logs: true,
+    call_data: true,
+    include_delegated_logs: true,
+    extra_actions: comet_base_balance_change,
+    |
+    info: CallInfo,
+    call_data: withdrawFromCall,
+    log_data: CompoundV3WithdrawFromCallLogs,
+    db_tx: &DB| {
+        comet_withdraw(
+            &info,
+            db_tx,
+            call_data.src,
+            call_data.to,
+            call_data.asset,
+            log_data
+                .withdraw_field
+                .map(|log| log.amount)
+                .or_else(|_| log_data.withdraw_collateral_field.map(|log| log.amount))
+                .unwrap_or(call_data.amount),
+            log_data.transfer_field.unwrap_or_default(),
+        )
+    }
+);
+
+/// Supplying the base token first pays down any debt of `dst`, only the
+/// remainder, which is minted as a `Transfer` from the zero address, is a
+/// supply. When both happen the call is classified as the repayment and
+/// [`compound_v3_base_balance_change`] classifies the supply next to it.
+fn comet_supply<DB: LibmdbxReader + DBWriter>(
+    info: &CallInfo,
+    db_tx: &DB,
+    from: Address,
+    dst: Address,
+    asset: Address,
+    amount: U256,
+    transfer_logs: Vec<Transfer>,
+) -> eyre::Result<Action> {
+    let base_token = db_tx.get_protocol_details(info.target_address)?.token0;
+    let token = db_tx.try_fetch_token_info(asset)?;
+
+    let repaid = if asset == base_token {
+        amount.saturating_sub(base_balance_change(&transfer_logs, true))
+    } else {
+        U256::ZERO
+    };
+
+    Ok(if repaid.is_zero() {
+        Action::Supply(NormalizedSupply {
+            protocol: Protocol::CompoundV3,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            supplier: from,
+            on_behalf_of: dst,
+            amount: amount.to_scaled_rational(token.decimals),
+            token,
+            msg_value: info.msg_value,
+        })
+    } else {
+        Action::Repayment(NormalizedRepayment {
+            protocol:         Protocol::CompoundV3,
+            trace_index:      info.trace_idx,
+            lender:           info.target_address,
+            borrower:         dst,
+            payer:            from,
+            repayment_amount: repaid.to_scaled_rational(token.decimals),
+            repayed_token:    token,
+            collateral:       FastHashMap::default(),
+            msg_value:        info.msg_value,
+        })
+    })
+}
+
+/// Withdrawing the base token first draws down the supplied balance of `src`,
+/// which is burnt as a `Transfer` to the zero address, anything past that is
+/// borrowed. When both happen the call is classified as the loan and
+/// [`compound_v3_base_balance_change`] classifies the withdrawal next to it.
+fn comet_withdraw<DB: LibmdbxReader + DBWriter>(
+    info: &CallInfo,
+    db_tx: &DB,
+    src: Address,
+    to: Address,
+    asset: Address,
+    amount: U256,
+    transfer_logs: Vec<Transfer>,
+) -> eyre::Result<Action> {
+    let base_token = db_tx.get_protocol_details(info.target_address)?.token0;
+    let token = db_tx.try_fetch_token_info(asset)?;
+
+    let borrowed = if asset == base_token {
+        amount.saturating_sub(base_balance_change(&transfer_logs, false))
+    } else {
+        U256::ZERO
+    };
+
+    Ok(if borrowed.is_zero() {
+        Action::Withdraw(NormalizedWithdraw {
+            protocol: Protocol::CompoundV3,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            owner: src,
+            recipient: to,
+            amount: amount.to_scaled_rational(token.decimals),
+            token,
+            msg_value: info.msg_value,
+        })
+    } else {
+        Action::Loan(NormalizedLoan {
+            protocol:     Protocol::CompoundV3,
+            trace_index:  info.trace_idx,
+            lender:       info.target_address,
+            borrower:     src,
+            recipient:    to,
+            loan_amount:  borrowed.to_scaled_rational(token.decimals),
+            loaned_token: token,
+            collateral:   FastHashMap::default(),
+            msg_value:    info.msg_value,
+        })
+    })
+}
+
+/// The base balance a comet minted (`Transfer`s from the zero address) or
+/// burnt (`Transfer`s to the zero address)
+fn base_balance_change<'a>(
+    transfers: impl IntoIterator<Item = &'a Transfer>,
+    minted: bool,
+) -> U256 {
+    transfers
+        .into_iter()
+        .filter(|t| if minted { t.from == Address::ZERO } else { t.to == Address::ZERO })
+        .fold(U256::ZERO, |acc, t| acc.saturating_add(t.amount))
+}
+
+/// The supply or withdrawal next to a repayment or loan, from the comet
+/// `Transfer`s of the call frame. The comet is a proxy, so those are logged by
+/// the delegated frame.
+fn comet_base_balance_change(action: &Action, call_info: &CallFrameInfo<'_>) -> Option<Action> {
+    let logs = call_info
+        .logs
+        .iter()
+        .chain(call_info.delegate_logs.iter().copied());
+
+    compound_v3_base_balance_change(action, logs)
+}
+
+/// A base token supply that repays all of the debt supplies the rest and a
+/// base token withdrawal past the supplied balance borrows the rest. Those
+/// calls are classified as the repayment / loan, this classifies the part of
+/// the supplied balance that was minted / burnt next to it.
+fn compound_v3_base_balance_change<'a>(
+    action: &Action,
+    logs: impl IntoIterator<Item = &'a Log>,
+) -> Option<Action> {
+    let balance_change = |comet: Address, minted: bool| {
+        let transfers = logs
+            .into_iter()
+            .filter(|log| log.address == comet)
+            .filter_map(|log| Transfer::decode_log_data(&log.data, false).ok())
+            .collect::<Vec<_>>();
+
+        Some(base_balance_change(&transfers, minted)).filter(|amount| !amount.is_zero())
+    };
+
+    match action {
+        Action::Repayment(r) if r.protocol == Protocol::CompoundV3 => {
+            let amount = balance_change(r.lender, true)?;
+            Some(Action::Supply(NormalizedSupply {
+                protocol:     r.protocol,
+                trace_index:  r.trace_index,
+                pool:         r.lender,
+                supplier:     r.payer,
+                on_behalf_of: r.borrower,
+                amount:       amount.to_scaled_rational(r.repayed_token.decimals),
+                token:        r.repayed_token.clone(),
+                msg_value:    r.msg_value,
+            }))
+        }
+        Action::Loan(l) if l.protocol == Protocol::CompoundV3 => {
+            let amount = balance_change(l.lender, false)?;
+            Some(Action::Withdraw(NormalizedWithdraw {
+                protocol:    l.protocol,
+                trace_index: l.trace_index,
+                pool:        l.lender,
+                owner:       l.borrower,
+                recipient:   l.recipient,
+                amount:      amount.to_scaled_rational(l.loaned_token.decimals),
+                token:       l.loaned_token.clone(),
+                msg_value:   l.msg_value,
+            }))
+        }
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use alloy_primitives::{hex, Address, B256, U256};
+    use alloy_primitives::{hex, Address, Log, B256, U256};
+    use alloy_sol_types::SolEvent;
     use brontes_types::{
-        db::token_info::TokenInfoWithAddress,
-        normalized_actions::{Action, NormalizedLiquidation},
-        Protocol, TreeSearchBuilder,
+        db::token_info::{TokenInfo, TokenInfoWithAddress},
+        normalized_actions::{
+            Action, NormalizedLiquidation, NormalizedLoan, NormalizedRepayment, NormalizedSupply,
+            NormalizedWithdraw,
+        },
+        FastHashMap, Protocol, TreeSearchBuilder,
     };
     use malachite::Rational;
 
-    use crate::test_utils::ClassifierTestUtils;
+    use super::{base_balance_change, compound_v3_base_balance_change};
+    use crate::{test_utils::ClassifierTestUtils, Comet::Transfer};
+
+    const COMET: Address = Address::new([0xc3; 20]);
+    const USER: Address = Address::new([0x01; 20]);
+
+    fn usdc() -> TokenInfoWithAddress {
+        TokenInfoWithAddress {
+            address: Address::new([0xa0; 20]),
+            inner:   TokenInfo { decimals: 6, symbol: "USDC".to_string() },
+        }
+    }
+
+    fn transfer_log(address: Address, from: Address, to: Address, amount: u64) -> Log {
+        Log { address, data: Transfer { from, to, amount: U256::from(amount) }.encode_log_data() }
+    }
+
+    fn repayment(repaid: u64) -> Action {
+        Action::Repayment(NormalizedRepayment {
+            protocol:         Protocol::CompoundV3,
+            trace_index:      1,
+            lender:           COMET,
+            borrower:         USER,
+            payer:            USER,
+            repayed_token:    usdc(),
+            repayment_amount: Rational::from(repaid),
+            collateral:       FastHashMap::default(),
+            msg_value:        U256::ZERO,
+        })
+    }
+
+    fn loan(borrowed: u64) -> Action {
+        Action::Loan(NormalizedLoan {
+            protocol:     Protocol::CompoundV3,
+            trace_index:  1,
+            lender:       COMET,
+            borrower:     USER,
+            recipient:    USER,
+            loaned_token: usdc(),
+            loan_amount:  Rational::from(borrowed),
+            collateral:   FastHashMap::default(),
+            msg_value:    U256::ZERO,
+        })
+    }
+
+    #[test]
+    fn test_base_balance_change_splits_mint_and_burn() {
+        let transfers = [
+            Transfer { from: Address::ZERO, to: USER, amount: U256::from(40) },
+            Transfer { from: USER, to: Address::ZERO, amount: U256::from(7) },
+            Transfer { from: Address::ZERO, to: USER, amount: U256::from(2) },
+        ];
+
+        assert_eq!(base_balance_change(&transfers, true), U256::from(42));
+        assert_eq!(base_balance_change(&transfers, false), U256::from(7));
+        assert_eq!(base_balance_change(&transfers[..0], true), U256::ZERO);
+    }
+
+    #[test]
+    fn test_repayment_with_remainder_supplies_minted_balance() {
+        let logs = [
+            transfer_log(COMET, Address::ZERO, USER, 250_000_000),
+            // the underlying moving into the comet isn't a comet balance change
+            transfer_log(usdc().address, Address::ZERO, USER, 1_000_000_000),
+        ];
+
+        let supply = compound_v3_base_balance_change(&repayment(750), &logs);
+
+        assert_eq!(
+            supply,
+            Some(Action::Supply(NormalizedSupply {
+                protocol:     Protocol::CompoundV3,
+                trace_index:  1,
+                pool:         COMET,
+                supplier:     USER,
+                on_behalf_of: USER,
+                token:        usdc(),
+                amount:       Rational::from(250),
+                msg_value:    U256::ZERO,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_loan_past_supplied_balance_withdraws_burnt_balance() {
+        let logs = [transfer_log(COMET, USER, Address::ZERO, 100_000_000)];
+
+        let withdraw = compound_v3_base_balance_change(&loan(900), &logs);
+
+        assert_eq!(
+            withdraw,
+            Some(Action::Withdraw(NormalizedWithdraw {
+                protocol:    Protocol::CompoundV3,
+                trace_index: 1,
+                pool:        COMET,
+                owner:       USER,
+                recipient:   USER,
+                token:       usdc(),
+                amount:      Rational::from(100),
+                msg_value:   U256::ZERO,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_full_repayment_and_full_borrow_have_no_balance_change() {
+        // a borrow doesn't mint and a repayment doesn't burn
+        let logs = [transfer_log(COMET, USER, Address::ZERO, 100_000_000)];
+        assert_eq!(compound_v3_base_balance_change(&repayment(10), &logs), None);
+
+        let logs = [transfer_log(COMET, Address::ZERO, USER, 100_000_000)];
+        assert_eq!(compound_v3_base_balance_change(&loan(10), &logs), None);
+    }
+
+    #[test]
+    fn test_other_protocols_are_ignored() {
+        let Action::Repayment(mut aave) = repayment(10) else { unreachable!() };
+        aave.protocol = Protocol::AaveV3;
+        let logs = [transfer_log(COMET, Address::ZERO, USER, 100_000_000)];
+
+        assert_eq!(compound_v3_base_balance_change(&Action::Repayment(aave), &logs), None);
+    }
 
     #[brontes_macros::test]
     async fn test_compound_v3_auction_participation() {
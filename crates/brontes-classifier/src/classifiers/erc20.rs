@@ -4,10 +4,16 @@ use alloy_primitives::{Address, Bytes};
 use alloy_sol_types::SolCall;
 use brontes_core::missing_token_info::load_missing_token_info;
 use brontes_types::{
-    db::traits::{DBWriter, LibmdbxReader},
-    normalized_actions::NormalizedTransfer,
+    chain::chain_config,
+    constants::ETH_ADDRESS,
+    db::{
+        token_info::{TokenInfo, TokenInfoWithAddress},
+        traits::{DBWriter, LibmdbxReader},
+    },
+    normalized_actions::{Action, NormalizedTransfer, NormalizedUnwrap, NormalizedWrap},
+    structured_trace::{TraceActions, TransactionTraceWithLogs},
     traits::TracingProvider,
-    ToScaledRational,
+    Protocol, ToScaledRational,
 };
 use malachite::{num::basic::traits::Zero, Rational};
 use reth_primitives::U256;
@@ -17,8 +23,93 @@ alloy_sol_macro::sol!(
     function transferFrom(address, address, uint) returns(bool);
     function withdraw(uint wad);
     function deposit();
+    function withdrawTo(address account, uint amount);
+    function depositTo(address account);
 );
 
+/// Deposits and withdrawals of the chain's wrapped native token. The unwrap
+/// credits the recipient with the eth, the call the wrapper pays it out with
+/// is left unclassified so it isn't counted twice, see
+/// [`is_native_unwrap_payout`].
+pub fn try_classify_native_wrap<DB: LibmdbxReader>(
+    trace: &TransactionTraceWithLogs,
+    trace_index: u64,
+    db: &DB,
+) -> Option<Action> {
+    let wrapper = chain_config().weth_address();
+    if trace.is_delegate_call() || trace.get_to_address() != wrapper {
+        return None
+    }
+
+    let from = trace.get_from_addr();
+    let value = trace.get_msg_value();
+    let (recipient, amount, is_wrap) = decode_native_wrap(&trace.get_calldata(), from, value)?;
+
+    let underlying = TokenInfoWithAddress {
+        address: ETH_ADDRESS,
+        inner:   TokenInfo::new(18, "ETH".to_string()),
+    };
+    let wrapped = db
+        .try_fetch_token_info(wrapper)
+        .unwrap_or_else(|_| TokenInfoWithAddress::weth());
+    let amount = amount.to_scaled_rational(18);
+
+    Some(if is_wrap {
+        Action::Wrap(NormalizedWrap {
+            protocol: Protocol::WETH,
+            trace_index,
+            from,
+            recipient,
+            wrapper,
+            underlying,
+            wrapped,
+            underlying_amount: amount.clone(),
+            wrapped_amount: amount,
+            msg_value: value,
+        })
+    } else {
+        Action::Unwrap(NormalizedUnwrap {
+            protocol: Protocol::WETH,
+            trace_index,
+            from,
+            recipient,
+            wrapper,
+            underlying,
+            wrapped,
+            underlying_amount: amount.clone(),
+            wrapped_amount: amount,
+            msg_value: value,
+        })
+    })
+}
+
+/// The wrapped native token only sends eth to pay out withdrawals
+pub fn is_native_unwrap_payout(trace: &TransactionTraceWithLogs) -> bool {
+    !trace.is_delegate_call() && trace.get_from_addr() == chain_config().weth_address()
+}
+
+/// Returns the recipient, the amount and whether it is a deposit
+fn decode_native_wrap(
+    calldata: &[u8],
+    from: Address,
+    value: U256,
+) -> Option<(Address, U256, bool)> {
+    // a plain eth transfer to the wrapper is a deposit
+    Some(if calldata.is_empty() && value > U256::ZERO {
+        (from, value, true)
+    } else if depositCall::abi_decode(calldata, false).is_ok() {
+        (from, value, true)
+    } else if let Ok(call) = depositToCall::abi_decode(calldata, false) {
+        (call.account, value, true)
+    } else if let Ok(call) = withdrawCall::abi_decode(calldata, false) {
+        (from, call.wad, false)
+    } else if let Ok(call) = withdrawToCall::abi_decode(calldata, false) {
+        (call.account, call.amount, false)
+    } else {
+        return None
+    })
+}
+
 pub async fn try_decode_transfer<T: TracingProvider, DB: LibmdbxReader + DBWriter>(
     idx: u64,
     calldata: Bytes,
@@ -62,3 +153,80 @@ pub async fn try_decode_transfer<T: TracingProvider, DB: LibmdbxReader + DBWrite
         fee:         Rational::ZERO,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use brontes_types::constants::arbitrum::WETH_ADDRESS;
+    use reth_rpc_types::trace::parity::{
+        Action as TraceAction, CallAction, CallType, TransactionTrace,
+    };
+
+    use super::*;
+
+    const USER: Address = Address::new([0x01; 20]);
+    const OTHER: Address = Address::new([0x02; 20]);
+
+    fn call_trace(from: Address, to: Address, value: U256) -> TransactionTraceWithLogs {
+        TransactionTraceWithLogs {
+            trace:        TransactionTrace {
+                action:        TraceAction::Call(CallAction {
+                    from,
+                    to,
+                    value,
+                    gas: Default::default(),
+                    input: Bytes::new(),
+                    call_type: CallType::Call,
+                }),
+                error:         None,
+                result:        None,
+                trace_address: vec![],
+                subtraces:     0,
+            },
+            logs:         vec![],
+            msg_sender:   from,
+            trace_idx:    0,
+            decoded_data: None,
+        }
+    }
+
+    #[test]
+    fn test_decode_native_wrap() {
+        let one = U256::from(10).pow(U256::from(18));
+
+        assert_eq!(decode_native_wrap(&[], USER, one), Some((USER, one, true)));
+        assert_eq!(decode_native_wrap(&[], USER, U256::ZERO), None);
+        assert_eq!(
+            decode_native_wrap(&depositCall {}.abi_encode(), USER, one),
+            Some((USER, one, true))
+        );
+        assert_eq!(
+            decode_native_wrap(&depositToCall { account: OTHER }.abi_encode(), USER, one),
+            Some((OTHER, one, true))
+        );
+        assert_eq!(
+            decode_native_wrap(&withdrawCall { wad: one }.abi_encode(), USER, U256::ZERO),
+            Some((USER, one, false))
+        );
+        assert_eq!(
+            decode_native_wrap(
+                &withdrawToCall { account: OTHER, amount: one }.abi_encode(),
+                USER,
+                U256::ZERO
+            ),
+            Some((OTHER, one, false))
+        );
+        assert_eq!(
+            decode_native_wrap(&transferCall { _0: OTHER, _1: one }.abi_encode(), USER, U256::ZERO),
+            None
+        );
+    }
+
+    #[test]
+    fn test_unwrap_payout_is_skipped() {
+        let payout = call_trace(WETH_ADDRESS, USER, U256::from(1));
+        assert!(is_native_unwrap_payout(&payout));
+
+        let eth_transfer = call_trace(OTHER, USER, U256::from(1));
+        assert!(!is_native_unwrap_payout(&eth_transfer));
+    }
+}
@@ -0,0 +1,177 @@
+use std::sync::Arc;
+
+use alloy_primitives::Address;
+use brontes_database::libmdbx::{DBWriter, LibmdbxReader};
+use brontes_macros::{action_impl, discovery_impl};
+use brontes_pricing::{make_call_request, Protocol};
+use brontes_types::{
+    db::token_info::TokenInfoWithAddress,
+    normalized_actions::{NormalizedNewPool, NormalizedUnwrap, NormalizedWrap},
+    structured_trace::CallInfo,
+    traits::TracingProvider,
+    ToScaledRational,
+};
+
+use crate::ERC4626::assetCall;
+
+// Fluid's fTokens are deployed through CREATE3, the proxy it deploys first
+// isn't a vault and is skipped by checking the asset
+discovery_impl!(
+    FluidFTokenDiscovery,
+    crate::FluidLendingFactory::createTokenCall,
+    0x54B91A0D94cb471F37f949c60F7Fa7935b551D03,
+    |deployed_address: Address, trace_idx: u64, call: createTokenCall, tracer: Arc<T>| async move {
+        parse_vault(deployed_address, call.asset_, trace_idx, tracer).await
+    }
+);
+
+action_impl!(
+    Protocol::ERC4626,
+    crate::ERC4626::depositCall,
+    Wrap,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: depositCall,
+    return_data: depositReturn,
+    db_tx: &DB| {
+        let (underlying, wrapped) = vault_tokens(db_tx, info.target_address)?;
+
+        Ok(NormalizedWrap {
+            protocol: Protocol::ERC4626,
+            trace_index: info.trace_idx,
+            from: info.msg_sender,
+            recipient: call_data.receiver,
+            wrapper: info.target_address,
+            underlying_amount: call_data.assets.to_scaled_rational(underlying.decimals),
+            wrapped_amount: return_data.shares.to_scaled_rational(wrapped.decimals),
+            underlying,
+            wrapped,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::ERC4626,
+    crate::ERC4626::mintCall,
+    Wrap,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: mintCall,
+    return_data: mintReturn,
+    db_tx: &DB| {
+        let (underlying, wrapped) = vault_tokens(db_tx, info.target_address)?;
+
+        Ok(NormalizedWrap {
+            protocol: Protocol::ERC4626,
+            trace_index: info.trace_idx,
+            from: info.msg_sender,
+            recipient: call_data.receiver,
+            wrapper: info.target_address,
+            underlying_amount: return_data.assets.to_scaled_rational(underlying.decimals),
+            wrapped_amount: call_data.shares.to_scaled_rational(wrapped.decimals),
+            underlying,
+            wrapped,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::ERC4626,
+    crate::ERC4626::withdrawCall,
+    Unwrap,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: withdrawCall,
+    return_data: withdrawReturn,
+    db_tx: &DB| {
+        let (underlying, wrapped) = vault_tokens(db_tx, info.target_address)?;
+
+        Ok(NormalizedUnwrap {
+            protocol: Protocol::ERC4626,
+            trace_index: info.trace_idx,
+            from: call_data.owner,
+            recipient: call_data.receiver,
+            wrapper: info.target_address,
+            underlying_amount: call_data.assets.to_scaled_rational(underlying.decimals),
+            wrapped_amount: return_data.shares.to_scaled_rational(wrapped.decimals),
+            underlying,
+            wrapped,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::ERC4626,
+    crate::ERC4626::redeemCall,
+    Unwrap,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: redeemCall,
+    return_data: redeemReturn,
+    db_tx: &DB| {
+        let (underlying, wrapped) = vault_tokens(db_tx, info.target_address)?;
+
+        Ok(NormalizedUnwrap {
+            protocol: Protocol::ERC4626,
+            trace_index: info.trace_idx,
+            from: call_data.owner,
+            recipient: call_data.receiver,
+            wrapper: info.target_address,
+            underlying_amount: return_data.assets.to_scaled_rational(underlying.decimals),
+            wrapped_amount: call_data.shares.to_scaled_rational(wrapped.decimals),
+            underlying,
+            wrapped,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+/// A vault is its own share token, the asset it wraps is stored as `token0`
+fn vault_tokens<DB: LibmdbxReader + DBWriter>(
+    db_tx: &DB,
+    vault: Address,
+) -> eyre::Result<(TokenInfoWithAddress, TokenInfoWithAddress)> {
+    let asset = db_tx.get_protocol_details(vault)?.token0;
+    if asset == Address::ZERO {
+        eyre::bail!("vault {vault:?} was registered without its asset")
+    }
+
+    Ok((db_tx.try_fetch_token_info(asset)?, db_tx.try_fetch_token_info(vault)?))
+}
+
+async fn parse_vault<T: TracingProvider>(
+    deployed_address: Address,
+    asset: Address,
+    trace_index: u64,
+    tracer: Arc<T>,
+) -> Vec<NormalizedNewPool> {
+    let Ok(vault_asset) = make_call_request(assetCall {}, &tracer, deployed_address, None).await
+    else {
+        return vec![]
+    };
+    if vault_asset._0 != asset {
+        return vec![]
+    }
+
+    vec![NormalizedNewPool {
+        trace_index,
+        protocol: Protocol::ERC4626,
+        pool_address: deployed_address,
+        tokens: vec![asset, deployed_address],
+    }]
+}
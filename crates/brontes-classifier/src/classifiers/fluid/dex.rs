@@ -123,6 +123,7 @@ action_impl!(
     [..],
     call_data:true,
     return_data :true,
+    include_delegated_logs: true,
     |
     info: CallInfo,
     call_data:swapOutCall,
@@ -168,6 +169,7 @@ action_impl!(
     [..],
     call_data:true,
     return_data :true,
+    include_delegated_logs: true,
     |
     info: CallInfo,
     call_data:swapOutWithCallbackCall,
@@ -213,6 +215,7 @@ action_impl!(
     [..],
     call_data: true,
     return_data: true,
+    include_delegated_logs: true,
     |info: CallInfo, _call_data: depositPerfectCall, return_data: depositPerfectReturn, db: &DB| {
         let recipient=info.msg_sender;
         let pool=info.target_address;
@@ -242,6 +245,7 @@ action_impl!(
     Mint,
     [..],
     call_data: true,
+    include_delegated_logs: true,
     |info: CallInfo, call_data: depositCall, db: &DB| {
         let recipient=info.msg_sender;
         let pool=info.target_address;
@@ -272,6 +276,7 @@ action_impl!(
     [..],
     call_data: true,
     return_data: true,
+    include_delegated_logs: true,
     |info: CallInfo, _call_data: withdrawPerfectCall,return_data: withdrawPerfectReturn, db: &DB| {
         let recipient=info.msg_sender;
         let pool=info.target_address;
@@ -300,6 +305,7 @@ action_impl!(
     Burn,
     [..],
     call_data: true,
+    include_delegated_logs: true,
     |info: CallInfo, call_data: withdrawCall, db: &DB| {
         let recipient=call_data.to_;
         let pool=info.target_address;
@@ -321,3 +327,80 @@ action_impl!(
         })
     }
 );
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Address, Log, U256};
+    use alloy_sol_types::{SolCall, SolEvent};
+    use brontes_classifier::test_utils::ClassifierTestUtils;
+    use brontes_database::libmdbx::DBWriter;
+    use brontes_pricing::types::DexPriceMsg;
+    use brontes_types::structured_trace::CallFrameInfo;
+
+    use super::*;
+    use crate::{
+        FluidDexT1::{swapOutCall, Swap},
+        IntoAction,
+    };
+
+    #[brontes_macros::test]
+    async fn test_swap_out_sends_delegated_logs_to_pricing() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        let libmdbx = classifier_utils.libmdbx;
+        let pool = Address::repeat_byte(0x51);
+        let (token0, token1) = (Address::repeat_byte(0x52), Address::repeat_byte(0x53));
+        libmdbx
+            .write_token_info(token0, 18, "T0".to_string())
+            .await
+            .unwrap();
+        libmdbx
+            .write_token_info(token1, 6, "T1".to_string())
+            .await
+            .unwrap();
+        libmdbx
+            .insert_pool(1, pool, &[token0, token1], None, Protocol::FluidDEX)
+            .await
+            .unwrap();
+
+        // swapOut runs in a module the dex delegatecalls into, so the swap log is
+        // on the delegated trace
+        let swap = Log {
+            address: pool,
+            data:    Swap {
+                swap0to1:  true,
+                amountIn:  U256::from(10u64.pow(18)),
+                amountOut: U256::from(2_000_000_000u64),
+                to:        Address::repeat_byte(2),
+            }
+            .encode_log_data(),
+        };
+        let call_info = CallFrameInfo {
+            trace_idx:      0,
+            call_data:      swapOutCall {
+                swap0to1_:    true,
+                amountOut_:   U256::from(2_000_000_000u64),
+                amountInMax_: U256::MAX,
+                to_:          Address::repeat_byte(2),
+            }
+            .abi_encode()
+            .into(),
+            return_data:    swapOutCall::abi_encode_returns(&(U256::from(10u64.pow(18)),)).into(),
+            target_address: pool,
+            from_address:   Address::repeat_byte(2),
+            logs:           &[],
+            delegate_logs:  vec![&swap],
+            msg_sender:     Address::repeat_byte(2),
+            msg_value:      U256::ZERO,
+        };
+
+        let (DexPriceMsg::Update(update), _) = FluidDEXSwapOutCall
+            .decode_call_trace(call_info, 1, 0, libmdbx, classifier_utils.get_tracing_provider())
+            .unwrap()
+        else {
+            panic!("expected a pool update")
+        };
+
+        assert!(update.action.is_swap());
+        assert_eq!(update.logs, vec![swap]);
+    }
+}
@@ -2,16 +2,20 @@
 use std::ops::{Div, Mul};
 use std::sync::Arc;
 
-use alloy_primitives::{Address, Uint, U256};
+use alloy_primitives::{Address, Uint, I256, U256};
 use brontes_macros::{action_impl, discovery_impl};
 use brontes_pricing::make_call_request;
 use brontes_types::{
-    constants::{FLUID_DEX_RESOLVER_ADDRESS, FLUID_VAULT_RESOLVER_ADDRESS},
-    normalized_actions::{NormalizedLiquidation, NormalizedNewPool},
+    constants::arbitrum::{FLUID_DEX_RESOLVER_ADDRESS, FLUID_VAULT_RESOLVER_ADDRESS},
+    normalized_actions::{
+        Action, NormalizedLiquidation, NormalizedLoan, NormalizedNewPool, NormalizedRepayment,
+        NormalizedSupply, NormalizedWithdraw,
+    },
     structured_trace::CallInfo,
     traits::TracingProvider,
-    Protocol, ToScaledRational,
+    FastHashMap, Protocol, ToScaledRational,
 };
+use malachite::Rational;
 
 use crate::{FluidDexResolver, FluidVaultResolver};
 discovery_impl!(
@@ -119,6 +123,106 @@ action_impl!(
     }
 );
 
+action_impl!(
+    Protocol::FluidLending,
+    crate::FluidVault::operateCall,
+    Action,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: operateCall,
+    return_data: operateReturn,
+    db_tx: &DB| {
+        let pool_address = info.target_address;
+        let protocol_details = db_tx.get_protocol_details(pool_address)?;
+
+        // only plain col / debt vaults have this `operate`, smart vaults take
+        // per token amounts instead
+        let supply_info = db_tx.try_fetch_token_info(protocol_details.token0)?;
+        let borrow_info = db_tx.try_fetch_token_info(
+            protocol_details.token2.ok_or_else(|| eyre::eyre!("Token3 does not exist"))?,
+        )?;
+
+        // the returned amounts are the ones actually moved, the requested ones
+        // can be `type(int256).min` to withdraw or pay back everything
+        let col_delta = return_data._1;
+        let debt_delta = return_data._2;
+        // `address(0)` sends withdrawn and borrowed funds to the caller
+        let recipient =
+            if call_data.to_ == Address::ZERO { info.msg_sender } else { call_data.to_ };
+
+        // a single operate can move both collateral and debt, the debt change is
+        // the primary action with the collateral change recorded alongside it
+        let mut collateral = FastHashMap::default();
+        if debt_delta != I256::ZERO && col_delta != I256::ZERO {
+            collateral.insert(supply_info.clone(), signed_amount(col_delta, supply_info.decimals));
+        }
+
+        if debt_delta > I256::ZERO {
+            Ok(Action::Loan(NormalizedLoan {
+                protocol: Protocol::FluidLending,
+                trace_index: info.trace_idx,
+                lender: pool_address,
+                borrower: info.msg_sender,
+                recipient,
+                loan_amount: debt_delta.unsigned_abs().to_scaled_rational(borrow_info.decimals),
+                loaned_token: borrow_info,
+                collateral,
+                msg_value: info.msg_value,
+            }))
+        } else if debt_delta < I256::ZERO {
+            Ok(Action::Repayment(NormalizedRepayment {
+                protocol: Protocol::FluidLending,
+                trace_index: info.trace_idx,
+                lender: pool_address,
+                borrower: info.msg_sender,
+                payer: info.msg_sender,
+                repayment_amount: debt_delta
+                    .unsigned_abs()
+                    .to_scaled_rational(borrow_info.decimals),
+                repayed_token: borrow_info,
+                collateral,
+                msg_value: info.msg_value,
+            }))
+        } else if col_delta > I256::ZERO {
+            Ok(Action::Supply(NormalizedSupply {
+                protocol: Protocol::FluidLending,
+                trace_index: info.trace_idx,
+                pool: pool_address,
+                supplier: info.msg_sender,
+                on_behalf_of: info.msg_sender,
+                amount: col_delta.unsigned_abs().to_scaled_rational(supply_info.decimals),
+                token: supply_info,
+                msg_value: info.msg_value,
+            }))
+        } else if col_delta < I256::ZERO {
+            Ok(Action::Withdraw(NormalizedWithdraw {
+                protocol: Protocol::FluidLending,
+                trace_index: info.trace_idx,
+                pool: pool_address,
+                owner: info.msg_sender,
+                recipient,
+                amount: col_delta.unsigned_abs().to_scaled_rational(supply_info.decimals),
+                token: supply_info,
+                msg_value: info.msg_value,
+            }))
+        } else {
+            eyre::bail!("operate without a collateral or debt change")
+        }
+    }
+);
+
+fn signed_amount(amount: I256, decimals: u8) -> Rational {
+    let abs = amount.unsigned_abs().to_scaled_rational(decimals);
+    if amount.is_negative() {
+        -abs
+    } else {
+        abs
+    }
+}
+
 pub async fn query_fluid_dex_state<T: TracingProvider>(
     tracer: &Arc<T>,
     vault: &Address,
@@ -1,8 +1,15 @@
+use alloy_primitives::U256;
 use brontes_macros::action_impl;
 use brontes_pricing::Protocol;
 use brontes_types::{
-    normalized_actions::NormalizedSwap, structured_trace::CallInfo, ToScaledRational,
+    normalized_actions::{NormalizedLiquidation, NormalizedPositionChange, NormalizedSwap},
+    structured_trace::CallInfo,
+    ToScaledRational,
 };
+use malachite::{num::basic::traits::Zero, Rational};
+
+/// GMX V1 usd values use 30 decimals
+const USD_DECIMALS: u8 = 30;
 
 action_impl!(
     Protocol::GMXV1,
@@ -45,6 +52,114 @@ action_impl!(
     }
 );
 
+action_impl!(
+    Protocol::GMXV1,
+    crate::GMXV1::increasePositionCall,
+    PositionChange,
+    [..IncreasePosition],
+    logs: true,
+    |
+    info: CallInfo,
+    logs: GMXV1IncreasePositionCallLogs,
+    db_tx: &DB| {
+        let log_data = logs.increase_position_field?;
+        let collateral_token = db_tx.try_fetch_token_info(log_data.collateralToken)?;
+
+        Ok(NormalizedPositionChange {
+            protocol: Protocol::GMXV1,
+            trace_index: info.trace_idx,
+            from: info.msg_sender,
+            account: log_data.account,
+            market: info.target_address,
+            collateral_token,
+            collateral_delta: usd_to_collateral(
+                log_data.collateralDelta,
+                log_data.price,
+                log_data.isLong,
+            ),
+            size_delta_usd: log_data.sizeDelta.to_scaled_rational(USD_DECIMALS),
+            is_long: log_data.isLong,
+            is_increase: true,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::GMXV1,
+    crate::GMXV1::decreasePositionCall,
+    PositionChange,
+    [..DecreasePosition],
+    logs: true,
+    |
+    info: CallInfo,
+    logs: GMXV1DecreasePositionCallLogs,
+    db_tx: &DB| {
+        let log_data = logs.decrease_position_field?;
+        let collateral_token = db_tx.try_fetch_token_info(log_data.collateralToken)?;
+
+        Ok(NormalizedPositionChange {
+            protocol: Protocol::GMXV1,
+            trace_index: info.trace_idx,
+            from: info.msg_sender,
+            account: log_data.account,
+            market: info.target_address,
+            collateral_token,
+            collateral_delta: usd_to_collateral(
+                log_data.collateralDelta,
+                log_data.price,
+                log_data.isLong,
+            ),
+            size_delta_usd: log_data.sizeDelta.to_scaled_rational(USD_DECIMALS),
+            is_long: log_data.isLong,
+            is_increase: false,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::GMXV1,
+    crate::GMXV1::liquidatePositionCall,
+    Liquidation,
+    [..LiquidatePosition],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: liquidatePositionCall,
+    logs: GMXV1LiquidatePositionCallLogs,
+    db_tx: &DB| {
+        let log_data = logs.liquidate_position_field?;
+        let collateral_asset = db_tx.try_fetch_token_info(log_data.collateralToken)?;
+
+        Ok(NormalizedLiquidation {
+            protocol: Protocol::GMXV1,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            liquidator: call_data._feeReceiver,
+            debtor: log_data.account,
+            // the fee receiver repays nothing, the vault takes the losses out of
+            // the collateral
+            debt_asset: collateral_asset.clone(),
+            collateral_asset,
+            covered_debt: Rational::ZERO,
+            // the liquidation fee sent to the fee receiver, filled in later
+            liquidated_collateral: Rational::ZERO,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+/// The vault accounts in usd. Longs are backed by the index token and shorts by
+/// a stable, so longs are converted at the index price and shorts one to one.
+fn usd_to_collateral(usd: U256, price: U256, is_long: bool) -> Rational {
+    let usd = usd.to_scaled_rational(USD_DECIMALS);
+    if is_long && !price.is_zero() {
+        usd / price.to_scaled_rational(USD_DECIMALS)
+    } else {
+        usd
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -62,6 +177,17 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_usd_to_collateral() {
+        let usd = |v: u64| U256::from(v) * U256::from(10).pow(U256::from(USD_DECIMALS));
+
+        // longs are backed by the index token
+        assert_eq!(usd_to_collateral(usd(3_000), usd(1_500), true), Rational::from(2));
+        // shorts by a stable
+        assert_eq!(usd_to_collateral(usd(3_000), usd(1_500), false), Rational::from(3_000));
+        assert_eq!(usd_to_collateral(usd(3_000), U256::ZERO, true), Rational::from(3_000));
+    }
+
     #[brontes_macros::test]
     async fn test_univ3_swap() {
         let classifier_utils = ClassifierTestUtils::new().await;
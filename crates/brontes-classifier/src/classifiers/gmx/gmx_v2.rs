@@ -0,0 +1,308 @@
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::SolCall;
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    normalized_actions::{
+        NormalizedAggregator, NormalizedLiquidation, NormalizedPerpOrder, NormalizedPositionChange,
+        NormalizedSwap,
+    },
+    structured_trace::CallInfo,
+    ToScaledRational,
+};
+use malachite::{num::basic::traits::Zero, Rational};
+
+use crate::GMXV2EventEmitter::EventLogData;
+
+/// GMX V2 usd values use 30 decimals
+const USD_DECIMALS: u8 = 30;
+
+const POSITION_INCREASE: &str = "PositionIncrease";
+const POSITION_DECREASE: &str = "PositionDecrease";
+
+action_impl!(
+    Protocol::GMXV2,
+    crate::GMXV2ExchangeRouter::createOrderCall,
+    PerpOrder,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: createOrderCall,
+    db_tx: &DB| {
+        let params = call_data.params;
+        let collateral_token =
+            db_tx.try_fetch_token_info(params.addresses.initialCollateralToken)?;
+        // for increase and swap orders the collateral is whatever was sent to the
+        // order vault beforehand, so this is only set for decreases
+        let collateral_amount = params
+            .numbers
+            .initialCollateralDeltaAmount
+            .to_scaled_rational(collateral_token.decimals);
+
+        Ok(NormalizedPerpOrder {
+            protocol: Protocol::GMXV2,
+            trace_index: info.trace_idx,
+            from: info.msg_sender,
+            receiver: params.addresses.receiver,
+            market: params.addresses.market,
+            collateral_token,
+            collateral_amount,
+            size_delta_usd: params.numbers.sizeDeltaUsd.to_scaled_rational(USD_DECIMALS),
+            is_long: params.isLong,
+            order_type: params.orderType,
+            execution_fee: params.numbers.executionFee,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::GMXV2,
+    crate::GMXV2OrderHandler::executeOrderCall,
+    Aggregator,
+    [],
+    |info: CallInfo, _| {
+        Ok(NormalizedAggregator {
+            protocol:      Protocol::GMXV2,
+            trace_index:   info.trace_idx,
+            from:          info.from_address,
+            to:            info.target_address,
+            recipient:     info.from_address,
+            // Filled in by the order's swaps and position changes
+            child_actions: vec![],
+            msg_value:     info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::GMXV2,
+    crate::GMXV2AdlHandler::executeAdlCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: executeAdlCall, _| {
+        Ok(NormalizedAggregator {
+            protocol:      Protocol::GMXV2,
+            trace_index:   info.trace_idx,
+            from:          info.from_address,
+            to:            info.target_address,
+            recipient:     call_data.account,
+            // Filled in by the position decrease of the deleveraged account
+            child_actions: vec![],
+            msg_value:     info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::GMXV2,
+    crate::GMXV2LiquidationHandler::executeLiquidationCall,
+    Liquidation,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: executeLiquidationCall,
+    db_tx: &DB| {
+        let collateral_asset = db_tx.try_fetch_token_info(call_data.collateralToken)?;
+
+        Ok(NormalizedLiquidation {
+            protocol: Protocol::GMXV2,
+            trace_index: info.trace_idx,
+            pool: call_data.market,
+            liquidator: info.msg_sender,
+            debtor: call_data.account,
+            // positions don't borrow a token and the keeper repays nothing, the
+            // losses are taken out of the collateral
+            debt_asset: collateral_asset.clone(),
+            collateral_asset,
+            covered_debt: Rational::ZERO,
+            // what the keeper is paid, filled in later
+            liquidated_collateral: Rational::ZERO,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::GMXV2,
+    crate::GMXV2SwapHandler::swapCall,
+    Swap,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: swapCall,
+    return_data: swapReturn,
+    db_tx: &DB| {
+        let params = call_data.params;
+        let pool = params
+            .swapPathMarkets
+            .first()
+            .map(|market| market.marketToken)
+            .unwrap_or(info.target_address);
+
+        let token_in = db_tx.try_fetch_token_info(params.tokenIn)?;
+        let token_out = db_tx.try_fetch_token_info(return_data._0)?;
+
+        let amount_in = params.amountIn.to_scaled_rational(token_in.decimals);
+        let amount_out = return_data._1.to_scaled_rational(token_out.decimals);
+
+        Ok(NormalizedSwap {
+            protocol: Protocol::GMXV2,
+            trace_index: info.trace_idx,
+            from: info.msg_sender,
+            recipient: params.receiver,
+            pool,
+            token_in,
+            token_out,
+            amount_in,
+            amount_out,
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::GMXV2,
+    crate::GMXV2EventEmitter::emitEventLog1Call,
+    PositionChange,
+    [],
+    call_data: true,
+    skip_call: is_skipped_event_call,
+    |
+    info: CallInfo,
+    call_data: emitEventLog1Call,
+    db_tx: &DB| {
+        // other events are skipped before decoding, see `is_skipped_event_call`
+        let is_increase = match call_data.eventName.as_str() {
+            POSITION_INCREASE => true,
+            POSITION_DECREASE => false,
+            name => eyre::bail!("{name} is not a position event"),
+        };
+        let data = call_data.eventData;
+
+        let collateral_token = db_tx.try_fetch_token_info(
+            address_item(&data, "collateralToken")
+                .ok_or(eyre::eyre!("position event without a collateral token"))?,
+        )?;
+        let collateral_delta = collateral_delta(&data)
+            .to_scaled_rational(collateral_token.decimals);
+        let size_delta_usd = uint_item(&data, "sizeDeltaUsd")
+            .unwrap_or_default()
+            .to_scaled_rational(USD_DECIMALS);
+
+        Ok(NormalizedPositionChange {
+            protocol: Protocol::GMXV2,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            account: address_item(&data, "account").unwrap_or_default(),
+            market: address_item(&data, "market").unwrap_or_default(),
+            collateral_token,
+            collateral_delta,
+            size_delta_usd,
+            is_long: bool_item(&data, "isLong").unwrap_or_default(),
+            is_increase,
+        })
+    }
+);
+
+/// The event emitter logs every GMX event through the same call, only the
+/// position ones are classified. The name is read straight from the calldata so
+/// the event data of everything else is never decoded.
+fn is_skipped_event_call(calldata: &[u8]) -> bool {
+    // `emitEventLog1Call` is imported by its `action_impl` above
+    if !calldata.starts_with(&emitEventLog1Call::SELECTOR) {
+        return false
+    }
+
+    !matches!(event_name(&calldata[4..]), Some(POSITION_INCREASE | POSITION_DECREASE))
+}
+
+/// The leading `string eventName` argument of an event emitter call
+fn event_name(args: &[u8]) -> Option<&str> {
+    let word = |at: usize| -> Option<usize> {
+        let word = args.get(at..at.checked_add(32)?)?;
+        usize::try_from(U256::from_be_slice(word)).ok()
+    };
+
+    let offset = word(0)?;
+    let len = word(offset)?;
+    let start = offset.checked_add(32)?;
+    std::str::from_utf8(args.get(start..start.checked_add(len)?)?).ok()
+}
+
+fn address_item(data: &EventLogData, key: &str) -> Option<Address> {
+    data.addressItems
+        .items
+        .iter()
+        .find(|item| item.key == key)
+        .map(|item| item.value)
+}
+
+fn uint_item(data: &EventLogData, key: &str) -> Option<U256> {
+    data.uintItems
+        .items
+        .iter()
+        .find(|item| item.key == key)
+        .map(|item| item.value)
+}
+
+fn bool_item(data: &EventLogData, key: &str) -> Option<bool> {
+    data.boolItems
+        .items
+        .iter()
+        .find(|item| item.key == key)
+        .map(|item| item.value)
+}
+
+/// Increases emit the collateral delta as an int, decreases as a uint
+fn collateral_delta(data: &EventLogData) -> U256 {
+    uint_item(data, "collateralDeltaAmount")
+        .or_else(|| {
+            data.intItems
+                .items
+                .iter()
+                .find(|item| item.key == "collateralDeltaAmount")
+                .map(|item| item.value.unsigned_abs())
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::B256;
+    use alloy_sol_types::SolValue;
+
+    use super::*;
+
+    fn event_call(name: &str) -> Vec<u8> {
+        let mut calldata = emitEventLog1Call::SELECTOR.to_vec();
+        calldata.extend((name.to_string(), B256::ZERO).abi_encode_params());
+        calldata
+    }
+
+    #[test]
+    fn test_event_name_is_read_from_calldata() {
+        let calldata = event_call(POSITION_DECREASE);
+        assert_eq!(event_name(&calldata[4..]), Some(POSITION_DECREASE));
+
+        assert_eq!(event_name(&[]), None);
+        assert_eq!(event_name(&calldata[4..40]), None);
+        assert_eq!(event_name(&[0xff; 64]), None);
+    }
+
+    #[test]
+    fn test_only_position_events_are_classified() {
+        assert!(!is_skipped_event_call(&event_call(POSITION_INCREASE)));
+        assert!(!is_skipped_event_call(&event_call(POSITION_DECREASE)));
+        assert!(is_skipped_event_call(&event_call("OrderCreated")));
+        assert!(is_skipped_event_call(&event_call("PositionFeesCollected")));
+
+        // other calls are left to their own classifiers
+        assert!(!is_skipped_event_call(&[0u8; 68]));
+    }
+}
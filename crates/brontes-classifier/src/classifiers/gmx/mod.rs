@@ -1,4 +1,7 @@
 #[allow(non_snake_case)]
 mod gmx_v1;
+#[allow(non_snake_case)]
+mod gmx_v2;
 
 pub use gmx_v1::*;
+pub use gmx_v2::*;
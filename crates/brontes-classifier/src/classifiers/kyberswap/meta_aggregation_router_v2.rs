@@ -0,0 +1,78 @@
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{normalized_actions::NormalizedAggregator, structured_trace::CallInfo};
+
+action_impl!(
+    Protocol::KyberSwap,
+    crate::KyberSwapMetaAggregationRouterV2::swapCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: swapCall,
+    _db_tx: &DB| {
+        let recipient = call_data.execution.desc.dstReceiver;
+        let recipient = if recipient.is_zero() { info.msg_sender } else { recipient };
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::KyberSwap,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::KyberSwap,
+    crate::KyberSwapMetaAggregationRouterV2::swapGenericCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: swapGenericCall,
+    _db_tx: &DB| {
+        let recipient = call_data.execution.desc.dstReceiver;
+        let recipient = if recipient.is_zero() { info.msg_sender } else { recipient };
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::KyberSwap,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::KyberSwap,
+    crate::KyberSwapMetaAggregationRouterV2::swapSimpleModeCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: swapSimpleModeCall,
+    _db_tx: &DB| {
+        let recipient = call_data.desc.dstReceiver;
+        let recipient = if recipient.is_zero() { info.msg_sender } else { recipient };
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::KyberSwap,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
@@ -1,9 +1,14 @@
-use alloy_primitives::U256;
+use alloy_primitives::{Address, B256, U256};
+use brontes_database::libmdbx::{DBWriter, LibmdbxReader};
 use brontes_macros::action_impl;
 use brontes_pricing::Protocol;
 use brontes_types::{
-    normalized_actions::NormalizedSwap, structured_trace::CallInfo, ToScaledRational,
+    db::token_info::TokenInfoWithAddress,
+    normalized_actions::{NormalizedBurn, NormalizedCollect, NormalizedMint, NormalizedSwap},
+    structured_trace::CallInfo,
+    ToScaledRational,
 };
+use malachite::Rational;
 
 // Assuming DB type, crate::LFJPair, crate::LFJV2_2Pair,
 // and log types (e.g., LFJV2_1SwapCallLogs) are accessible.
@@ -64,6 +69,94 @@ pub mod lfj_v2_1 {
             })
         }
     );
+
+    action_impl!(
+        Protocol::LFJV2_1,
+        crate::LFJPair::mintCall,
+        Mint,
+        [..DepositedToBins],
+        call_data: true,
+        logs: true,
+        include_delegated_logs: true,
+        |
+        info: CallInfo,
+        call_data: mintCall,
+        logs: LFJV2_1MintCallLogs,
+        db_tx: &DB| {
+            let deposited = logs.deposited_to_bins_field?;
+            let [token_x, token_y] = pair_tokens(db_tx, info.target_address)?;
+            let (token, amount) = bin_amounts(&deposited.amounts, [token_x, token_y]);
+
+            Ok(NormalizedMint {
+                protocol: Protocol::LFJV2_1,
+                trace_index: info.trace_idx,
+                from: info.from_address,
+                recipient: call_data.to,
+                pool: info.target_address,
+                token,
+                amount,
+            })
+        }
+    );
+
+    action_impl!(
+        Protocol::LFJV2_1,
+        crate::LFJPair::burnCall,
+        Burn,
+        [],
+        call_data: true,
+        return_data: true,
+        |
+        info: CallInfo,
+        call_data: burnCall,
+        return_data: burnReturn,
+        db_tx: &DB| {
+            let [token_x, token_y] = pair_tokens(db_tx, info.target_address)?;
+            let (token, amount) = bin_amounts(&return_data.amounts, [token_x, token_y]);
+
+            Ok(NormalizedBurn {
+                protocol: Protocol::LFJV2_1,
+                trace_index: info.trace_idx,
+                from: info.from_address,
+                recipient: call_data.to,
+                pool: info.target_address,
+                token,
+                amount,
+            })
+        }
+    );
+
+    action_impl!(
+        Protocol::LFJV2_1,
+        crate::LFJPair::collectProtocolFeesCall,
+        Collect,
+        [CollectedProtocolFees],
+        return_data: true,
+        logs: true,
+        include_delegated_logs: true,
+        |
+        info: CallInfo,
+        return_data: collectProtocolFeesReturn,
+        logs: LFJV2_1CollectProtocolFeesCallLogs,
+        db_tx: &DB| {
+            let collected = logs.collected_protocol_fees_field?;
+            let [token_x, token_y] = pair_tokens(db_tx, info.target_address)?;
+            let (amount_x, amount_y) = decode_amounts(return_data.collectedProtocolFees);
+
+            Ok(NormalizedCollect {
+                protocol: Protocol::LFJV2_1,
+                trace_index: info.trace_idx,
+                from: info.from_address,
+                recipient: collected.feeRecipient,
+                pool: info.target_address,
+                amount: vec![
+                    amount_x.to_scaled_rational(token_x.decimals),
+                    amount_y.to_scaled_rational(token_y.decimals),
+                ],
+                token: vec![token_x, token_y],
+            })
+        }
+    );
 }
 
 pub mod lfj_v2_2 {
@@ -124,6 +217,129 @@ pub mod lfj_v2_2 {
             })
         }
     );
+
+    action_impl!(
+        Protocol::LFJV2_2,
+        crate::LFJV2_2Pair::mintCall,
+        Mint,
+        [..DepositedToBins],
+        call_data: true,
+        logs: true,
+        include_delegated_logs: true,
+        |
+        info: CallInfo,
+        call_data: mintCall,
+        logs: LFJV2_2MintCallLogs,
+        db_tx: &DB| {
+            let deposited = logs.deposited_to_bins_field?;
+            let [token_x, token_y] = pair_tokens(db_tx, info.target_address)?;
+            let (token, amount) = bin_amounts(&deposited.amounts, [token_x, token_y]);
+
+            Ok(NormalizedMint {
+                protocol: Protocol::LFJV2_2,
+                trace_index: info.trace_idx,
+                from: info.from_address,
+                recipient: call_data.to,
+                pool: info.target_address,
+                token,
+                amount,
+            })
+        }
+    );
+
+    action_impl!(
+        Protocol::LFJV2_2,
+        crate::LFJV2_2Pair::burnCall,
+        Burn,
+        [],
+        call_data: true,
+        return_data: true,
+        |
+        info: CallInfo,
+        call_data: burnCall,
+        return_data: burnReturn,
+        db_tx: &DB| {
+            let [token_x, token_y] = pair_tokens(db_tx, info.target_address)?;
+            let (token, amount) = bin_amounts(&return_data.amounts, [token_x, token_y]);
+
+            Ok(NormalizedBurn {
+                protocol: Protocol::LFJV2_2,
+                trace_index: info.trace_idx,
+                from: info.from_address,
+                recipient: call_data.to,
+                pool: info.target_address,
+                token,
+                amount,
+            })
+        }
+    );
+
+    action_impl!(
+        Protocol::LFJV2_2,
+        crate::LFJV2_2Pair::collectProtocolFeesCall,
+        Collect,
+        [CollectedProtocolFees],
+        return_data: true,
+        logs: true,
+        include_delegated_logs: true,
+        |
+        info: CallInfo,
+        return_data: collectProtocolFeesReturn,
+        logs: LFJV2_2CollectProtocolFeesCallLogs,
+        db_tx: &DB| {
+            let collected = logs.collected_protocol_fees_field?;
+            let [token_x, token_y] = pair_tokens(db_tx, info.target_address)?;
+            let (amount_x, amount_y) = decode_amounts(return_data.collectedProtocolFees);
+
+            Ok(NormalizedCollect {
+                protocol: Protocol::LFJV2_2,
+                trace_index: info.trace_idx,
+                from: info.from_address,
+                recipient: collected.feeRecipient,
+                pool: info.target_address,
+                amount: vec![
+                    amount_x.to_scaled_rational(token_x.decimals),
+                    amount_y.to_scaled_rational(token_y.decimals),
+                ],
+                token: vec![token_x, token_y],
+            })
+        }
+    );
+}
+
+/// Liquidity book amounts pack the X amount in the lower 128 bits and the Y
+/// amount in the upper 128 bits
+fn decode_amounts(packed: B256) -> (U256, U256) {
+    let amounts = U256::from_be_bytes(packed.0);
+    (amounts & U256::from(u128::MAX), amounts >> U256::from(128))
+}
+
+/// The amounts moved in or out of each bin, in bin order with an entry per
+/// token the bin moved. A bin left of the active one only holds Y and a bin
+/// right of it only X, so the bins liquidity was provided to stay visible
+fn bin_amounts(
+    amounts: &[B256],
+    [token_x, token_y]: [TokenInfoWithAddress; 2],
+) -> (Vec<TokenInfoWithAddress>, Vec<Rational>) {
+    amounts
+        .iter()
+        .flat_map(|packed| {
+            let (x, y) = decode_amounts(*packed);
+            [(&token_x, x), (&token_y, y)]
+        })
+        .filter(|(_, amount)| !amount.is_zero())
+        .map(|(token, amount)| (token.clone(), amount.to_scaled_rational(token.decimals)))
+        .unzip()
+}
+
+/// The pair's tokens in X, Y order, which is how the bin amounts are packed
+fn pair_tokens<DB: LibmdbxReader + DBWriter>(
+    db_tx: &DB,
+    pool: Address,
+) -> eyre::Result<[TokenInfoWithAddress; 2]> {
+    let details = db_tx.get_protocol_details(pool)?;
+
+    Ok([db_tx.try_fetch_token_info(details.token0)?, db_tx.try_fetch_token_info(details.token1)?])
 }
 
 #[cfg(test)]
@@ -142,6 +358,56 @@ mod tests {
 
     use super::*;
 
+    fn packed(x: u128, y: u128) -> B256 {
+        B256::from(((U256::from(y) << 128) | U256::from(x)).to_be_bytes::<32>())
+    }
+
+    fn token(byte: u8, decimals: u8, symbol: &str) -> TokenInfoWithAddress {
+        TokenInfoWithAddress {
+            address: Address::repeat_byte(byte),
+            inner:   brontes_types::db::token_info::TokenInfo {
+                decimals,
+                symbol: symbol.to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_decode_amounts() {
+        assert_eq!(decode_amounts(packed(7, 9)), (U256::from(7), U256::from(9)));
+        assert_eq!(decode_amounts(packed(u128::MAX, 0)), (U256::from(u128::MAX), U256::ZERO));
+    }
+
+    #[test]
+    fn test_bin_amounts_are_kept_per_bin() {
+        let x = token(1, 18, "WETH");
+        let y = token(2, 6, "USDC");
+        // a bin below the active one, the active bin and a bin above it
+        let amounts = [packed(0, 2_000_000), packed(10u128.pow(18), 3_000_000), packed(5, 0)];
+
+        let (tokens, amounts) = bin_amounts(&amounts, [x.clone(), y.clone()]);
+
+        assert_eq!(tokens, vec![y.clone(), x.clone(), y, x]);
+        assert_eq!(
+            amounts,
+            vec![
+                Rational::from(2),
+                Rational::from(1),
+                Rational::from(3),
+                Rational::from_unsigneds(5u64, 10u64.pow(18)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_empty_bins_are_skipped() {
+        let (tokens, amounts) =
+            bin_amounts(&[packed(0, 0), packed(0, 0)], [token(1, 18, "X"), token(2, 18, "Y")]);
+
+        assert!(tokens.is_empty());
+        assert!(amounts.is_empty());
+    }
+
     #[brontes_macros::test]
     async fn test_lfj_swap() {
         let classifier_utils = ClassifierTestUtils::new().await;
@@ -126,7 +126,7 @@ mod tests {
     use alloy_primitives::{hex, Address, B256};
     use brontes_classifier::test_utils::ClassifierTestUtils;
     use brontes_types::{
-        constants::WETH_ADDRESS,
+        constants::arbitrum::WETH_ADDRESS,
         db::token_info::{TokenInfo, TokenInfoWithAddress},
         normalized_actions::Action,
         TreeSearchBuilder,
@@ -1,7 +1,11 @@
 use brontes_macros::{action_dispatch, discovery_dispatch};
 use futures::StreamExt;
+pub mod arbos;
 pub mod erc20;
 
+pub mod erc4626;
+pub use erc4626::*;
+
 pub mod uniswap;
 pub use uniswap::*;
 
@@ -47,6 +51,15 @@ pub use cowswap::*;
 pub mod oneinch;
 pub use oneinch::*;
 
+pub mod odos;
+pub use odos::*;
+
+pub mod paraswap;
+pub use paraswap::*;
+
+pub mod kyberswap;
+pub use kyberswap::*;
+
 pub mod clipper;
 pub use clipper::*;
 
@@ -65,6 +78,12 @@ pub use dexalot::*;
 pub mod lfj;
 pub use lfj::*;
 
+pub mod ramses;
+pub use ramses::*;
+
+pub mod bridges;
+pub use bridges::*;
+
 discovery_dispatch!(
     DiscoveryClassifier,
     SushiSwapV2Discovery,
@@ -90,7 +109,10 @@ discovery_dispatch!(
     // BalancerV1SmartPoolDiscovery,
     CamelotV2Discovery,
     CamelotV3Discovery,
+    RamsesV1Discovery,
+    RamsesV2Discovery,
     FluidLendingDiscovery,
+    FluidFTokenDiscovery,
     PendleV2Discovery
 );
 
@@ -109,6 +131,10 @@ action_dispatch!(
     UniswapV3MintCall,
     UniswapV3BurnCall,
     UniswapV3CollectCall,
+    UniswapV4InitializeCall,
+    UniswapV4SwapCall,
+    UniswapV4ModifyLiquidityCall,
+    UniswapV4DonateCall,
     SushiSwapV3SwapCall,
     SushiSwapV3MintCall,
     SushiSwapV3BurnCall,
@@ -178,6 +204,15 @@ action_dispatch!(
     AaveV2FlashLoanCall,
     AaveV3FlashLoanCall,
     AaveV3FlashLoanSimpleCall,
+    AaveV2DepositCall,
+    AaveV2WithdrawCall,
+    AaveV2BorrowCall,
+    AaveV2RepayCall,
+    AaveV3SupplyCall,
+    AaveV3DepositCall,
+    AaveV3WithdrawCall,
+    AaveV3BorrowCall,
+    AaveV3RepayCall,
     BalancerV1SwapExactAmountInCall,
     BalancerV1SwapExactAmountOutCall,
     BalancerV1BindCall,
@@ -199,6 +234,34 @@ action_dispatch!(
     OneInchV5UniswapV3SwapToCall,
     OneInchV5UniswapV3SwapToWithPermitCall,
     OneInchFusionSettleOrdersCall,
+    OneInchV6SwapCall,
+    OneInchV6UnoswapCall,
+    OneInchV6UnoswapToCall,
+    OneInchV6EthUnoswapCall,
+    OneInchV6EthUnoswapToCall,
+    OneInchV6Unoswap2Call,
+    OneInchV6UnoswapTo2Call,
+    OneInchV6EthUnoswap2Call,
+    OneInchV6EthUnoswapTo2Call,
+    OneInchV6Unoswap3Call,
+    OneInchV6UnoswapTo3Call,
+    OneInchV6EthUnoswap3Call,
+    OneInchV6EthUnoswapTo3Call,
+    OdosV2SwapCall,
+    OdosV2SwapPermit2Call,
+    OdosV2SwapMultiCall,
+    OdosV2SwapMultiPermit2Call,
+    OdosV2SwapCompactCall,
+    OdosV2SwapMultiCompactCall,
+    ParaSwapV6SwapExactAmountInCall,
+    ParaSwapV6SwapExactAmountOutCall,
+    ParaSwapV6SwapExactAmountInOnUniswapV2Call,
+    ParaSwapV6SwapExactAmountOutOnUniswapV2Call,
+    ParaSwapV6SwapExactAmountInOnUniswapV3Call,
+    ParaSwapV6SwapExactAmountOutOnUniswapV3Call,
+    KyberSwapSwapCall,
+    KyberSwapSwapGenericCall,
+    KyberSwapSwapSimpleModeCall,
     ClipperExchangeSwapCall,
     ClipperExchangeSellEthForTokenCall,
     ClipperExchangeSellTokenForEthCall,
@@ -250,10 +313,53 @@ action_dispatch!(
     // PendleV2CreateNewMarketCall,
     PendleV2CreateYieldContractCall,
     CompoundV3SetConfigurationCall,
+    CompoundV3SupplyCall,
+    CompoundV3SupplyToCall,
+    CompoundV3SupplyFromCall,
+    CompoundV3WithdrawCall,
+    CompoundV3WithdrawToCall,
+    CompoundV3WithdrawFromCall,
+    FluidLendingOperateCall,
+    ERC4626DepositCall,
+    ERC4626MintCall,
+    ERC4626WithdrawCall,
+    ERC4626RedeemCall,
     DexalotPartialSwapCall,
     DexalotSimpleSwapCall,
     LFJV2_1SwapCall,
+    LFJV2_1MintCall,
+    LFJV2_1BurnCall,
+    LFJV2_1CollectProtocolFeesCall,
     LFJV2_1CreateLBPairCall,
     LFJV2_2CreateLBPairCall,
-    LFJV2_2SwapCall
+    LFJV2_2SwapCall,
+    LFJV2_2MintCall,
+    LFJV2_2BurnCall,
+    LFJV2_2CollectProtocolFeesCall,
+    RamsesV1SwapCall,
+    RamsesV1MintCall,
+    RamsesV1BurnCall,
+    RamsesV2SwapCall,
+    RamsesV2MintCall,
+    RamsesV2BurnCall,
+    RamsesV2CollectCall,
+    AcrossV3DepositV3Call,
+    AcrossV3FillV3RelayCall,
+    StargateV1SwapCall,
+    StargateV1SwapRemoteCall,
+    HopSwapAndSendCall,
+    HopBondWithdrawalAndDistributeCall,
+    HopDistributeCall,
+    CCTPDepositForBurnCall,
+    CCTPDepositForBurnWithCallerCall,
+    CCTPHandleReceiveMessageCall,
+    GMXV1IncreasePositionCall,
+    GMXV1DecreasePositionCall,
+    GMXV1LiquidatePositionCall,
+    GMXV2CreateOrderCall,
+    GMXV2ExecuteOrderCall,
+    GMXV2ExecuteAdlCall,
+    GMXV2ExecuteLiquidationCall,
+    GMXV2SwapCall,
+    GMXV2EmitEventLog1Call
 );
@@ -0,0 +1,3 @@
+mod odos_v2;
+
+pub use odos_v2::*;
@@ -0,0 +1,308 @@
+use alloy_primitives::{Address, Bytes};
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{normalized_actions::NormalizedAggregator, structured_trace::CallInfo};
+
+action_impl!(
+    Protocol::OdosV2,
+    crate::OdosRouterV2::swapCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: swapCall,
+    _db_tx: &DB| {
+        let recipient = call_data.tokenInfo.outputReceiver;
+        let recipient = if recipient.is_zero() { info.msg_sender } else { recipient };
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::OdosV2,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OdosV2,
+    crate::OdosRouterV2::swapPermit2Call,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: swapPermit2Call,
+    _db_tx: &DB| {
+        let recipient = call_data.tokenInfo.outputReceiver;
+        let recipient = if recipient.is_zero() { info.msg_sender } else { recipient };
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::OdosV2,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OdosV2,
+    crate::OdosRouterV2::swapMultiCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: swapMultiCall,
+    _db_tx: &DB| {
+        let recipient = call_data
+            .outputs
+            .first()
+            .map(|output| output.receiver)
+            .unwrap_or_default();
+        let recipient = if recipient.is_zero() { info.msg_sender } else { recipient };
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::OdosV2,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OdosV2,
+    crate::OdosRouterV2::swapMultiPermit2Call,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: swapMultiPermit2Call,
+    _db_tx: &DB| {
+        let recipient = call_data
+            .outputs
+            .first()
+            .map(|output| output.receiver)
+            .unwrap_or_default();
+        let recipient = if recipient.is_zero() { info.msg_sender } else { recipient };
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::OdosV2,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OdosV2,
+    crate::OdosRouterV2::swapCompactCall,
+    Aggregator,
+    [],
+    raw_call_data: true,
+    |info: CallInfo, call_data: &Bytes, _db_tx: &DB| {
+        let recipient = compact_output_receiver(call_data).unwrap_or(info.msg_sender);
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::OdosV2,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OdosV2,
+    crate::OdosRouterV2::swapMultiCompactCall,
+    Aggregator,
+    [],
+    raw_call_data: true,
+    |info: CallInfo, call_data: &Bytes, _db_tx: &DB| {
+        let recipient = compact_multi_output_receiver(call_data).unwrap_or(info.msg_sender);
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::OdosV2,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+/// The output receiver of a `swapCompact` call. `None` when it defaults to the
+/// sender or comes from the router's cached address list, which would need a
+/// storage read.
+fn compact_output_receiver(call_data: &[u8]) -> Option<Address> {
+    let mut cursor = CompactCursor { data: call_data, pos: 4 };
+    // input and output token
+    cursor.address()?;
+    cursor.address()?;
+    // input amount and output quote
+    cursor.amount()?;
+    cursor.amount()?;
+    // slippage tolerance
+    cursor.take(3)?;
+    // executor and input receiver
+    cursor.address()?;
+    cursor.address()?;
+
+    cursor.address()?
+}
+
+/// The receiver of the first output of a `swapMultiCompact` call, same as
+/// `swapMulti`.
+fn compact_multi_output_receiver(call_data: &[u8]) -> Option<Address> {
+    let inputs = *call_data.get(4)?;
+    let outputs = *call_data.get(5)?;
+    if outputs == 0 {
+        return None
+    }
+
+    let mut cursor = CompactCursor { data: call_data, pos: 6 };
+    // minimum output value, executor and referral code
+    cursor.amount()?;
+    cursor.address()?;
+    cursor.take(4)?;
+
+    for _ in 0..inputs {
+        // token, amount and input receiver
+        cursor.address()?;
+        cursor.amount()?;
+        cursor.address()?;
+    }
+
+    // token and relative value
+    cursor.address()?;
+    cursor.take(2)?;
+
+    cursor.address()?
+}
+
+/// Reads the packed encoding of the compact calls. Addresses are a two byte
+/// tag, zero for the default, one for an inline address and otherwise an index
+/// into the router's cached address list. Amounts are prefixed with their
+/// length in bytes.
+struct CompactCursor<'a> {
+    data: &'a [u8],
+    pos:  usize,
+}
+
+impl<'a> CompactCursor<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self.data.get(self.pos..self.pos + len)?;
+        self.pos += len;
+        Some(bytes)
+    }
+
+    fn amount(&mut self) -> Option<()> {
+        let len = self.take(1)?[0] as usize;
+        self.take(len).map(|_| ())
+    }
+
+    /// `Some(None)` for an address that isn't given inline
+    fn address(&mut self) -> Option<Option<Address>> {
+        match u16::from_be_bytes(self.take(2)?.try_into().ok()?) {
+            1 => self
+                .take(20)
+                .map(|address| Some(Address::from_slice(address))),
+            _ => Some(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::hex;
+
+    use super::*;
+
+    const RECEIVER: Address = Address::new(hex!("00000000000000000000000000000000000000aa"));
+
+    fn inline(address: Address) -> Vec<u8> {
+        [&[0, 1][..], address.as_slice()].concat()
+    }
+
+    fn swap_compact(output_receiver: &[u8]) -> Vec<u8> {
+        [
+            // selector
+            &[0x83, 0xbd, 0x37, 0xf9][..],
+            // input token from the address list, native output token
+            &[0, 2],
+            &[0, 0],
+            // input amount and output quote
+            &[2, 0x12, 0x34],
+            &[1, 0x56],
+            // slippage tolerance
+            &[0, 0, 1],
+            // executor and input receiver
+            &inline(Address::repeat_byte(0xee)),
+            &[0, 0],
+            output_receiver,
+            // referral code and path
+            &[0, 0, 0, 1, 0xff, 0xff],
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn test_compact_output_receiver() {
+        assert_eq!(compact_output_receiver(&swap_compact(&inline(RECEIVER))), Some(RECEIVER));
+        assert_eq!(compact_output_receiver(&swap_compact(&[0, 0])), None);
+        assert_eq!(compact_output_receiver(&swap_compact(&[0, 3])), None);
+        assert_eq!(compact_output_receiver(&swap_compact(&[0, 1, 0xaa])), None);
+    }
+
+    #[test]
+    fn test_compact_multi_output_receiver() {
+        let call_data = [
+            // selector
+            &[0x84, 0xa7, 0xf3, 0xdd][..],
+            // two inputs, one output
+            &[2, 1],
+            // minimum output value
+            &[1, 0x10],
+            // executor and referral code
+            &inline(Address::repeat_byte(0xee)),
+            &[0, 0, 0, 0],
+            // inputs
+            &[0, 2],
+            &[1, 0x01],
+            &[0, 0],
+            &inline(Address::repeat_byte(0x01)),
+            &[0],
+            &inline(Address::repeat_byte(0xee)),
+            // output
+            &[0, 3],
+            &[0xff, 0xff],
+            &inline(RECEIVER),
+        ]
+        .concat();
+
+        assert_eq!(compact_multi_output_receiver(&call_data), Some(RECEIVER));
+        assert_eq!(compact_multi_output_receiver(&call_data[..call_data.len() - 1]), None);
+    }
+}
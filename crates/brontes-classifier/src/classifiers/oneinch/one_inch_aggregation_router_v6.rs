@@ -0,0 +1,298 @@
+use alloy_primitives::{Address, U256};
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{normalized_actions::NormalizedAggregator, structured_trace::CallInfo};
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::swapCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: swapCall,
+    _db_tx: &DB| {
+        let recipient = call_data.desc.dstReceiver;
+        let recipient = if recipient.is_zero() { info.msg_sender } else { recipient };
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::OneInchV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::unoswapCall,
+    Aggregator,
+    [],
+    |info: CallInfo, _db_tx: &DB| {
+        let recipient = info.msg_sender;
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::OneInchV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::unoswapToCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: unoswapToCall,
+    _db_tx: &DB| {
+        let recipient = unpack_address(call_data.to);
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::OneInchV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::ethUnoswapCall,
+    Aggregator,
+    [],
+    |info: CallInfo, _db_tx: &DB| {
+        let recipient = info.msg_sender;
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::OneInchV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::ethUnoswapToCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: ethUnoswapToCall,
+    _db_tx: &DB| {
+        let recipient = unpack_address(call_data.to);
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::OneInchV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::unoswap2Call,
+    Aggregator,
+    [],
+    |info: CallInfo, _db_tx: &DB| {
+        let recipient = info.msg_sender;
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::OneInchV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::unoswapTo2Call,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: unoswapTo2Call,
+    _db_tx: &DB| {
+        let recipient = unpack_address(call_data.to);
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::OneInchV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::ethUnoswap2Call,
+    Aggregator,
+    [],
+    |info: CallInfo, _db_tx: &DB| {
+        let recipient = info.msg_sender;
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::OneInchV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::ethUnoswapTo2Call,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: ethUnoswapTo2Call,
+    _db_tx: &DB| {
+        let recipient = unpack_address(call_data.to);
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::OneInchV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::unoswap3Call,
+    Aggregator,
+    [],
+    |info: CallInfo, _db_tx: &DB| {
+        let recipient = info.msg_sender;
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::OneInchV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::unoswapTo3Call,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: unoswapTo3Call,
+    _db_tx: &DB| {
+        let recipient = unpack_address(call_data.to);
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::OneInchV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::ethUnoswap3Call,
+    Aggregator,
+    [],
+    |info: CallInfo, _db_tx: &DB| {
+        let recipient = info.msg_sender;
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::OneInchV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OneInchV6,
+    crate::OneInchAggregationRouterV6::ethUnoswapTo3Call,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: ethUnoswapTo3Call,
+    _db_tx: &DB| {
+        let recipient = unpack_address(call_data.to);
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::OneInchV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+/// V6 passes addresses as a uint256 with flags packed into the upper bits
+fn unpack_address(word: U256) -> Address {
+    Address::from_word(word.into())
+}
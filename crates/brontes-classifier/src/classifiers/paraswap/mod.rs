@@ -0,0 +1,3 @@
+mod paraswap_v6;
+
+pub use paraswap_v6::*;
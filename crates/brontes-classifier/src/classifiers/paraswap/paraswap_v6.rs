@@ -0,0 +1,153 @@
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{normalized_actions::NormalizedAggregator, structured_trace::CallInfo};
+
+action_impl!(
+    Protocol::ParaSwapV6,
+    crate::ParaSwapAugustusV6::swapExactAmountInCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: swapExactAmountInCall,
+    _db_tx: &DB| {
+        let recipient = call_data.swapData.beneficiary;
+        let recipient = if recipient.is_zero() { info.msg_sender } else { recipient };
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::ParaSwapV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::ParaSwapV6,
+    crate::ParaSwapAugustusV6::swapExactAmountOutCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: swapExactAmountOutCall,
+    _db_tx: &DB| {
+        let recipient = call_data.swapData.beneficiary;
+        let recipient = if recipient.is_zero() { info.msg_sender } else { recipient };
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::ParaSwapV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::ParaSwapV6,
+    crate::ParaSwapAugustusV6::swapExactAmountInOnUniswapV2Call,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: swapExactAmountInOnUniswapV2Call,
+    _db_tx: &DB| {
+        let recipient = call_data.uniData.beneficiary;
+        let recipient = if recipient.is_zero() { info.msg_sender } else { recipient };
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::ParaSwapV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::ParaSwapV6,
+    crate::ParaSwapAugustusV6::swapExactAmountOutOnUniswapV2Call,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: swapExactAmountOutOnUniswapV2Call,
+    _db_tx: &DB| {
+        let recipient = call_data.uniData.beneficiary;
+        let recipient = if recipient.is_zero() { info.msg_sender } else { recipient };
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::ParaSwapV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::ParaSwapV6,
+    crate::ParaSwapAugustusV6::swapExactAmountInOnUniswapV3Call,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: swapExactAmountInOnUniswapV3Call,
+    _db_tx: &DB| {
+        let recipient = call_data.uniData.beneficiary;
+        let recipient = if recipient.is_zero() { info.msg_sender } else { recipient };
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::ParaSwapV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::ParaSwapV6,
+    crate::ParaSwapAugustusV6::swapExactAmountOutOnUniswapV3Call,
+    Aggregator,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: swapExactAmountOutOnUniswapV3Call,
+    _db_tx: &DB| {
+        let recipient = call_data.uniData.beneficiary;
+        let recipient = if recipient.is_zero() { info.msg_sender } else { recipient };
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::ParaSwapV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value
+        })
+    }
+);
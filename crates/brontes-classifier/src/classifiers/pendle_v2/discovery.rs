@@ -6,7 +6,7 @@ use brontes_pricing::{make_call_request, Protocol};
 use brontes_types::{
     normalized_actions::NormalizedNewPool, structured_trace::CallInfo, traits::TracingProvider,
 };
-use brontes_types::constants::PENDLE_V2_SY_ASSETS_API_URL;
+use brontes_types::{chain::chain_config, constants::PENDLE_V2_API_URL};
 use serde::{Deserialize, Serialize};
 
 discovery_impl!(
@@ -110,7 +110,7 @@ pub struct PendleAssetsResponse {
 }
 
 pub async fn get_pendle_v2_sy_pools<T: TracingProvider>(tracer: &Arc<T>) -> Result<Vec<NormalizedNewPool>, Box<dyn std::error::Error>> {
-    let url = PENDLE_V2_SY_ASSETS_API_URL;
+    let url = format!("{PENDLE_V2_API_URL}/{}/assets/all", chain_config().named_chain() as u64);
     
     let client = reqwest::Client::new();
     let response = client
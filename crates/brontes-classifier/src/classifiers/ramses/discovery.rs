@@ -0,0 +1,44 @@
+use alloy_primitives::Address;
+use brontes_macros::discovery_impl;
+use brontes_pricing::Protocol;
+
+discovery_impl!(
+    RamsesV1Discovery,
+    crate::RamsesPairFactory::createPairCall,
+    0xAAA20D08e59F6561f242b08513D36266C5A29415,
+    |deployed_address: Address, trace_index: u64, call_data: createPairCall, _| async move {
+        let mut token_a = call_data.tokenA;
+        let mut token_b = call_data.tokenB;
+        if token_a > token_b {
+            std::mem::swap(&mut token_a, &mut token_b)
+        }
+
+        vec![NormalizedNewPool {
+            pool_address: deployed_address,
+            trace_index,
+            protocol: Protocol::RamsesV1,
+            tokens: vec![token_a, token_b],
+        }]
+    }
+);
+
+discovery_impl!(
+    RamsesV2Discovery,
+    crate::UniswapV3Factory::createPoolCall,
+    0xAA2cd7477c451E703f3B9Ba5663334914763edF8,
+    |deployed_address: Address, trace_index: u64, call_data: createPoolCall, _| async move {
+        let mut token_a = call_data.tokenA;
+        let mut token_b = call_data.tokenB;
+
+        if token_a > token_b {
+            std::mem::swap(&mut token_a, &mut token_b)
+        }
+
+        vec![NormalizedNewPool {
+            pool_address: deployed_address,
+            trace_index,
+            protocol: Protocol::RamsesV2,
+            tokens: vec![token_a, token_b],
+        }]
+    }
+);
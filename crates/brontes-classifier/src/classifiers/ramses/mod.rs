@@ -0,0 +1,9 @@
+mod discovery;
+#[allow(non_snake_case)]
+mod ramses_v1;
+#[allow(non_snake_case)]
+mod ramses_v2;
+
+pub use discovery::*;
+pub use ramses_v1::*;
+pub use ramses_v2::*;
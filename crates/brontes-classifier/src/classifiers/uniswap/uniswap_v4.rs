@@ -1,73 +1,319 @@
-use alloy_primitives::{keccak256, Address, U256};
-use alloy_sol_types::{sol_data, SolType};
+use alloy_primitives::{keccak256, Address, I256, U256};
+use alloy_sol_types::{sol_data, SolCall, SolType};
 use brontes_macros::action_impl;
 use brontes_pricing::Protocol;
 use brontes_types::{
-    normalized_actions::NormalizedSwap, structured_trace::CallInfo, ToScaledRational,
+    db::{address_to_protocol_info::UniswapV4PoolKey, token_info::TokenInfoWithAddress},
+    normalized_actions::{
+        pool::NormalizedPoolConfigUpdate, Action, NormalizedBurn, NormalizedCollect,
+        NormalizedMint, NormalizedSwap, NormalizedSwapWithFee,
+    },
+    structured_trace::{CallFrameInfo, CallInfo},
+    ToScaledRational,
 };
+use malachite::Rational;
+
+use crate::UniswapV4::PoolKey;
+
+action_impl!(
+    Protocol::UniswapV4,
+    crate::UniswapV4::initializeCall,
+    PoolConfigUpdate,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: initializeCall, _| {
+        let pool_key = uniswap_v4_pool_key(&call_data.key);
+
+        Ok(NormalizedPoolConfigUpdate {
+            trace_index: info.trace_idx,
+            protocol: Protocol::UniswapV4,
+            pool_address: pool_key.pool_address(),
+            tokens: vec![call_data.key.currency0, call_data.key.currency1],
+            uniswap_v4_pool_key: Some(pool_key),
+        })
+    }
+);
 
 action_impl!(
     Protocol::UniswapV4,
     crate::UniswapV4::swapCall,
-    Swap,
+    Action,
     [Swap],
+    logs: true,
     call_data: true,
     return_data: true,
     |
     info: CallInfo,
     call_data: swapCall,
     return_data: swapReturn,
+    log_data: UniswapV4SwapCallLogs,
     db_tx: &DB| {
+        let logs = log_data.swap_field?;
+        let pool_key = uniswap_v4_pool_key(&call_data.key);
 
-        type PoolKey = (sol_data::Address, sol_data::Address, sol_data::Uint<256>, sol_data::Uint<256>, sol_data::Address);
+        let t0_info = db_tx.try_fetch_token_info(call_data.key.currency0)?;
+        let t1_info = db_tx.try_fetch_token_info(call_data.key.currency1)?;
 
-        let call_params=call_data.params;
-        let pool_key=call_data.key;
+        // the event holds what the pool traded, the returned delta what the
+        // caller settled, which includes anything taken by the hook
+        let pool_amounts = (logs.amount0.unsigned_abs(), logs.amount1.unsigned_abs());
+        let (caller0, caller1) = unpack_balance_delta(return_data.swapDelta);
+        let caller_amounts = (caller0.unsigned_abs(), caller1.unsigned_abs());
 
-        let encoded_data = PoolKey::abi_encode(&
-            (
-                pool_key.currency0,
-                pool_key.currency1,
-                U256::from(pool_key.fee),
-                U256::from(pool_key.tickSpacing as u64),
-                pool_key.hooks,
-            )
-        );
-        let target_address = Address::from_slice(&keccak256(&encoded_data)[..20]);
-        let exact_in=call_params.amountSpecified.is_negative();
-        let zero_for_one=call_params.zeroForOne;
-
-        let swap_delta=return_data.swapDelta;
-
-        let t0_info = db_tx.try_fetch_token_info(pool_key.currency0)?;
-        let t1_info = db_tx.try_fetch_token_info(pool_key.currency1)?;
-        let (token_in, token_out)= if zero_for_one {
-            (t0_info, t1_info)
-        }else {
-            (t1_info, t0_info)
-        };
-        let (amount_in, amount_out) =
-            if exact_in {
-                (call_params.amountSpecified.abs().to_scaled_rational(token_in.decimals),swap_delta.abs().to_scaled_rational(token_out.decimals))
-            }else {
-                (swap_delta.abs().to_scaled_rational(token_in.decimals),call_params.amountSpecified.abs().to_scaled_rational(token_out.decimals))
+        let (token_in, token_out, (pool_in, pool_out), (caller_in, caller_out)) =
+            if call_data.params.zeroForOne {
+                (t0_info, t1_info, pool_amounts, caller_amounts)
+            } else {
+                (
+                    t1_info,
+                    t0_info,
+                    (pool_amounts.1, pool_amounts.0),
+                    (caller_amounts.1, caller_amounts.0),
+                )
             };
 
-        Ok(NormalizedSwap {
+        // swaps made by the hook itself are attributed to the hook
+        let (from, recipient) = if pool_key.has_hooks() && info.from_address == pool_key.hooks {
+            (pool_key.hooks, pool_key.hooks)
+        } else {
+            (info.from_address, info.msg_sender)
+        };
+
+        let swap = NormalizedSwap {
             protocol: Protocol::UniswapV4,
             trace_index: info.trace_idx,
-            from: info.from_address,
-            pool: target_address,
-            recipient: info.msg_sender,
+            from,
+            recipient,
+            pool: pool_key.pool_address(),
+            amount_in: U256::from(caller_in).to_scaled_rational(token_in.decimals),
+            amount_out: U256::from(caller_out).to_scaled_rational(token_out.decimals),
             token_in,
             token_out,
-            amount_in,
-            amount_out,
-            msg_value: info.msg_value
+            msg_value: info.msg_value,
+        };
+
+        let hook_fee = match hook_fee((pool_in, pool_out), (caller_in, caller_out)) {
+            Some(HookFee::In(fee)) => Some((swap.token_in.clone(), fee)),
+            Some(HookFee::Out(fee)) => Some((swap.token_out.clone(), fee)),
+            None => None,
+        };
+
+        Ok(match hook_fee {
+            Some((fee_token, fee)) => Action::SwapWithFee(NormalizedSwapWithFee {
+                fee_amount: U256::from(fee).to_scaled_rational(fee_token.decimals),
+                fee_token,
+                swap,
+            }),
+            None => Action::Swap(swap),
+        })
+    }
+);
+
+action_impl!(
+    Protocol::UniswapV4,
+    crate::UniswapV4::modifyLiquidityCall,
+    Action,
+    [],
+    call_data: true,
+    return_data: true,
+    extra_actions: accrued_fees,
+    |
+    info: CallInfo,
+    call_data: modifyLiquidityCall,
+    return_data: modifyLiquidityReturn,
+    db_tx: &DB| {
+        let pool = uniswap_v4_pool_key(&call_data.key).pool_address();
+        let token = vec![
+            db_tx.try_fetch_token_info(call_data.key.currency0)?,
+            db_tx.try_fetch_token_info(call_data.key.currency1)?,
+        ];
+
+        // the caller delta is the principal plus any fees that were accrued
+        // by the position, which are always collected on modification. When
+        // the liquidity changes those fees become their own collect, see
+        // `accrued_fees`
+        let liquidity_delta = call_data.params.liquidityDelta;
+        let amounts = modify_liquidity_amounts(
+            unpack_balance_delta(return_data.callerDelta),
+            unpack_balance_delta(return_data.feesAccrued),
+            liquidity_delta.is_zero(),
+        );
+        let amount = scale_amounts(&token, amounts);
+
+        Ok(if liquidity_delta.is_positive() {
+            Action::Mint(NormalizedMint {
+                protocol: Protocol::UniswapV4,
+                trace_index: info.trace_idx,
+                from: info.from_address,
+                recipient: info.from_address,
+                pool,
+                token,
+                amount,
+            })
+        } else if liquidity_delta.is_negative() {
+            Action::Burn(NormalizedBurn {
+                protocol: Protocol::UniswapV4,
+                trace_index: info.trace_idx,
+                from: info.from_address,
+                recipient: info.from_address,
+                pool,
+                token,
+                amount,
+            })
+        } else {
+            Action::Collect(NormalizedCollect {
+                protocol: Protocol::UniswapV4,
+                trace_index: info.trace_idx,
+                from: info.from_address,
+                recipient: info.from_address,
+                pool,
+                token,
+                amount,
+            })
+        })
+    }
+);
+
+action_impl!(
+    Protocol::UniswapV4,
+    crate::UniswapV4::donateCall,
+    Mint,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: donateCall,
+    return_data: donateReturn,
+    db_tx: &DB| {
+        let pool = uniswap_v4_pool_key(&call_data.key).pool_address();
+        let token = vec![
+            db_tx.try_fetch_token_info(call_data.key.currency0)?,
+            db_tx.try_fetch_token_info(call_data.key.currency1)?,
+        ];
+        // what the donor settled, including anything taken by the hook
+        let (delta0, delta1) = unpack_balance_delta(return_data.delta);
+        let amount = scale_amounts(&token, [delta0, delta1]);
+
+        // a donation is liquidity added on behalf of the in range positions
+        Ok(NormalizedMint {
+            protocol: Protocol::UniswapV4,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            recipient: pool,
+            pool,
+            token,
+            amount,
         })
     }
 );
 
+/// The fees a `modifyLiquidity` that changed the position's liquidity
+/// collected on the way, classified next to its mint or burn
+fn accrued_fees(action: &Action, call_info: &CallFrameInfo<'_>) -> Option<Action> {
+    let (protocol, trace_index, from, recipient, pool, token) = match action {
+        Action::Mint(m) => (m.protocol, m.trace_index, m.from, m.recipient, m.pool, &m.token),
+        Action::Burn(b) => (b.protocol, b.trace_index, b.from, b.recipient, b.pool, &b.token),
+        // a modification that doesn't change the liquidity is the collect itself
+        _ => return None,
+    };
+
+    let fees = modifyLiquidityCall::abi_decode_returns(&call_info.return_data, false).ok()?;
+    let (fees0, fees1) = unpack_balance_delta(fees.feesAccrued);
+    if fees0 == 0 && fees1 == 0 {
+        return None
+    }
+
+    Some(Action::Collect(NormalizedCollect {
+        protocol,
+        trace_index,
+        from,
+        recipient,
+        pool,
+        amount: scale_amounts(token, [fees0, fees1]),
+        token: token.clone(),
+    }))
+}
+
+/// V4 pools are identified by the hash of their `PoolKey`, the first 20
+/// bytes of which are used as the pool's address
+pub fn uniswap_v4_pool_key(key: &PoolKey) -> UniswapV4PoolKey {
+    type PoolKeyEncoding = (
+        sol_data::Address,
+        sol_data::Address,
+        sol_data::Uint<24>,
+        sol_data::Int<24>,
+        sol_data::Address,
+    );
+
+    let encoded = PoolKeyEncoding::abi_encode(&(
+        key.currency0,
+        key.currency1,
+        key.fee,
+        key.tickSpacing,
+        key.hooks,
+    ));
+
+    UniswapV4PoolKey {
+        pool_id:      keccak256(encoded),
+        fee:          key.fee,
+        tick_spacing: key.tickSpacing,
+        hooks:        key.hooks,
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum HookFee {
+    In(u128),
+    Out(u128),
+}
+
+/// A hook with return deltas can take a cut of either side of the swap: the
+/// caller then pays more than the pool received or gets less than the pool
+/// sent
+fn hook_fee(pool: (u128, u128), caller: (u128, u128)) -> Option<HookFee> {
+    let ((pool_in, pool_out), (caller_in, caller_out)) = (pool, caller);
+    if caller_in > pool_in {
+        Some(HookFee::In(caller_in - pool_in))
+    } else if pool_out > caller_out {
+        Some(HookFee::Out(pool_out - caller_out))
+    } else {
+        None
+    }
+}
+
+/// The fees accrued by the position when only collecting, the principal moved
+/// otherwise
+fn modify_liquidity_amounts(
+    (caller0, caller1): (i128, i128),
+    (fees0, fees1): (i128, i128),
+    is_collect: bool,
+) -> [i128; 2] {
+    if is_collect {
+        [fees0, fees1]
+    } else {
+        [caller0.saturating_sub(fees0), caller1.saturating_sub(fees1)]
+    }
+}
+
+/// A `BalanceDelta` packs the currency0 amount in the upper 128 bits and the
+/// currency1 amount in the lower 128 bits
+fn unpack_balance_delta(delta: I256) -> (i128, i128) {
+    let limbs = delta.into_raw().into_limbs();
+    let amount0 = (limbs[2] as u128 | (limbs[3] as u128) << 64) as i128;
+    let amount1 = (limbs[0] as u128 | (limbs[1] as u128) << 64) as i128;
+
+    (amount0, amount1)
+}
+
+fn scale_amounts(tokens: &[TokenInfoWithAddress], amounts: [i128; 2]) -> Vec<Rational> {
+    tokens
+        .iter()
+        .zip(amounts)
+        .map(|(token, amount)| U256::from(amount.unsigned_abs()).to_scaled_rational(token.decimals))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -75,7 +321,7 @@ mod tests {
     use alloy_primitives::{hex, Address, B256};
     use brontes_classifier::test_utils::ClassifierTestUtils;
     use brontes_types::{
-        db::token_info::TokenInfoWithAddress,
+        db::token_info::{TokenInfo, TokenInfoWithAddress},
         normalized_actions::{
             Action, NormalizedBurn, NormalizedCollect, NormalizedMint, NormalizedSwap,
         },
@@ -85,6 +331,103 @@ mod tests {
 
     use super::*;
 
+    fn balance_delta(amount0: i128, amount1: i128) -> I256 {
+        let raw = (U256::from(amount0 as u128) << 128) | U256::from(amount1 as u128);
+        I256::from_raw(raw)
+    }
+
+    fn modify_liquidity_frame(fees_accrued: I256) -> CallFrameInfo<'static> {
+        CallFrameInfo {
+            trace_idx:      4,
+            call_data:      Default::default(),
+            return_data:    modifyLiquidityCall::abi_encode_returns(&(
+                balance_delta(10_000, 20_000),
+                fees_accrued,
+            ))
+            .into(),
+            target_address: Address::repeat_byte(8),
+            from_address:   Address::repeat_byte(1),
+            logs:           &[],
+            delegate_logs:  vec![],
+            msg_sender:     Address::repeat_byte(1),
+            msg_value:      U256::ZERO,
+        }
+    }
+
+    fn token(byte: u8, decimals: u8) -> TokenInfoWithAddress {
+        TokenInfoWithAddress {
+            address: Address::repeat_byte(byte),
+            inner:   TokenInfo { decimals, symbol: String::new() },
+        }
+    }
+
+    #[test]
+    fn test_unpack_balance_delta() {
+        assert_eq!(unpack_balance_delta(balance_delta(-5, 7)), (-5, 7));
+        assert_eq!(unpack_balance_delta(balance_delta(3, -1)), (3, -1));
+        assert_eq!(
+            unpack_balance_delta(balance_delta(i128::MIN, i128::MAX)),
+            (i128::MIN, i128::MAX)
+        );
+    }
+
+    #[test]
+    fn test_swap_hook_fee() {
+        // the pool took exactly what the caller paid
+        assert_eq!(hook_fee((100, 95), (100, 95)), None);
+        // the hook kept 3 of the input
+        assert_eq!(hook_fee((100, 95), (103, 95)), Some(HookFee::In(3)));
+        // the hook kept 2 of the output
+        assert_eq!(hook_fee((100, 95), (100, 93)), Some(HookFee::Out(2)));
+    }
+
+    #[test]
+    fn test_modify_liquidity_splits_out_fees() {
+        // adding 100/200 while collecting 3/4 of fees leaves the caller owing
+        // 97/196
+        let caller = (-97, -196);
+        let fees = (3, 4);
+        assert_eq!(modify_liquidity_amounts(caller, fees, false), [-100, -200]);
+        assert_eq!(modify_liquidity_amounts((3, 4), fees, true), [3, 4]);
+    }
+
+    #[test]
+    fn test_accrued_fees_become_a_collect() {
+        let pool = Address::repeat_byte(9);
+        let lp = Address::repeat_byte(1);
+        let tokens = vec![token(2, 18), token(3, 6)];
+        let burn = Action::Burn(NormalizedBurn {
+            protocol: UniswapV4,
+            trace_index: 4,
+            from: lp,
+            recipient: lp,
+            pool,
+            token: tokens.clone(),
+            amount: vec![Rational::from(1), Rational::from(1)],
+        });
+
+        let frame = modify_liquidity_frame(balance_delta(1_000, 2_000));
+        let Some(Action::Collect(collect)) = accrued_fees(&burn, &frame) else {
+            panic!("expected the accrued fees to be collected")
+        };
+        assert_eq!(collect.trace_index, 4);
+        assert_eq!(collect.recipient, lp);
+        assert_eq!(collect.token, tokens);
+        assert_eq!(
+            collect.amount,
+            vec![U256::from(1_000).to_scaled_rational(18), U256::from(2_000).to_scaled_rational(6)]
+        );
+
+        // nothing accrued
+        let frame = modify_liquidity_frame(balance_delta(0, 0));
+        assert!(accrued_fees(&burn, &frame).is_none());
+
+        // a modification that only collects is classified as the collect itself
+        let frame = modify_liquidity_frame(balance_delta(1, 2));
+        let collect = accrued_fees(&burn, &frame).unwrap();
+        assert!(accrued_fees(&collect, &frame).is_none());
+    }
+
     #[brontes_macros::test]
     async fn test_univ4_swap() {
         let classifier_utils = ClassifierTestUtils::new().await;
@@ -6,7 +6,7 @@ use alloy_sol_macro::sol;
 use alloy_sol_types::SolEvent;
 use brontes_database::libmdbx::{DBWriter, LibmdbxReader};
 use brontes_types::{
-    constants::FLUID_VAULT_RESOLVER_ADDRESS, normalized_actions::pool::NormalizedNewPool,
+    constants::arbitrum::FLUID_VAULT_RESOLVER_ADDRESS, normalized_actions::pool::NormalizedNewPool,
     traits::TracingProvider, Protocol,
 };
 use futures::future::join_all;
@@ -52,6 +52,11 @@ sol!(
     FluidDEX,
     "./classifier-abis/fluid/FluidDexFactory.json"
 );
+sol!(
+    #![sol(all_derives)]
+    RamsesV1,
+    "./classifier-abis/ramses/RamsesPairFactory.json"
+);
 sol!(
     #![sol(all_derives)]
     PendleV2Market,
@@ -80,7 +85,14 @@ pub async fn decode_event<T: TracingProvider>(
             let decoded = UniswapV2::PairCreated::decode_log(plog, true)?;
             (decoded.pair, vec![decoded.token0, decoded.token1])
         }
-        Protocol::UniswapV3 | Protocol::SushiSwapV3 | Protocol::PancakeSwapV3 => {
+        Protocol::RamsesV1 => {
+            let decoded = RamsesV1::PairCreated::decode_log(plog, true)?;
+            (decoded.pair, vec![decoded.token0, decoded.token1])
+        }
+        Protocol::UniswapV3
+        | Protocol::SushiSwapV3
+        | Protocol::PancakeSwapV3
+        | Protocol::RamsesV2 => {
             let decoded = UniswapV3::PoolCreated::decode_log(plog, true)?;
             (decoded.pool, vec![decoded.token0, decoded.token1])
         }
@@ -89,6 +89,7 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> DiscoveryOnlyClassif
                             effective_gas_price: trace.effective_price,
                             priority_fee:        trace.effective_price
                                 - (header.base_fee_per_gas.unwrap_or_default() as u128),
+                            l1_gas_used:         trace.gas_used_for_l1,
                         },
                         data_store: NodeData(vec![Some(action)]),
                         timeboosted: trace.timeboosted,
@@ -233,6 +234,16 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> DiscoveryOnlyClassif
                 {
                     error!(pool=?p.pool_address,"failed to update pool config");
                 }
+                if let Some(pool_key) = p.uniswap_v4_pool_key.clone() {
+                    if self
+                        .libmdbx
+                        .insert_uniswap_v4_pool_key(p.pool_address, pool_key)
+                        .await
+                        .is_err()
+                    {
+                        error!(pool=?p.pool_address, "failed to store uniswap v4 pool key");
+                    }
+                }
             }
         } else {
             self.classify_transfer(trace_index, &trace, block).await
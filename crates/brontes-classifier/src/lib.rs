@@ -58,7 +58,11 @@ sol!(MakerDssFlash, "./classifier-abis/maker/MakerDssFlash.json");
 sol!(CompoundV2CToken, "./classifier-abis/CompoundV2CToken.json");
 sol!(Comet, "./classifier-abis/Comet.json");
 sol!(OneInchAggregationRouterV5, "./classifier-abis/OneInchAggregationRouterV5.json");
+sol!(OneInchAggregationRouterV6, "./classifier-abis/OneInchAggregationRouterV6.json");
 sol!(OneInchFusionSettlement, "./classifier-abis/OneInchFusionSettlement.json");
+sol!(OdosRouterV2, "./classifier-abis/odos/OdosRouterV2.json");
+sol!(ParaSwapAugustusV6, "./classifier-abis/paraswap/AugustusV6.json");
+sol!(KyberSwapMetaAggregationRouterV2, "./classifier-abis/kyberswap/MetaAggregationRouterV2.json");
 sol!(ClipperExchange, "./classifier-abis/ClipperExchange.json");
 sol!(CowswapGPv2Settlement, "./classifier-abis/cowswap/GPv2Settlement.json");
 sol!(ZeroXUniswapFeaure, "./classifier-abis/zero-x/ZeroXUniswapFeature.json");
@@ -81,6 +85,12 @@ sol!(PendlePrincipalToken, "./classifier-abis/pendle_v2/PendlePrincipalToken.jso
 sol!(PendleYieldToken, "./classifier-abis/pendle_v2/PendleYieldToken.json");
 sol!(PendleSYToken, "./classifier-abis/pendle_v2/IStandardizedYield.json");
 sol!(GMXV1, "./classifier-abis/gmx_v1/Vault.json");
+sol!(GMXV2ExchangeRouter, "./classifier-abis/gmx_v2/ExchangeRouter.json");
+sol!(GMXV2OrderHandler, "./classifier-abis/gmx_v2/OrderHandler.json");
+sol!(GMXV2LiquidationHandler, "./classifier-abis/gmx_v2/LiquidationHandler.json");
+sol!(GMXV2AdlHandler, "./classifier-abis/gmx_v2/AdlHandler.json");
+sol!(GMXV2SwapHandler, "./classifier-abis/gmx_v2/SwapHandler.json");
+sol!(GMXV2EventEmitter, "./classifier-abis/gmx_v2/EventEmitter.json");
 sol!(BalancerV3Vault, "./classifier-abis/balancer/BalancerV3Vault.json");
 sol!(FluidDexT1, "./classifier-abis/fluid/FluidDexT1.json");
 sol!(FluidLending, "./classifier-abis/fluid/FluidLending.json");
@@ -89,6 +99,13 @@ sol!(FluidVaultResolver, "./classifier-abis/fluid/FluidVaultResolver.json");
 sol!(FluidDexResolver, "./classifier-abis/fluid/FluidDexResolver.json");
 sol!(FluidSmartLending, "./classifier-abis/fluid/FluidSmartLending.json");
 sol!(FluidVault, "./classifier-abis/fluid/FluidVault.json");
+sol!(ERC4626, "./classifier-abis/ERC4626.json");
+sol!(AcrossSpokePool, "./classifier-abis/across/SpokePool.json");
+sol!(StargatePool, "./classifier-abis/stargate/StargatePool.json");
+sol!(StargateRouter, "./classifier-abis/stargate/StargateRouter.json");
+sol!(HopL2AmmWrapper, "./classifier-abis/hop/L2AmmWrapper.json");
+sol!(HopL2Bridge, "./classifier-abis/hop/L2Bridge.json");
+sol!(CCTPTokenMessenger, "./classifier-abis/cctp/TokenMessenger.json");
 // Discovery
 sol!(BalancerV3VaultExtension, "./classifier-abis/balancer/BalancerV3VaultExtension.json");
 sol!(UniswapV2Factory, "./classifier-abis/UniswapV2Factory.json");
@@ -111,6 +128,7 @@ sol!(DodoDSPFactory, "./classifier-abis/dodo/DSPFactory.json");
 sol!(MaverickV2Factory, "./classifier-abis/maverick_v2/MaverickV2Factory.json");
 sol!(CamelotV2Factory, "./classifier-abis/CamelotV2Factory.json");
 sol!(CamelotV3Factory, "./classifier-abis/Algebra1_9Factory.json");
+sol!(RamsesPairFactory, "./classifier-abis/ramses/RamsesPairFactory.json");
 sol!(LFJFactory, "./classifier-abis/LFJ/ILBFactory.json");
 sol!(LFJV2_2Factory, "./classifier-abis/LFJ/ILBFactory.json");
 sol!(PendleMarketV3Factory, "./classifier-abis/pendle_v2/PendleMarketFactoryV3.json");
@@ -119,6 +137,7 @@ sol!(FluidDexFactory, "./classifier-abis/fluid/FluidDexFactory.json");
 sol!(DolomiteAdmin, "./classifier-abis/dolomite/Admin.json");
 sol!(FluidForwarder, "./classifier-abis/fluid/AvocadoForwarder.json");
 sol!(FluidVaultFactory, "./classifier-abis/fluid/FluidVaultFactory.json");
+sol!(FluidLendingFactory, "./classifier-abis/fluid/FluidLendingFactory.json");
 // Balancer Pool Interfaces
 sol! {
     enum SwapKind {
@@ -175,7 +194,7 @@ pub trait ActionCollection: Sync + Send {
         block: u64,
         tx_idx: u64,
         tracer: Arc<T>,
-    ) -> Option<(DexPriceMsg, Action)>;
+    ) -> Option<(DexPriceMsg, Action, Vec<Action>)>;
 }
 
 pub trait IntoAction: Debug + Send + Sync {
@@ -186,7 +205,12 @@ pub trait IntoAction: Debug + Send + Sync {
         tx_idx: u64,
         db_tx: &DB,
         tracer: Arc<T>,
-    ) -> eyre::Result<DexPriceMsg>;
+    ) -> eyre::Result<(DexPriceMsg, Vec<Action>)>;
+
+    /// Whether the call is left unclassified without being decoded
+    fn skip_call(&self, _call_data: &[u8]) -> bool {
+        false
+    }
 }
 
 pub trait FactoryDiscovery {
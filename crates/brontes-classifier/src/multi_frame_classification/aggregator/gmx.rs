@@ -0,0 +1,53 @@
+use brontes_types::{
+    normalized_actions::{
+        Action, MultiCallFrameClassification, MultiFrameAction, MultiFrameRequest,
+    },
+    Protocol, TreeSearchBuilder,
+};
+
+use crate::multi_frame_classification::MultiCallFrameClassifier;
+
+/// Keeper execution of a GMX V2 order or ADL, made up of the swaps and position
+/// changes it triggers
+pub struct GMXV2Order;
+
+impl MultiCallFrameClassifier for GMXV2Order {
+    const KEY: [u8; 2] = [Protocol::GMXV2 as u8, MultiFrameAction::Aggregator as u8];
+
+    fn create_classifier(
+        request: MultiFrameRequest,
+    ) -> Option<MultiCallFrameClassification<Action>> {
+        Some(MultiCallFrameClassification {
+            trace_index:         request.trace_idx,
+            tree_search_builder: TreeSearchBuilder::new().with_actions([
+                Action::is_swap,
+                Action::is_position_change,
+                Action::is_transfer,
+                Action::is_eth_transfer,
+            ]),
+            parse_fn:            Box::new(|this_action, child_nodes| {
+                let this = this_action.try_aggregator_mut().unwrap();
+                let mut prune_nodes = Vec::new();
+
+                for (trace_index, action) in child_nodes {
+                    match &action {
+                        Action::PositionChange(position) => {
+                            this.recipient = position.account;
+                        }
+                        Action::Swap(swap) if this.recipient == this.from => {
+                            this.recipient = swap.recipient;
+                        }
+                        Action::Swap(_)
+                        | Action::SwapWithFee(_)
+                        | Action::Transfer(_)
+                        | Action::EthTransfer(_) => {}
+                        _ => continue,
+                    }
+                    this.child_actions.push(action);
+                    prune_nodes.push(trace_index);
+                }
+                prune_nodes
+            }),
+        })
+    }
+}
@@ -1,4 +1,8 @@
+pub mod gmx;
+pub use gmx::*;
 pub mod one_inch;
 pub use one_inch::*;
+pub mod routers;
+pub use routers::*;
 pub mod zero_x;
 pub use zero_x::*;
@@ -0,0 +1,119 @@
+use brontes_types::{
+    normalized_actions::{
+        Action, MultiCallFrameClassification, MultiFrameAction, MultiFrameRequest, NodeDataIndex,
+    },
+    Protocol, TreeSearchBuilder,
+};
+
+use crate::multi_frame_classification::MultiCallFrameClassifier;
+
+pub struct OdosAggregator;
+pub struct ParaSwapAggregator;
+pub struct KyberSwapAggregator;
+
+impl MultiCallFrameClassifier for OdosAggregator {
+    const KEY: [u8; 2] = [Protocol::OdosV2 as u8, MultiFrameAction::Aggregator as u8];
+
+    fn create_classifier(
+        request: MultiFrameRequest,
+    ) -> Option<MultiCallFrameClassification<Action>> {
+        Some(router_classifier(request))
+    }
+}
+
+impl MultiCallFrameClassifier for ParaSwapAggregator {
+    const KEY: [u8; 2] = [Protocol::ParaSwapV6 as u8, MultiFrameAction::Aggregator as u8];
+
+    fn create_classifier(
+        request: MultiFrameRequest,
+    ) -> Option<MultiCallFrameClassification<Action>> {
+        Some(router_classifier(request))
+    }
+}
+
+impl MultiCallFrameClassifier for KyberSwapAggregator {
+    const KEY: [u8; 2] = [Protocol::KyberSwap as u8, MultiFrameAction::Aggregator as u8];
+
+    fn create_classifier(
+        request: MultiFrameRequest,
+    ) -> Option<MultiCallFrameClassification<Action>> {
+        Some(router_classifier(request))
+    }
+}
+
+/// These routers hand the route to an executor contract, so everything below
+/// the router call belongs to the aggregated swap
+fn router_classifier(request: MultiFrameRequest) -> MultiCallFrameClassification<Action> {
+    MultiCallFrameClassification {
+        trace_index:         request.trace_idx,
+        tree_search_builder: TreeSearchBuilder::new().with_actions([
+            Action::is_swap,
+            Action::is_transfer,
+            Action::is_eth_transfer,
+        ]),
+        parse_fn:            Box::new(parse_router),
+    }
+}
+
+fn parse_router(
+    this_action: &mut Action,
+    child_nodes: Vec<(NodeDataIndex, Action)>,
+) -> Vec<NodeDataIndex> {
+    let this = this_action.try_aggregator_mut().unwrap();
+    let mut prune_nodes = Vec::new();
+
+    for (trace_index, action) in child_nodes {
+        match action {
+            Action::Swap(_)
+            | Action::SwapWithFee(_)
+            | Action::Transfer(_)
+            | Action::EthTransfer(_) => {
+                this.child_actions.push(action);
+                prune_nodes.push(trace_index);
+            }
+            _ => {}
+        }
+    }
+    prune_nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Address, U256};
+    use brontes_types::normalized_actions::{
+        NormalizedAggregator, NormalizedMint, NormalizedSwap, NormalizedTransfer,
+    };
+
+    use super::*;
+
+    fn node(trace_index: u64) -> NodeDataIndex {
+        NodeDataIndex { trace_index, data_idx: 0, multi_data_idx: 0 }
+    }
+
+    #[test]
+    fn test_router_takes_the_route_below_it() {
+        let mut aggregator = Action::Aggregator(NormalizedAggregator {
+            protocol:      Protocol::OdosV2,
+            trace_index:   0,
+            from:          Address::repeat_byte(1),
+            to:            Address::repeat_byte(2),
+            recipient:     Address::repeat_byte(1),
+            child_actions: vec![],
+            msg_value:     U256::ZERO,
+        });
+
+        let children = vec![
+            (node(1), Action::Transfer(NormalizedTransfer::default())),
+            (node(2), Action::Swap(NormalizedSwap::default())),
+            (node(3), Action::Mint(NormalizedMint::default())),
+        ];
+        let pruned = parse_router(&mut aggregator, children);
+
+        let pruned = pruned
+            .iter()
+            .map(|node| node.trace_index)
+            .collect::<Vec<_>>();
+        assert_eq!(pruned, vec![1, 2]);
+        assert_eq!(aggregator.try_aggregator_ref().unwrap().child_actions.len(), 2);
+    }
+}
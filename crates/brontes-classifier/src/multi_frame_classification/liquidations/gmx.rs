@@ -0,0 +1,148 @@
+use brontes_types::{
+    normalized_actions::{
+        Action, MultiCallFrameClassification, MultiFrameAction, MultiFrameRequest, NodeDataIndex,
+    },
+    Protocol, TreeSearchBuilder,
+};
+
+use crate::multi_frame_classification::MultiCallFrameClassifier;
+
+pub struct GMXV1Liquidation;
+pub struct GMXV2Liquidation;
+
+impl MultiCallFrameClassifier for GMXV1Liquidation {
+    const KEY: [u8; 2] = [Protocol::GMXV1 as u8, MultiFrameAction::Liquidation as u8];
+
+    fn create_classifier(
+        request: MultiFrameRequest,
+    ) -> Option<MultiCallFrameClassification<Action>> {
+        Some(MultiCallFrameClassification {
+            trace_index:         request.trace_idx,
+            tree_search_builder: TreeSearchBuilder::new().with_action(Action::is_transfer),
+            parse_fn:            Box::new(parse_keeper_fee),
+        })
+    }
+}
+
+impl MultiCallFrameClassifier for GMXV2Liquidation {
+    const KEY: [u8; 2] = [Protocol::GMXV2 as u8, MultiFrameAction::Liquidation as u8];
+
+    fn create_classifier(
+        request: MultiFrameRequest,
+    ) -> Option<MultiCallFrameClassification<Action>> {
+        Some(MultiCallFrameClassification {
+            trace_index:         request.trace_idx,
+            tree_search_builder: TreeSearchBuilder::new().with_action(Action::is_transfer),
+            parse_fn:            Box::new(parse_keeper_fee),
+        })
+    }
+}
+
+/// The liquidator is only paid what is transferred to it out of the
+/// position's collateral. On v1 that is the liquidation fee, v2 keepers are
+/// usually paid nothing and the rest of the collateral goes back to the pool.
+fn parse_keeper_fee(
+    this: &mut Action,
+    child_nodes: Vec<(NodeDataIndex, Action)>,
+) -> Vec<NodeDataIndex> {
+    let this = this.try_liquidation_mut().unwrap();
+    this.liquidated_collateral = child_nodes
+        .into_iter()
+        .filter_map(|(_, action)| action.try_transfer())
+        .filter(|transfer| {
+            transfer.to == this.liquidator
+                && transfer.token.address == this.collateral_asset.address
+        })
+        .map(|transfer| transfer.amount)
+        .sum();
+
+    vec![]
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::Address;
+    use brontes_types::{
+        db::token_info::{TokenInfo, TokenInfoWithAddress},
+        normalized_actions::{NormalizedLiquidation, NormalizedTransfer},
+    };
+    use malachite::Rational;
+
+    use super::*;
+
+    fn token(byte: u8) -> TokenInfoWithAddress {
+        TokenInfoWithAddress {
+            address: Address::repeat_byte(byte),
+            inner:   TokenInfo { decimals: 18, symbol: String::new() },
+        }
+    }
+
+    fn transfer(to: Address, token: TokenInfoWithAddress, amount: u64) -> (NodeDataIndex, Action) {
+        let index = NodeDataIndex { trace_index: 1, data_idx: 0, multi_data_idx: 0 };
+        let transfer =
+            NormalizedTransfer { to, token, amount: Rational::from(amount), ..Default::default() };
+
+        (index, Action::Transfer(transfer))
+    }
+
+    #[test]
+    fn test_liquidator_is_only_credited_its_fee() {
+        let keeper = Address::repeat_byte(1);
+        let pool = Address::repeat_byte(2);
+        let mut liquidation = Action::Liquidation(NormalizedLiquidation {
+            protocol: Protocol::GMXV2,
+            liquidator: keeper,
+            debtor: Address::repeat_byte(3),
+            collateral_asset: token(10),
+            debt_asset: token(10),
+            ..Default::default()
+        });
+
+        let children = vec![
+            // the remaining collateral goes back to the pool
+            transfer(pool, token(10), 900),
+            transfer(keeper, token(10), 5),
+            transfer(keeper, token(11), 7),
+        ];
+        parse_keeper_fee(&mut liquidation, children);
+
+        let liquidation = liquidation.try_liquidation().unwrap();
+        assert_eq!(liquidation.liquidated_collateral, Rational::from(5));
+        assert_eq!(liquidation.covered_debt, Rational::from(0));
+    }
+
+    #[test]
+    fn test_gmx_v1_fee_receiver_gets_the_liquidation_fee() {
+        let fee_receiver = Address::repeat_byte(1);
+        let mut liquidation = Action::Liquidation(NormalizedLiquidation {
+            protocol: Protocol::GMXV1,
+            liquidator: fee_receiver,
+            debtor: Address::repeat_byte(3),
+            collateral_asset: token(10),
+            debt_asset: token(10),
+            ..Default::default()
+        });
+
+        parse_keeper_fee(&mut liquidation, vec![transfer(fee_receiver, token(10), 5)]);
+
+        let liquidation = liquidation.try_liquidation().unwrap();
+        assert_eq!(liquidation.liquidated_collateral, Rational::from(5));
+        assert_eq!(liquidation.covered_debt, Rational::from(0));
+    }
+
+    #[test]
+    fn test_keeper_without_transfers_makes_nothing() {
+        let mut liquidation = Action::Liquidation(NormalizedLiquidation {
+            protocol: Protocol::GMXV2,
+            liquidator: Address::repeat_byte(1),
+            collateral_asset: token(10),
+            debt_asset: token(10),
+            ..Default::default()
+        });
+
+        parse_keeper_fee(&mut liquidation, vec![transfer(Address::repeat_byte(2), token(10), 900)]);
+
+        let liquidation = liquidation.try_liquidation().unwrap();
+        assert_eq!(liquidation.liquidated_collateral, Rational::from(0));
+    }
+}
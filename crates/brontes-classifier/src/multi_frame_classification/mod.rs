@@ -9,12 +9,15 @@ pub mod batch;
 pub mod flash_loan;
 pub mod liquidations;
 
-use aggregator::{OneInchAggregator, OneInchFusion, ZeroXAgg};
+use aggregator::{
+    GMXV2Order, KyberSwapAggregator, OdosAggregator, OneInchAggregator, OneInchFusion,
+    OneInchV6Aggregator, ParaSwapAggregator, ZeroXAgg,
+};
 use batch::{Cowswap, UniswapX, ZeroXBatch};
 use brontes_types::normalized_actions::{Action, MultiCallFrameClassification, MultiFrameRequest};
 use flash_loan::{BalancerV2, MakerDss};
 use itertools::Itertools;
-use liquidations::{AaveV2, AaveV3};
+use liquidations::{AaveV2, AaveV3, GMXV1Liquidation, GMXV2Liquidation};
 use tracing::debug;
 
 use self::flash_loan::Dodo;
@@ -39,6 +42,10 @@ pub fn parse_multi_frame_requests(
         .filter_map(|request| match request.make_key() {
             OneInchAggregator::KEY => OneInchAggregator::create_classifier(request),
             OneInchFusion::KEY => OneInchFusion::create_classifier(request),
+            OneInchV6Aggregator::KEY => OneInchV6Aggregator::create_classifier(request),
+            OdosAggregator::KEY => OdosAggregator::create_classifier(request),
+            ParaSwapAggregator::KEY => ParaSwapAggregator::create_classifier(request),
+            KyberSwapAggregator::KEY => KyberSwapAggregator::create_classifier(request),
             UniswapX::KEY => UniswapX::create_classifier(request),
             Cowswap::KEY => Cowswap::create_classifier(request),
             BalancerV2::KEY => BalancerV2::create_classifier(request),
@@ -48,6 +55,9 @@ pub fn parse_multi_frame_requests(
             ZeroXBatch::KEY => ZeroXBatch::create_classifier(request),
             MakerDss::KEY => MakerDss::create_classifier(request),
             Dodo::KEY => Dodo::create_classifier(request),
+            GMXV2Order::KEY => GMXV2Order::create_classifier(request),
+            GMXV1Liquidation::KEY => GMXV1Liquidation::create_classifier(request),
+            GMXV2Liquidation::KEY => GMXV2Liquidation::create_classifier(request),
             _ => {
                 debug!(?request, "no multi frame classification impl for this request");
                 None
@@ -638,6 +638,7 @@ impl ClassifierTestUtils {
                         token4,
                         curve_lp_token,
                         init_block: 0,
+                        uniswap_v4_pool_key: None,
                     },
                 },
             ])
@@ -4,6 +4,10 @@ use alloy_primitives::{Log, U256};
 use brontes_core::missing_token_info::load_missing_token_info;
 use brontes_pricing::types::PoolUpdate;
 use brontes_types::{
+    chain::chain_config,
+    constants::arbitrum::{
+        ARBITRUM_DEPOSIT_TX_TYPE, ARBITRUM_RETRY_TX_TYPE, ARBITRUM_SUBMIT_RETRYABLE_TX_TYPE,
+    },
     normalized_actions::{
         pool::NormalizedNewPool, MultiCallFrameClassification, MultiFrameRequest, NormalizedAction,
         NormalizedEthTransfer, NormalizedTransfer,
@@ -33,7 +37,13 @@ use tracing::{error, trace};
 use tree_pruning::{account_for_tax_tokens, remove_possible_transfer_double_counts};
 use utils::{decode_transfer, get_coinbase_transfer};
 
-use self::erc20::try_decode_transfer;
+use self::{
+    arbos::{
+        classify_l1_deposit, classify_retry_tx, try_classify_arbos_call,
+        try_classify_submit_retryable,
+    },
+    erc20::{is_native_unwrap_payout, try_classify_native_wrap, try_decode_transfer},
+};
 use crate::{
     classifiers::*, multi_frame_classification::parse_multi_frame_requests, ActionCollection,
     FactoryDiscoveryDispatch,
@@ -155,8 +165,25 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
                     let address = root_trace.get_from_addr();
                     let trace_idx = root_trace.trace_idx;
 
-                    let classification = self
-                        .process_classification(
+                    // deposits and retryable submissions are executed by ArbOS, their root
+                    // call is the action itself
+                    let arbos_classification = match trace.tx_type {
+                        ARBITRUM_DEPOSIT_TX_TYPE => {
+                            Some(classify_l1_deposit(&root_trace, trace_idx))
+                        }
+                        ARBITRUM_SUBMIT_RETRYABLE_TX_TYPE => {
+                            try_classify_submit_retryable(&root_trace, trace_idx, trace.tx_hash)
+                        }
+                        _ => None,
+                    };
+
+                    let retry_tx = (trace.tx_type == ARBITRUM_RETRY_TX_TYPE)
+                        .then(|| classify_retry_tx(&root_trace, trace_idx));
+
+                    let mut classification = if let Some(action) = arbos_classification {
+                        vec![action]
+                    } else {
+                        self.process_classification(
                             header.number,
                             None,
                             &NodeData(vec![]),
@@ -167,7 +194,9 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
                             &mut further_classification_requests,
                             &mut pool_updates,
                         )
-                        .await;
+                        .await
+                    };
+                    classification.extend(retry_tx);
 
                     let node = Node::new(trace_idx, address, vec![]);
 
@@ -188,6 +217,7 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
                             effective_gas_price: trace.effective_price,
                             priority_fee:        trace.effective_price
                                 - (header.base_fee_per_gas.unwrap_or_default() as u128),
+                            l1_gas_used:         trace.gas_used_for_l1,
                         },
                         data_store: NodeData(vec![Some(classification)]),
                         timeboosted: trace.timeboosted,
@@ -370,6 +400,14 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
         if trace.is_static_call() {
             return (vec![], vec![Action::Unclassified(trace)])
         }
+        if let Some(action) = try_classify_arbos_call(&trace, trace_index) {
+            return (vec![], vec![action])
+        }
+        // eth and the wrapped native token share a price, so there is no pool
+        // state to update
+        if let Some(action) = try_classify_native_wrap(&trace, trace_index, self.libmdbx) {
+            return (vec![], vec![action])
+        }
         let mut call_info = trace.get_callframe_info();
 
         // Add logs of delegated calls to the root trace, only if the delegated call is
@@ -410,9 +448,19 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
                 {
                     error!(pool=?p.pool_address,"failed to update pool config");
                 }
+                if let Some(pool_key) = p.uniswap_v4_pool_key.clone() {
+                    if self
+                        .libmdbx
+                        .insert_uniswap_v4_pool_key(p.pool_address, pool_key)
+                        .await
+                        .is_err()
+                    {
+                        error!(pool=?p.pool_address, "failed to store uniswap v4 pool key");
+                    }
+                }
             }
 
-            (vec![results.0], vec![results.1])
+            (vec![results.0], [results.1].into_iter().chain(results.2).collect())
         } else if let Some(transfer) = self
             .classify_transfer(tx_idx, trace_index, &trace, block)
             .await
@@ -538,6 +586,11 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
         trace: &TransactionTraceWithLogs,
         trace_index: u64,
     ) -> Option<Action> {
+        // the unwrap that paid the eth out already credits it
+        if is_native_unwrap_payout(trace) {
+            return None
+        }
+
         (trace.get_msg_value() > U256::ZERO && trace.get_calldata().is_empty()).then(|| {
             Action::EthTransfer(NormalizedEthTransfer {
                 from: trace.get_from_addr(),
@@ -621,6 +674,8 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
                 .dispatch(self.provider.clone(), search_data, created_addr, trace_index)
                 .await
                 .into_iter()
+                // pools of protocols that aren't deployed on the chain are false positives
+                .filter(|pool| chain_config().supports_protocol(pool.protocol))
                 // insert the pool returning if it has token values.
                 .map(|pool| async {
                     trace!(
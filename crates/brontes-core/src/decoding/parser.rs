@@ -334,6 +334,9 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter> TraceParser<T, DB> {
                     receipt.inner.transaction_index.unwrap(),
                     receipt.timeboosted,
                     receipt.inner.gas_used,
+                    receipt.gas_used_for_l1,
+                    receipt.l1_block_number,
+                    receipt.inner.inner.r#type,
                     receipt.inner.effective_gas_price,
                 )
             })
@@ -363,6 +366,9 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter> TraceParser<T, DB> {
         tx_idx: u64,
         timeboosted: bool,
         gas_used: u128,
+        gas_used_for_l1: u128,
+        l1_block_number: u64,
+        tx_type: u8,
         effective_gas_price: u128,
     ) -> (TxTrace, TransactionStats) {
         let stats = TransactionStats {
@@ -388,7 +394,11 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter> TraceParser<T, DB> {
         });
 
         tx_trace.effective_price = effective_gas_price;
-        tx_trace.gas_used = gas_used;
+        // arbitrum receipts report the L1 data gas as part of `gasUsed`
+        tx_trace.gas_used = gas_used.saturating_sub(gas_used_for_l1);
+        tx_trace.gas_used_for_l1 = gas_used_for_l1;
+        tx_trace.l1_block_number = l1_block_number;
+        tx_trace.tx_type = tx_type;
         tx_trace.timeboosted = timeboosted;
 
         (tx_trace, stats)
@@ -306,17 +306,17 @@ mod tests {
         // Create a filter with a specific address
         // Using USDC contract address on Ethereum mainnet as an example
         let addresses = vec![
-            brontes_types::constants::UNISWAP_V2_FACTORY_ADDRESS,
-            brontes_types::constants::UNISWAP_V3_FACTORY_ADDRESS,
-            brontes_types::constants::UNISWAP_V4_FACTORY_ADDRESS,
-            brontes_types::constants::BALANCER_V2_VAULT_ADDRESS,
-            brontes_types::constants::CAMELOT_V2_FACTORY_ADDRESS,
-            brontes_types::constants::CAMELOT_V3_FACTORY_ADDRESS,
-            brontes_types::constants::FLUID_DEX_FACTORY_ADDRESS,
-            brontes_types::constants::SUSHISWAP_V2_FACTORY_ADDRESS,
-            brontes_types::constants::SUSHISWAP_V3_FACTORY_ADDRESS,
-            brontes_types::constants::PANCAKESWAP_V2_FACTORY_ADDRESS,
-            brontes_types::constants::PANCAKESWAP_V3_FACTORY_ADDRESS,
+            brontes_types::constants::arbitrum::UNISWAP_V2_FACTORY_ADDRESS,
+            brontes_types::constants::arbitrum::UNISWAP_V3_FACTORY_ADDRESS,
+            brontes_types::constants::arbitrum::UNISWAP_V4_FACTORY_ADDRESS,
+            brontes_types::constants::arbitrum::BALANCER_V2_VAULT_ADDRESS,
+            brontes_types::constants::arbitrum::CAMELOT_V2_FACTORY_ADDRESS,
+            brontes_types::constants::arbitrum::CAMELOT_V3_FACTORY_ADDRESS,
+            brontes_types::constants::arbitrum::FLUID_DEX_FACTORY_ADDRESS,
+            brontes_types::constants::arbitrum::SUSHISWAP_V2_FACTORY_ADDRESS,
+            brontes_types::constants::arbitrum::SUSHISWAP_V3_FACTORY_ADDRESS,
+            brontes_types::constants::arbitrum::PANCAKESWAP_V2_FACTORY_ADDRESS,
+            brontes_types::constants::arbitrum::PANCAKESWAP_V3_FACTORY_ADDRESS,
         ];
 
         let topics = vec![
@@ -395,6 +395,9 @@ impl RpcClient {
                         tx_index: tx_index as u64,
                         timeboosted: false,
                         is_success,
+                        gas_used_for_l1: 0,
+                        l1_block_number: 0,
+                        tx_type: 0,
                     }
                 })
                 .collect()
@@ -444,6 +447,9 @@ impl RpcClient {
                         tx_index: tx_index as u64,
                         timeboosted: false,
                         is_success,
+                        gas_used_for_l1: 0,
+                        l1_block_number: 0,
+                        tx_type: 0,
                     }
                 })
                 .collect()
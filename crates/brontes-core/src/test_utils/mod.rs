@@ -13,7 +13,7 @@ use brontes_database::{
 };
 use brontes_metrics::ParserMetricEvents;
 use brontes_types::{
-    constants::USDT_ADDRESS,
+    constants::arbitrum::USDT_ADDRESS,
     db::{
         cex::trades::{window_loader::CexWindow, CexTradeMap},
         metadata::Metadata,
@@ -18,6 +18,7 @@ use brontes_types::{
             BestCexPerPair,
         },
         dex::{DexQuotes, DexQuotesWithBlockNumber},
+        express_lane::ExpressLaneRoundReport,
         metadata::{BlockMetadata, BlockMetadataInner, Metadata},
         normalized_actions::TransactionRoot,
         searcher::SearcherInfo,
@@ -204,6 +205,7 @@ impl Clickhouse {
                     BundleData::Liquidation(s) => {
                         tx.send(vec![(s, self.tip, self.run_id).into()])?
                     }
+                    BundleData::Timeboost(s) => tx.send(vec![(s, self.tip, self.run_id).into()])?,
                     BundleData::Unknown(s) => tx.send(vec![(s, self.tip, self.run_id).into()])?,
                 };
 
@@ -299,6 +301,24 @@ impl Clickhouse {
         Ok(())
     }
 
+    pub async fn write_express_lane_reports(
+        &self,
+        reports: Vec<ExpressLaneRoundReport>,
+    ) -> eyre::Result<()> {
+        if let Some(tx) = self.buffered_insert_tx.as_ref() {
+            // reports are written once per batch of rounds, so insert them right away
+            tx.send(
+                reports
+                    .into_iter()
+                    .map(|report| (report, true, self.run_id))
+                    .map(Into::into)
+                    .collect(),
+            )?
+        };
+
+        Ok(())
+    }
+
     /// Store transaction traces using the same tuple layout understood by
     /// `clickhouse_serde::tx_trace`'s deserializer.
     pub async fn save_traces(&self, block: u64, traces: Vec<TxTrace>) -> eyre::Result<()> {
@@ -1176,7 +1196,7 @@ mod tests {
         mev::{
             ArbDetails, AtomicArb, BundleHeader, CexDex, CexDexQuote, JitLiquidity,
             JitLiquiditySandwich, Liquidation, OptimisticTrade, PossibleMev, PossibleMevCollection,
-            Sandwich,
+            Sandwich, Timeboost, TimeboostStrategy,
         },
         normalized_actions::{
             NormalizedBurn, NormalizedLiquidation, NormalizedMint, NormalizedSwap,
@@ -1410,6 +1430,7 @@ mod tests {
                 priority_fee:        0,
                 gas_used:            271686,
                 effective_gas_price: 8875282233,
+                l1_gas_used:         0,
             },
             profit_usd:        12951.829205242997,
             protocols:         vec!["UniswapV2".to_string()],
@@ -1508,6 +1529,21 @@ mod tests {
             .unwrap();
     }
 
+    async fn timeboost(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
+        let swap = NormalizedSwap::default();
+        let case0 = Timeboost {
+            strategy: TimeboostStrategy::Backrun,
+            swaps: vec![swap.clone()],
+            victim_tx_hashes: vec![Default::default()],
+            victim_swaps: vec![vec![swap]],
+            ..Timeboost::default()
+        };
+
+        db.insert_one::<MevTimeboost>(&DbDataWithRunId::new_with_run_id(case0, 0))
+            .await
+            .unwrap();
+    }
+
     async fn pools(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
         let case0 = ProtocolInfoClickhouse {
             protocol:         "NONE".to_string(),
@@ -1537,6 +1573,14 @@ mod tests {
             .unwrap();
     }
 
+    async fn express_lane_reports(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
+        let case0 = ExpressLaneRoundReport { roi: Some(0.5), ..Default::default() };
+
+        db.insert_one::<BrontesExpress_Lane_Reports>(&DbDataWithRunId::new_with_run_id(case0, 0))
+            .await
+            .unwrap();
+    }
+
     async fn tree(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
         let tree = load_tree().await;
 
@@ -1557,6 +1601,7 @@ mod tests {
     async fn run_all(database: &ClickhouseTestClient<BrontesClickhouseTables>) {
         pools(database).await;
         atomic_arb(database).await;
+        timeboost(database).await;
         sandwich(database).await;
         bundle_header(database).await;
         liquidations(database).await;
@@ -1569,6 +1614,7 @@ mod tests {
         token_info(database).await;
         tree(database).await;
         block_analysis(database).await;
+        express_lane_reports(database).await;
     }
 
     #[brontes_macros::test]
@@ -1,8 +1,9 @@
 use brontes_types::{
     db::{
         address_to_protocol_info::ProtocolInfoClickhouse, block_analysis::BlockAnalysis,
-        dex::DexQuotesWithBlockNumber, normalized_actions::TransactionRoot,
-        token_info::TokenInfoWithAddress, DbDataWithRunId, RunId,
+        dex::DexQuotesWithBlockNumber, express_lane::ExpressLaneRoundReport,
+        normalized_actions::TransactionRoot, token_info::TokenInfoWithAddress, DbDataWithRunId,
+        RunId,
     },
     mev::*,
 };
@@ -23,9 +24,11 @@ clickhouse_dbms!(
         MevJit,
         MevSandwiches,
         MevAtomic_Arbs,
+        MevTimeboost,
         BrontesToken_Info,
         EthereumPools,
         BrontesTree,
+        BrontesExpress_Lane_Reports,
         BrontesRun_Id
     ]
 );
@@ -124,6 +127,13 @@ remote_clickhouse_table!(
     "crates/brontes-database/brontes-db/src/clickhouse/tables/"
 );
 
+remote_clickhouse_table!(
+    BrontesClickhouseTables,
+    [Mev, Timeboost],
+    DbDataWithRunId<Timeboost>,
+    "crates/brontes-database/brontes-db/src/clickhouse/tables/"
+);
+
 remote_clickhouse_table!(
     BrontesClickhouseTables,
     [Brontes, Token_Info],
@@ -145,6 +155,13 @@ remote_clickhouse_table!(
     "crates/brontes-database/brontes-db/src/clickhouse/tables/"
 );
 
+remote_clickhouse_table!(
+    BrontesClickhouseTables,
+    [Brontes, Express_Lane_Reports],
+    DbDataWithRunId<ExpressLaneRoundReport>,
+    "crates/brontes-database/brontes-db/src/clickhouse/tables/"
+);
+
 remote_clickhouse_table!(
     BrontesClickhouseTables,
     [Brontes, Run_Id],
@@ -239,9 +256,11 @@ db_types!(
     (JitLiquidity, MevJit, true),
     (Sandwich, MevSandwiches, true),
     (AtomicArb, MevAtomic_Arbs, true),
+    (Timeboost, MevTimeboost, true),
     (TokenInfoWithAddress, BrontesToken_Info, false),
     (ProtocolInfoClickhouse, EthereumPools, false),
     (TransactionRoot, BrontesTree, true),
     (BlockAnalysis, BrontesBlock_Analysis, true),
+    (ExpressLaneRoundReport, BrontesExpress_Lane_Reports, true),
     (RunId, BrontesRun_Id, false)
 );
@@ -285,7 +285,7 @@ impl ClickhouseHandle for ClickhouseHttpClient {
 #[cfg(test)]
 pub mod test {
 
-    use brontes_types::constants::USDT_ADDRESS;
+    use brontes_types::constants::arbitrum::USDT_ADDRESS;
 
     use crate::{clickhouse::ClickhouseHandle, libmdbx::test_utils::load_clickhouse};
 
@@ -4,10 +4,11 @@ use alloy_primitives::Address;
 use brontes_types::{
     db::{
         address_metadata::AddressMetadata,
-        address_to_protocol_info::ProtocolInfo,
+        address_to_protocol_info::{ProtocolInfo, UniswapV4PoolKey},
         block_analysis::BlockAnalysis,
         builder::BuilderInfo,
         dex::DexQuotes,
+        express_lane::{ExpressLaneRound, ExpressLaneRoundBids, ExpressLaneRoundReport},
         metadata::Metadata,
         mev_block::MevBlockWithClassified,
         searcher::SearcherInfo,
@@ -166,6 +167,17 @@ impl<I: DBWriter + Send + Sync> DBWriter for ClickhouseMiddleware<I> {
 
         self.inner().save_traces(block, traces).await
     }
+
+    async fn write_express_lane_reports(
+        &self,
+        reports: Vec<ExpressLaneRoundReport>,
+    ) -> eyre::Result<()> {
+        self.client
+            .write_express_lane_reports(reports.clone())
+            .await?;
+
+        self.inner().write_express_lane_reports(reports).await
+    }
 }
 
 impl<I: LibmdbxInit> LibmdbxInit for ClickhouseMiddleware<I> {
@@ -385,6 +397,28 @@ impl<I: LibmdbxInit> LibmdbxReader for ClickhouseMiddleware<I> {
     fn load_trace(&self, block_num: u64) -> eyre::Result<Vec<TxTrace>> {
         self.inner.load_trace(block_num)
     }
+
+    fn try_fetch_express_lane_round(&self, round: u64) -> eyre::Result<Option<ExpressLaneRound>> {
+        self.inner.try_fetch_express_lane_round(round)
+    }
+
+    fn try_fetch_express_lane_round_at(
+        &self,
+        timestamp: u64,
+    ) -> eyre::Result<Option<ExpressLaneRound>> {
+        self.inner.try_fetch_express_lane_round_at(timestamp)
+    }
+
+    fn try_fetch_express_lane_bids(
+        &self,
+        round: u64,
+    ) -> eyre::Result<Option<ExpressLaneRoundBids>> {
+        self.inner.try_fetch_express_lane_bids(round)
+    }
+
+    fn fetch_all_express_lane_reports(&self) -> eyre::Result<Vec<ExpressLaneRoundReport>> {
+        self.inner.fetch_all_express_lane_reports()
+    }
 }
 
 pub struct ReadOnlyMiddleware<I: DBWriter> {
@@ -485,6 +519,15 @@ impl<I: DBWriter + Send + Sync> DBWriter for ReadOnlyMiddleware<I> {
             .await
     }
 
+    async fn insert_uniswap_v4_pool_key(
+        &self,
+        _: Address,
+        _: UniswapV4PoolKey,
+    ) -> eyre::Result<()> {
+        // pool keys only live in libmdbx
+        Ok(())
+    }
+
     async fn insert_tree(&self, tree: BlockTree<Action>) -> eyre::Result<()> {
         self.client.insert_tree(tree).await?;
 
@@ -494,6 +537,23 @@ impl<I: DBWriter + Send + Sync> DBWriter for ReadOnlyMiddleware<I> {
     async fn save_traces(&self, block: u64, traces: Vec<TxTrace>) -> eyre::Result<()> {
         self.client.save_traces(block, traces.clone()).await
     }
+
+    async fn write_express_lane_rounds(&self, _: Vec<ExpressLaneRound>) -> eyre::Result<()> {
+        // express lane rounds only live in libmdbx
+        Ok(())
+    }
+
+    async fn write_express_lane_bids(&self, _: Vec<ExpressLaneRoundBids>) -> eyre::Result<()> {
+        // express lane bids only live in libmdbx
+        Ok(())
+    }
+
+    async fn write_express_lane_reports(
+        &self,
+        reports: Vec<ExpressLaneRoundReport>,
+    ) -> eyre::Result<()> {
+        self.client.write_express_lane_reports(reports).await
+    }
 }
 
 impl<I: LibmdbxInit> LibmdbxInit for ReadOnlyMiddleware<I> {
@@ -707,4 +767,26 @@ impl<I: LibmdbxInit> LibmdbxReader for ReadOnlyMiddleware<I> {
     fn load_trace(&self, block_num: u64) -> eyre::Result<Vec<TxTrace>> {
         self.inner.load_trace(block_num)
     }
+
+    fn try_fetch_express_lane_round(&self, round: u64) -> eyre::Result<Option<ExpressLaneRound>> {
+        self.inner.try_fetch_express_lane_round(round)
+    }
+
+    fn try_fetch_express_lane_round_at(
+        &self,
+        timestamp: u64,
+    ) -> eyre::Result<Option<ExpressLaneRound>> {
+        self.inner.try_fetch_express_lane_round_at(timestamp)
+    }
+
+    fn try_fetch_express_lane_bids(
+        &self,
+        round: u64,
+    ) -> eyre::Result<Option<ExpressLaneRoundBids>> {
+        self.inner.try_fetch_express_lane_bids(round)
+    }
+
+    fn fetch_all_express_lane_reports(&self) -> eyre::Result<Vec<ExpressLaneRoundReport>> {
+        self.inner.fetch_all_express_lane_reports()
+    }
 }
@@ -127,11 +127,13 @@ impl ClickhouseBuffered {
             (MevSandwiches, Sandwich),
             (MevAtomic_Arbs, AtomicArb),
             (MevLiquidations, Liquidation),
+            (MevTimeboost, Timeboost),
             (BrontesDex_Price_Mapping, DexQuotesWithBlockNumber),
             (BrontesToken_Info, TokenInfoWithAddress),
             (EthereumPools, ProtocolInfoClickhouse),
             (BrontesTree, TransactionRoot),
             (BrontesBlock_Analysis, BlockAnalysis),
+            (BrontesExpress_Lane_Reports, ExpressLaneRoundReport),
             (BrontesRun_Id, RunId)
         );
 
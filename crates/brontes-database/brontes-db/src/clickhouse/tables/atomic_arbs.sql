@@ -17,7 +17,8 @@ CREATE TABLE IF NOT EXISTS mev.atomic_arbs
         `coinbase_transfer` Nullable(UInt128), 
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `l1_gas_used` UInt128
     ),
     `arb_type` String,
     `run_id` UInt64,
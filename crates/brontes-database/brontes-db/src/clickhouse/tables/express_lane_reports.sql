@@ -0,0 +1,23 @@
+CREATE TABLE IF NOT EXISTS brontes.express_lane_reports 
+(
+    `round` UInt64,
+    `round_start_timestamp` UInt64,
+    `round_end_timestamp` UInt64,
+    `start_block` UInt64,
+    `end_block` UInt64,
+    `bidder` Nullable(String),
+    `controller` String,
+    `price` Nullable(String),
+    `price_usd` Float64,
+    `extracted_usd` Float64,
+    `secondary_extracted_usd` Float64,
+    `net_profit_usd` Float64,
+    `roi` Nullable(Float64),
+    `timeboosted_tx_count` UInt64,
+    `timeboosted_mev_count` UInt64,
+    `transfer_count` UInt64,
+    `run_id` UInt64
+) 
+ENGINE = MergeTree()
+PRIMARY KEY (`round`)
+ORDER BY (`round`)
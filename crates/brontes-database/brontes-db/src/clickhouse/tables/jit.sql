@@ -14,7 +14,8 @@ CREATE TABLE IF NOT EXISTS mev.jit
         `coinbase_transfer` Nullable(UInt128), 
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `l1_gas_used` UInt128
     ),
     `victim_swaps` Nested(
         `tx_hash` String,
@@ -32,7 +33,8 @@ CREATE TABLE IF NOT EXISTS mev.jit
         `coinbase_transfer` Nullable(UInt128), 
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `l1_gas_used` UInt128
     ),
     `backrun_burn_tx_hash` String,
     `backrun_burns` Nested(
@@ -47,7 +49,8 @@ CREATE TABLE IF NOT EXISTS mev.jit
         `coinbase_transfer` Nullable(UInt128), 
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `l1_gas_used` UInt128
     ),
     `run_id` UInt64
 ) 
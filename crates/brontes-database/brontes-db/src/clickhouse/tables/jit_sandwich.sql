@@ -27,7 +27,8 @@ CREATE TABLE IF NOT EXISTS mev.jit_sandwich
         `coinbase_transfer` Nullable(UInt128), 
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `l1_gas_used` UInt128
     ),
     `victim_swaps` Nested(
         `tx_hash` String,
@@ -45,7 +46,8 @@ CREATE TABLE IF NOT EXISTS mev.jit_sandwich
         `coinbase_transfer` Nullable(UInt128), 
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `l1_gas_used` UInt128
     ),
     `backrun_tx_hash` String,
     `backrun_swaps` Nested(
@@ -73,7 +75,8 @@ CREATE TABLE IF NOT EXISTS mev.jit_sandwich
         `coinbase_transfer` Nullable(UInt128), 
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `l1_gas_used` UInt128
     ),
     `run_id` UInt64
 ) 
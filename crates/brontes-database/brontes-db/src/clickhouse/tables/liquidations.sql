@@ -26,7 +26,8 @@ CREATE TABLE IF NOT EXISTS mev.liquidations
         `coinbase_transfer` Nullable(UInt128), 
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `l1_gas_used` UInt128
     ),
     `run_id` UInt64,
     `profit_usd` Float64,
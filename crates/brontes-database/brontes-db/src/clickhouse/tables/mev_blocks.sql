@@ -35,6 +35,17 @@ CREATE TABLE IF NOT EXISTS mev.mev_blocks
     `timeboosted_profit_usd` Float64,
     `timeboosted_tx_count` UInt64,
     `timeboosted_tx_mev_count` UInt64,
+    `express_lane_round` Nullable(UInt64),
+    `sequencer_revenue` Nested (
+        `base_fee_paid` UInt128,
+        `network_fee_collected` UInt128,
+        `infra_fee_collected` UInt128,
+        `l1_surplus_fee` UInt128,
+        `express_lane_proceeds` UInt128,
+        `mev_fees_paid` UInt128,
+        `mev_fee_share` Float64,
+        `total_revenue_usd` Float64
+    ),
     `possible_mev` Nested (
         `tx_hash` String,
         `tx_idx` UInt64,
@@ -42,6 +53,7 @@ CREATE TABLE IF NOT EXISTS mev.mev_blocks
         `gas_details.priority_fee` UInt128,
         `gas_details.gas_used` UInt128,
         `gas_details.effective_gas_price` UInt128,
+        `gas_details.l1_gas_used` UInt128,
         `triggers.is_private` Bool,
         `triggers.coinbase_transfer` Bool,
         `triggers.high_priority_fee` Bool
@@ -18,7 +18,8 @@ CREATE TABLE IF NOT EXISTS mev.sandwiches
         `coinbase_transfer` Nullable(UInt128), 
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `l1_gas_used` UInt128
     ),
     `victim_swaps` Nested(
         `tx_hash` String,
@@ -36,7 +37,8 @@ CREATE TABLE IF NOT EXISTS mev.sandwiches
         `coinbase_transfer` Nullable(UInt128), 
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `l1_gas_used` UInt128
     ),
     `backrun_tx_hash` String,
     `backrun_swaps` Nested(
@@ -55,7 +57,8 @@ CREATE TABLE IF NOT EXISTS mev.sandwiches
         `coinbase_transfer` Nullable(UInt128), 
         `priority_fee` UInt128,
         `gas_used` UInt128,
-        `effective_gas_price` UInt128
+        `effective_gas_price` UInt128,
+        `l1_gas_used` UInt128
     ),
     `run_id` UInt64
 ) 
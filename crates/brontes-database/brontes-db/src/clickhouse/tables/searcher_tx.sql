@@ -12,7 +12,7 @@ CREATE TABLE IF NOT EXISTS mev.searcher_tx
         `fee` Tuple(UInt256, UInt256),
         `msg_value` UInt256
     ),
-    `gas_details` Tuple(Nullable(UInt128), UInt128, UInt128, UInt128),
+    `gas_details` Tuple(Nullable(UInt128), UInt128, UInt128, UInt128, UInt128),
     `run_id` UInt64
 ) 
 ENGINE = MergeTree()
@@ -0,0 +1,41 @@
+CREATE TABLE IF NOT EXISTS mev.timeboost 
+(
+    `tx_hash` String,
+    `block_number` UInt64,
+    `express_lane_round` UInt64,
+    `strategy` String,
+    `swaps` Nested(
+        `trace_idx` UInt64,
+        `from` String,
+        `recipient` String,
+        `pool` String,
+        `token_in` Tuple(String, String),
+        `token_out` Tuple(String, String),
+        `amount_in` Tuple(UInt256, UInt256),
+        `amount_out` Tuple(UInt256, UInt256)
+    ),
+    `victim_swaps` Nested(
+        `tx_hash` String,
+        `trace_idx` UInt64,
+        `from` String,
+        `recipient` String,
+        `pool` String,
+        `token_in` Tuple(String, String),
+        `token_out` Tuple(String, String),
+        `amount_in` Tuple(UInt256, UInt256),
+        `amount_out` Tuple(UInt256, UInt256)
+    ),
+    `cex_price_move_bps` Nullable(Float64),
+    `gas_details` Tuple(
+        `coinbase_transfer` Nullable(UInt128), 
+        `priority_fee` UInt128,
+        `gas_used` UInt128,
+        `effective_gas_price` UInt128,
+        `l1_gas_used` UInt128
+    ),
+    `profit_usd` Float64,
+    `run_id` UInt64
+) 
+ENGINE = MergeTree()
+PRIMARY KEY (`block_number`, `tx_hash`)
+ORDER BY (`block_number`, `tx_hash`)
@@ -5,7 +5,7 @@ CREATE TABLE IF NOT EXISTS brontes.tree
     `tx_idx` UInt64,
     `from` String,
     `to` Nullable(String),
-    `gas_details` Tuple(coinbase_transfer Nullable(UInt128), priority_fee UInt128, gas_used UInt128, effective_gas_price UInt128),
+    `gas_details` Tuple(coinbase_transfer Nullable(UInt128), priority_fee UInt128, gas_used UInt128, effective_gas_price UInt128, l1_gas_used UInt128),
     `trace_nodes.trace_idx` Array(UInt64),
     `trace_nodes.trace_address` Array(Array(UInt64)),
     `trace_nodes.action_kind` Array(Nullable(String)),
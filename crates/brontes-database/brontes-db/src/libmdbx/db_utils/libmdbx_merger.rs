@@ -49,7 +49,10 @@ pub fn merge_libmdbx_dbs(
                 Builder,
                 AddressToProtocolInfo,
                 TokenDecimals,
-                DexPrice
+                DexPrice,
+                ExpressLaneRounds,
+                ExpressLaneReports,
+                ExpressLaneBids
                 );
                 total_progress_bar.inc(1);
 
@@ -104,7 +104,10 @@ impl LibmdbxPartitioner {
             SearcherContracts,
             Builder,
             AddressToProtocolInfo,
-            TokenDecimals
+            TokenDecimals,
+            ExpressLaneRounds,
+            ExpressLaneReports,
+            ExpressLaneBids
         );
 
         // because we are just doing read operations. we can do all this in parallel
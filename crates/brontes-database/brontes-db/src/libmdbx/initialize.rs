@@ -364,7 +364,22 @@ impl<TP: TracingProvider, CH: ClickhouseHandle> LibmdbxInitializer<TP, CH> {
             let protocol: Protocol = protocol.parse().unwrap();
             for (address, table) in inner.as_table().unwrap() {
                 let token_addr: Address = address.parse().unwrap();
-                let init_block = table.get("init_block").unwrap().as_integer().unwrap() as u64;
+                // entries without an init block are resolved to their deployment block
+                let init_block = match table.get("init_block") {
+                    Some(block) => block.as_integer().unwrap() as u64,
+                    None => match self.deployment_block(token_addr).await {
+                        Ok(block) => block,
+                        Err(e) => {
+                            error!(
+                                target: "brontes::init",
+                                ?protocol,
+                                address = ?token_addr,
+                                "failed to resolve init block: {e}"
+                            );
+                            continue
+                        }
+                    },
+                };
 
                 let table: Vec<TokenInfoWithAddressToml> = table
                     .get("token_info")
@@ -379,10 +394,11 @@ impl<TP: TracingProvider, CH: ClickhouseHandle> LibmdbxInitializer<TP, CH> {
                         .unwrap();
                 }
 
-                let token_addrs = if table.len() < 2 {
-                    [Address::default(), Address::default()]
-                } else {
-                    [table[0].address, table[1].address]
+                // single token entries, e.g. bridge pools, only set token0
+                let token_addrs = match table.as_slice() {
+                    [] => [Address::default(), Address::default()],
+                    [token0] => [token0.address, Address::default()],
+                    [token0, token1, ..] => [token0.address, token1.address],
                 };
 
                 self.libmdbx
@@ -393,6 +409,38 @@ impl<TP: TracingProvider, CH: ClickhouseHandle> LibmdbxInitializer<TP, CH> {
         }
     }
 
+    /// Binary searches for the first block at which the address has code.
+    async fn deployment_block(&self, address: Address) -> eyre::Result<u64> {
+        #[cfg(feature = "local-reth")]
+        let mut high = self.tracer.best_block_number()?;
+        #[cfg(not(feature = "local-reth"))]
+        let mut high = self.tracer.best_block_number().await?;
+
+        if !self.has_code(high, address).await? {
+            eyre::bail!("no code deployed at {address:?}");
+        }
+
+        let mut low = 0;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.has_code(mid, address).await? {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+
+        Ok(low)
+    }
+
+    async fn has_code(&self, block: u64, address: Address) -> eyre::Result<bool> {
+        Ok(self
+            .tracer
+            .get_bytecode(Some(block), address)
+            .await?
+            .is_some_and(|code| !code.is_empty()))
+    }
+
     async fn load_builder_config_data(&self) {
         let mut builder_config_path = workspace_dir();
         builder_config_path.push(BUILDER_CONFIG_FILE);
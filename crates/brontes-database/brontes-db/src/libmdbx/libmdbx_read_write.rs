@@ -4,13 +4,14 @@ use alloy_primitives::Address;
 use brontes_metrics::db_reads::LibmdbxMetrics;
 use brontes_pricing::Protocol;
 use brontes_types::{
-    constants,
+    chain::chain_config,
     db::{
         address_metadata::AddressMetadata,
-        address_to_protocol_info::ProtocolInfo,
+        address_to_protocol_info::{ProtocolInfo, UniswapV4PoolKey},
         builder::BuilderInfo,
         cex::{quotes::CexPriceMap, trades::CexTradeMap},
         dex::{make_filter_key_range, DexPrices, DexQuotes},
+        express_lane::{ExpressLaneRound, ExpressLaneRoundBids, ExpressLaneRoundReport},
         initialized_state::{
             InitializedStateMeta, CEX_QUOTES_FLAG, CEX_TRADES_FLAG, DATA_NOT_PRESENT_NOT_AVAILABLE,
             DATA_PRESENT, DEX_PRICE_FLAG, META_FLAG,
@@ -514,13 +515,15 @@ impl LibmdbxReader for LibmdbxReadWriter {
 
     #[brontes_macros::metrics_call(ptr=metrics,scope, db_read, "try_fetch_token_info")]
     fn try_fetch_token_info(&self, og_address: Address) -> eyre::Result<TokenInfoWithAddress> {
-        let address = if constants::ETH_ADDRESSES.contains(&og_address) { constants::WETH_ADDRESS } else { og_address };
+        let chain = chain_config();
+        let is_eth = chain.eth_addresses().contains(&og_address);
+        let address = if is_eth { chain.weth_address() } else { og_address };
 
         self.db
             .view_db(|tx| match self.cache.token_info(true, |lock| lock.get(&address)) {
                 Some(Some(e)) => {
                     let mut info = TokenInfoWithAddress { inner: e, address: og_address };
-                    if constants::ETH_ADDRESSES.contains(&og_address) {
+                    if is_eth {
                         info.symbol = "ETH".to_string();
                     }
                     Ok(info)
@@ -536,7 +539,7 @@ impl LibmdbxReader for LibmdbxReadWriter {
                     .map(|inner| TokenInfoWithAddress { inner, address: og_address })
                     .map(|mut inner| {
                         // quick patch
-                        if constants::ETH_ADDRESSES.contains(&og_address) {
+                        if is_eth {
                             inner.symbol = "ETH".to_string();
                             inner
                         } else {
@@ -901,6 +904,69 @@ impl LibmdbxReader for LibmdbxReadWriter {
             |cursor| Ok(cursor.next().map(|inner| inner.map(|i| (i.0, i.1)))?),
         )
     }
+
+    fn try_fetch_express_lane_round(&self, round: u64) -> eyre::Result<Option<ExpressLaneRound>> {
+        self.db
+            .view_db(|tx| tx.get::<ExpressLaneRounds>(round).map_err(ErrReport::from))
+    }
+
+    fn try_fetch_express_lane_round_at(
+        &self,
+        timestamp: u64,
+    ) -> eyre::Result<Option<ExpressLaneRound>> {
+        self.db.view_db(|tx| {
+            // the round schedule can change, so the round number can't be derived from
+            // the timing of a single round. Round numbers keep increasing across
+            // changes, so we search for the last round that starts at or before the
+            // timestamp and check it against that round's own bounds.
+            let mut cursor = tx.cursor_read::<ExpressLaneRounds>()?;
+            let (Some((mut lo, _)), Some((mut hi, _))) = (cursor.first()?, cursor.last()?) else {
+                return Ok(None)
+            };
+
+            let mut found = None;
+            while lo <= hi {
+                let mid = lo + (hi - lo) / 2;
+                let Some((key, round)) = cursor.seek(mid)? else { break };
+                if round.round_start_timestamp <= timestamp {
+                    lo = key + 1;
+                    found = Some(round);
+                } else if mid == 0 {
+                    break
+                } else {
+                    hi = mid - 1;
+                }
+            }
+
+            Ok(found.filter(|round| round.contains_timestamp(timestamp)))
+        })
+    }
+
+    fn try_fetch_express_lane_bids(
+        &self,
+        round: u64,
+    ) -> eyre::Result<Option<ExpressLaneRoundBids>> {
+        self.db
+            .view_db(|tx| tx.get::<ExpressLaneBids>(round).map_err(ErrReport::from))
+    }
+
+    fn fetch_all_express_lane_reports(&self) -> eyre::Result<Vec<ExpressLaneRoundReport>> {
+        self.db.export_db(
+            None,
+            |start_key, tx| {
+                let mut cur = tx.cursor_read::<ExpressLaneReports>()?;
+                if let Some(key) = start_key {
+                    let _ = cur.seek(key);
+                } else {
+                    // move to first entry and make sure .next() is first
+                    let _ = cur.first();
+                    let _ = cur.prev();
+                }
+                Ok(cur)
+            },
+            |cursor| Ok(cursor.next().map(|inner| inner.map(|i| i.1))?),
+        )
+    }
 }
 
 impl DBWriter for LibmdbxReadWriter {
@@ -1042,6 +1108,7 @@ impl DBWriter for LibmdbxReadWriter {
                 token3: tokens_i.next().cloned(),
                 token4: tokens_i.next().cloned(),
                 curve_lp_token,
+                uniswap_v4_pool_key: None,
             };
             handle.insert(address, Some(details.clone()));
         });
@@ -1066,6 +1133,27 @@ impl DBWriter for LibmdbxReadWriter {
             .send(WriterMessage::Traces { block, traces }.stamp())?)
     }
 
+    async fn write_express_lane_rounds(&self, rounds: Vec<ExpressLaneRound>) -> eyre::Result<()> {
+        Ok(self
+            .tx
+            .send(WriterMessage::ExpressLaneRounds { rounds }.stamp())?)
+    }
+
+    async fn write_express_lane_bids(&self, bids: Vec<ExpressLaneRoundBids>) -> eyre::Result<()> {
+        Ok(self
+            .tx
+            .send(WriterMessage::ExpressLaneBids { bids }.stamp())?)
+    }
+
+    async fn write_express_lane_reports(
+        &self,
+        reports: Vec<ExpressLaneRoundReport>,
+    ) -> eyre::Result<()> {
+        Ok(self
+            .tx
+            .send(WriterMessage::ExpressLaneReports { reports }.stamp())?)
+    }
+
     async fn write_builder_info(
         &self,
         builder_address: Address,
@@ -1077,6 +1165,23 @@ impl DBWriter for LibmdbxReadWriter {
         )?)
     }
 
+    async fn insert_uniswap_v4_pool_key(
+        &self,
+        address: Address,
+        pool_key: UniswapV4PoolKey,
+    ) -> eyre::Result<()> {
+        self.cache.protocol_info(false, |handle| {
+            if let Some(Some(mut details)) = handle.get(&address) {
+                details.uniswap_v4_pool_key = Some(pool_key.clone());
+                handle.insert(address, Some(details));
+            }
+        });
+
+        Ok(self
+            .tx
+            .send(WriterMessage::UniswapV4PoolKey { address, pool_key }.stamp())?)
+    }
+
     /// only for internal functionality (i.e. clickhouse)
     async fn insert_tree(&self, _tree: BlockTree<Action>) -> eyre::Result<()> {
         Ok(())
@@ -1318,7 +1423,7 @@ pub fn determine_eth_prices(
     Some(
         cex_quotes
             .get_quote_from_most_liquid_exchange(
-                &Pair(quote_asset, constants::WETH_ADDRESS),
+                &Pair(quote_asset, chain_config().weth_address()),
                 block_timestamp,
                 None,
             )?
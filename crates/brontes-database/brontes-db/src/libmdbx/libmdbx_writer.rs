@@ -10,9 +10,10 @@ use brontes_metrics::db_writer::WriterMetrics;
 use brontes_types::{
     db::{
         address_metadata::AddressMetadata,
-        address_to_protocol_info::ProtocolInfo,
+        address_to_protocol_info::{ProtocolInfo, UniswapV4PoolKey},
         builder::BuilderInfo,
         dex::{make_key, DexQuoteWithIndex, DexQuotes},
+        express_lane::{ExpressLaneRound, ExpressLaneRoundBids, ExpressLaneRoundReport},
         initialized_state::{DATA_PRESENT, DEX_PRICE_FLAG, TRACE_FLAG},
         mev_block::MevBlockWithClassified,
         pool_creation_block::PoolsToAddresses,
@@ -93,10 +94,23 @@ pub enum WriterMessage {
         curve_lp_token:  Option<Address>,
         classifier_name: Protocol,
     },
+    UniswapV4PoolKey {
+        address:  Address,
+        pool_key: UniswapV4PoolKey,
+    },
     Traces {
         block:  u64,
         traces: Vec<TxTrace>,
     },
+    ExpressLaneRounds {
+        rounds: Vec<ExpressLaneRound>,
+    },
+    ExpressLaneReports {
+        reports: Vec<ExpressLaneRoundReport>,
+    },
+    ExpressLaneBids {
+        bids: Vec<ExpressLaneRoundBids>,
+    },
     Init(InitTables, Arc<Notify>),
 }
 
@@ -214,7 +228,10 @@ init!(
     MevBlocks,
     SearcherEOAs,
     SearcherContracts,
-    InitializedState
+    InitializedState,
+    ExpressLaneRounds,
+    ExpressLaneReports,
+    ExpressLaneBids
 );
 
 /// due to libmdbx's 1 write tx limit. it makes sense
@@ -242,6 +259,10 @@ impl LibmdbxWriter {
                 self.insert_pool(block, address, &tokens, curve_lp_token, classifier_name)?;
                 "pool"
             }
+            WriterMessage::UniswapV4PoolKey { address, pool_key } => {
+                self.insert_uniswap_v4_pool_key(address, pool_key)?;
+                "uniswap_v4_pool_key"
+            }
             WriterMessage::Traces { block, traces } => {
                 self.save_traces(block, traces)?;
                 "traces"
@@ -283,6 +304,18 @@ impl LibmdbxWriter {
                 self.write_searcher_contract_info(searcher_contract, *searcher_info)?;
                 "searchercontractinfo"
             }
+            WriterMessage::ExpressLaneRounds { rounds } => {
+                self.write_express_lane_rounds(rounds)?;
+                "expresslanerounds"
+            }
+            WriterMessage::ExpressLaneReports { reports } => {
+                self.write_express_lane_reports(reports)?;
+                "expresslanereports"
+            }
+            WriterMessage::ExpressLaneBids { bids } => {
+                self.write_express_lane_bids(bids)?;
+                "expresslanebids"
+            }
             WriterMessage::Init(init, not) => {
                 init.write_data(self.db.clone())?;
                 not.notify_one();
@@ -400,6 +433,45 @@ impl LibmdbxWriter {
         Ok(())
     }
 
+    #[instrument(target = "libmdbx_read_write::express_lane_rounds", skip_all, level = "warn")]
+    fn write_express_lane_rounds(&self, rounds: Vec<ExpressLaneRound>) -> eyre::Result<()> {
+        let data = rounds
+            .into_iter()
+            .map(|round| ExpressLaneRoundsData::new(round.round, round))
+            .collect::<Vec<_>>();
+
+        self.instrumented_write::<ExpressLaneRounds, ExpressLaneRoundsData>(&data)
+            .expect("libmdbx write failure");
+
+        Ok(())
+    }
+
+    #[instrument(target = "libmdbx_read_write::express_lane_reports", skip_all, level = "warn")]
+    fn write_express_lane_reports(&self, reports: Vec<ExpressLaneRoundReport>) -> eyre::Result<()> {
+        let data = reports
+            .into_iter()
+            .map(|report| ExpressLaneReportsData::new(report.round, report))
+            .collect::<Vec<_>>();
+
+        self.instrumented_write::<ExpressLaneReports, ExpressLaneReportsData>(&data)
+            .expect("libmdbx write failure");
+
+        Ok(())
+    }
+
+    #[instrument(target = "libmdbx_read_write::express_lane_bids", skip_all, level = "warn")]
+    fn write_express_lane_bids(&self, bids: Vec<ExpressLaneRoundBids>) -> eyre::Result<()> {
+        let data = bids
+            .into_iter()
+            .map(|bids| ExpressLaneBidsData::new(bids.round, bids))
+            .collect::<Vec<_>>();
+
+        self.instrumented_write::<ExpressLaneBids, ExpressLaneBidsData>(&data)
+            .expect("libmdbx write failure");
+
+        Ok(())
+    }
+
     #[instrument(target = "libmdbx_read_write::write_address_meta", skip_all, level = "warn")]
     fn save_mev_blocks(
         &mut self,
@@ -491,6 +563,7 @@ impl LibmdbxWriter {
                     token3: tokens.next().cloned(),
                     token4: tokens.next().cloned(),
                     curve_lp_token,
+                    uniswap_v4_pool_key: None,
                 },
             ),
         ])
@@ -514,6 +587,33 @@ impl LibmdbxWriter {
         })
     }
 
+    #[instrument(
+        target = "libmdbx_read_write::insert_uniswap_v4_pool_key",
+        skip_all,
+        level = "warn"
+    )]
+    fn insert_uniswap_v4_pool_key(
+        &self,
+        address: Address,
+        pool_key: UniswapV4PoolKey,
+    ) -> eyre::Result<()> {
+        let Some(mut details) = self
+            .db
+            .view_db(|tx| tx.get::<AddressToProtocolInfo>(address).map_err(Into::into))?
+        else {
+            tracing::warn!(?address, "no protocol info to attach uniswap v4 pool key to");
+            return Ok(())
+        };
+        details.uniswap_v4_pool_key = Some(pool_key);
+
+        self.instrumented_write::<AddressToProtocolInfo, AddressToProtocolInfoData>(&[
+            AddressToProtocolInfoData::new(address, details),
+        ])
+        .expect("libmdbx write failure");
+
+        Ok(())
+    }
+
     #[instrument(target = "libmdbx_read_write::save_traces", skip_all, level = "warn")]
     fn save_traces(&mut self, block: u64, traces: Vec<TxTrace>) -> eyre::Result<()> {
         let data = TxTracesData::new(block, TxTracesInner { traces: Some(traces) }).into_key_val();
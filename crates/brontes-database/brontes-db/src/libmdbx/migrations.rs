@@ -0,0 +1,247 @@
+//! Versioning of the archived layout of the values brontes stores in libmdbx.
+//!
+//! rkyv values are read back without any schema, so rows written before a
+//! field was added to a table's value misdecode. Every such change bumps
+//! [`BRONTES_DB_VERSION`] and registers a [`Migration`]. When a database
+//! written by an older version is opened, the tables that changed since are
+//! either re-encoded in the new layout or cleared with their initialized state
+//! flags reset, so that their data is rebuilt instead of being misread.
+
+use std::path::Path;
+
+use alloy_primitives::Address;
+use brontes_types::db::{
+    address_to_protocol_info::{LegacyProtocolInfoRedefined, ProtocolInfoRedefined},
+    initialized_state::TRACE_FLAG,
+};
+use reth_db::{cursor::DbCursorRO, table::Table};
+use tracing::warn;
+
+use super::{tables::*, Libmdbx};
+
+/// Current layout version of the brontes tables.
+pub const BRONTES_DB_VERSION: u64 = 1;
+/// File in the database directory holding the layout version it was written
+/// with. Databases without it predate the versioning and are at version 0.
+pub const BRONTES_DB_VERSION_FILE: &str = "brontes-database.version";
+
+/// A change to the value layout of a table.
+#[derive(Debug, Clone, Copy)]
+pub struct Migration {
+    /// Version the new layout was introduced in
+    pub version:    u64,
+    pub table:      Tables,
+    /// Initialized state flag of the table's data, if it is tracked there
+    pub reset_flag: Option<u16>,
+    pub reason:     &'static str,
+}
+
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version:    1,
+        table:      Tables::MevBlocks,
+        reset_flag: None,
+        reason:     "mev blocks record the express lane round they were built in and the l1 gas \
+                     used of their transactions",
+    },
+    Migration {
+        version:    1,
+        table:      Tables::TxTraces,
+        reset_flag: Some(TRACE_FLAG),
+        reason:     "traces record the gas used for l1 and the l1 block number",
+    },
+    Migration {
+        version:    1,
+        table:      Tables::AddressToProtocolInfo,
+        reset_flag: None,
+        reason:     "protocol info holds the pool key of uniswap v4 pools, existing rows are \
+                     re-encoded without one",
+    },
+];
+
+/// [`AddressToProtocolInfo`] as written before version 1, read with the
+/// archived layout it had then.
+#[derive(Debug, Clone, Copy, Default)]
+struct LegacyAddressToProtocolInfo;
+
+impl Table for LegacyAddressToProtocolInfo {
+    type Key = Address;
+    type Value = LegacyProtocolInfoRedefined;
+
+    const NAME: &'static str = AddressToProtocolInfo::NAME;
+    // unused, see the tables in `tables`
+    const TABLE: reth_db::Tables = reth_db::Tables::CanonicalHeaders;
+}
+
+/// Migrations that have to be applied to a database at `version`.
+pub fn pending_migrations(version: u64) -> impl Iterator<Item = &'static Migration> {
+    MIGRATIONS
+        .iter()
+        .filter(move |migration| migration.version > version)
+}
+
+impl Libmdbx {
+    /// Brings a database written by an older version of brontes up to
+    /// [`BRONTES_DB_VERSION`]. A freshly created database is only stamped.
+    pub(crate) fn migrate(&self, path: &Path, is_new: bool) -> eyre::Result<()> {
+        let version_file = path.join(BRONTES_DB_VERSION_FILE);
+        let version = if is_new {
+            BRONTES_DB_VERSION
+        } else {
+            match std::fs::read_to_string(&version_file) {
+                Ok(version) => version.trim().parse()?,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => 0,
+                Err(e) => return Err(e.into()),
+            }
+        };
+
+        if version > BRONTES_DB_VERSION {
+            eyre::bail!(
+                "database at {} was written by a newer version of brontes (layout version \
+                 {version}, expected at most {BRONTES_DB_VERSION})",
+                path.display()
+            )
+        }
+
+        for migration in pending_migrations(version) {
+            warn!(
+                target: "brontes::init",
+                table = migration.table.name(),
+                version = migration.version,
+                reason = migration.reason,
+                "value layout changed, migrating the table"
+            );
+            self.migrate_table(migration.table)?;
+            if let Some(flag) = migration.reset_flag {
+                self.reset_initialized_flag(flag)?;
+            }
+        }
+
+        std::fs::write(version_file, BRONTES_DB_VERSION.to_string())?;
+
+        Ok(())
+    }
+
+    fn migrate_table(&self, table: Tables) -> eyre::Result<()> {
+        match table {
+            Tables::MevBlocks => self.clear_table::<MevBlocks>(),
+            Tables::TxTraces => self.clear_table::<TxTraces>(),
+            // the protocol info can't be rebuilt from the traces alone
+            Tables::AddressToProtocolInfo => self.reencode_protocol_info(),
+            table => unreachable!("no migration for {table:?}"),
+        }
+    }
+
+    /// Rewrites the protocol info rows in the current layout. None of them
+    /// can be a uniswap v4 pool, as those were only stored with their key.
+    fn reencode_protocol_info(&self) -> eyre::Result<()> {
+        let rows = self.view_db(|tx| {
+            let mut cur = tx.0.new_cursor::<LegacyAddressToProtocolInfo>()?;
+            let rows = cur
+                .walk(None)?
+                .map(|row| {
+                    row.map(|(address, info)| {
+                        let info = ProtocolInfoRedefined::from(info);
+                        AddressToProtocolInfoData::new(address, info.into())
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(rows)
+        })?;
+        self.write_table(&rows)?;
+
+        Ok(())
+    }
+
+    fn reset_initialized_flag(&self, flag: u16) -> eyre::Result<()> {
+        let updated = self.view_db(|tx| {
+            let mut cur = tx.new_cursor::<InitializedState>()?;
+            let mut updated = Vec::new();
+            for (block, mut state) in cur.walk_range(..)?.flatten() {
+                state.apply_reset_key(flag);
+                updated.push(InitializedStateData::new(block, state));
+            }
+
+            Ok(updated)
+        })?;
+        self.write_table(&updated)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brontes_types::{db::redefined_types::primitives::AddressRedefined, Protocol};
+    use redefined::RedefinedConvert;
+    use reth_db::transaction::DbTxMut;
+
+    use super::*;
+
+    #[test]
+    fn test_pending_migrations() {
+        assert_eq!(pending_migrations(0).count(), MIGRATIONS.len());
+        assert_eq!(pending_migrations(BRONTES_DB_VERSION).count(), 0);
+        // versions only ever increase
+        assert!(MIGRATIONS.windows(2).all(|w| w[0].version <= w[1].version));
+        assert!(MIGRATIONS
+            .iter()
+            .all(|migration| migration.version <= BRONTES_DB_VERSION));
+    }
+
+    #[test]
+    fn test_trace_migration_resets_trace_flag() {
+        let traces = MIGRATIONS
+            .iter()
+            .find(|migration| migration.table == Tables::TxTraces)
+            .unwrap();
+
+        assert_eq!(traces.reset_flag, Some(TRACE_FLAG));
+    }
+
+    #[test]
+    fn test_protocol_info_is_migrated_without_a_flag() {
+        let protocol_info = MIGRATIONS
+            .iter()
+            .find(|migration| migration.table == Tables::AddressToProtocolInfo)
+            .unwrap();
+
+        // discovered pools aren't tracked per block
+        assert_eq!(protocol_info.reset_flag, None);
+    }
+
+    #[test]
+    fn test_protocol_info_is_reencoded() {
+        let path = std::env::temp_dir().join(format!("brontes-migrations-{}", std::process::id()));
+        let db = Libmdbx::init_db(&path, None).unwrap();
+
+        let pool = Address::repeat_byte(1);
+        let legacy = LegacyProtocolInfoRedefined {
+            protocol:       Protocol::UniswapV2,
+            token0:         AddressRedefined::from_source(Address::repeat_byte(2)),
+            token1:         AddressRedefined::from_source(Address::repeat_byte(3)),
+            token2:         None,
+            token3:         None,
+            token4:         None,
+            curve_lp_token: None,
+            init_block:     10,
+        };
+        db.update_db(|tx| tx.0.put::<LegacyAddressToProtocolInfo>(pool, legacy))
+            .unwrap()
+            .unwrap();
+
+        db.reencode_protocol_info().unwrap();
+
+        let info = db
+            .view_db(|tx| Ok(tx.get::<AddressToProtocolInfo>(pool)?))
+            .unwrap()
+            .unwrap();
+        assert_eq!(info.protocol, Protocol::UniswapV2);
+        assert_eq!(info.get_tokens(), vec![Address::repeat_byte(2), Address::repeat_byte(3)]);
+        assert_eq!(info.init_block, 10);
+        assert_eq!(info.uniswap_v4_pool_key, None);
+
+        std::fs::remove_dir_all(path).unwrap();
+    }
+}
@@ -18,6 +18,7 @@ pub mod libmdbx_writer;
 
 pub mod initialize;
 mod libmdbx_read_write;
+pub mod migrations;
 use brontes_libmdbx::{RO, RW};
 use env::{DatabaseArguments, DatabaseEnv, DatabaseEnvKind};
 use eyre::Context;
@@ -70,7 +71,8 @@ impl Libmdbx {
     /// path. Creates tables if necessary. Opens in read/write mode.
     pub fn init_db<P: AsRef<Path>>(path: P, log_level: Option<LogLevel>) -> eyre::Result<Self> {
         let rpath = path.as_ref();
-        if is_database_empty(rpath) {
+        let is_new = is_database_empty(rpath);
+        if is_new {
             std::fs::create_dir_all(rpath).wrap_err_with(|| {
                 format!("Could not create database directory {}", rpath.display())
             })?;
@@ -100,6 +102,7 @@ impl Libmdbx {
 
         let this = Self(db);
         this.create_tables()?;
+        this.migrate(rpath, is_new)?;
 
         Ok(this)
     }
@@ -17,6 +17,10 @@ use brontes_types::{
         },
         clickhouse_serde::tx_trace::tx_traces_inner,
         dex::{DexKey, DexQuoteWithIndex, DexQuoteWithIndexRedefined},
+        express_lane::{
+            ExpressLaneRound, ExpressLaneRoundBids, ExpressLaneRoundBidsRedefined,
+            ExpressLaneRoundRedefined, ExpressLaneRoundReport, ExpressLaneRoundReportRedefined,
+        },
         initialized_state::{
             InitializedStateMeta, CEX_QUOTES_FLAG, CEX_TRADES_FLAG, DEX_PRICE_FLAG, META_FLAG,
             TRACE_FLAG,
@@ -58,7 +62,7 @@ use super::{
     CompressedTable,
 };
 
-pub const NUM_TABLES: usize = 14;
+pub const NUM_TABLES: usize = 17;
 
 macro_rules! tables {
     ($($table:ident),*) => {
@@ -276,7 +280,12 @@ impl Tables {
                     )
                     .await
             }
-            Tables::SearcherEOAs | Tables::SearcherContracts | Tables::InitializedState => Ok(()),
+            Tables::SearcherEOAs
+            | Tables::SearcherContracts
+            | Tables::InitializedState
+            | Tables::ExpressLaneRounds
+            | Tables::ExpressLaneReports
+            | Tables::ExpressLaneBids => Ok(()),
             _ => unimplemented!("'initialize_table' not implemented for {:?}", self),
         }
     }
@@ -373,6 +382,7 @@ impl Tables {
             Self::MevBlocks => exporter.export_mev_blocks().await,
             Self::SearcherContracts | Self::SearcherEOAs => exporter.export_searcher_info().await,
             Self::Builder => exporter.export_builder_info().await,
+            Self::ExpressLaneReports => exporter.export_express_lane_reports().await,
             _ => unreachable!("Parquet export not yet supported for this table"),
         }
     }
@@ -537,7 +547,10 @@ tables!(
     SearcherEOAs,
     SearcherContracts,
     InitializedState,
-    CexTrades
+    CexTrades,
+    ExpressLaneRounds,
+    ExpressLaneReports,
+    ExpressLaneBids
 );
 
 /// Must be in this order when defining
@@ -993,6 +1006,60 @@ compressed_table!(
     }
 );
 
+compressed_table!(
+    Table ExpressLaneRounds {
+        Data {
+            key: u64,
+            value: ExpressLaneRound,
+            compressed_value: ExpressLaneRoundRedefined
+        },
+        Init {
+            init_size: None,
+            init_method: Other,
+            http_endpoint: None
+        },
+        CLI {
+            can_insert: False
+        }
+    }
+);
+
+compressed_table!(
+    Table ExpressLaneReports {
+        Data {
+            key: u64,
+            value: ExpressLaneRoundReport,
+            compressed_value: ExpressLaneRoundReportRedefined
+        },
+        Init {
+            init_size: None,
+            init_method: Other,
+            http_endpoint: None
+        },
+        CLI {
+            can_insert: False
+        }
+    }
+);
+
+compressed_table!(
+    Table ExpressLaneBids {
+        Data {
+            key: u64,
+            value: ExpressLaneRoundBids,
+            compressed_value: ExpressLaneRoundBidsRedefined
+        },
+        Init {
+            init_size: None,
+            init_method: Other,
+            http_endpoint: None
+        },
+        CLI {
+            can_insert: False
+        }
+    }
+);
+
 compressed_table!(
     Table PoolCreationBlocks {
         #[serde_as]
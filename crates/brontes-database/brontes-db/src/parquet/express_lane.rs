@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use arrow::{
+    array::Float64Array,
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use brontes_types::db::express_lane::ExpressLaneRoundReport;
+use itertools::Itertools;
+
+use super::utils::{
+    build_float64_array, build_record_batch, build_string_array, build_uint64_array,
+    get_string_array_from_owned,
+};
+
+pub fn express_lane_reports_to_record_batch(
+    reports: Vec<ExpressLaneRoundReport>,
+) -> Result<RecordBatch, ArrowError> {
+    let round_array = build_uint64_array(reports.iter().map(|r| r.round).collect());
+    let round_start_array =
+        build_uint64_array(reports.iter().map(|r| r.round_start_timestamp).collect());
+    let round_end_array =
+        build_uint64_array(reports.iter().map(|r| r.round_end_timestamp).collect());
+    let start_block_array = build_uint64_array(reports.iter().map(|r| r.start_block).collect());
+    let end_block_array = build_uint64_array(reports.iter().map(|r| r.end_block).collect());
+
+    let bidder_array = get_string_array_from_owned(
+        reports
+            .iter()
+            .map(|r| r.bidder.map(|addr| addr.to_string()))
+            .collect_vec(),
+    );
+    let controller_array =
+        build_string_array(reports.iter().map(|r| r.controller.to_string()).collect());
+    let price_array = get_string_array_from_owned(
+        reports
+            .iter()
+            .map(|r| r.price.map(|price| price.to_string()))
+            .collect_vec(),
+    );
+
+    let price_usd_array = build_float64_array(reports.iter().map(|r| r.price_usd).collect());
+    let extracted_usd_array =
+        build_float64_array(reports.iter().map(|r| r.extracted_usd).collect());
+    let secondary_extracted_usd_array =
+        build_float64_array(reports.iter().map(|r| r.secondary_extracted_usd).collect());
+    let net_profit_usd_array =
+        build_float64_array(reports.iter().map(|r| r.net_profit_usd).collect());
+    let roi_array = Float64Array::from(reports.iter().map(|r| r.roi).collect_vec());
+    let timeboosted_tx_count_array =
+        build_uint64_array(reports.iter().map(|r| r.timeboosted_tx_count).collect());
+    let timeboosted_mev_count_array =
+        build_uint64_array(reports.iter().map(|r| r.timeboosted_mev_count).collect());
+    let transfer_count_array =
+        build_uint64_array(reports.iter().map(|r| r.transfer_count).collect());
+
+    let schema = Schema::new(vec![
+        Field::new("round", DataType::UInt64, false),
+        Field::new("round_start_timestamp", DataType::UInt64, false),
+        Field::new("round_end_timestamp", DataType::UInt64, false),
+        Field::new("start_block", DataType::UInt64, false),
+        Field::new("end_block", DataType::UInt64, false),
+        Field::new("bidder", DataType::Utf8, true),
+        Field::new("controller", DataType::Utf8, false),
+        Field::new("price", DataType::Utf8, true),
+        Field::new("price_usd", DataType::Float64, false),
+        Field::new("extracted_usd", DataType::Float64, false),
+        Field::new("secondary_extracted_usd", DataType::Float64, false),
+        Field::new("net_profit_usd", DataType::Float64, false),
+        Field::new("roi", DataType::Float64, true),
+        Field::new("timeboosted_tx_count", DataType::UInt64, false),
+        Field::new("timeboosted_mev_count", DataType::UInt64, false),
+        Field::new("transfer_count", DataType::UInt64, false),
+    ]);
+
+    build_record_batch(
+        schema,
+        vec![
+            Arc::new(round_array),
+            Arc::new(round_start_array),
+            Arc::new(round_end_array),
+            Arc::new(start_block_array),
+            Arc::new(end_block_array),
+            Arc::new(bidder_array),
+            Arc::new(controller_array),
+            Arc::new(price_array),
+            Arc::new(price_usd_array),
+            Arc::new(extracted_usd_array),
+            Arc::new(secondary_extracted_usd_array),
+            Arc::new(net_profit_usd_array),
+            Arc::new(roi_array),
+            Arc::new(timeboosted_tx_count_array),
+            Arc::new(timeboosted_mev_count_array),
+            Arc::new(transfer_count_array),
+        ],
+    )
+}
@@ -24,6 +24,7 @@ use crate::Tables;
 mod address_meta;
 mod builder;
 mod bundle_header;
+mod express_lane;
 mod mev_block;
 mod mev_data;
 mod normalized_actions;
@@ -33,6 +34,7 @@ pub mod utils;
 use address_meta::address_metadata_to_record_batch;
 use builder::builder_info_to_record_batch;
 use bundle_header::bundle_headers_to_record_batch;
+use express_lane::express_lane_reports_to_record_batch;
 use mev_block::mev_block_to_record_batch;
 use mev_data::*;
 use searcher::searcher_info_to_record_batch;
@@ -334,6 +336,35 @@ where
 
         Ok(())
     }
+
+    pub async fn export_express_lane_reports(&self) -> Result<(), Error> {
+        let reports = self
+            .db
+            .fetch_all_express_lane_reports()
+            .wrap_err("Failed to query express lane reports table")?
+            .into_iter()
+            .filter(|report| {
+                self.start_block
+                    .map_or(true, |start| report.end_block >= start)
+                    && self.end_block.map_or(true, |end| report.start_block <= end)
+            })
+            .collect::<Vec<_>>();
+
+        if reports.is_empty() {
+            error!("No express lane reports fetched for the given range.");
+            return Err(Error::msg("No express lane reports fetched for the given range."))
+        }
+
+        let reports_batch = express_lane_reports_to_record_batch(reports)
+            .wrap_err("Failed to convert express lane reports to record batch")?;
+
+        write_parquet(
+            reports_batch,
+            get_path(self.base_dir_path.clone(), Tables::ExpressLaneReports, None)?,
+        )
+        .await
+        .wrap_err("Failed to write express lane reports to parquet file")
+    }
 }
 
 async fn write_parquet(record_batch: RecordBatch, file_path: PathBuf) -> Result<()> {
@@ -424,6 +455,7 @@ impl Tables {
             Tables::SearcherEOAs => DEFAULT_SEARCHER_INFO_DIR,
             Tables::SearcherContracts => DEFAULT_SEARCHER_INFO_DIR,
             Tables::Builder => DEFAULT_BUILDER_INFO_DIR,
+            Tables::ExpressLaneReports => DEFAULT_EXPRESS_LANE_REPORTS_DIR,
             _ => panic!("Unsupported table type"),
         }
     }
@@ -433,3 +465,4 @@ pub const DEFAULT_BLOCK_DIR: &str = "mev";
 pub const DEFAULT_METADATA_DIR: &str = "address_metadata";
 pub const DEFAULT_SEARCHER_INFO_DIR: &str = "searcher_info";
 pub const DEFAULT_BUILDER_INFO_DIR: &str = "builder-info";
+pub const DEFAULT_EXPRESS_LANE_REPORTS_DIR: &str = "express_lane_reports";
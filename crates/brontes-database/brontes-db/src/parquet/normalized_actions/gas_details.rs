@@ -40,6 +40,11 @@ pub fn get_gas_details_list_array(gas_details_list: Vec<&Vec<GasDetails>>) -> Li
                 .field_builder::<StringBuilder>(3)
                 .unwrap()
                 .append_value(gas_details.effective_gas_price.to_string());
+
+            struct_builder
+                .field_builder::<StringBuilder>(4)
+                .unwrap()
+                .append_value(gas_details.l1_gas_used.to_string());
             struct_builder.append(true);
         }
 
@@ -83,6 +88,11 @@ pub fn get_gas_details_array(gas_details: Vec<GasDetails>) -> StructArray {
             .unwrap()
             .append_value(gas_detail.effective_gas_price.to_string());
 
+        struct_builder
+            .field_builder::<StringBuilder>(4)
+            .unwrap()
+            .append_value(gas_detail.l1_gas_used.to_string());
+
         struct_builder.append(true);
     }
 
@@ -95,6 +105,7 @@ fn gas_details_fields() -> Vec<Field> {
         Field::new("priority_fee", DataType::Utf8, false),
         Field::new("gas_used", DataType::Utf8, false),
         Field::new("effective_gas_price", DataType::Utf8, false),
+        Field::new("l1_gas_used", DataType::Utf8, false),
     ]
 }
 
@@ -104,5 +115,6 @@ fn gas_details_struct_builder() -> Vec<Box<dyn ArrayBuilder>> {
         Box::new(StringBuilder::new()),
         Box::new(StringBuilder::new()),
         Box::new(StringBuilder::new()),
+        Box::new(StringBuilder::new()),
     ]
 }
@@ -1,6 +1,6 @@
 use brontes_database::{clickhouse::Clickhouse, libmdbx::cex_utils::CexRangeOrArbitrary};
 use brontes_types::{
-    constants::{USDT_ADDRESS, WETH_ADDRESS},
+    constants::arbitrum::{USDT_ADDRESS, WETH_ADDRESS},
     db::cex::{
         quotes::{CexQuote, CexQuotesConverter},
         CexExchange,
@@ -109,9 +109,34 @@ define_mev_precedence!(
     Unknown, SearcherTx => CexDexQuotes;
     Unknown, SearcherTx => CexDexTrades;
     Unknown, SearcherTx => AtomicArb;
-    Unknown, SearcherTx, AtomicArb => Jit;
-    Unknown, SearcherTx, AtomicArb, CexDexQuotes,CexDexTrades  => Liquidation;
-    Unknown, SearcherTx, AtomicArb, CexDexQuotes,CexDexTrades  => Sandwich;
-    Unknown, SearcherTx, AtomicArb, Jit, CexDexQuotes, CexDexTrades=> JitCexDex;
-    Unknown, SearcherTx, AtomicArb, CexDexQuotes, CexDexTrades, Jit, Sandwich => JitSandwich;
+    // express lane backruns and cex races are also arbs, keep them as timeboost
+    // so that the round they were bought in isn't lost
+    Unknown, SearcherTx, AtomicArb, CexDexQuotes, CexDexTrades => Timeboost;
+    Unknown, SearcherTx, Timeboost, AtomicArb => Jit;
+    Unknown, SearcherTx, Timeboost, AtomicArb, CexDexQuotes,CexDexTrades  => Liquidation;
+    Unknown, SearcherTx, Timeboost, AtomicArb, CexDexQuotes,CexDexTrades  => Sandwich;
+    Unknown, SearcherTx, Timeboost, AtomicArb, Jit, CexDexQuotes, CexDexTrades=> JitCexDex;
+    Unknown, SearcherTx, Timeboost, AtomicArb, CexDexQuotes, CexDexTrades, Jit, Sandwich
+        => JitSandwich;
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subordinates_of(dominant: MevType) -> Vec<MevType> {
+        MEV_DEDUPLICATION_FILTER
+            .iter()
+            .filter(|(mev_type, ..)| *mev_type == dominant)
+            .flat_map(|(_, _, subordinates)| subordinates.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_timeboost_survives_arbs() {
+        for arb in [MevType::AtomicArb, MevType::CexDexQuotes, MevType::CexDexTrades] {
+            assert!(!subordinates_of(arb).contains(&MevType::Timeboost));
+            assert!(subordinates_of(MevType::Timeboost).contains(&arb));
+        }
+    }
+}
@@ -1,12 +1,17 @@
 use std::sync::Arc;
 
-use alloy_primitives::{Address, FixedBytes};
+use alloy_primitives::{Address, FixedBytes, B256, U256};
 use brontes_types::{
+    chain::chain_config,
     db::{builder::BuilderInfo, metadata::Metadata, traits::LibmdbxReader},
-    mev::{Bundle, Mev, MevBlock, MevCount, MevType, PossibleMevCollection},
+    express_lane::ExpressLaneMetaData,
+    mev::{
+        Bundle, BundleData, Mev, MevBlock, MevCount, MevType, PossibleMevCollection,
+        SequencerRevenue,
+    },
     normalized_actions::Action,
     tree::BlockTree,
-    FastHashMap, GasDetails, ToFloatNearest, ToScaledRational, TreeSearchBuilder,
+    FastHashMap, FastHashSet, GasDetails, ToFloatNearest, ToScaledRational, TreeSearchBuilder,
 };
 use malachite::{num::conversion::traits::RoundingFrom, rounding_modes::RoundingMode};
 
@@ -31,7 +36,16 @@ pub(crate) fn build_mev_header<DB: LibmdbxReader>(
 
     let pre_processing = pre_process(tree.clone());
 
-    let block_pnl = calculate_builder_profit(tree.clone(), metadata, orchestra_data, &pre_processing);
+    // there is no builder or proposer on sequencer chains, the sequencer summary
+    // takes their place
+    let (block_pnl, sequencer_revenue) = if chain_config().has_sequencer() {
+        (
+            BlockPnL::without_builder(calculate_timeboosted_profit(orchestra_data)),
+            Some(calculate_sequencer_revenue(tree.clone(), metadata, orchestra_data, quote_token)),
+        )
+    } else {
+        (calculate_builder_profit(tree.clone(), metadata, orchestra_data, &pre_processing), None)
+    };
 
     let builder_searcher_bribes_usd = f64::rounding_from(
         block_pnl.builder_searcher_tip.to_scaled_rational(18) * &eth_price,
@@ -84,6 +98,11 @@ pub(crate) fn build_mev_header<DB: LibmdbxReader>(
         timeboosted_profit_usd: block_pnl.timeboosted_profit,
         timeboosted_tx_count,
         timeboosted_tx_mev_count,
+        express_lane_round: metadata
+            .express_lane_auction
+            .as_ref()
+            .map(|auction| auction.round),
+        sequencer_revenue,
         possible_mev,
     }
 }
@@ -197,6 +216,7 @@ fn update_mev_count(mev_count: &mut MevCount, mev_type: MevType, count: u64) {
         MevType::AtomicArb => mev_count.atomic_backrun_count = Some(count),
         MevType::Liquidation => mev_count.liquidation_count = Some(count),
         MevType::SearcherTx => mev_count.searcher_tx_count = Some(count),
+        MevType::Timeboost => mev_count.timeboost_count = Some(count),
         MevType::Unknown => (),
     }
 }
@@ -244,6 +264,11 @@ impl BlockPnL {
             timeboosted_profit,
         }
     }
+
+    /// PnL of a block that has no builder or proposer to pay
+    pub fn without_builder(timeboosted_profit: f64) -> Self {
+        Self::new(0, 0, 0.0, None, None, 0, false, timeboosted_profit)
+    }
 }
 
 /// Calculate builder's block PnL
@@ -266,10 +291,7 @@ pub fn calculate_builder_profit(
     let mut mev_searching_profit = 0.0;
     let mut vertically_integrated_searcher_tip = 0;
 
-    let timeboosted_profit = bundles
-        .iter()
-        .filter(|bundle| bundle.header.timeboosted)
-        .fold(0.0, |acc, bundle| acc + bundle.header.profit_usd);
+    let timeboosted_profit = calculate_timeboosted_profit(bundles);
 
     // Calculate the proposer's mev reward & find the proposer fee recipient address
     // If this fails we fallback to the default values queried from the mev-boost
@@ -320,6 +342,124 @@ pub fn calculate_builder_profit(
     )
 }
 
+/// Calculates where the fees of an Arbitrum block went
+///
+/// Execution fees are split between the infra and network fee accounts at the
+/// minimum base fee, L1 data fees are kept apart and the express lane price of
+/// the round is spread evenly over the blocks it covers.
+pub fn calculate_sequencer_revenue(
+    tree: Arc<BlockTree<Action>>,
+    metadata: &Arc<Metadata>,
+    bundles: &[Bundle],
+    quote_token: Address,
+) -> SequencerRevenue {
+    let base_fee = tree.header.base_fee_per_gas.unwrap_or_default() as u128;
+    let infra_fee_price = base_fee.min(chain_config().min_base_fee());
+
+    let (base_fee_paid, execution_fees, infra_fee_collected, l1_surplus_fee) =
+        tree.tx_roots.iter().fold(
+            (0u128, 0u128, 0u128, 0u128),
+            |(base_fee_paid, execution_fees, infra_fees, l1_fees), root| {
+                let gas_details = &root.gas_details;
+                (
+                    base_fee_paid + gas_details.gas_used * base_fee,
+                    execution_fees
+                        + gas_details.execution_fee_paid()
+                        + gas_details.coinbase_transfer(),
+                    infra_fees + gas_details.gas_used * infra_fee_price,
+                    l1_fees + gas_details.l1_fee_paid(),
+                )
+            },
+        );
+
+    let express_lane_proceeds = metadata
+        .express_lane_auction
+        .as_ref()
+        .map(express_lane_proceeds_per_block)
+        .unwrap_or_default();
+
+    let mev_fees_paid = calculate_mev_fees_paid(
+        bundles,
+        tree.tx_roots
+            .iter()
+            .map(|root| (root.tx_hash, &root.gas_details)),
+    );
+
+    let mut revenue = SequencerRevenue {
+        base_fee_paid,
+        network_fee_collected: execution_fees.saturating_sub(infra_fee_collected),
+        infra_fee_collected,
+        l1_surplus_fee,
+        express_lane_proceeds,
+        mev_fees_paid,
+        ..Default::default()
+    };
+
+    let total_fees = revenue.total_fees();
+    if total_fees != 0 {
+        revenue.mev_fee_share = mev_fees_paid as f64 / total_fees as f64;
+    }
+    revenue.total_revenue_usd = metadata
+        .get_gas_price_usd(total_fees + express_lane_proceeds, quote_token)
+        .to_float();
+
+    revenue
+}
+
+/// Share of the round's second price attributed to a single block. Round
+/// timestamps are inclusive, so a round lasts `end - start + 1` seconds.
+fn express_lane_proceeds_per_block(auction: &ExpressLaneMetaData) -> u128 {
+    let Some(price) = auction.price else { return 0 };
+    if auction.round_end_timestamp == 0
+        || auction.round_end_timestamp < auction.round_start_timestamp
+    {
+        return 0
+    }
+    let round_millis = (auction.round_end_timestamp - auction.round_start_timestamp + 1) * 1000;
+
+    (price * U256::from(chain_config().block_time_millis()) / U256::from(round_millis))
+        .saturating_to()
+}
+
+/// Gas paid by the searcher transactions of the bundles. A transaction that is
+/// part of several bundles is only counted once, and sandwich victims aren't
+/// counted at all.
+fn calculate_mev_fees_paid<'a>(
+    bundles: &[Bundle],
+    txes: impl Iterator<Item = (B256, &'a GasDetails)>,
+) -> u128 {
+    let searcher_txes = bundles
+        .iter()
+        .flat_map(|bundle| match &bundle.data {
+            BundleData::Sandwich(sandwich) => sandwich
+                .frontrun_tx_hash
+                .iter()
+                .copied()
+                .chain([sandwich.backrun_tx_hash])
+                .collect(),
+            BundleData::JitSandwich(sandwich) => sandwich
+                .frontrun_tx_hash
+                .iter()
+                .copied()
+                .chain([sandwich.backrun_tx_hash])
+                .collect(),
+            data => data.mev_transaction_hashes(),
+        })
+        .collect::<FastHashSet<_>>();
+
+    txes.filter(|(tx_hash, _)| searcher_txes.contains(tx_hash))
+        .map(|(_, gas_details)| gas_details.gas_paid())
+        .sum()
+}
+
+/// Sums the profit of the timeboosted bundles in the block
+fn calculate_timeboosted_profit(bundles: &[Bundle]) -> f64 {
+    bundles
+        .iter()
+        .filter(|bundle| bundle.header.timeboosted)
+        .fold(0.0, |acc, bundle| acc + bundle.header.profit_usd)
+}
+
 fn proposer_payment(
     tree: &Arc<BlockTree<Action>>,
     builder_address: Address,
@@ -487,3 +627,54 @@ fn calculate_block_mev_stats(orchestra_data: &[Bundle], base_fee: u128) -> (u128
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use brontes_types::mev::{AtomicArb, BundleHeader, Sandwich, Timeboost};
+
+    use super::*;
+
+    #[test]
+    fn test_express_lane_proceeds_cover_the_full_round() {
+        let auction = ExpressLaneMetaData {
+            price: Some(U256::from(60_000_000u64)),
+            round_start_timestamp: 1_000,
+            round_end_timestamp: 1_059,
+            ..Default::default()
+        };
+
+        // a 60 second round, both timestamps inclusive
+        let block_time = chain_config().block_time_millis() as u128;
+        assert_eq!(express_lane_proceeds_per_block(&auction), 1_000 * block_time);
+
+        let unresolved = ExpressLaneMetaData { price: None, ..auction.clone() };
+        assert_eq!(express_lane_proceeds_per_block(&unresolved), 0);
+        let no_round = ExpressLaneMetaData { round_end_timestamp: 0, ..auction };
+        assert_eq!(express_lane_proceeds_per_block(&no_round), 0);
+    }
+
+    #[test]
+    fn test_mev_fees_paid_counts_each_tx_once() {
+        let [arb, frontrun, victim, backrun] = [1, 2, 3, 4].map(B256::with_last_byte);
+        let bundle = |data| Bundle { header: BundleHeader::default(), data };
+
+        let bundles = vec![
+            bundle(BundleData::AtomicArb(AtomicArb { tx_hash: arb, ..Default::default() })),
+            bundle(BundleData::Timeboost(Timeboost { tx_hash: arb, ..Default::default() })),
+            bundle(BundleData::Sandwich(Sandwich {
+                frontrun_tx_hash: vec![frontrun],
+                victim_swaps_tx_hashes: vec![vec![victim]],
+                backrun_tx_hash: backrun,
+                ..Default::default()
+            })),
+        ];
+
+        let gas = |gas_used| GasDetails { gas_used, effective_gas_price: 1, ..Default::default() };
+        let txes = [(arb, gas(100)), (frontrun, gas(10)), (victim, gas(1_000)), (backrun, gas(1))];
+
+        assert_eq!(
+            calculate_mev_fees_paid(&bundles, txes.iter().map(|(hash, gas)| (*hash, gas))),
+            111
+        );
+    }
+}
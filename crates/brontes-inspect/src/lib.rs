@@ -34,6 +34,7 @@
 //! - [`sandwich`](sandwich/index.html)
 //! - [`liquidations`](liquidations/index.html)
 //! - [`long_tail`](long_tail/index.html)
+//! - [`timeboost`](timeboost/index.html)
 //!
 //! Each inspector implements the `Inspector` trait and provides its own
 //! implementation of the `inspect_block` method.
@@ -75,7 +76,7 @@ use brontes_types::{
         metadata::Metadata,
         traits::LibmdbxReader,
     },
-    mev::{Bundle, BundleData},
+    mev::{Bundle, BundleData, MevType},
     normalized_actions::Action,
     tree::BlockTree,
     MultiBlockData,
@@ -84,6 +85,7 @@ use cex_dex::{markout::CexDexMarkoutInspector, quotes::CexDexQuotesInspector};
 use jit::JitCexDex;
 use liquidations::LiquidationInspector;
 use sandwich::SandwichInspector;
+use timeboost::TimeboostInspector;
 
 use crate::jit::jit_liquidity::JitInspector;
 
@@ -112,11 +114,27 @@ pub enum Inspectors {
     SearcherActivity,
     CexDexMarkout,
     JitCexDex,
+    Timeboost,
 }
 
 type DynMevInspector = &'static (dyn Inspector<Result = Vec<Bundle>> + 'static);
 
 impl Inspectors {
+    /// The mev type of the bundles the inspector produces
+    pub fn mev_type(&self) -> MevType {
+        match self {
+            Self::AtomicArb => MevType::AtomicArb,
+            Self::CexDex => MevType::CexDexQuotes,
+            Self::Jit => MevType::Jit,
+            Self::Liquidations => MevType::Liquidation,
+            Self::Sandwich => MevType::Sandwich,
+            Self::SearcherActivity => MevType::SearcherTx,
+            Self::CexDexMarkout => MevType::CexDexTrades,
+            Self::JitCexDex => MevType::JitCexDex,
+            Self::Timeboost => MevType::Timeboost,
+        }
+    }
+
     pub fn init_mev_inspector<DB: LibmdbxReader>(
         &self,
         quote_token: Address,
@@ -177,6 +195,10 @@ impl Inspectors {
                 ),
                 jit:     JitInspector::new(quote_token, db, metrics, profit_metrics),
             }) as DynMevInspector,
+            Self::Timeboost => {
+                static_object(TimeboostInspector::new(quote_token, db, metrics, profit_metrics))
+                    as DynMevInspector
+            }
         }
     }
 }
@@ -3,15 +3,12 @@ use std::sync::Arc;
 use brontes_database::libmdbx::LibmdbxReader;
 use brontes_metrics::inspectors::{OutlierMetrics, ProfitMetrics};
 use brontes_types::{
-    constants::{
-        get_stable_type, is_euro_stable, is_gold_stable, is_usd_stable, StableType,
-        FILTER_TRANSFER_ADDRESSES,
-    },
+    chain::chain_config,
     db::dex::PriceAt,
     mev::{AtomicArb, AtomicArbType, Bundle, BundleData, MevType},
     normalized_actions::{
-        accounting::ActionAccounting, Action, NormalizedEthTransfer, NormalizedSwap,
-        NormalizedTransfer,
+        accounting::ActionAccounting, Action, NormalizedBridge, NormalizedEthTransfer,
+        NormalizedSwap, NormalizedTransfer, NormalizedUnwrap, NormalizedWrap,
     },
     BlockData, FastHashSet, IntoZip, MultiBlockData, ToFloatNearest, TreeBase, TreeCollector,
     TreeSearchBuilder, TxInfo,
@@ -67,6 +64,9 @@ impl<DB: LibmdbxReader> Inspector for AtomicArbInspector<'_, DB> {
                     Action::is_swap,
                     Action::is_transfer,
                     Action::is_eth_transfer,
+                    Action::is_wrap,
+                    Action::is_unwrap,
+                    Action::is_bridge,
                     Action::is_nested_action,
                 ]))
                 .t_full_map(|(tree, v)| {
@@ -85,12 +85,23 @@ impl<DB: LibmdbxReader> Inspector for AtomicArbInspector<'_, DB> {
                     let info = info??;
                     let actions = action?;
 
-                    let (swaps, transfers, eth_transfers, burn, mint) = actions
-                        .into_iter()
-                        .split_actions::<(Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>), _>((
+                    let (swaps, transfers, eth_transfers, wraps, unwraps, bridges, burn, mint) =
+                        actions.into_iter().split_actions::<(
+                            Vec<_>,
+                            Vec<_>,
+                            Vec<_>,
+                            Vec<_>,
+                            Vec<_>,
+                            Vec<_>,
+                            Vec<_>,
+                            Vec<_>,
+                        ), _>((
                             Action::try_swaps_merged,
                             Action::try_transfer,
                             Action::try_eth_transfer,
+                            Action::try_wrap,
+                            Action::try_unwrap,
+                            Action::try_bridge,
                             Action::try_burn,
                             Action::try_mint,
                         ));
@@ -106,7 +117,7 @@ impl<DB: LibmdbxReader> Inspector for AtomicArbInspector<'_, DB> {
                             .collect_vec(),
                         info,
                         metadata.clone(),
-                        (swaps, transfers, eth_transfers),
+                        (swaps, transfers, eth_transfers, wraps, unwraps, bridges),
                     )
                 })
                 .collect::<Vec<_>>()
@@ -125,10 +136,17 @@ impl<DB: LibmdbxReader> AtomicArbInspector<'_, DB> {
         trees: Vec<Arc<BlockTree<Action>>>,
         info: TxInfo,
         metadata: Arc<Metadata>,
-        data: (Vec<NormalizedSwap>, Vec<NormalizedTransfer>, Vec<NormalizedEthTransfer>),
+        data: (
+            Vec<NormalizedSwap>,
+            Vec<NormalizedTransfer>,
+            Vec<NormalizedEthTransfer>,
+            Vec<NormalizedWrap>,
+            Vec<NormalizedUnwrap>,
+            Vec<NormalizedBridge>,
+        ),
     ) -> Option<Bundle> {
         tracing::trace!(?info, "trying atomic");
-        let (mut swaps, transfers, eth_transfers) = data;
+        let (mut swaps, transfers, eth_transfers, wraps, unwraps, bridges) = data;
 
         let mut mev_addresses: FastHashSet<Address> = info.collect_address_set_for_accounting();
 
@@ -155,13 +173,16 @@ impl<DB: LibmdbxReader> AtomicArbInspector<'_, DB> {
 
         swaps.extend(self.utils.try_create_swaps(&transfers, ignore_addresses));
 
-        let possible_arb_type = self.is_possible_arb(&swaps, &transfers)?;
+        let possible_arb_type =
+            self.is_possible_arb(&swaps, &transfers, &bridges, &mev_addresses)?;
 
         let account_deltas = transfers
             .clone()
             .into_iter()
             .map(Action::from)
             .chain(eth_transfers.into_iter().map(Action::from))
+            .chain(wraps.into_iter().map(Action::from))
+            .chain(unwraps.into_iter().map(Action::from))
             .chain(info.get_total_eth_value().iter().cloned().map(Action::from))
             .account_for_actions();
 
@@ -382,10 +403,14 @@ impl<DB: LibmdbxReader> AtomicArbInspector<'_, DB> {
         &self,
         swaps: &[NormalizedSwap],
         transfers: &[NormalizedTransfer],
+        bridges: &[NormalizedBridge],
+        mev_addresses: &FastHashSet<Address>,
     ) -> Option<AtomicArbType> {
         // We filter out all the possible arbitrages that interacts with Crosschain swap
         // protocols.
-        if self.is_bridge_or_crosschain_arb(transfers) {
+        if is_bridged_arb(swaps, bridges, mev_addresses)
+            || self.is_bridge_or_crosschain_arb(transfers)
+        {
             return Some(AtomicArbType::CrossChain)
         }
 
@@ -472,13 +497,29 @@ impl<DB: LibmdbxReader> AtomicArbInspector<'_, DB> {
     }
 
     fn is_bridge_or_crosschain_arb(&self, transfers: &[NormalizedTransfer]) -> bool {
+        let filter_addresses = chain_config().filter_transfer_addresses();
         transfers.iter().any(|transfer| {
-            FILTER_TRANSFER_ADDRESSES.contains(&transfer.from)
-                || FILTER_TRANSFER_ADDRESSES.contains(&transfer.to)
+            filter_addresses.contains(&transfer.from) || filter_addresses.contains(&transfer.to)
         })
     }
 }
 
+/// A bridge only makes the arb cross chain when it moves one of the swapped
+/// tokens to or from the searcher, unrelated bridge calls in the same tx don't.
+fn is_bridged_arb(
+    swaps: &[NormalizedSwap],
+    bridges: &[NormalizedBridge],
+    mev_addresses: &FastHashSet<Address>,
+) -> bool {
+    bridges.iter().any(|bridge| {
+        (mev_addresses.contains(&bridge.from) || mev_addresses.contains(&bridge.recipient))
+            && swaps.iter().any(|swap| {
+                swap.token_in.address == bridge.token.address
+                    || swap.token_out.address == bridge.token.address
+            })
+    })
+}
+
 fn identify_arb_sequence(swaps: &[NormalizedSwap]) -> Option<AtomicArbType> {
     let start_token = &swaps.first().unwrap().token_in.symbol;
     let end_token = &swaps.last().unwrap().token_out.symbol;
@@ -507,27 +548,73 @@ fn identify_arb_sequence(swaps: &[NormalizedSwap]) -> Option<AtomicArbType> {
 }
 
 pub fn is_stable_pair(token_in: &str, token_out: &str) -> bool {
-    if let Some(stable_type) = get_stable_type(token_in) {
-        match stable_type {
-            StableType::USD => is_usd_stable(token_out),
-            StableType::EURO => is_euro_stable(token_out),
-            StableType::GOLD => is_gold_stable(token_out),
-        }
-    } else {
-        false
-    }
+    let chain = chain_config();
+    chain
+        .stable_type(token_in)
+        .is_some_and(|stable_type| chain.stable_type(token_out) == Some(stable_type))
 }
 
 #[cfg(test)]
 mod tests {
     use alloy_primitives::hex;
-    use brontes_types::constants::USDT_ADDRESS;
+    use brontes_types::{
+        constants::arbitrum::USDT_ADDRESS,
+        db::token_info::{TokenInfo, TokenInfoWithAddress},
+        normalized_actions::{NormalizedBridge, NormalizedSwap},
+        FastHashSet,
+    };
+    use reth_primitives::Address;
 
+    use super::is_bridged_arb;
     use crate::{
         test_utils::{InspectorTestUtils, InspectorTxRunConfig, USDC_ADDRESS, WETH_ADDRESS},
         Inspectors,
     };
 
+    fn token(address: Address) -> TokenInfoWithAddress {
+        TokenInfoWithAddress { address, inner: TokenInfo { decimals: 18, symbol: String::new() } }
+    }
+
+    #[test]
+    fn test_only_bridges_in_the_arb_flow_are_cross_chain() {
+        let searcher = Address::repeat_byte(1);
+        let swaps = vec![
+            NormalizedSwap {
+                token_in: token(WETH_ADDRESS),
+                token_out: token(USDC_ADDRESS),
+                ..Default::default()
+            },
+            NormalizedSwap {
+                token_in: token(USDC_ADDRESS),
+                token_out: token(USDT_ADDRESS),
+                ..Default::default()
+            },
+        ];
+        let mev_addresses = FastHashSet::from_iter([searcher]);
+
+        let bridge = |from: Address, token_address: Address| NormalizedBridge {
+            from,
+            recipient: from,
+            token: token(token_address),
+            ..Default::default()
+        };
+
+        // the searcher bridges out the token it bought
+        assert!(is_bridged_arb(&swaps, &[bridge(searcher, USDT_ADDRESS)], &mev_addresses));
+        // someone else's bridge call in the same tx
+        assert!(!is_bridged_arb(
+            &swaps,
+            &[bridge(Address::repeat_byte(2), USDT_ADDRESS)],
+            &mev_addresses
+        ));
+        // the searcher bridging a token the arb never touched
+        assert!(!is_bridged_arb(
+            &swaps,
+            &[bridge(searcher, Address::repeat_byte(3))],
+            &mev_addresses
+        ));
+    }
+
     #[brontes_macros::test]
     async fn test_backrun() {
         let inspector_util = InspectorTestUtils::new(USDC_ADDRESS, 0.5).await;
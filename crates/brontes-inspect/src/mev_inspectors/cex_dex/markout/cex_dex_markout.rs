@@ -751,7 +751,7 @@ pub fn max_arb_delta(tx_info: &TxInfo, pnl: &Rational) -> Rational {
 mod tests {
 
     use alloy_primitives::hex;
-    use brontes_types::constants::USDT_ADDRESS;
+    use brontes_types::constants::arbitrum::USDT_ADDRESS;
 
     use crate::{
         test_utils::{InspectorTestUtils, InspectorTxRunConfig},
@@ -2,6 +2,7 @@ use std::{fmt, sync::Arc};
 
 use alloy_primitives::FixedBytes;
 use brontes_types::{
+    chain::chain_config,
     db::cex::{
         trades::{
             optimistic::OptimisticPrice,
@@ -676,10 +677,7 @@ pub fn log_cex_trade_price_delta(
 
     let arb_percent = (arb_ratio.clone().to_float() - 1.0) * 100.0;
 
-    #[cfg(feature = "arbitrum")]
-    let explorer_url = "https://arbiscan.io";
-    #[cfg(not(feature = "arbitrum"))]
-    let explorer_url = "https://etherscan.io";
+    let explorer_url = chain_config().explorer_url();
 
     warn!(
         "\n\x1b[1;35mSignificant CEX trade price discrepancy detected for {} - {}:\x1b[0m\n- \
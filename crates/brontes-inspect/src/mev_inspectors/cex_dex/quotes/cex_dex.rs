@@ -599,7 +599,7 @@ pub fn max_arb_delta(tx_info: &TxInfo, pnl: &Rational) -> Rational {
 mod tests {
 
     use alloy_primitives::hex;
-    use brontes_types::constants::{USDT_ADDRESS, WBTC_ADDRESS, WETH_ADDRESS};
+    use brontes_types::constants::arbitrum::{USDT_ADDRESS, WBTC_ADDRESS, WETH_ADDRESS};
 
     use crate::{
         test_utils::{InspectorTestUtils, InspectorTxRunConfig},
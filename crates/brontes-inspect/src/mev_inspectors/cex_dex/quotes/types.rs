@@ -1,4 +1,5 @@
 use brontes_types::{
+    chain::chain_config,
     db::cex::CexExchange,
     mev::{BundleData, CexDexQuote},
     normalized_actions::NormalizedSwap,
@@ -113,10 +114,7 @@ pub fn log_cex_dex_quote_delta(
 
     let arb_percent = (arb_ratio.clone().to_float() - 1.0) * 100.0;
 
-    #[cfg(feature = "arbitrum")]
-    let explorer_url = "https://arbiscan.io";
-    #[cfg(not(feature = "arbitrum"))]
-    let explorer_url = "https://etherscan.io";
+    let explorer_url = chain_config().explorer_url();
 
     warn!(
         "\n\x1b[1;35mSignificant Cex-Dex quote discrepancy detected for {} - {} on {}:\x1b[0m\n- \
@@ -54,6 +54,10 @@ impl<DB: LibmdbxReader> Inspector for LiquidationInspector<'_, DB> {
                     Action::is_transfer,
                     Action::is_eth_transfer,
                     Action::is_aggregator,
+                    Action::is_loan,
+                    Action::is_repayment,
+                    Action::is_supply,
+                    Action::is_withdraw,
                 ]))
                 .unzip();
             let tx_info = tree.get_tx_info_batch(&tx, self.utils.db);
@@ -118,7 +122,16 @@ impl<DB: LibmdbxReader> LiquidationInspector<'_, DB> {
         let deltas = actions
             .into_iter()
             .chain(info.get_total_eth_value().iter().cloned().map(Action::from))
-            .filter(|a| a.is_eth_transfer() || a.is_transfer())
+            // lending actions taken to set up the liquidation replace the transfers
+            // they cover
+            .filter(|a| {
+                a.is_eth_transfer()
+                    || a.is_transfer()
+                    || a.is_loan()
+                    || a.is_repayment()
+                    || a.is_supply()
+                    || a.is_withdraw()
+            })
             .account_for_actions();
 
         let (rev, mut has_dex_price) = if let Some(rev) = self.utils.get_deltas_usd(
@@ -6,6 +6,7 @@ pub mod liquidations;
 pub mod sandwich;
 pub mod searcher_activity;
 pub mod shared_utils;
+pub mod timeboost;
 
 use malachite::Rational;
 
@@ -87,6 +87,8 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
             Action::is_swap,
             Action::is_transfer,
             Action::is_eth_transfer,
+            Action::is_wrap,
+            Action::is_unwrap,
             Action::is_nested_action,
         ]);
 
@@ -303,7 +305,7 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
             .into_iter()
             .flatten()
             .chain(back_run_actions)
-            .filter(|f| f.is_transfer() || f.is_eth_transfer())
+            .filter(|f| f.is_transfer() || f.is_eth_transfer() || f.is_wrap() || f.is_unwrap())
             .chain(
                 possible_front_runs_info
                     .iter()
@@ -1142,7 +1144,7 @@ fn get_possible_sandwich_duplicate_contracts(
 mod tests {
 
     use alloy_primitives::hex;
-    use brontes_types::constants::{DAI_ADDRESS, USDT_ADDRESS, WETH_ADDRESS};
+    use brontes_types::constants::arbitrum::{DAI_ADDRESS, USDT_ADDRESS, WETH_ADDRESS};
 
     use super::*;
     use crate::{
@@ -878,7 +878,7 @@ impl<DB: LibmdbxReader> SharedInspectorUtils<'_, DB> {
 pub mod test {
     use brontes_core::LibmdbxReadWriter;
     use brontes_types::{
-        constants::{USDC_ADDRESS, USDT_ADDRESS, WETH_ADDRESS},
+        constants::arbitrum::{USDC_ADDRESS, USDT_ADDRESS, WETH_ADDRESS},
         normalized_actions::NormalizedSwap,
     };
     use malachite::Rational;
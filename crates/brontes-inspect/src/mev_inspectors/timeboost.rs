@@ -0,0 +1,477 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_metrics::inspectors::{OutlierMetrics, ProfitMetrics};
+use brontes_types::{
+    db::dex::PriceAt,
+    mev::{Bundle, BundleData, MevType, Timeboost, TimeboostStrategy},
+    normalized_actions::{accounting::ActionAccounting, Action, NormalizedSwap},
+    pair::Pair,
+    tree::BlockTree,
+    ActionIter, BlockData, FastHashMap, MultiBlockData, ToFloatNearest, TreeSearchBuilder, TxInfo,
+};
+use itertools::{multizip, Itertools};
+use malachite::{num::basic::traits::Zero, Rational};
+use reth_primitives::{Address, B256};
+
+use super::{MAX_PROFIT, MIN_PROFIT};
+use crate::{shared_utils::SharedInspectorUtils, Inspector, Metadata};
+
+/// How far back from the block timestamp the CEX price is compared against
+/// when looking for a price move the boosted transaction reacted to.
+const CEX_PRICE_MOVE_WINDOW_US: u64 = 1_000_000;
+/// Smallest CEX mid price move, in basis points, that counts as a move a
+/// boosted swap could have been racing to capture.
+const MIN_CEX_PRICE_MOVE_BPS: f64 = 5.0;
+/// Blocks, including the inspected one, over which a searcher's CEX price
+/// races are counted. About two seconds of Arbitrum blocks.
+const CEX_PRICE_RACE_WINDOW: usize = 8;
+/// Boosted trades after a CEX price move a searcher needs within
+/// [`CEX_PRICE_RACE_WINDOW`] before they are flagged. A single trade after a
+/// move is as likely to be a coincidence as a race.
+const MIN_CEX_PRICE_RACES: usize = 2;
+
+type TxActions = (TxInfo, Vec<NormalizedSwap>, Vec<Action>);
+
+/// What a boosted transaction used its head start for
+#[derive(Debug, Clone, PartialEq)]
+enum Race {
+    Backrun { victim_tx_hashes: Vec<B256>, victim_swaps: Vec<Vec<NormalizedSwap>> },
+    CexPriceMove { move_bps: f64 },
+}
+
+/// Flags timeboosted transactions whose strategy relies on the head start the
+/// express lane gives over the regular sequencer queue:
+///
+/// - back-running a non boosted swap that landed earlier in the same block
+/// - repeatedly trading pairs right after their CEX price moved
+pub struct TimeboostInspector<'db, DB: LibmdbxReader> {
+    utils: SharedInspectorUtils<'db, DB>,
+}
+
+impl<'db, DB: LibmdbxReader> TimeboostInspector<'db, DB> {
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        metrics: Option<OutlierMetrics>,
+        profit_metrics: Option<ProfitMetrics>,
+    ) -> Self {
+        Self { utils: SharedInspectorUtils::new(quote, db, metrics, profit_metrics) }
+    }
+}
+
+impl<DB: LibmdbxReader> Inspector for TimeboostInspector<'_, DB> {
+    type Result = Vec<Bundle>;
+
+    // the earlier blocks are needed to tell repeated cex price races apart
+    // from a single trade that happened to follow a move
+    fn block_window(&self) -> usize {
+        CEX_PRICE_RACE_WINDOW
+    }
+
+    fn get_id(&self) -> &str {
+        "Timeboost"
+    }
+
+    fn get_quote_token(&self) -> Address {
+        self.utils.quote
+    }
+
+    fn inspect_block(&self, data: MultiBlockData) -> Self::Result {
+        self.utils
+            .get_metrics()
+            .map(|m| m.run_inspector(MevType::Timeboost, || self.inspect_block_inner(&data)))
+            .unwrap_or_else(|| self.inspect_block_inner(&data))
+    }
+}
+
+impl<DB: LibmdbxReader> TimeboostInspector<'_, DB> {
+    fn inspect_block_inner(&self, data: &MultiBlockData) -> Vec<Bundle> {
+        let BlockData { metadata, tree } = data.get_most_recent_block();
+        let Some(express_lane_round) = metadata.express_lane_auction.as_ref().map(|a| a.round)
+        else {
+            return vec![]
+        };
+
+        let txes = self.collect_txes(tree.clone());
+        let races = self.races(&txes, metadata);
+
+        let earlier_blocks = data.per_block_data.iter().rev().skip(1);
+        let cex_races = earlier_blocks
+            .flat_map(|block| {
+                let txes = self.collect_txes(block.tree.clone());
+                self.races(&txes, &block.metadata)
+                    .into_iter()
+                    .filter(|(_, race)| matches!(race, Race::CexPriceMove { .. }))
+                    .map(|(i, _)| Self::searcher(&txes[i].0))
+                    .collect_vec()
+            })
+            .chain(
+                races
+                    .iter()
+                    .filter(|(_, race)| matches!(race, Race::CexPriceMove { .. }))
+                    .map(|(i, _)| Self::searcher(&txes[*i].0)),
+            )
+            .fold(FastHashMap::default(), |mut acc, searcher| {
+                *acc.entry(searcher).or_insert(0) += 1;
+                acc
+            });
+
+        races
+            .into_iter()
+            .filter_map(|(i, race)| {
+                let (info, swaps, actions) = &txes[i];
+
+                let (strategy, victim_tx_hashes, victim_swaps, cex_price_move_bps) = match race {
+                    Race::Backrun { victim_tx_hashes, victim_swaps } => {
+                        (TimeboostStrategy::Backrun, victim_tx_hashes, victim_swaps, None)
+                    }
+                    Race::CexPriceMove { move_bps } => {
+                        if !Self::is_repeated_racer(&cex_races, info) {
+                            return None
+                        }
+                        (TimeboostStrategy::CexPriceMove, vec![], vec![], Some(move_bps))
+                    }
+                };
+
+                self.build_bundle(
+                    info,
+                    actions.clone(),
+                    metadata.clone(),
+                    Timeboost {
+                        tx_hash: info.tx_hash,
+                        block_number: metadata.block_num,
+                        express_lane_round,
+                        strategy,
+                        swaps: swaps.clone(),
+                        victim_tx_hashes,
+                        victim_swaps,
+                        cex_price_move_bps,
+                        gas_details: info.gas_details,
+                        profit_usd: 0.0,
+                    },
+                )
+            })
+            .collect::<Vec<_>>()
+    }
+
+    /// Every transaction of the block with its swaps and flattened actions,
+    /// ordered by transaction index.
+    fn collect_txes(&self, tree: Arc<BlockTree<Action>>) -> Vec<TxActions> {
+        let search_args = TreeSearchBuilder::default().with_actions([
+            Action::is_swap,
+            Action::is_transfer,
+            Action::is_eth_transfer,
+            Action::is_nested_action,
+        ]);
+
+        let (hashes, actions): (Vec<_>, Vec<_>) = tree.clone().collect_all(search_args).unzip();
+        let tx_info = tree.get_tx_info_batch(&hashes, self.utils.db);
+
+        multizip((tx_info, actions))
+            .filter_map(|(info, actions)| {
+                let info = info?;
+                let actions = self
+                    .utils
+                    .flatten_nested_actions_default(actions.into_iter())
+                    .collect::<Vec<_>>();
+                let swaps = actions
+                    .iter()
+                    .cloned()
+                    .collect_action_vec(Action::try_swaps_merged);
+
+                Some((info, swaps, actions))
+            })
+            .sorted_by_key(|(info, ..)| info.tx_index)
+            .collect_vec()
+    }
+
+    /// The boosted transactions of the block that raced someone, by index
+    /// into `txes`. A backrun takes precedence over a CEX price move.
+    fn races(&self, txes: &[TxActions], metadata: &Metadata) -> Vec<(usize, Race)> {
+        txes.iter()
+            .enumerate()
+            .filter(|(_, (info, swaps, _))| info.is_timeboosted() && !swaps.is_empty())
+            .filter_map(|(i, (info, swaps, _))| {
+                let (victim_tx_hashes, victim_swaps) = Self::backrun_victims(info, swaps, txes);
+                if !victim_tx_hashes.is_empty() {
+                    return Some((i, Race::Backrun { victim_tx_hashes, victim_swaps }))
+                }
+
+                let move_bps = self.max_cex_price_move_bps(swaps, metadata)?;
+                Some((i, Race::CexPriceMove { move_bps }))
+            })
+            .collect()
+    }
+
+    /// Searchers rotate eoas behind the same contract, so races are counted
+    /// per contract when there is one.
+    fn searcher(info: &TxInfo) -> Address {
+        info.mev_contract.unwrap_or(info.eoa)
+    }
+
+    fn is_repeated_racer(cex_races: &FastHashMap<Address, usize>, info: &TxInfo) -> bool {
+        cex_races
+            .get(&Self::searcher(info))
+            .is_some_and(|races| *races >= MIN_CEX_PRICE_RACES)
+    }
+
+    /// Non boosted transactions from other searchers whose swap was the last
+    /// one on a pool before the boosted transaction swapped back against it.
+    /// A victim's swap only counts if no other swap on the pool came in
+    /// between and it went in the opposite direction of the boosted swap.
+    fn backrun_victims(
+        info: &TxInfo,
+        swaps: &[NormalizedSwap],
+        txes: &[TxActions],
+    ) -> (Vec<B256>, Vec<Vec<NormalizedSwap>>) {
+        let mut victims: BTreeMap<u64, (B256, Vec<NormalizedSwap>)> = BTreeMap::new();
+
+        for swap in swaps {
+            let Some((victim, victim_swap)) = txes
+                .iter()
+                .take_while(|(victim, ..)| victim.tx_index < info.tx_index)
+                .filter_map(|(victim, victim_swaps, _)| {
+                    let last_swap = victim_swaps.iter().rfind(|s| s.pool == swap.pool)?;
+                    Some((victim, last_swap))
+                })
+                .last()
+            else {
+                continue
+            };
+
+            if victim.is_timeboosted()
+                || victim.eoa == info.eoa
+                || victim_swap.token_in.address != swap.token_out.address
+                || victim_swap.token_out.address != swap.token_in.address
+            {
+                continue
+            }
+
+            let (_, victim_swaps) = victims
+                .entry(victim.tx_index)
+                .or_insert_with(|| (victim.tx_hash, vec![]));
+            if !victim_swaps.contains(victim_swap) {
+                victim_swaps.push(victim_swap.clone());
+            }
+        }
+
+        victims.into_values().unzip()
+    }
+
+    /// Largest move of the CEX mid price over the pairs traded by the swaps,
+    /// between the start of the window and the block timestamp. Returns `None`
+    /// if no pair moved by at least [`MIN_CEX_PRICE_MOVE_BPS`].
+    fn max_cex_price_move_bps(&self, swaps: &[NormalizedSwap], metadata: &Metadata) -> Option<f64> {
+        let block_timestamp = metadata.microseconds_block_timestamp();
+
+        swaps
+            .iter()
+            .filter_map(|swap| {
+                let pair = Pair(swap.token_in.address, swap.token_out.address);
+                let quote_at = |timestamp| {
+                    metadata
+                        .cex_quotes
+                        .get_quote_from_most_liquid_exchange(&pair, timestamp, None)
+                        .map(|quote| quote.maker_taker_mid().0.to_float())
+                };
+
+                let before = quote_at(block_timestamp.saturating_sub(CEX_PRICE_MOVE_WINDOW_US))?;
+                let after = quote_at(block_timestamp)?;
+                if before == 0.0 {
+                    return None
+                }
+
+                Some(((after - before) / before).abs() * 10_000.0)
+            })
+            .max_by(|a, b| a.total_cmp(b))
+            .filter(|move_bps| *move_bps >= MIN_CEX_PRICE_MOVE_BPS)
+    }
+
+    fn build_bundle(
+        &self,
+        info: &TxInfo,
+        actions: Vec<Action>,
+        metadata: Arc<Metadata>,
+        mut timeboost: Timeboost,
+    ) -> Option<Bundle> {
+        let mev_addresses = info.collect_address_set_for_accounting();
+
+        let deltas = actions
+            .into_iter()
+            .chain(info.get_total_eth_value().iter().cloned().map(Action::from))
+            .filter(|a| a.is_eth_transfer() || a.is_transfer())
+            .account_for_actions();
+
+        let (rev, mut has_dex_price) = if let Some(rev) = self.utils.get_deltas_usd(
+            info.tx_index,
+            PriceAt::Average,
+            &mev_addresses,
+            &deltas,
+            metadata.clone(),
+            false,
+        ) {
+            (Some(rev), true)
+        } else {
+            (Some(Rational::ZERO), false)
+        };
+
+        let gas_paid = metadata.get_gas_price_usd(info.gas_details.gas_paid(), self.utils.quote);
+
+        let mut profit = rev
+            .map(|rev| rev - gas_paid)
+            .filter(|_| has_dex_price)
+            .unwrap_or_default();
+
+        if profit >= MAX_PROFIT || profit <= MIN_PROFIT {
+            has_dex_price = false;
+            profit = Rational::ZERO;
+        }
+
+        timeboost.profit_usd = profit.to_float();
+
+        let header = self.utils.build_bundle_header(
+            vec![deltas],
+            vec![info.tx_hash],
+            info,
+            timeboost.profit_usd,
+            &[info.gas_details],
+            metadata.clone(),
+            MevType::Timeboost,
+            !has_dex_price,
+            |this, token, amount| {
+                this.get_token_value_dex(
+                    info.tx_index as usize,
+                    PriceAt::Average,
+                    token,
+                    &amount,
+                    &metadata,
+                )
+            },
+        );
+
+        Some(Bundle { header, data: BundleData::Timeboost(timeboost) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{address, B256};
+    use brontes_core::LibmdbxReadWriter;
+    use brontes_types::{
+        db::token_info::TokenInfoWithAddress, normalized_actions::NormalizedSwap, FastHashMap,
+        GasDetails, TxInfo,
+    };
+
+    use super::{TimeboostInspector, TxActions};
+
+    type Timeboost = TimeboostInspector<'static, LibmdbxReadWriter>;
+
+    fn tx(tx_index: u64, eoa: alloy_primitives::Address, timeboosted: bool) -> TxInfo {
+        TxInfo::new(
+            1,
+            tx_index,
+            eoa,
+            None,
+            None,
+            B256::with_last_byte(tx_index as u8),
+            GasDetails::default(),
+            false,
+            false,
+            false,
+            false,
+            None,
+            None,
+            vec![],
+            timeboosted,
+        )
+    }
+
+    const WETH: alloy_primitives::Address = address!("00000000000000000000000000000000000000e1");
+    const USDC: alloy_primitives::Address = address!("00000000000000000000000000000000000000e2");
+
+    fn swap(
+        pool: alloy_primitives::Address,
+        token_in: alloy_primitives::Address,
+        token_out: alloy_primitives::Address,
+    ) -> NormalizedSwap {
+        let token = |address| TokenInfoWithAddress { address, ..Default::default() };
+        NormalizedSwap {
+            pool,
+            token_in: token(token_in),
+            token_out: token(token_out),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_backrun_victims() {
+        let searcher = address!("00000000000000000000000000000000000000aa");
+        let user = address!("00000000000000000000000000000000000000bb");
+        let pool = address!("0000000000000000000000000000000000000001");
+        let other_pool = address!("0000000000000000000000000000000000000002");
+
+        let txes = vec![
+            (tx(0, user, false), vec![swap(other_pool, USDC, WETH)], vec![]),
+            (tx(1, user, false), vec![swap(pool, USDC, WETH)], vec![]),
+            // swaps after the boosted transaction aren't backrun
+            (tx(2, searcher, true), vec![swap(pool, WETH, USDC)], vec![]),
+            (tx(3, user, false), vec![swap(pool, USDC, WETH)], vec![]),
+        ];
+
+        let (hashes, swaps) = Timeboost::backrun_victims(&txes[2].0, &txes[2].1, &txes);
+        assert_eq!(hashes, vec![txes[1].0.tx_hash]);
+        assert_eq!(swaps, vec![vec![swap(pool, USDC, WETH)]]);
+    }
+
+    #[test]
+    fn test_backrun_victims_skips_swaps_not_directly_before() {
+        let searcher = address!("00000000000000000000000000000000000000aa");
+        let user = address!("00000000000000000000000000000000000000bb");
+        let other_user = address!("00000000000000000000000000000000000000cc");
+        let pool = address!("0000000000000000000000000000000000000001");
+
+        let boosted = (tx(3, searcher, true), vec![swap(pool, WETH, USDC)], vec![]);
+        let backrun = |txes: &[TxActions]| Timeboost::backrun_victims(&boosted.0, &boosted.1, txes);
+
+        // an unrelated swap on the pool sits between the user's and the boosted one
+        let txes = vec![
+            (tx(1, user, false), vec![swap(pool, USDC, WETH)], vec![]),
+            (tx(2, other_user, false), vec![swap(pool, WETH, USDC)], vec![]),
+            boosted.clone(),
+        ];
+        assert_eq!(backrun(&txes), (vec![], vec![]));
+
+        // the swap directly before went in the same direction
+        let txes =
+            vec![(tx(2, user, false), vec![swap(pool, WETH, USDC)], vec![]), boosted.clone()];
+        assert_eq!(backrun(&txes), (vec![], vec![]));
+
+        // boosted swaps and the searcher's own swaps aren't victims
+        let txes = vec![(tx(2, user, true), vec![swap(pool, USDC, WETH)], vec![]), boosted.clone()];
+        assert_eq!(backrun(&txes), (vec![], vec![]));
+        let txes =
+            vec![(tx(2, searcher, false), vec![swap(pool, USDC, WETH)], vec![]), boosted.clone()];
+        assert_eq!(backrun(&txes), (vec![], vec![]));
+    }
+
+    #[test]
+    fn test_single_cex_price_move_is_not_a_race() {
+        let searcher = address!("00000000000000000000000000000000000000aa");
+        let contract = address!("00000000000000000000000000000000000000cc");
+        let mut info = tx(0, searcher, true);
+
+        let mut cex_races = FastHashMap::default();
+        cex_races.insert(searcher, 1);
+        assert!(!Timeboost::is_repeated_racer(&cex_races, &info));
+
+        cex_races.insert(searcher, 2);
+        assert!(Timeboost::is_repeated_racer(&cex_races, &info));
+
+        // races are counted per contract when the searcher trades through one
+        info.mev_contract = Some(contract);
+        assert!(!Timeboost::is_repeated_racer(&cex_races, &info));
+        cex_races.insert(contract, 3);
+        assert!(Timeboost::is_repeated_racer(&cex_races, &info));
+    }
+}
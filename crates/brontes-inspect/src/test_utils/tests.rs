@@ -24,7 +24,7 @@
 use alloy_primitives::{Address, TxHash};
 use brontes_classifier::test_utils::{ClassifierTestUtils, ClassifierTestUtilsError};
 use brontes_core::{LibmdbxReadWriter, TraceLoaderError};
-pub use brontes_types::constants::*;
+pub use brontes_types::constants::arbitrum::*;
 use brontes_types::{
     db::{
         cex::{trades::CexDexTradeConfig, CexExchange},
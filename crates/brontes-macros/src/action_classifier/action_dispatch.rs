@@ -58,7 +58,8 @@ impl ActionDispatch {
                     tracer: std::sync::Arc<T>
                 ) -> Option<(
                         ::brontes_pricing::types::DexPriceMsg,
-                        ::brontes_types::normalized_actions::Action
+                        ::brontes_types::normalized_actions::Action,
+                        Vec<::brontes_types::normalized_actions::Action>
                     )> {
 
                     let protocol_byte = db_tx.get_protocol(call_info.target_address)
@@ -115,6 +116,10 @@ fn expand_match_dispatch(
         match sig_w_byte {
         #(
             #var_name => {
+                if crate::IntoAction::skip_call(&self.#var_idx, &call_info.call_data) {
+                    return None
+                }
+
                 let target_address = call_info.target_address;
                  return crate::IntoAction::decode_call_trace(
                     &self.#var_idx,
@@ -123,9 +128,9 @@ fn expand_match_dispatch(
                     tx_idx,
                     db_tx,
                     #tracer
-                ).map(|res| {
+                ).map(|(res, extra_actions)| {
                     let action = res.get_action();
-                    Some((res, action))
+                    Some((res, action, extra_actions))
                  }).unwrap_or_else(|e| {
                         ::tracing::warn!(error=%e, ?block, ?tx_idx,
                             "classifier: {} failed on function sig: {:?} for address: {:?}",
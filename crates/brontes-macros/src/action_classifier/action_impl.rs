@@ -23,8 +23,16 @@ pub struct ActionMacro {
     give_returns:           bool,
     /// whether we want call_data or not
     give_call_data:         bool,
+    /// whether we want the undecoded call_data, for packed encodings
+    give_raw_call_data:     bool,
     // whether we pass down logs from delegate call in the same call frame
     include_delegated_logs: bool,
+    /// fn over the raw call data, calls it returns true for are left
+    /// unclassified without being decoded
+    skip_call:              Option<Path>,
+    /// fn over the classified action and the call frame, returning the
+    /// actions classified next to it
+    extra_actions:          Option<Path>,
     /// The closure that we use to construct the normalized type
     call_function:          ExprClosure,
 }
@@ -39,7 +47,10 @@ impl ActionMacro {
             log_types,
             give_logs,
             give_call_data,
+            give_raw_call_data,
             include_delegated_logs,
+            skip_call,
+            extra_actions,
             give_returns,
             call_function,
         } = self;
@@ -47,6 +58,7 @@ impl ActionMacro {
         let call_data = CallDataParsing::new(
             give_logs,
             give_call_data,
+            give_raw_call_data,
             give_returns,
             include_delegated_logs,
             &exchange_name_w_call,
@@ -70,23 +82,63 @@ impl ActionMacro {
         call.value_mut().ident = Ident::new(&solidity, call.span());
         return_import.segments.push(call.into_value());
 
+        // pools behind a proxy emit their logs from the delegated frame
+        let update_logs = if include_delegated_logs {
+            quote!(call_info
+                .logs
+                .iter()
+                .chain(call_info.delegate_logs.iter().copied())
+                .cloned()
+                .collect())
+        } else {
+            quote!(call_info.logs.clone().to_vec())
+        };
+
+        let skip_call = skip_call.map(|skip_call| {
+            quote!(
+                fn skip_call(&self, call_data: &[u8]) -> bool {
+                    #skip_call(call_data)
+                }
+            )
+        });
+
         let dex_price_return = if action_type.to_string().to_lowercase().as_str()
             == "poolconfigupdate"
         {
-            quote!(Ok(::brontes_pricing::types::DexPriceMsg::DiscoveredPool(result)))
+            quote!(::brontes_pricing::types::DexPriceMsg::DiscoveredPool(result))
+        } else if action_type == "Action" {
+            // the closure picks the action variant itself
+            quote!(
+                ::brontes_pricing::types::DexPriceMsg::Update(
+                    ::brontes_pricing::types::PoolUpdate {
+                        block,
+                        tx_idx,
+                        logs: #update_logs,
+                        action: result
+                    },
+                )
+            )
         } else {
             quote!(
-                Ok(::brontes_pricing::types::DexPriceMsg::Update(
+                ::brontes_pricing::types::DexPriceMsg::Update(
                     ::brontes_pricing::types::PoolUpdate {
                         block,
                         tx_idx,
-                        logs: call_info.logs.clone().to_vec(),
+                        logs: #update_logs,
                         action: ::brontes_types::normalized_actions::Action::#action_type(result)
                     },
-                ))
+                )
             )
         };
 
+        let extra_actions = extra_actions
+            .map(|extra_actions| {
+                quote!(#extra_actions(&dex_price_msg.get_action(), &call_info)
+                    .into_iter()
+                    .collect())
+            })
+            .unwrap_or_else(|| quote!(vec![]));
+
         Ok(quote! {
             #[allow(unused_imports)]
             use #path_to_call;
@@ -118,10 +170,18 @@ impl ActionMacro {
                     tx_idx: u64,
                     db_tx: &DB,
                     tracer: std::sync::Arc<T>
-                    ) -> ::eyre::Result<::brontes_pricing::types::DexPriceMsg> {
+                    ) -> ::eyre::Result<(
+                        ::brontes_pricing::types::DexPriceMsg,
+                        Vec<::brontes_types::normalized_actions::Action>
+                    )> {
                     #call_data
-                    #dex_price_return
+                    let dex_price_msg = #dex_price_return;
+                    let extra_actions = #extra_actions;
+
+                    Ok((dex_price_msg, extra_actions))
                 }
+
+                #skip_call
             }
         })
     }
@@ -141,7 +201,15 @@ impl Parse for ActionMacro {
         let possible_logs = parse_logs(&mut input)?;
         input.parse::<Token![,]>()?;
 
-        let (logs, return_data, call_data, include_delegated_logs) = parse_config(&mut input)?;
+        let (
+            logs,
+            return_data,
+            call_data,
+            raw_call_data,
+            include_delegated_logs,
+            skip_call,
+            extra_actions,
+        ) = parse_config(&mut input)?;
         let call_function = parse_closure(&mut input)?;
 
         let uppercase_path_to_call = uppercase_first_char(
@@ -166,7 +234,10 @@ impl Parse for ActionMacro {
             call_function,
             give_logs: logs,
             give_call_data: call_data,
+            give_raw_call_data: raw_call_data,
             include_delegated_logs,
+            skip_call,
+            extra_actions,
             action_type,
             protocol_path,
             exchange_name_w_call,
@@ -191,28 +262,37 @@ fn parse_closure(input: &mut syn::parse::ParseStream) -> syn::Result<ExprClosure
     Ok(call_function)
 }
 
-fn parse_config(input: &mut syn::parse::ParseStream) -> syn::Result<(bool, bool, bool, bool)> {
+fn parse_config(
+    input: &mut syn::parse::ParseStream,
+) -> syn::Result<(bool, bool, bool, bool, bool, Option<Path>, Option<Path>)> {
     let mut logs = false;
     let mut return_data = false;
     let mut call_data = false;
+    let mut raw_call_data = false;
     let mut include_delegated_logs = false;
+    let mut skip_call = None;
+    let mut extra_actions = None;
 
     while !input.peek(Token![|]) {
         let arg: Ident = input.parse()?;
         input.parse::<Token![:]>()?;
-        let enabled: LitBool = input.parse()?;
 
         match arg.to_string().to_lowercase().as_str() {
-            "logs" => logs = enabled.value(),
-            "call_data" => call_data = enabled.value(),
-            "return_data" => return_data = enabled.value(),
-            "include_delegated_logs" => include_delegated_logs = enabled.value(),
+            // hooks take the path to a fn rather than a bool
+            "skip_call" => skip_call = Some(input.parse::<Path>()?),
+            "extra_actions" => extra_actions = Some(input.parse::<Path>()?),
+            "logs" => logs = input.parse::<LitBool>()?.value(),
+            "call_data" => call_data = input.parse::<LitBool>()?.value(),
+            "raw_call_data" => raw_call_data = input.parse::<LitBool>()?.value(),
+            "return_data" => return_data = input.parse::<LitBool>()?.value(),
+            "include_delegated_logs" => include_delegated_logs = input.parse::<LitBool>()?.value(),
             _ => {
                 return Err(Error::new(
                     arg.span(),
                     format!(
                         "{} is not a valid config option, valid options are: \n logs , call_data, \
-                         return_data , include_delegated_logs",
+                         raw_call_data, return_data , include_delegated_logs, skip_call, \
+                         extra_actions",
                         arg,
                     ),
                 ))
@@ -221,7 +301,22 @@ fn parse_config(input: &mut syn::parse::ParseStream) -> syn::Result<(bool, bool,
         input.parse::<Token![,]>()?;
     }
 
-    Ok((logs, return_data, call_data, include_delegated_logs))
+    if call_data && raw_call_data {
+        return Err(Error::new(
+            input.span(),
+            "call_data and raw_call_data are both passed as the call data, only enable one",
+        ))
+    }
+
+    Ok((
+        logs,
+        return_data,
+        call_data,
+        raw_call_data,
+        include_delegated_logs,
+        skip_call,
+        extra_actions,
+    ))
 }
 
 fn parse_protocol_path(input: &mut syn::parse::ParseStream) -> syn::Result<Path> {
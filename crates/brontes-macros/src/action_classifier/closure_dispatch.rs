@@ -2,15 +2,22 @@ use quote::{quote, ToTokens};
 use syn::ExprClosure;
 
 pub struct ClosureDispatch {
-    logs:        bool,
-    call_data:   bool,
-    return_data: bool,
-    closure:     ExprClosure,
+    logs:          bool,
+    call_data:     bool,
+    raw_call_data: bool,
+    return_data:   bool,
+    closure:       ExprClosure,
 }
 
 impl ClosureDispatch {
-    pub fn new(logs: bool, call_data: bool, return_data: bool, closure: ExprClosure) -> Self {
-        Self { closure, call_data, return_data, logs }
+    pub fn new(
+        logs: bool,
+        call_data: bool,
+        raw_call_data: bool,
+        return_data: bool,
+        closure: ExprClosure,
+    ) -> Self {
+        Self { closure, call_data, raw_call_data, return_data, logs }
     }
 }
 
@@ -18,10 +25,13 @@ impl ToTokens for ClosureDispatch {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let closure = &self.closure;
 
-        let call_data = self
-            .call_data
-            .then_some(quote!(call_data,))
-            .unwrap_or_default();
+        let call_data = if self.call_data {
+            quote!(call_data,)
+        } else if self.raw_call_data {
+            quote!(&call_info.call_data,)
+        } else {
+            quote!()
+        };
 
         let return_data = self
             .return_data
@@ -20,6 +20,7 @@ impl<'a> CallDataParsing<'a> {
     pub fn new(
         logs: bool,
         call_data: bool,
+        raw_call_data: bool,
         return_data: bool,
         include_delegated_logs: bool,
         exchange_name: &'a Ident,
@@ -28,7 +29,7 @@ impl<'a> CallDataParsing<'a> {
         log_config: &'a [LogConfig],
         closure: ExprClosure,
     ) -> Self {
-        let closure = ClosureDispatch::new(logs, call_data, return_data, closure);
+        let closure = ClosureDispatch::new(logs, call_data, raw_call_data, return_data, closure);
 
         let log_data = if logs {
             Some(LogData::new(
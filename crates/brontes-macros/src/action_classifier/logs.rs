@@ -120,6 +120,9 @@ impl<'a> LogData<'a> {
         let return_struct_build_fields = log_ident
             .iter()
             .flat_map(|names| {
+                // only one log of a group with fallbacks is ever emitted, the missing
+                // ones are warned about together below
+                let has_fallbacks = names.len() > 1;
                 names
                     .iter()
                     .map(|name| {
@@ -130,15 +133,47 @@ impl<'a> LogData<'a> {
                             name
                         );
 
-                        quote!([<#field:snake>]: self.[<#field:snake>].ok_or_else(|| {
-                                ::tracing::warn!(?call_info, "{}", #message);
-                                ::eyre::eyre!("call_info: {:?}, {}",call_info, #message)
-                        }))
+                        if has_fallbacks {
+                            quote!([<#field:snake>]: self.[<#field:snake>].ok_or_else(|| {
+                                    ::eyre::eyre!("call_info: {:?}, {}",call_info, #message)
+                            }))
+                        } else {
+                            quote!([<#field:snake>]: self.[<#field:snake>].ok_or_else(|| {
+                                    ::tracing::warn!(?call_info, "{}", #message);
+                                    ::eyre::eyre!("call_info: {:?}, {}",call_info, #message)
+                            }))
+                        }
                     })
                     .collect::<Vec<_>>()
             })
             .collect::<Vec<_>>();
 
+        let fallback_group_checks = log_ident
+            .iter()
+            .filter(|names| names.len() > 1)
+            .map(|names| {
+                let fields = names
+                    .iter()
+                    .map(|name| Ident::new(&(name.to_string() + "_field"), Span::call_site()))
+                    .collect::<Vec<_>>();
+                let message = format!(
+                    "logs are not setup properly for this macro as none of the requested logs {} \
+                     were found",
+                    names
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(" | ")
+                );
+
+                quote!(
+                    if #(self.[<#fields:snake>].is_none() &&)* true {
+                        ::tracing::warn!(?call_info, "{}", #message);
+                    }
+                )
+            })
+            .collect::<Vec<_>>();
+
         let log_field_ty = log_repeating
             .iter()
             .zip(log_ident.iter())
@@ -187,6 +222,8 @@ impl<'a> LogData<'a> {
                             call_info: &::brontes_types::structured_trace::CallFrameInfo<'_>
                             )
                             -> #log_return_struct_name {
+                                #(#fallback_group_checks)*
+
                                 #log_return_struct_name {
                                 #(
                                     #return_struct_build_fields
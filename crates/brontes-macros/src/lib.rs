@@ -30,6 +30,10 @@ use crate::action_classifier::{ActionDispatch, ActionMacro};
 /// The Array of log types are expected to be in the order that they are emitted
 /// in. Otherwise the decoding will fail
 ///
+/// If the action type depends on the call, e.g. a single function that both
+/// adds and removes liquidity, use `Action` as the CallType and return the
+/// full `Action` from the closure.
+///
 ///  ## Examples
 /// ```ignore
 /// action_impl!(
@@ -92,6 +96,18 @@ use crate::action_classifier::{ActionDispatch, ActionMacro};
 ///  call_data: true
 ///  ````
 ///  ```|index, from_address, target_address, return_data, log_data|```
+///
+/// For calls with a packed calldata encoding that can't be abi decoded, use
+/// `raw_call_data: true` instead of `call_data: true`. The closure then gets
+/// the undecoded `&Bytes` in the place of `call_data`.
+///
+/// Calls that share a selector with the classified one but aren't covered by
+/// it can be left unclassified before anything is decoded with
+/// `skip_call: <path>`, a `fn(&[u8]) -> bool` over the raw call data.
+///
+/// Calls that are normalized into more than one action can classify the rest
+/// next to the returned one with `extra_actions: <path>`, a
+/// `fn(&Action, &CallFrameInfo<'_>)` returning an iterator of the actions.
 pub fn action_impl(input: TokenStream) -> TokenStream {
     parse_macro_input!(input as ActionMacro)
         .expand()
@@ -1,4 +1,4 @@
-use brontes_types::constants::USDC_ADDRESS;
+use brontes_types::constants::arbitrum::USDC_ADDRESS;
 use criterion::Criterion;
 use pricing_test_utils::bench::BrontesPricingBencher;
 
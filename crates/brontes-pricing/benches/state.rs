@@ -1,6 +1,6 @@
 use alloy_primitives::hex;
 use brontes_types::{
-    constants::{ETH_ADDRESS, USDC_ADDRESS, WBTC_ADDRESS},
+    constants::arbitrum::{ETH_ADDRESS, USDC_ADDRESS, WBTC_ADDRESS},
     pair::Pair,
 };
 use criterion::Criterion;
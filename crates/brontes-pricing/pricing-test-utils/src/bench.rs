@@ -106,6 +106,7 @@ impl BrontesPricingBencher {
             .rt
             .block_on(protocol.try_load_state(
                 pool,
+                None,
                 self.inner.get_tracing_provider(),
                 block_number,
                 pool_pair,
@@ -114,8 +115,10 @@ impl BrontesPricingBencher {
             .unwrap()
             .2;
 
-        let pair_1 = pool_pair.0;
-        c.bench_function(bench_name, move |b| b.iter(|| black_box(state.price(pair_1).unwrap())));
+        let Pair(base, quote) = pool_pair;
+        c.bench_function(bench_name, move |b| {
+            b.iter(|| black_box(state.price(base, quote).unwrap()))
+        });
 
         Ok(())
     }
@@ -135,6 +138,7 @@ impl BrontesPricingBencher {
                     protocol
                         .try_load_state(
                             pool,
+                            None,
                             self.inner.get_tracing_provider(),
                             block_number,
                             pool_pair,
@@ -199,6 +199,11 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
             }
         }
 
+        updates
+            .iter()
+            .filter_map(PoolUpdate::uniswap_v4_pool_id)
+            .for_each(|pool_id| self.lazy_loader.add_v4_pool_id(pool_id));
+
         // insert new pools accessed on this block.
         updates
             .iter()
@@ -1253,8 +1258,12 @@ impl<T: TracingProvider> Stream for BrontesBatchPricer<T> {
                             protocol,
                             tokens,
                             pool_address,
+                            uniswap_v4_pool_key,
                             ..
                         }) => {
+                            if let Some(key) = uniswap_v4_pool_key {
+                                self.lazy_loader.add_v4_pool_id(key.pool_id);
+                            }
                             if protocol.has_state_updater() {
                                 self.new_graph_pairs
                                     .insert(pool_address, (protocol, Pair(tokens[0], tokens[1])));
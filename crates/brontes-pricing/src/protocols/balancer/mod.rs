@@ -0,0 +1,562 @@
+mod stable;
+mod weighted;
+
+use std::sync::Arc;
+
+use alloy_primitives::{Address, Log, B256, U256};
+use alloy_sol_macro::sol;
+use alloy_sol_types::SolEvent;
+use async_trait::async_trait;
+use brontes_types::{
+    constants::{BALANCER_V2_VAULT_ADDRESS, BALANCER_V3_VAULT_ADDRESS},
+    normalized_actions::Action,
+    traits::TracingProvider,
+    ToScaledRational,
+};
+use malachite::{
+    num::{arithmetic::traits::Pow, basic::traits::Zero},
+    Natural, Rational,
+};
+use serde::{Deserialize, Serialize};
+
+use super::make_call_request;
+use crate::{
+    errors::{AmmError, ArithmeticError, EventLogError},
+    Protocol, UpdatableProtocol,
+};
+
+sol!(
+    interface IBalancerPool {
+        function getPoolId() external view returns (bytes32);
+        function getScalingFactors() external view returns (uint256[]);
+        function getNormalizedWeights() external view returns (uint256[]);
+        function getAmplificationParameter()
+            external
+            view
+            returns (uint256 value, bool isUpdating, uint256 precision);
+    }
+);
+
+sol!(
+    interface IBalancerToken {
+        function decimals() external view returns (uint8);
+    }
+);
+
+sol!(
+    interface IBalancerV2Vault {
+        function getPoolTokens(bytes32 poolId)
+            external
+            view
+            returns (address[] tokens, uint256[] balances, uint256 lastChangeBlock);
+
+        event Swap(
+            bytes32 indexed poolId,
+            address indexed tokenIn,
+            address indexed tokenOut,
+            uint256 amountIn,
+            uint256 amountOut
+        );
+        event PoolBalanceChanged(
+            bytes32 indexed poolId,
+            address indexed liquidityProvider,
+            address[] tokens,
+            int256[] deltas,
+            uint256[] protocolFeeAmounts
+        );
+    }
+);
+
+sol!(
+    interface IBalancerV3Vault {
+        struct TokenInfo {
+            uint8 tokenType;
+            address rateProvider;
+            bool paysYieldFees;
+        }
+
+        function getPoolTokenInfo(address pool)
+            external
+            view
+            returns (
+                address[] tokens,
+                TokenInfo[] tokenInfo,
+                uint256[] balancesRaw,
+                uint256[] lastBalancesLiveScaled18
+            );
+        function getPoolTokenRates(address pool)
+            external
+            view
+            returns (uint256[] decimalScalingFactors, uint256[] tokenRates);
+
+        event Swap(
+            address indexed pool,
+            address indexed tokenIn,
+            address indexed tokenOut,
+            uint256 amountIn,
+            uint256 amountOut,
+            uint256 swapFeePercentage,
+            uint256 swapFeeAmount
+        );
+        event PoolBalanceChanged(
+            address indexed pool,
+            address indexed liquidityProvider,
+            uint256 totalSupply,
+            int256[] deltas,
+            uint256[] swapFeeAmountsRaw
+        );
+    }
+);
+
+/// The invariant a Balancer pool trades on
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub enum BalancerInvariant {
+    /// Normalized weights, scaled by 1e18
+    Weighted(Vec<U256>),
+    /// Amplification parameter, scaled by `precision`
+    Stable { amp: U256, precision: U256 },
+}
+
+impl Default for BalancerInvariant {
+    fn default() -> Self {
+        Self::Weighted(vec![])
+    }
+}
+
+/// A Balancer V2 or V3 pool. Balances are held by the vault, so they are
+/// loaded from it and kept current through the vault's swap and liquidity
+/// events
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct BalancerPool {
+    pub address:   Address,
+    pub protocol:  Protocol,
+    /// Vault id of a V2 pool, V3 pools are keyed by their address
+    pub pool_id:   B256,
+    pub tokens:    Vec<Address>,
+    pub decimals:  Vec<u8>,
+    pub balances:  Vec<U256>,
+    /// Value of one whole token in the invariant's units, scaled by 1e18. This
+    /// is the rate provider's rate for yield bearing and boosted tokens
+    pub rates:     Vec<U256>,
+    pub invariant: BalancerInvariant,
+}
+
+#[async_trait]
+impl UpdatableProtocol for BalancerPool {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    /// V2 swaps are classified from the pool's `onSwap` hook, which returns
+    /// before the vault logs the swap, so those updates are applied from the
+    /// action instead
+    fn sync_from_action(&mut self, action: Action) -> Result<(), AmmError> {
+        let Action::Swap(swap) = action else { return Err(AmmError::SyncError(self.address)) };
+
+        let amount_in = to_raw(&swap.amount_in, swap.token_in.decimals)
+            .ok_or(AmmError::SyncError(self.address))?;
+        let amount_out = to_raw(&swap.amount_out, swap.token_out.decimals)
+            .ok_or(AmmError::SyncError(self.address))?;
+
+        self.apply_swap(swap.token_in.address, swap.token_out.address, amount_in, amount_out)
+    }
+
+    fn sync_from_log(&mut self, log: Log) -> Result<(), AmmError> {
+        let event_signature = log.topics()[0];
+
+        if event_signature == IBalancerV2Vault::Swap::SIGNATURE_HASH {
+            let swap = IBalancerV2Vault::Swap::decode_log_data(&log, false)?;
+            if swap.poolId == self.pool_id {
+                self.apply_swap(swap.tokenIn, swap.tokenOut, swap.amountIn, swap.amountOut)?;
+            }
+        } else if event_signature == IBalancerV2Vault::PoolBalanceChanged::SIGNATURE_HASH {
+            let change = IBalancerV2Vault::PoolBalanceChanged::decode_log_data(&log, false)?;
+            if change.poolId == self.pool_id {
+                // protocol fees are paid out of the pool's balance on top of the delta
+                for ((token, delta), fee) in change
+                    .tokens
+                    .iter()
+                    .zip(&change.deltas)
+                    .zip(&change.protocolFeeAmounts)
+                {
+                    let i = self.token_index(*token)?;
+                    self.balances[i] =
+                        apply_delta(self.balances[i], delta.unsigned_abs(), delta.is_negative())
+                            .saturating_sub(*fee);
+                }
+            }
+        } else if event_signature == IBalancerV3Vault::Swap::SIGNATURE_HASH {
+            let swap = IBalancerV3Vault::Swap::decode_log_data(&log, false)?;
+            if swap.pool == self.address {
+                self.apply_swap(swap.tokenIn, swap.tokenOut, swap.amountIn, swap.amountOut)?;
+            }
+        } else if event_signature == IBalancerV3Vault::PoolBalanceChanged::SIGNATURE_HASH {
+            let change = IBalancerV3Vault::PoolBalanceChanged::decode_log_data(&log, false)?;
+            if change.pool == self.address {
+                // v3 deltas are ordered the same as the pool's tokens
+                for (balance, delta) in self.balances.iter_mut().zip(&change.deltas) {
+                    *balance = apply_delta(*balance, delta.unsigned_abs(), delta.is_negative());
+                }
+            }
+        } else {
+            return Err(AmmError::EventLogError(EventLogError::InvalidEventSignature))
+        }
+
+        Ok(())
+    }
+
+    fn calculate_price(
+        &self,
+        base_token: Address,
+        quote_token: Address,
+    ) -> Result<Rational, ArithmeticError> {
+        let (base, quote) = (self.price_index(base_token)?, self.price_index(quote_token)?);
+
+        let xp = self.upscaled_balances();
+        let price = match &self.invariant {
+            BalancerInvariant::Weighted(weights) => {
+                weighted::spot_price(&xp, weights, base, quote)?
+            }
+            BalancerInvariant::Stable { amp, precision } => {
+                // composable stable pools hold their own bpt, which isn't part of the
+                // invariant
+                let bpt = self.bpt_index();
+                let xp = xp
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(i, _)| Some(*i) != bpt)
+                    .map(|(_, x)| x)
+                    .collect::<Vec<_>>();
+                let without_bpt = |i: usize| if bpt.is_some_and(|bpt| bpt < i) { i - 1 } else { i };
+
+                stable::spot_price(&xp, *amp, *precision, without_bpt(base), without_bpt(quote))?
+            }
+        };
+
+        Ok(price * self.rates[base].to_scaled_rational(18)
+            / self.rates[quote].to_scaled_rational(18))
+    }
+
+    fn tokens(&self) -> Vec<Address> {
+        self.tokens.clone()
+    }
+}
+
+impl BalancerPool {
+    pub async fn new_load_on_block<M: TracingProvider>(
+        address: Address,
+        protocol: Protocol,
+        middleware: Arc<M>,
+        block: u64,
+    ) -> Result<Self, AmmError> {
+        let block = Some(block);
+
+        let (pool_id, tokens, balances, rates) = match protocol {
+            Protocol::BalancerV2 => Self::load_v2(address, &middleware, block).await?,
+            Protocol::BalancerV3 => Self::load_v3(address, &middleware, block).await?,
+            _ => return Err(AmmError::UnsupportedProtocol),
+        };
+
+        let mut decimals = Vec::with_capacity(tokens.len());
+        for token in &tokens {
+            decimals.push(
+                make_call_request(
+                    IBalancerToken::decimalsCall::new(()),
+                    &middleware,
+                    *token,
+                    block,
+                )
+                .await?
+                ._0,
+            );
+        }
+
+        // v2 scaling factors fold the decimals into the rate, tokens without one trade
+        // at face value
+        let rates = match (protocol, rates) {
+            (Protocol::BalancerV2, Some(factors)) => factors
+                .into_iter()
+                .zip(&decimals)
+                .map(|(factor, decimals)| {
+                    factor / U256::from(10).pow(U256::from(18u8.saturating_sub(*decimals)))
+                })
+                .collect(),
+            (_, Some(rates)) => rates,
+            (_, None) => vec![U256::from(10).pow(U256::from(18)); tokens.len()],
+        };
+
+        let invariant = Self::load_invariant(address, &middleware, block).await?;
+
+        let pool = BalancerPool {
+            address,
+            protocol,
+            pool_id,
+            tokens,
+            decimals,
+            balances,
+            rates,
+            invariant,
+        };
+
+        if !pool.data_is_populated() {
+            return Err(AmmError::NoStateError(address))
+        }
+
+        Ok(pool)
+    }
+
+    async fn load_v2<M: TracingProvider>(
+        address: Address,
+        middleware: &Arc<M>,
+        block: Option<u64>,
+    ) -> Result<(B256, Vec<Address>, Vec<U256>, Option<Vec<U256>>), AmmError> {
+        let pool_id =
+            make_call_request(IBalancerPool::getPoolIdCall::new(()), middleware, address, block)
+                .await?
+                ._0;
+        let pool_tokens = make_call_request(
+            IBalancerV2Vault::getPoolTokensCall::new((pool_id,)),
+            middleware,
+            BALANCER_V2_VAULT_ADDRESS,
+            block,
+        )
+        .await?;
+
+        // pools that predate rate providers don't expose their scaling factors
+        let scaling_factors = make_call_request(
+            IBalancerPool::getScalingFactorsCall::new(()),
+            middleware,
+            address,
+            block,
+        )
+        .await
+        .map(|res| res._0)
+        .ok()
+        .filter(|factors| factors.len() == pool_tokens.tokens.len());
+
+        Ok((pool_id, pool_tokens.tokens, pool_tokens.balances, scaling_factors))
+    }
+
+    async fn load_v3<M: TracingProvider>(
+        address: Address,
+        middleware: &Arc<M>,
+        block: Option<u64>,
+    ) -> Result<(B256, Vec<Address>, Vec<U256>, Option<Vec<U256>>), AmmError> {
+        let pool_tokens = make_call_request(
+            IBalancerV3Vault::getPoolTokenInfoCall::new((address,)),
+            middleware,
+            BALANCER_V3_VAULT_ADDRESS,
+            block,
+        )
+        .await?;
+        let rates = make_call_request(
+            IBalancerV3Vault::getPoolTokenRatesCall::new((address,)),
+            middleware,
+            BALANCER_V3_VAULT_ADDRESS,
+            block,
+        )
+        .await?
+        .tokenRates;
+
+        Ok((B256::ZERO, pool_tokens.tokens, pool_tokens.balancesRaw, Some(rates)))
+    }
+
+    async fn load_invariant<M: TracingProvider>(
+        address: Address,
+        middleware: &Arc<M>,
+        block: Option<u64>,
+    ) -> Result<BalancerInvariant, AmmError> {
+        if let Ok(weights) = make_call_request(
+            IBalancerPool::getNormalizedWeightsCall::new(()),
+            middleware,
+            address,
+            block,
+        )
+        .await
+        {
+            return Ok(BalancerInvariant::Weighted(weights._0))
+        }
+
+        let amp = make_call_request(
+            IBalancerPool::getAmplificationParameterCall::new(()),
+            middleware,
+            address,
+            block,
+        )
+        .await?;
+
+        Ok(BalancerInvariant::Stable { amp: amp.value, precision: amp.precision })
+    }
+
+    pub fn data_is_populated(&self) -> bool {
+        let bpt = self.bpt_index();
+        let invariant_is_populated = match &self.invariant {
+            BalancerInvariant::Weighted(weights) => {
+                weights.len() == self.tokens.len() && weights.iter().all(|w| !w.is_zero())
+            }
+            BalancerInvariant::Stable { amp, precision } => !(amp.is_zero() || precision.is_zero()),
+        };
+
+        invariant_is_populated
+            && self.tokens.len() >= 2
+            && self.balances.len() == self.tokens.len()
+            && self.rates.len() == self.tokens.len()
+            && self
+                .balances
+                .iter()
+                .zip(&self.rates)
+                .enumerate()
+                .filter(|(i, _)| Some(*i) != bpt)
+                .all(|(_, (balance, rate))| !(balance.is_zero() || rate.is_zero()))
+    }
+
+    pub fn get_tvl(&self, base: Address, quote: Address) -> (Rational, Rational) {
+        let reserve = |token: Address| {
+            self.token_index(token)
+                .map(|i| self.balances[i].to_scaled_rational(self.decimals[i]))
+                .unwrap_or(Rational::ZERO)
+        };
+
+        (reserve(base), reserve(quote))
+    }
+
+    /// Whether the log is one of the vault events that moves this pool's
+    /// balances
+    pub fn is_vault_log(&self, log: &Log) -> bool {
+        let (Some(event_signature), Some(pool)) = (log.topics().first(), log.topics().get(1))
+        else {
+            return false
+        };
+
+        if *event_signature == IBalancerV2Vault::Swap::SIGNATURE_HASH
+            || *event_signature == IBalancerV2Vault::PoolBalanceChanged::SIGNATURE_HASH
+        {
+            *pool == self.pool_id
+        } else if *event_signature == IBalancerV3Vault::Swap::SIGNATURE_HASH
+            || *event_signature == IBalancerV3Vault::PoolBalanceChanged::SIGNATURE_HASH
+        {
+            *pool == self.address.into_word()
+        } else {
+            false
+        }
+    }
+
+    fn token_index(&self, token: Address) -> Result<usize, ArithmeticError> {
+        self.tokens
+            .iter()
+            .position(|t| *t == token)
+            .ok_or(ArithmeticError::BalancerUnknownToken(token))
+    }
+
+    /// Index of a token that can be priced off the invariant, which excludes
+    /// the pool's own bpt
+    fn price_index(&self, token: Address) -> Result<usize, ArithmeticError> {
+        self.token_index(token)
+            .ok()
+            .filter(|i| Some(*i) != self.bpt_index())
+            .ok_or(ArithmeticError::BalancerUnknownToken(token))
+    }
+
+    fn bpt_index(&self) -> Option<usize> {
+        self.tokens.iter().position(|t| *t == self.address)
+    }
+
+    /// Balances in the invariant's units, scaled to 18 decimals the way the
+    /// vault upscales them
+    fn upscaled_balances(&self) -> Vec<U256> {
+        self.balances
+            .iter()
+            .zip(&self.decimals)
+            .zip(&self.rates)
+            .map(|((balance, decimals), rate)| {
+                balance.saturating_mul(*rate) / U256::from(10).pow(U256::from(*decimals))
+            })
+            .collect()
+    }
+
+    fn apply_swap(
+        &mut self,
+        token_in: Address,
+        token_out: Address,
+        amount_in: U256,
+        amount_out: U256,
+    ) -> Result<(), AmmError> {
+        let (i, o) = (self.token_index(token_in)?, self.token_index(token_out)?);
+        self.balances[i] = self.balances[i].saturating_add(amount_in);
+        self.balances[o] = self.balances[o].saturating_sub(amount_out);
+
+        Ok(())
+    }
+}
+
+fn apply_delta(balance: U256, delta: U256, negative: bool) -> U256 {
+    if negative {
+        balance.saturating_sub(delta)
+    } else {
+        balance.saturating_add(delta)
+    }
+}
+
+/// Raw token amount of a decimal adjusted action amount
+fn to_raw(amount: &Rational, decimals: u8) -> Option<U256> {
+    let raw = Natural::try_from(amount * Rational::from(10u64).pow(decimals as u64)).ok()?;
+    U256::checked_from_limbs_slice(&raw.to_limbs_asc())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::address;
+    use malachite::num::basic::traits::One;
+
+    use super::*;
+
+    const WETH: Address = address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+    const USDC: Address = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+
+    fn one() -> U256 {
+        U256::from(10).pow(U256::from(18))
+    }
+
+    #[test]
+    fn test_weighted_pool_price_follows_weights() {
+        // 80/20 pool holding 1 weth per 500 usdc, which prices weth at 2000 usdc
+        let pool = BalancerPool {
+            tokens: vec![WETH, USDC],
+            decimals: vec![18, 6],
+            balances: vec![U256::from(100) * one(), U256::from(50_000_000_000u64)],
+            rates: vec![one(); 2],
+            invariant: BalancerInvariant::Weighted(vec![
+                U256::from(8) * one() / U256::from(10),
+                U256::from(2) * one() / U256::from(10),
+            ]),
+            ..Default::default()
+        };
+
+        assert_eq!(pool.calculate_price(WETH, USDC).unwrap(), Rational::from(2000));
+        assert_eq!(pool.calculate_price(USDC, WETH).unwrap(), Rational::ONE / Rational::from(2000));
+    }
+
+    #[test]
+    fn test_composable_stable_pool_ignores_bpt() {
+        let bpt = address!("1000000000000000000000000000000000000001");
+        let pool = BalancerPool {
+            address: bpt,
+            tokens: vec![WETH, bpt, USDC],
+            decimals: vec![18, 18, 6],
+            balances: vec![
+                U256::from(1_000_000) * one(),
+                U256::MAX >> 128,
+                U256::from(1_000_000_000_000u64),
+            ],
+            rates: vec![one(); 3],
+            invariant: BalancerInvariant::Stable {
+                amp:       U256::from(200_000),
+                precision: U256::from(1000),
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(pool.calculate_price(WETH, USDC).unwrap(), Rational::ONE);
+        assert!(pool.calculate_price(bpt, USDC).is_err());
+    }
+}
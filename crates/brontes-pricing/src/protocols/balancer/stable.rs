@@ -0,0 +1,84 @@
+use alloy_primitives::U256;
+use brontes_types::ToScaledRational;
+use malachite::{
+    num::{
+        arithmetic::traits::Pow,
+        basic::traits::{One, Zero},
+    },
+    Rational,
+};
+
+use crate::errors::ArithmeticError;
+
+/// Spot price of `base` in `quote` for the StableMath invariant. With `A` the
+/// amplification times the token count,
+/// `F = A * S + D - A * D - D^(n+1) / (n^n * prod(x))` and the price is the
+/// ratio of the partial derivatives `A + D^(n+1) / (n^n * prod(x)) / x_k`
+pub(super) fn spot_price(
+    balances: &[U256],
+    amp: U256,
+    precision: U256,
+    base: usize,
+    quote: usize,
+) -> Result<Rational, ArithmeticError> {
+    let invariant =
+        calculate_invariant(balances, amp, precision).ok_or(ArithmeticError::BalancerDivZero)?;
+
+    let n = balances.len() as u64;
+    let xp = balances
+        .iter()
+        .map(|x| x.to_scaled_rational(18))
+        .collect::<Vec<_>>();
+    let d = invariant.to_scaled_rational(18);
+
+    let prod = xp.iter().fold(Rational::ONE, |acc, x| acc * x);
+    if prod == Rational::ZERO {
+        return Err(ArithmeticError::BalancerDivZero)
+    }
+
+    let amp_times_total =
+        Rational::from(n) * amp.to_scaled_rational(0) / precision.to_scaled_rational(0);
+    let k = d.pow(n + 1) / (Rational::from(n).pow(n) * prod);
+
+    let (x_base, x_quote) = (&xp[base], &xp[quote]);
+    Ok(x_quote * (&k + &amp_times_total * x_base) / (x_base * (&k + &amp_times_total * x_quote)))
+}
+
+/// The invariant by newton's method, the way `StableMath` computes it
+fn calculate_invariant(balances: &[U256], amp: U256, precision: U256) -> Option<U256> {
+    let n = U256::from(balances.len());
+    let sum = balances.iter().fold(U256::ZERO, |acc, x| acc + x);
+    if sum.is_zero() {
+        return Some(U256::ZERO)
+    }
+
+    let amp_times_total = amp.checked_mul(n)?;
+    let mut invariant = sum;
+    for _ in 0..255 {
+        let mut d_p = invariant;
+        for balance in balances {
+            d_p = d_p
+                .checked_mul(invariant)?
+                .checked_div(balance.checked_mul(n)?)?;
+        }
+
+        let prev_invariant = invariant;
+        let numerator = amp_times_total
+            .checked_mul(sum)?
+            .checked_div(precision)?
+            .checked_add(d_p.checked_mul(n)?)?
+            .checked_mul(invariant)?;
+        let denominator = amp_times_total
+            .checked_sub(precision)?
+            .checked_mul(invariant)?
+            .checked_div(precision)?
+            .checked_add(n.checked_add(U256::from(1))?.checked_mul(d_p)?)?;
+        invariant = numerator.checked_div(denominator)?;
+
+        if invariant.abs_diff(prev_invariant) <= U256::from(1) {
+            return Some(invariant)
+        }
+    }
+
+    None
+}
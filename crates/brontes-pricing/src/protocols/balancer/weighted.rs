@@ -0,0 +1,26 @@
+use alloy_primitives::U256;
+use brontes_types::ToScaledRational;
+use malachite::{num::basic::traits::Zero, Rational};
+
+use crate::errors::ArithmeticError;
+
+/// Spot price of `base` in `quote` for the weighted invariant `prod(x_i ^
+/// w_i)`, which is the ratio of the weight normalized balances
+pub(super) fn spot_price(
+    balances: &[U256],
+    weights: &[U256],
+    base: usize,
+    quote: usize,
+) -> Result<Rational, ArithmeticError> {
+    let (x_base, x_quote) =
+        (balances[base].to_scaled_rational(18), balances[quote].to_scaled_rational(18));
+    let (w_base, w_quote) =
+        (weights[base].to_scaled_rational(18), weights[quote].to_scaled_rational(18));
+
+    let denominator = x_base * w_quote;
+    if denominator == Rational::ZERO {
+        return Err(ArithmeticError::BalancerDivZero)
+    }
+
+    Ok(x_quote * w_base / denominator)
+}
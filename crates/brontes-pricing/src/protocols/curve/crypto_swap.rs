@@ -0,0 +1,524 @@
+use std::sync::Arc;
+
+use alloy_primitives::{Address, Log, U256};
+use alloy_sol_macro::sol;
+use async_trait::async_trait;
+use brontes_types::{normalized_actions::Action, traits::TracingProvider, ToScaledRational};
+use malachite::{
+    num::{
+        arithmetic::traits::Pow,
+        basic::traits::{One, Zero},
+    },
+    Rational,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    apply_amounts, coin_from_log, coin_index, invariant_balances, load_coins, load_decimals,
+    log_words, marginal_price, reserves, signature_hash, ICurvePool,
+};
+use crate::{
+    errors::{AmmError, ArithmeticError, EventLogError},
+    make_call_request, Protocol, UpdatableProtocol,
+};
+
+/// `A` is stored multiplied by this to keep precision at low values
+const A_MULTIPLIER: u64 = 10_000;
+
+sol!(
+    interface ICurveCryptoSwap {
+        function gamma() external view returns (uint256);
+        function D() external view returns (uint256);
+    }
+);
+
+sol!(
+    interface ICurveTwoCrypto {
+        function price_scale() external view returns (uint256);
+    }
+);
+
+sol!(
+    interface ICurveTriCrypto {
+        function price_scale(uint256 k) external view returns (uint256);
+    }
+);
+
+/// A Curve CryptoSwap or TriCrypto pool. Balances are repegged around
+/// `price_scale`, the price is the marginal rate of the CryptoSwap invariant
+/// at the pool's `D`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct CurveCryptoSwapPool {
+    pub address:     Address,
+    pub protocol:    Protocol,
+    pub coins:       Vec<Address>,
+    pub decimals:    Vec<u8>,
+    pub balances:    Vec<U256>,
+    /// Price of each coin in the first coin, scaled by 1e18. The first entry is
+    /// always one
+    pub price_scale: Vec<U256>,
+    pub d:           U256,
+    /// `A * N^N`, scaled by [`A_MULTIPLIER`]
+    pub amp:         U256,
+    /// Scaled by 1e18
+    pub gamma:       U256,
+}
+
+#[async_trait]
+impl UpdatableProtocol for CurveCryptoSwapPool {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn sync_from_action(&mut self, _action: Action) -> Result<(), AmmError> {
+        todo!("syncing from actions is currently not supported for curve pools")
+    }
+
+    fn sync_from_log(&mut self, log: Log) -> Result<(), AmmError> {
+        let event_signature = log.topics()[0];
+        let n = self.coins.len();
+        let words = log_words(&log);
+
+        // the ng pools append fields to the original events, the leading ones are
+        // shared
+        let signature = |events: &[String]| {
+            events
+                .iter()
+                .any(|event| signature_hash(event) == event_signature)
+        };
+
+        // the ng pools also log the price scale the action left behind, as the last
+        // field
+        let ng_price_scale = |fields: usize| (words.len() == fields).then(|| words[fields - 1]);
+
+        let packed_price_scale = if signature(&[
+            "TokenExchange(address,uint256,uint256,uint256,uint256)".to_string(),
+            "TokenExchange(address,uint256,uint256,uint256,uint256,uint256,uint256)".to_string(),
+        ]) {
+            let (sold, bought) = (
+                coin_from_log(self.address, n, words[0])?,
+                coin_from_log(self.address, n, words[2])?,
+            );
+            self.balances[sold] = self.balances[sold].saturating_add(words[1]);
+            self.balances[bought] = self.balances[bought].saturating_sub(words[3]);
+            ng_price_scale(6)
+        } else if signature(&[
+            format!("AddLiquidity(address,uint256[{n}],uint256,uint256)"),
+            format!("AddLiquidity(address,uint256[{n}],uint256,uint256,uint256)"),
+        ]) {
+            apply_amounts(&mut self.balances, &words[..n], true);
+            ng_price_scale(n + 3)
+        } else if signature(&[format!("RemoveLiquidity(address,uint256[{n}],uint256)")]) {
+            apply_amounts(&mut self.balances, &words[..n], false);
+            None
+        } else if signature(&[
+            "RemoveLiquidityOne(address,uint256,uint256,uint256)".to_string(),
+            "RemoveLiquidityOne(address,uint256,uint256,uint256,uint256,uint256)".to_string(),
+        ]) {
+            let coin = coin_from_log(self.address, n, words[1])?;
+            self.balances[coin] = self.balances[coin].saturating_sub(words[2]);
+            ng_price_scale(5)
+        } else {
+            return Err(AmmError::EventLogError(EventLogError::InvalidEventSignature))
+        };
+
+        // older pools don't log their rebalances, their price scale stays at the
+        // loaded one
+        if let Some(packed) = packed_price_scale {
+            self.price_scale = unpack_price_scale(packed, n);
+        }
+
+        let xp = invariant_balances(&self.balances, &self.price_scale, &self.decimals);
+        self.d =
+            newton_d(self.amp, self.gamma, &xp, self.d).ok_or(AmmError::SyncError(self.address))?;
+
+        Ok(())
+    }
+
+    fn calculate_price(
+        &self,
+        base_token: Address,
+        quote_token: Address,
+    ) -> Result<Rational, ArithmeticError> {
+        let (base, quote) =
+            (coin_index(&self.coins, base_token)?, coin_index(&self.coins, quote_token)?);
+
+        let n = self.coins.len() as u64;
+        let xp = invariant_balances(&self.balances, &self.price_scale, &self.decimals)
+            .into_iter()
+            .map(|x| x.to_scaled_rational(18))
+            .collect::<Vec<_>>();
+        let d = self.d.to_scaled_rational(18);
+        let gamma = self.gamma.to_scaled_rational(18);
+        let amp = self.amp.to_scaled_rational(0) / Rational::from(A_MULTIPLIER);
+
+        // F = K * D^(n-1) * S + prod(x) - K * D^n - (D / n)^n where
+        // K = A * K0 * gamma^2 / (gamma + 1 - K0)^2 and K0 = prod(x) * n^n / D^n, so
+        // dF/dx_k = (dK/dK0 * K0 * (D^(n-1) * S - D^n) + prod(x)) / x_k + K * D^(n-1)
+        let sum = xp.iter().fold(Rational::ZERO, |acc, x| acc + x);
+        let prod = xp.iter().fold(Rational::ONE, |acc, x| acc * x);
+        let d_n = d.clone().pow(n);
+        let d_n1 = d.pow(n - 1);
+        if d_n == Rational::ZERO {
+            return Err(ArithmeticError::CurveDivZero)
+        }
+
+        let k0 = &prod * Rational::from(n).pow(n) / &d_n;
+        let g1k0 = &gamma + Rational::ONE - &k0;
+        if g1k0 == Rational::ZERO {
+            return Err(ArithmeticError::CurveDivZero)
+        }
+
+        let gamma2 = gamma.clone().pow(2u64);
+        let k = &amp * &k0 * &gamma2 / g1k0.clone().pow(2u64);
+        let dk_dk0 = &amp * &gamma2 * (&gamma + Rational::ONE + &k0) / g1k0.pow(3u64);
+
+        let g = dk_dk0 * k0 * (&d_n1 * sum - d_n) + prod;
+        let c = k * d_n1;
+
+        let price = marginal_price(&xp[base], &xp[quote], &g, &c)?;
+
+        Ok(price * self.price_scale[base].to_scaled_rational(18)
+            / self.price_scale[quote].to_scaled_rational(18))
+    }
+
+    fn tokens(&self) -> Vec<Address> {
+        self.coins.clone()
+    }
+}
+
+impl CurveCryptoSwapPool {
+    pub async fn new_load_on_block<M: TracingProvider>(
+        address: Address,
+        protocol: Protocol,
+        middleware: Arc<M>,
+        block: u64,
+    ) -> Result<Self, AmmError> {
+        let block = Some(block);
+        let (coins, balances) = load_coins(address, &middleware, block).await?;
+        let decimals = load_decimals(&coins, &middleware, block).await?;
+
+        let amp = make_call_request(ICurvePool::ACall::new(()), &middleware, address, block)
+            .await?
+            ._0;
+        let gamma =
+            make_call_request(ICurveCryptoSwap::gammaCall::new(()), &middleware, address, block)
+                .await?
+                ._0;
+        let d = make_call_request(ICurveCryptoSwap::DCall::new(()), &middleware, address, block)
+            .await?
+            ._0;
+
+        let mut price_scale = vec![one()];
+        if coins.len() == 2 {
+            price_scale.push(
+                make_call_request(
+                    ICurveTwoCrypto::price_scaleCall::new(()),
+                    &middleware,
+                    address,
+                    block,
+                )
+                .await?
+                ._0,
+            );
+        } else {
+            for k in 0..coins.len() - 1 {
+                price_scale.push(
+                    make_call_request(
+                        ICurveTriCrypto::price_scaleCall::new((U256::from(k),)),
+                        &middleware,
+                        address,
+                        block,
+                    )
+                    .await?
+                    ._0,
+                );
+            }
+        }
+
+        let pool = CurveCryptoSwapPool {
+            address,
+            protocol,
+            coins,
+            decimals,
+            balances,
+            price_scale,
+            d,
+            amp,
+            gamma,
+        };
+
+        if !pool.data_is_populated() {
+            return Err(AmmError::NoStateError(address))
+        }
+
+        Ok(pool)
+    }
+
+    pub fn data_is_populated(&self) -> bool {
+        !(self.d.is_zero()
+            || self.amp.is_zero()
+            || self.gamma.is_zero()
+            || self.balances.iter().any(|balance| balance.is_zero())
+            || self.price_scale.iter().any(|price| price.is_zero()))
+    }
+
+    pub fn get_tvl(&self, base: Address, quote: Address) -> (Rational, Rational) {
+        reserves(&self.coins, &self.balances, &self.decimals, base, quote)
+    }
+}
+
+fn one() -> U256 {
+    U256::from(10).pow(U256::from(18))
+}
+
+/// The ng pools pack the price scales of every coin but the first into one
+/// word, 128 bits each starting from the bottom
+fn unpack_price_scale(packed: U256, coins: usize) -> Vec<U256> {
+    let mask = (U256::from(1) << 128) - U256::from(1);
+
+    std::iter::once(one())
+        .chain((0..coins - 1).map(|k| (packed >> (128 * k)) & mask))
+        .collect()
+}
+
+/// The CryptoSwap invariant by newton's method, the way the pools compute it.
+/// `ann` is `A * N^N` scaled by [`A_MULTIPLIER`], the search starts from the
+/// previous `D`
+fn newton_d(ann: U256, gamma: U256, xp: &[U256], d_prev: U256) -> Option<U256> {
+    let one = one();
+    let n = U256::from(xp.len());
+    let s = xp
+        .iter()
+        .try_fold(U256::ZERO, |acc, x| acc.checked_add(*x))?;
+    let mut d = if d_prev.is_zero() { s } else { d_prev };
+
+    for _ in 0..255 {
+        let d_prev = d;
+
+        // K0 = prod(x) * N^N / D^N, scaled by 1e18
+        let k0 = xp
+            .iter()
+            .try_fold(one, |k0, x| k0.checked_mul(*x)?.checked_mul(n)?.checked_div(d))?;
+        let g1k0 = (gamma + one).abs_diff(k0) + U256::from(1);
+
+        // D / (A * N^N) * g1k0^2 / gamma^2
+        let mul1 = one
+            .checked_mul(d)?
+            .checked_div(gamma)?
+            .checked_mul(g1k0)?
+            .checked_div(gamma)?
+            .checked_mul(g1k0)?
+            .checked_mul(U256::from(A_MULTIPLIER))?
+            .checked_div(ann)?;
+        // 2 * N * K0 / g1k0
+        let mul2 = (one * U256::from(2))
+            .checked_mul(n)?
+            .checked_mul(k0)?
+            .checked_div(g1k0)?;
+
+        let neg_fprime = s
+            .checked_add(s.checked_mul(mul2)? / one)?
+            .checked_add(mul1.checked_mul(n)?.checked_div(k0)?)?
+            .checked_sub(mul2.checked_mul(d)? / one)?;
+
+        let d_plus = d
+            .checked_mul(neg_fprime.checked_add(s)?)?
+            .checked_div(neg_fprime)?;
+        let d_minus = d.checked_mul(d)?.checked_div(neg_fprime)?;
+        let correction = (d.checked_mul(mul1 / neg_fprime)? / one)
+            .checked_mul(k0.abs_diff(one))?
+            .checked_div(k0)?;
+        let d_minus = if one > k0 {
+            d_minus.checked_add(correction)?
+        } else {
+            d_minus.checked_sub(correction)?
+        };
+
+        d = if d_plus > d_minus { d_plus - d_minus } else { (d_minus - d_plus) / U256::from(2) };
+
+        if d.abs_diff(d_prev) * U256::from(10).pow(U256::from(14))
+            < d.max(U256::from(10).pow(U256::from(16)))
+        {
+            return Some(d)
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{address, Bytes, LogData, B256};
+
+    use super::*;
+
+    fn e18(amount: u64) -> U256 {
+        U256::from(amount) * one()
+    }
+
+    fn pool() -> CurveCryptoSwapPool {
+        let xp = [e18(1_000_000), e18(1_000_000)];
+        let amp = U256::from(400_000) * U256::from(4);
+        let gamma = U256::from(145_000_000_000_000u64);
+
+        CurveCryptoSwapPool {
+            address: address!("00000000000000000000000000000000000000cc"),
+            coins: vec![
+                address!("fd086bc7cd5c481dcc9c85ebe478a1c0b69fcbb9"),
+                address!("82af49447d8a07e3bd95bd0d56f35241523fbab1"),
+            ],
+            decimals: vec![6, 18],
+            // a million usdt against 500 weth at 2000
+            balances: vec![U256::from(1_000_000_000_000u64), e18(500)],
+            price_scale: vec![one(), e18(2000)],
+            d: newton_d(amp, gamma, &xp, U256::ZERO).unwrap(),
+            amp,
+            gamma,
+            ..Default::default()
+        }
+    }
+
+    fn log(pool: Address, event: &str, words: &[U256]) -> Log {
+        let data = words
+            .iter()
+            .flat_map(|word| word.to_be_bytes::<32>())
+            .collect::<Vec<_>>();
+
+        Log {
+            address: pool,
+            data:    LogData::new_unchecked(
+                vec![signature_hash(event), B256::ZERO],
+                Bytes::from(data),
+            ),
+        }
+    }
+
+    #[test]
+    fn test_balanced_d_is_the_sum_of_balances() {
+        let xp = [e18(1_000_000), e18(1_000_000)];
+        let d =
+            newton_d(U256::from(1_600_000), U256::from(145_000_000_000_000u64), &xp, U256::ZERO)
+                .unwrap();
+
+        assert_eq!(d, e18(2_000_000));
+    }
+
+    #[test]
+    fn test_imbalanced_d_is_between_the_means() {
+        let xp = [e18(1_000_000), e18(4_000_000)];
+        let d =
+            newton_d(U256::from(1_600_000), U256::from(145_000_000_000_000u64), &xp, U256::ZERO)
+                .unwrap();
+
+        // 2 * sqrt(x0 * x1) <= D <= x0 + x1
+        assert!(d > e18(4_000_000) && d < e18(5_000_000));
+    }
+
+    #[test]
+    fn test_unpack_price_scale() {
+        let packed = (e18(60_000) << 128) | e18(3_000);
+
+        assert_eq!(unpack_price_scale(packed, 3), vec![one(), e18(3_000), e18(60_000)]);
+        assert_eq!(unpack_price_scale(e18(3_000), 2), vec![one(), e18(3_000)]);
+    }
+
+    #[test]
+    fn test_exchange_moves_balances_and_d() {
+        let mut pool = pool();
+        let d = pool.d;
+
+        // sells 2000 usdt for a weth
+        let exchange = log(
+            pool.address,
+            "TokenExchange(address,uint256,uint256,uint256,uint256)",
+            &[U256::ZERO, U256::from(2_000_000_000u64), U256::from(1), one()],
+        );
+        pool.sync_from_log(exchange).unwrap();
+
+        assert_eq!(pool.balances, vec![U256::from(1_002_000_000_000u64), e18(499)]);
+        assert_eq!(pool.price_scale, vec![one(), e18(2000)]);
+        assert_ne!(pool.d, d);
+        let xp = invariant_balances(&pool.balances, &pool.price_scale, &pool.decimals);
+        assert_eq!(Some(pool.d), newton_d(pool.amp, pool.gamma, &xp, d));
+    }
+
+    #[test]
+    fn test_ng_exchange_updates_the_price_scale() {
+        let mut pool = pool();
+        let d = pool.d;
+
+        let exchange = log(
+            pool.address,
+            "TokenExchange(address,uint256,uint256,uint256,uint256,uint256,uint256)",
+            &[
+                U256::ZERO,
+                U256::from(2_000_000_000u64),
+                U256::from(1),
+                one(),
+                U256::ZERO,
+                e18(2010),
+            ],
+        );
+        pool.sync_from_log(exchange).unwrap();
+
+        assert_eq!(pool.price_scale, vec![one(), e18(2010)]);
+        let xp = invariant_balances(&pool.balances, &pool.price_scale, &pool.decimals);
+        assert_eq!(Some(pool.d), newton_d(pool.amp, pool.gamma, &xp, d));
+    }
+
+    /// The marginal price should match a small `get_dy` net of the pool's fee
+    #[brontes_macros::test]
+    #[cfg(feature = "local-reth")]
+    async fn test_tricrypto_price_matches_get_dy() {
+        sol!(
+            interface ICurveCryptoQuote {
+                function get_dy(uint256 i, uint256 j, uint256 dx) external view returns (uint256);
+                function fee() external view returns (uint256);
+            }
+        );
+
+        let loader = brontes_core::test_utils::TraceLoader::new().await;
+        let provider = loader.get_provider();
+        let block = 200_000_000;
+        // usdt, wbtc, weth
+        let address = address!("960ea3e3c7fb317332d990873d354e18d7645590");
+
+        let pool = CurveCryptoSwapPool::new_load_on_block(
+            address,
+            Protocol::CurveTriCryptoPool,
+            provider.clone(),
+            block,
+        )
+        .await
+        .unwrap();
+
+        let dx = U256::from(10).pow(U256::from(15));
+        let dy = make_call_request(
+            ICurveCryptoQuote::get_dyCall::new((U256::from(2), U256::ZERO, dx)),
+            &provider,
+            address,
+            Some(block),
+        )
+        .await
+        .unwrap()
+        ._0;
+        let fee =
+            make_call_request(ICurveCryptoQuote::feeCall::new(()), &provider, address, Some(block))
+                .await
+                .unwrap()
+                ._0;
+
+        let quoted = dy.to_scaled_rational(pool.decimals[0])
+            / dx.to_scaled_rational(18)
+            / (Rational::ONE - fee.to_scaled_rational(10));
+        let price = pool.calculate_price(pool.coins[2], pool.coins[0]).unwrap();
+
+        let diff = (&price - &quoted) / &quoted;
+        assert!(
+            diff < Rational::from_unsigneds(1u64, 1000u64)
+                && diff > -Rational::from_unsigneds(1u64, 1000u64),
+            "price {price} quoted {quoted}"
+        );
+    }
+}
@@ -0,0 +1,212 @@
+mod crypto_swap;
+mod stable_swap;
+
+use std::sync::Arc;
+
+use alloy_primitives::{keccak256, Address, Log, B256, U256};
+use alloy_sol_macro::sol;
+use brontes_types::{constants::ETH_ADDRESS, traits::TracingProvider, ToScaledRational};
+pub use crypto_swap::CurveCryptoSwapPool;
+use malachite::{num::basic::traits::Zero, Rational};
+pub use stable_swap::CurveStableSwapPool;
+
+use super::make_call_request;
+use crate::errors::{AmmError, ArithmeticError};
+
+/// Upper bound on the coins a pool can hold, used to stop probing `coins(i)`
+const MAX_COINS: usize = 8;
+
+sol!(
+    interface ICurvePool {
+        function coins(uint256 i) external view returns (address);
+        function balances(uint256 i) external view returns (uint256);
+        function A() external view returns (uint256);
+    }
+);
+
+sol!(
+    interface ICurvePoolInt128 {
+        function coins(int128 i) external view returns (address);
+        function balances(int128 i) external view returns (uint256);
+    }
+);
+
+sol!(
+    interface ICurveCoin {
+        function decimals() external view returns (uint8);
+    }
+);
+
+/// Coins and balances of a pool. The oldest pools index coins with an
+/// `int128`, everything since takes a `uint256`
+async fn load_coins<M: TracingProvider>(
+    address: Address,
+    middleware: &Arc<M>,
+    block: Option<u64>,
+) -> Result<(Vec<Address>, Vec<U256>), AmmError> {
+    let int128_index =
+        make_call_request(ICurvePool::coinsCall::new((U256::ZERO,)), middleware, address, block)
+            .await
+            .is_err();
+
+    let mut coins = Vec::new();
+    let mut balances = Vec::new();
+    for i in 0..MAX_COINS {
+        let coin = if int128_index {
+            make_call_request(
+                ICurvePoolInt128::coinsCall::new((i as i128,)),
+                middleware,
+                address,
+                block,
+            )
+            .await
+            .map(|res| res._0)
+        } else {
+            make_call_request(
+                ICurvePool::coinsCall::new((U256::from(i),)),
+                middleware,
+                address,
+                block,
+            )
+            .await
+            .map(|res| res._0)
+        };
+        let Ok(coin) = coin else { break };
+
+        let balance = if int128_index {
+            make_call_request(
+                ICurvePoolInt128::balancesCall::new((i as i128,)),
+                middleware,
+                address,
+                block,
+            )
+            .await?
+            ._0
+        } else {
+            make_call_request(
+                ICurvePool::balancesCall::new((U256::from(i),)),
+                middleware,
+                address,
+                block,
+            )
+            .await?
+            ._0
+        };
+
+        coins.push(coin);
+        balances.push(balance);
+    }
+
+    if coins.len() < 2 {
+        return Err(AmmError::NoStateError(address))
+    }
+
+    Ok((coins, balances))
+}
+
+async fn load_decimals<M: TracingProvider>(
+    coins: &[Address],
+    middleware: &Arc<M>,
+    block: Option<u64>,
+) -> Result<Vec<u8>, AmmError> {
+    let mut decimals = Vec::with_capacity(coins.len());
+    for coin in coins {
+        if *coin == ETH_ADDRESS {
+            decimals.push(18);
+            continue
+        }
+        decimals.push(
+            make_call_request(ICurveCoin::decimalsCall::new(()), middleware, *coin, block)
+                .await?
+                ._0,
+        );
+    }
+
+    Ok(decimals)
+}
+
+/// Balances in the invariant's units, scaled by 1e18. `rates` are the value of
+/// one whole coin in those units
+fn invariant_balances(balances: &[U256], rates: &[U256], decimals: &[u8]) -> Vec<U256> {
+    balances
+        .iter()
+        .zip(rates)
+        .zip(decimals)
+        .map(|((balance, rate), decimals)| {
+            balance.saturating_mul(*rate) / U256::from(10).pow(U256::from(*decimals))
+        })
+        .collect()
+}
+
+/// Marginal price of `base` in `quote` for invariants whose gradient is
+/// `g / x_k + c`. Both StableSwap and CryptoSwap reduce to this form, and
+/// moving along the curve trades `x_quote` for `x_base` at the ratio of
+/// their partial derivatives
+fn marginal_price(
+    x_base: &Rational,
+    x_quote: &Rational,
+    g: &Rational,
+    c: &Rational,
+) -> Result<Rational, ArithmeticError> {
+    let denominator = x_base * (g + c * x_quote);
+    if *x_base == Rational::ZERO || denominator == Rational::ZERO {
+        return Err(ArithmeticError::CurveDivZero)
+    }
+
+    Ok(x_quote * (g + c * x_base) / denominator)
+}
+
+fn coin_index(coins: &[Address], coin: Address) -> Result<usize, ArithmeticError> {
+    coins
+        .iter()
+        .position(|c| *c == coin)
+        .ok_or(ArithmeticError::CurveUnknownCoin(coin))
+}
+
+/// Coin index logged by an event
+fn coin_from_log(pool: Address, coins: usize, coin: U256) -> Result<usize, AmmError> {
+    usize::try_from(coin)
+        .ok()
+        .filter(|coin| *coin < coins)
+        .ok_or(AmmError::SyncError(pool))
+}
+
+fn reserves(
+    coins: &[Address],
+    balances: &[U256],
+    decimals: &[u8],
+    base: Address,
+    quote: Address,
+) -> (Rational, Rational) {
+    let reserve = |coin: Address| {
+        coin_index(coins, coin)
+            .map(|i| balances[i].to_scaled_rational(decimals[i]))
+            .unwrap_or(Rational::ZERO)
+    };
+
+    (reserve(base), reserve(quote))
+}
+
+fn signature_hash(event: &str) -> B256 {
+    keccak256(event)
+}
+
+/// Fixed size event fields, one word each
+fn log_words(log: &Log) -> Vec<U256> {
+    log.data
+        .data
+        .chunks_exact(32)
+        .map(U256::from_be_slice)
+        .collect()
+}
+
+/// Adds or removes the per coin amounts of a liquidity event
+fn apply_amounts(balances: &mut [U256], amounts: &[U256], add: bool) {
+    balances
+        .iter_mut()
+        .zip(amounts)
+        .for_each(|(balance, amount)| {
+            *balance =
+                if add { balance.saturating_add(*amount) } else { balance.saturating_sub(*amount) };
+        });
+}
@@ -0,0 +1,354 @@
+use std::sync::Arc;
+
+use alloy_primitives::{Address, Log, U256};
+use alloy_sol_macro::sol;
+use alloy_sol_types::SolEvent;
+use async_trait::async_trait;
+use brontes_types::{normalized_actions::Action, traits::TracingProvider, ToScaledRational};
+use malachite::{
+    num::{
+        arithmetic::traits::Pow,
+        basic::traits::{One, Zero},
+    },
+    Rational,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    apply_amounts, coin_from_log, coin_index, invariant_balances, load_coins, load_decimals,
+    log_words, marginal_price, reserves, signature_hash, ICurvePool,
+};
+use crate::{
+    errors::{AmmError, ArithmeticError, EventLogError},
+    make_call_request, Protocol, UpdatableProtocol,
+};
+
+sol!(
+    interface ICurveStableSwap {
+        function stored_rates() external view returns (uint256[]);
+        function base_pool() external view returns (address);
+        function get_virtual_price() external view returns (uint256);
+
+        event AddLiquidity(
+            address indexed provider,
+            uint256[] token_amounts,
+            uint256[] fees,
+            uint256 invariant,
+            uint256 token_supply
+        );
+        event RemoveLiquidity(
+            address indexed provider,
+            uint256[] token_amounts,
+            uint256[] fees,
+            uint256 token_supply
+        );
+        event RemoveLiquidityImbalance(
+            address indexed provider,
+            uint256[] token_amounts,
+            uint256[] fees,
+            uint256 invariant,
+            uint256 token_supply
+        );
+    }
+);
+
+sol!(
+    interface ICurveStableSwapRated2 {
+        function stored_rates() external view returns (uint256[2]);
+    }
+);
+
+/// A Curve StableSwap pool, covering base, plain and meta pools. The price is
+/// the marginal rate of the invariant at the pool's current balances
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct CurveStableSwapPool {
+    pub address:  Address,
+    pub protocol: Protocol,
+    pub coins:    Vec<Address>,
+    pub decimals: Vec<u8>,
+    pub balances: Vec<U256>,
+    /// Value of one whole coin in the invariant's units, scaled by 1e18. This
+    /// is the oracle rate for lst pools and the base pool's virtual price for
+    /// the lp token of a metapool
+    pub rates:    Vec<U256>,
+    pub amp:      U256,
+}
+
+#[async_trait]
+impl UpdatableProtocol for CurveStableSwapPool {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn sync_from_action(&mut self, _action: Action) -> Result<(), AmmError> {
+        todo!("syncing from actions is currently not supported for curve pools")
+    }
+
+    fn sync_from_log(&mut self, log: Log) -> Result<(), AmmError> {
+        let event_signature = log.topics()[0];
+        let n = self.coins.len();
+
+        if event_signature == ICurveStableSwap::AddLiquidity::SIGNATURE_HASH {
+            let add = ICurveStableSwap::AddLiquidity::decode_log_data(&log, false)?;
+            apply_amounts(&mut self.balances, &add.token_amounts, true);
+        } else if event_signature == ICurveStableSwap::RemoveLiquidity::SIGNATURE_HASH {
+            let remove = ICurveStableSwap::RemoveLiquidity::decode_log_data(&log, false)?;
+            apply_amounts(&mut self.balances, &remove.token_amounts, false);
+        } else if event_signature == ICurveStableSwap::RemoveLiquidityImbalance::SIGNATURE_HASH {
+            let remove = ICurveStableSwap::RemoveLiquidityImbalance::decode_log_data(&log, false)?;
+            apply_amounts(&mut self.balances, &remove.token_amounts, false);
+        } else {
+            // the remaining events have fixed size fields, so the coin count is part of
+            // their signature
+            let words = log_words(&log);
+            let signature = |event: &str| signature_hash(event) == event_signature;
+
+            if signature("TokenExchange(address,int128,uint256,int128,uint256)") {
+                let (sold, bought) = (
+                    coin_from_log(self.address, n, words[0])?,
+                    coin_from_log(self.address, n, words[2])?,
+                );
+                self.balances[sold] = self.balances[sold].saturating_add(words[1]);
+                self.balances[bought] = self.balances[bought].saturating_sub(words[3]);
+            } else if signature(&format!(
+                "AddLiquidity(address,uint256[{n}],uint256[{n}],uint256,uint256)"
+            )) {
+                apply_amounts(&mut self.balances, &words[..n], true);
+            } else if signature(&format!(
+                "RemoveLiquidity(address,uint256[{n}],uint256[{n}],uint256)"
+            )) || signature(&format!(
+                "RemoveLiquidityImbalance(address,uint256[{n}],uint256[{n}],uint256,uint256)"
+            )) {
+                apply_amounts(&mut self.balances, &words[..n], false);
+            } else if signature("RemoveLiquidityOne(address,int128,uint256,uint256,uint256)") {
+                let coin = coin_from_log(self.address, n, words[0])?;
+                self.balances[coin] = self.balances[coin].saturating_sub(words[2]);
+            } else {
+                // older single coin withdrawals don't log the coin, underlying exchanges
+                // don't log the lp tokens moved. Both leave the state stale until reloaded
+                return Err(AmmError::EventLogError(EventLogError::InvalidEventSignature))
+            }
+        }
+
+        Ok(())
+    }
+
+    fn calculate_price(
+        &self,
+        base_token: Address,
+        quote_token: Address,
+    ) -> Result<Rational, ArithmeticError> {
+        let (base, quote) =
+            (coin_index(&self.coins, base_token)?, coin_index(&self.coins, quote_token)?);
+
+        let xp = invariant_balances(&self.balances, &self.rates, &self.decimals);
+        let d = get_d(&xp, self.amp).ok_or(ArithmeticError::CurveDivZero)?;
+
+        let n = xp.len() as u64;
+        let xp = xp
+            .into_iter()
+            .map(|x| x.to_scaled_rational(18))
+            .collect::<Vec<_>>();
+        let d = d.to_scaled_rational(18);
+
+        // F = Ann * S + D - Ann * D - D^(n+1) / (n^n * prod(x)), so
+        // dF/dx_k = Ann + D^(n+1) / (n^n * prod(x)) / x_k
+        let prod = xp.iter().fold(Rational::ONE, |acc, x| acc * x);
+        let ann = Rational::from(n) * self.amp.to_scaled_rational(0);
+        let k = d.pow(n + 1) / (Rational::from(n).pow(n) * prod);
+
+        let price = marginal_price(&xp[base], &xp[quote], &k, &ann)?;
+
+        Ok(price * self.rates[base].to_scaled_rational(18)
+            / self.rates[quote].to_scaled_rational(18))
+    }
+
+    fn tokens(&self) -> Vec<Address> {
+        self.coins.clone()
+    }
+}
+
+impl CurveStableSwapPool {
+    pub async fn new_load_on_block<M: TracingProvider>(
+        address: Address,
+        protocol: Protocol,
+        middleware: Arc<M>,
+        block: u64,
+    ) -> Result<Self, AmmError> {
+        let block = Some(block);
+        let (coins, balances) = load_coins(address, &middleware, block).await?;
+        let decimals = load_decimals(&coins, &middleware, block).await?;
+
+        let amp = make_call_request(ICurvePool::ACall::new(()), &middleware, address, block)
+            .await?
+            ._0;
+
+        let rates = Self::load_rates(address, protocol, &decimals, &middleware, block).await;
+
+        let pool = CurveStableSwapPool { address, protocol, coins, decimals, balances, rates, amp };
+
+        if !pool.data_is_populated() {
+            return Err(AmmError::NoStateError(address))
+        }
+
+        Ok(pool)
+    }
+
+    /// Pools with rate oracles expose them through `stored_rates`, scaled so
+    /// every coin has 36 decimals. Metapools without it price their lp token
+    /// at the base pool's virtual price, everything else trades at par
+    async fn load_rates<M: TracingProvider>(
+        address: Address,
+        protocol: Protocol,
+        decimals: &[u8],
+        middleware: &Arc<M>,
+        block: Option<u64>,
+    ) -> Vec<U256> {
+        let one = U256::from(10).pow(U256::from(18));
+        let from_stored = |stored: Vec<U256>| {
+            stored
+                .into_iter()
+                .zip(decimals)
+                .map(|(rate, decimals)| rate * U256::from(10).pow(U256::from(*decimals)) / one)
+                .collect::<Vec<_>>()
+        };
+
+        if let Ok(stored) = make_call_request(
+            ICurveStableSwap::stored_ratesCall::new(()),
+            middleware,
+            address,
+            block,
+        )
+        .await
+        {
+            if stored._0.len() == decimals.len() {
+                return from_stored(stored._0)
+            }
+        }
+
+        if decimals.len() == 2 {
+            if let Ok(stored) = make_call_request(
+                ICurveStableSwapRated2::stored_ratesCall::new(()),
+                middleware,
+                address,
+                block,
+            )
+            .await
+            {
+                return from_stored(stored._0.to_vec())
+            }
+        }
+
+        let mut rates = vec![one; decimals.len()];
+        if matches!(
+            protocol,
+            Protocol::CurveV1MetaPool | Protocol::CurveV2MetaPool | Protocol::CurvecrvUSDMetaPool
+        ) {
+            if let Ok(base_pool) = make_call_request(
+                ICurveStableSwap::base_poolCall::new(()),
+                middleware,
+                address,
+                block,
+            )
+            .await
+            {
+                if let Ok(virtual_price) = make_call_request(
+                    ICurveStableSwap::get_virtual_priceCall::new(()),
+                    middleware,
+                    base_pool._0,
+                    block,
+                )
+                .await
+                {
+                    rates[decimals.len() - 1] = virtual_price._0;
+                }
+            }
+        }
+
+        rates
+    }
+
+    pub fn data_is_populated(&self) -> bool {
+        !(self.amp.is_zero()
+            || self.balances.iter().any(|balance| balance.is_zero())
+            || self.rates.iter().any(|rate| rate.is_zero()))
+    }
+
+    pub fn get_tvl(&self, base: Address, quote: Address) -> (Rational, Rational) {
+        reserves(&self.coins, &self.balances, &self.decimals, base, quote)
+    }
+}
+
+/// The StableSwap invariant by newton's method, the way the pools compute it
+fn get_d(xp: &[U256], amp: U256) -> Option<U256> {
+    let n = U256::from(xp.len());
+    let s = xp.iter().fold(U256::ZERO, |acc, x| acc + x);
+    if s.is_zero() {
+        return Some(U256::ZERO)
+    }
+
+    let ann = amp.checked_mul(n)?;
+    let mut d = s;
+    for _ in 0..255 {
+        let mut d_p = d;
+        for x in xp {
+            d_p = d_p.checked_mul(d)?.checked_div(x.checked_mul(n)?)?;
+        }
+
+        let d_prev = d;
+        let numerator = ann
+            .checked_mul(s)?
+            .checked_add(d_p.checked_mul(n)?)?
+            .checked_mul(d)?;
+        let denominator = ann
+            .checked_sub(U256::from(1))?
+            .checked_mul(d)?
+            .checked_add(n.checked_add(U256::from(1))?.checked_mul(d_p)?)?;
+        d = numerator.checked_div(denominator)?;
+
+        if d.abs_diff(d_prev) <= U256::from(1) {
+            return Some(d)
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::address;
+
+    use super::*;
+
+    fn pool(balances: [u128; 2]) -> CurveStableSwapPool {
+        CurveStableSwapPool {
+            coins: vec![
+                address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"),
+                address!("6b175474e89094c44da98b954eedeac495271d0f"),
+            ],
+            decimals: vec![6, 18],
+            balances: balances.into_iter().map(U256::from).collect(),
+            rates: vec![U256::from(10).pow(U256::from(18)); 2],
+            amp: U256::from(200),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_balanced_pool_trades_at_par() {
+        let pool = pool([1_000_000 * 10u128.pow(6), 1_000_000 * 10u128.pow(18)]);
+        let price = pool.calculate_price(pool.coins[0], pool.coins[1]).unwrap();
+
+        assert_eq!(price, Rational::ONE);
+    }
+
+    #[test]
+    fn test_imbalanced_pool_prices_the_scarce_coin_higher() {
+        let pool = pool([500_000 * 10u128.pow(6), 1_500_000 * 10u128.pow(18)]);
+        let scarce = pool.calculate_price(pool.coins[0], pool.coins[1]).unwrap();
+        let abundant = pool.calculate_price(pool.coins[1], pool.coins[0]).unwrap();
+
+        assert!(scarce > Rational::ONE);
+        assert_eq!(scarce * abundant, Rational::ONE);
+    }
+}
@@ -0,0 +1,289 @@
+use std::sync::Arc;
+
+use alloy_primitives::{Address, Log, U256};
+use alloy_sol_macro::sol;
+use alloy_sol_types::SolEvent;
+use async_trait::async_trait;
+use brontes_types::{normalized_actions::Action, traits::TracingProvider, ToScaledRational};
+use malachite::{
+    num::basic::traits::{One, Zero},
+    Rational,
+};
+use serde::{Deserialize, Serialize};
+
+use super::make_call_request;
+use crate::{
+    errors::{AmmError, ArithmeticError, EventLogError},
+    UpdatableProtocol,
+};
+
+sol!(
+    interface IERC4626 {
+        function asset() external view returns (address);
+        function decimals() external view returns (uint8);
+        function totalAssets() external view returns (uint256);
+        function totalSupply() external view returns (uint256);
+        function convertToAssets(uint256 shares) external view returns (uint256);
+
+        event Deposit(
+            address indexed sender,
+            address indexed owner,
+            uint256 assets,
+            uint256 shares
+        );
+        event Withdraw(
+            address indexed sender,
+            address indexed receiver,
+            address indexed owner,
+            uint256 assets,
+            uint256 shares
+        );
+    }
+);
+
+/// An ERC-4626 vault as a pool between its asset and its own share token.
+/// The share price is the vault's `convertToAssets` rate
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct ERC4626Vault {
+    pub address:          Address,
+    pub asset:            Address,
+    pub asset_decimals:   u8,
+    pub share_decimals:   u8,
+    pub total_assets:     U256,
+    pub total_supply:     U256,
+    /// Assets redeemable for one whole share
+    pub assets_per_share: U256,
+}
+
+#[async_trait]
+impl UpdatableProtocol for ERC4626Vault {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn sync_from_action(&mut self, _action: Action) -> Result<(), AmmError> {
+        todo!("syncing from actions is currently not supported for erc4626 vaults")
+    }
+
+    fn sync_from_log(&mut self, log: Log) -> Result<(), AmmError> {
+        let event_signature = log.topics()[0];
+
+        // the ratio of a deposit or withdrawal is the exchange rate the vault used,
+        // this keeps the rate current as yield accrues
+        let (assets, shares) = if event_signature == IERC4626::Deposit::SIGNATURE_HASH {
+            let deposit = IERC4626::Deposit::decode_log_data(&log, false)?;
+            self.total_assets = self.total_assets.saturating_add(deposit.assets);
+            self.total_supply = self.total_supply.saturating_add(deposit.shares);
+            (deposit.assets, deposit.shares)
+        } else if event_signature == IERC4626::Withdraw::SIGNATURE_HASH {
+            let withdraw = IERC4626::Withdraw::decode_log_data(&log, false)?;
+            self.total_assets = self.total_assets.saturating_sub(withdraw.assets);
+            self.total_supply = self.total_supply.saturating_sub(withdraw.shares);
+            (withdraw.assets, withdraw.shares)
+        } else {
+            return Err(AmmError::EventLogError(EventLogError::InvalidEventSignature))
+        };
+
+        if let Some(assets_per_share) = share_rate(assets, shares, self.one_share()) {
+            self.assets_per_share = assets_per_share;
+        }
+
+        Ok(())
+    }
+
+    fn calculate_price(
+        &self,
+        base_token: Address,
+        _quote_token: Address,
+    ) -> Result<Rational, ArithmeticError> {
+        let assets_per_share = self
+            .assets_per_share
+            .to_scaled_rational(self.asset_decimals);
+        if assets_per_share == Rational::ZERO {
+            return Err(ArithmeticError::ERC4626DivZero)
+        }
+
+        if base_token == self.address {
+            Ok(assets_per_share)
+        } else {
+            Ok(Rational::ONE / assets_per_share)
+        }
+    }
+
+    fn tokens(&self) -> Vec<Address> {
+        vec![self.asset, self.address]
+    }
+}
+
+impl ERC4626Vault {
+    pub async fn new_load_on_block<M: TracingProvider>(
+        address: Address,
+        middleware: Arc<M>,
+        block: u64,
+    ) -> Result<Self, AmmError> {
+        let block = Some(block);
+        let asset = make_call_request(IERC4626::assetCall::new(()), &middleware, address, block)
+            .await?
+            ._0;
+        let asset_decimals =
+            make_call_request(IERC4626::decimalsCall::new(()), &middleware, asset, block)
+                .await?
+                ._0;
+        let share_decimals =
+            make_call_request(IERC4626::decimalsCall::new(()), &middleware, address, block)
+                .await?
+                ._0;
+        let total_assets =
+            make_call_request(IERC4626::totalAssetsCall::new(()), &middleware, address, block)
+                .await?
+                ._0;
+        let total_supply =
+            make_call_request(IERC4626::totalSupplyCall::new(()), &middleware, address, block)
+                .await?
+                ._0;
+
+        let mut vault = ERC4626Vault {
+            address,
+            asset,
+            asset_decimals,
+            share_decimals,
+            total_assets,
+            total_supply,
+            assets_per_share: U256::ZERO,
+        };
+        vault.assets_per_share = make_call_request(
+            IERC4626::convertToAssetsCall::new((vault.one_share(),)),
+            &middleware,
+            address,
+            block,
+        )
+        .await?
+        ._0;
+
+        if !vault.data_is_populated() {
+            return Err(AmmError::NoStateError(address))
+        }
+
+        Ok(vault)
+    }
+
+    pub fn data_is_populated(&self) -> bool {
+        !(self.asset.is_zero() || self.total_supply.is_zero() || self.assets_per_share.is_zero())
+    }
+
+    fn one_share(&self) -> U256 {
+        U256::from(10).pow(U256::from(self.share_decimals))
+    }
+
+    pub fn get_tvl(&self, base: Address) -> (Rational, Rational) {
+        let assets = self.total_assets.to_scaled_rational(self.asset_decimals);
+        let shares = self.total_supply.to_scaled_rational(self.share_decimals);
+
+        if base == self.asset {
+            (assets, shares)
+        } else {
+            (shares, assets)
+        }
+    }
+}
+
+/// The assets one whole share is worth at the ratio of a deposit or
+/// withdrawal. Scaling `assets` up first keeps the precision, dividing first is
+/// only needed for amounts large enough to overflow
+fn share_rate(assets: U256, shares: U256, one_share: U256) -> Option<U256> {
+    if shares.is_zero() {
+        return None
+    }
+
+    assets
+        .checked_mul(one_share)
+        .map(|scaled| scaled / shares)
+        .or_else(|| (assets / shares).checked_mul(one_share))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::LogData;
+
+    use super::*;
+
+    fn vault() -> ERC4626Vault {
+        ERC4626Vault {
+            address:          Address::new([0x4a; 20]),
+            asset:            Address::new([0xa0; 20]),
+            asset_decimals:   6,
+            share_decimals:   6,
+            total_assets:     U256::from(1_000_000_000u64),
+            total_supply:     U256::from(900_000_000u64),
+            assets_per_share: U256::from(1_100_000u64),
+        }
+    }
+
+    fn log(vault: &ERC4626Vault, data: LogData) -> Log {
+        Log { address: vault.address, data }
+    }
+
+    #[test]
+    fn test_share_rate() {
+        let one = U256::from(1_000_000u64);
+        assert_eq!(
+            share_rate(U256::from(3_000_000u64), U256::from(2_000_000u64), one),
+            Some(U256::from(1_500_000u64))
+        );
+        assert_eq!(share_rate(U256::from(1u64), U256::ZERO, one), None);
+
+        // scaling up first would overflow, dividing first still gives the rate
+        let shares = U256::MAX / U256::from(4u64);
+        assert_eq!(
+            share_rate(shares * U256::from(2u64), shares, one),
+            Some(U256::from(2_000_000u64))
+        );
+    }
+
+    #[test]
+    fn test_deposit_updates_rate_and_totals() {
+        let mut vault = vault();
+        let deposit = IERC4626::Deposit {
+            sender: Address::new([0x01; 20]),
+            owner:  Address::new([0x01; 20]),
+            assets: U256::from(12_000_000u64),
+            shares: U256::from(10_000_000u64),
+        };
+
+        vault
+            .sync_from_log(log(&vault, deposit.encode_log_data()))
+            .unwrap();
+
+        assert_eq!(vault.assets_per_share, U256::from(1_200_000u64));
+        assert_eq!(vault.total_assets, U256::from(1_012_000_000u64));
+        assert_eq!(vault.total_supply, U256::from(910_000_000u64));
+    }
+
+    #[test]
+    fn test_withdraw_of_zero_shares_keeps_rate() {
+        let mut vault = vault();
+        let withdraw = IERC4626::Withdraw {
+            sender:   Address::new([0x01; 20]),
+            receiver: Address::new([0x01; 20]),
+            owner:    Address::new([0x01; 20]),
+            assets:   U256::ZERO,
+            shares:   U256::ZERO,
+        };
+
+        vault
+            .sync_from_log(log(&vault, withdraw.encode_log_data()))
+            .unwrap();
+
+        assert_eq!(vault.assets_per_share, U256::from(1_100_000u64));
+    }
+
+    #[test]
+    fn test_price_of_share_and_asset() {
+        let vault = vault();
+        let share_price = vault.calculate_price(vault.address, vault.asset).unwrap();
+        let asset_price = vault.calculate_price(vault.asset, vault.address).unwrap();
+
+        assert_eq!(share_price, Rational::from_unsigneds(11u64, 10));
+        assert_eq!(asset_price, Rational::from_unsigneds(10u64, 11));
+    }
+}
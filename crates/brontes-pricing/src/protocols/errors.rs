@@ -73,6 +73,30 @@ pub enum ArithmeticError {
     UniswapV3MathError(#[from] UniswapV3MathError),
     #[error("v2 div by zero")]
     UniV2DivZero,
+    #[error("erc4626 div by zero")]
+    ERC4626DivZero,
+    #[error("curve div by zero")]
+    CurveDivZero,
+    #[error("coin {0:?} is not in the curve pool")]
+    CurveUnknownCoin(Address),
+    #[error("balancer div by zero")]
+    BalancerDivZero,
+    #[error("token {0:?} is not in the balancer pool")]
+    BalancerUnknownToken(Address),
+    #[error("token {0:?} is not in the lfj pair")]
+    LFJUnknownToken(Address),
+    #[error("maverick v2 tick {0} holds no liquidity")]
+    MaverickV2EmptyTick(i32),
+    #[error("maverick v2 tick {0} isn't loaded")]
+    MaverickV2TickNotLoaded(i32),
+    #[error("price of maverick v2 tick {0} is out of range")]
+    MaverickV2PriceOverflow(i32),
+    #[error("token {0:?} is not in the maverick v2 pool")]
+    MaverickV2UnknownToken(Address),
+    #[error("fluid dex div by zero")]
+    FluidDexDivZero,
+    #[error("token {0:?} is not in the fluid dex")]
+    FluidDexUnknownToken(Address),
 }
 
 #[derive(Error, Debug)]
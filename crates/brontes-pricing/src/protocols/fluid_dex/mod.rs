@@ -0,0 +1,639 @@
+use std::sync::Arc;
+
+use alloy_primitives::{Address, Log, U256};
+use alloy_sol_macro::sol;
+use alloy_sol_types::SolEvent;
+use async_trait::async_trait;
+use brontes_types::{
+    constants::arbitrum::FLUID_DEX_RESOLVER_ADDRESS, normalized_actions::Action,
+    traits::TracingProvider, ToScaledRational,
+};
+use malachite::{
+    num::basic::traits::{One, Zero},
+    Rational,
+};
+use serde::{Deserialize, Serialize};
+
+use super::make_call_request;
+use crate::{
+    errors::{AmmError, ArithmeticError, EventLogError},
+    UpdatableProtocol,
+};
+
+sol!(
+    interface IFluidDexResolver {
+        struct CollateralReserves {
+            uint256 token0RealReserves;
+            uint256 token1RealReserves;
+            uint256 token0ImaginaryReserves;
+            uint256 token1ImaginaryReserves;
+        }
+        struct DebtReserves {
+            uint256 token0Debt;
+            uint256 token1Debt;
+            uint256 token0RealReserves;
+            uint256 token1RealReserves;
+            uint256 token0ImaginaryReserves;
+            uint256 token1ImaginaryReserves;
+        }
+        struct ShiftData {
+            uint256 oldUpper;
+            uint256 oldLower;
+            uint256 duration;
+            uint256 startTimestamp;
+            uint256 oldTime;
+        }
+        struct CenterPriceShift {
+            uint256 shiftPercentage;
+            uint256 duration;
+            uint256 startTimestamp;
+        }
+        struct ShiftChanges {
+            bool isRangeChangeActive;
+            bool isThresholdChangeActive;
+            bool isCenterPriceShiftActive;
+            ShiftData rangeShift;
+            ShiftData thresholdShift;
+            CenterPriceShift centerPriceShift;
+        }
+        struct DexState {
+            uint256 lastToLastStoredPrice;
+            uint256 lastStoredPrice;
+            uint256 centerPrice;
+            uint256 lastUpdateTimestamp;
+            uint256 lastPricesTimeDiff;
+            uint256 oracleCheckPoint;
+            uint256 oracleMapping;
+            uint256 totalSupplyShares;
+            uint256 totalBorrowShares;
+            bool isSwapAndArbitragePaused;
+            ShiftChanges shifts;
+            uint256 token0PerSupplyShare;
+            uint256 token1PerSupplyShare;
+            uint256 token0PerBorrowShare;
+            uint256 token1PerBorrowShare;
+        }
+        struct Configs {
+            bool isSmartCollateralEnabled;
+            bool isSmartDebtEnabled;
+            uint256 fee;
+            uint256 revenueCut;
+            uint256 upperRange;
+            uint256 lowerRange;
+            uint256 upperShiftThreshold;
+            uint256 lowerShiftThreshold;
+            uint256 shiftingTime;
+            address centerPriceAddress;
+            address hookAddress;
+            uint256 maxCenterPrice;
+            uint256 minCenterPrice;
+            uint256 utilizationLimitToken0;
+            uint256 utilizationLimitToken1;
+            uint256 maxSupplyShares;
+            uint256 maxBorrowShares;
+        }
+        struct ConstantViews2 {
+            uint256 token0NumeratorPrecision;
+            uint256 token0DenominatorPrecision;
+            uint256 token1NumeratorPrecision;
+            uint256 token1DenominatorPrecision;
+        }
+
+        function getDexTokens(address dex_)
+            external
+            view
+            returns (address token0_, address token1_);
+        function getDexConstantsView2(address dex_) external view returns (ConstantViews2 memory);
+        function getDexCollateralReserves(address dex_)
+            external
+            view
+            returns (CollateralReserves memory);
+        function getDexDebtReserves(address dex_) external view returns (DebtReserves memory);
+        function getDexState(address dex_) external view returns (DexState memory);
+        function getDexConfigs(address dex_) external view returns (Configs memory);
+    }
+);
+
+sol!(
+    interface IFluidDexT1 {
+        event Swap(bool swap0to1, uint256 amountIn, uint256 amountOut, address to);
+        event LogDepositPerfectColLiquidity(uint256 shares, uint256 token0Amt, uint256 token1Amt);
+        event LogWithdrawPerfectColLiquidity(uint256 shares, uint256 token0Amt, uint256 token1Amt);
+        event LogBorrowPerfectDebtLiquidity(uint256 shares, uint256 token0Amt, uint256 token1Amt);
+        event LogPaybackPerfectDebtLiquidity(uint256 shares, uint256 token0Amt, uint256 token1Amt);
+        event LogDepositColLiquidity(uint256 amount0, uint256 amount1, uint256 shares);
+        event LogWithdrawColLiquidity(uint256 amount0, uint256 amount1, uint256 shares);
+        event LogBorrowDebtLiquidity(uint256 amount0, uint256 amount1, uint256 shares);
+        event LogPaybackDebtLiquidity(uint256 amount0, uint256 amount1, uint256 shares);
+        event LogWithdrawColInOneToken(uint256 shares, uint256 token0Amt, uint256 token1Amt);
+        event LogPaybackDebtInOneToken(uint256 shares, uint256 token0Amt, uint256 token1Amt);
+    }
+);
+
+/// Reserves of one side of a Fluid DEX, in the dex's 12 decimal units. Real
+/// reserves are what can be withdrawn or borrowed, imaginary reserves are the
+/// constant product curve the side trades on around the center price
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct FluidDexReserves {
+    pub token0_real:      U256,
+    pub token1_real:      U256,
+    pub token0_imaginary: U256,
+    pub token1_imaginary: U256,
+}
+
+impl FluidDexReserves {
+    fn apply_swap(&mut self, swap0to1: bool, amount_in: U256, amount_out: U256) {
+        let (real_in, imaginary_in, real_out, imaginary_out) = if swap0to1 {
+            (
+                &mut self.token0_real,
+                &mut self.token0_imaginary,
+                &mut self.token1_real,
+                &mut self.token1_imaginary,
+            )
+        } else {
+            (
+                &mut self.token1_real,
+                &mut self.token1_imaginary,
+                &mut self.token0_real,
+                &mut self.token0_imaginary,
+            )
+        };
+
+        *real_in = real_in.saturating_add(amount_in);
+        *imaginary_in = imaginary_in.saturating_add(amount_in);
+        *real_out = real_out.saturating_sub(amount_out);
+        *imaginary_out = imaginary_out.saturating_sub(amount_out);
+    }
+
+    fn deposit(&mut self, amount0: U256, amount1: U256) {
+        let before = self.value();
+        self.token0_real = self.token0_real.saturating_add(amount0);
+        self.token1_real = self.token1_real.saturating_add(amount1);
+        self.scale_imaginary(before, before.saturating_add(self.value_of(amount0, amount1)));
+    }
+
+    fn withdraw(&mut self, amount0: U256, amount1: U256) {
+        let before = self.value();
+        self.token0_real = self.token0_real.saturating_sub(amount0);
+        self.token1_real = self.token1_real.saturating_sub(amount1);
+        self.scale_imaginary(before, before.saturating_sub(self.value_of(amount0, amount1)));
+    }
+
+    fn value(&self) -> U256 {
+        self.value_of(self.token0_real, self.token1_real)
+    }
+
+    /// Value of the amounts at the side's price, in token1 times the token0
+    /// imaginary reserve
+    fn value_of(&self, amount0: U256, amount1: U256) -> U256 {
+        amount0
+            .saturating_mul(self.token1_imaginary)
+            .saturating_add(amount1.saturating_mul(self.token0_imaginary))
+    }
+
+    /// The dex rebuilds the imaginary reserves from the real ones around the
+    /// current price, so both grow and shrink with the side's liquidity
+    fn scale_imaginary(&mut self, before: U256, after: U256) {
+        if before.is_zero() {
+            return
+        }
+        self.token0_imaginary = self.token0_imaginary.saturating_mul(after) / before;
+        self.token1_imaginary = self.token1_imaginary.saturating_mul(after) / before;
+    }
+}
+
+/// A Fluid DEX (T1) pool. Liquidity comes from smart collateral, which
+/// trades like a regular pool, and smart debt, where swaps pay back one
+/// token's debt and borrow the other. Either side can be disabled. Both
+/// trade around the same center price, so the spot price is that of their
+/// combined imaginary reserves.
+///
+/// Deposits and borrows add to a side's liquidity at its current price.
+/// Imbalanced ones are partly swapped by the dex, which isn't followed here
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct FluidDexPool {
+    pub address: Address,
+    pub token0: Address,
+    pub token1: Address,
+    /// Token amounts are scaled by `numerator / denominator` into the dex's
+    /// 12 decimal units
+    pub token0_numerator_precision: U256,
+    pub token0_denominator_precision: U256,
+    pub token1_numerator_precision: U256,
+    pub token1_denominator_precision: U256,
+    pub collateral: FluidDexReserves,
+    pub debt: FluidDexReserves,
+    /// A range, threshold or center price shift was in progress when the
+    /// pool was loaded, or the center price comes from an external contract.
+    /// The imaginary reserves then move without a log, so the first update
+    /// drops them instead of pricing from stale ones. Shifts the dex starts
+    /// on its own after the pool is loaded aren't caught
+    pub center_price_shifting: bool,
+}
+
+#[async_trait]
+impl UpdatableProtocol for FluidDexPool {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn sync_from_action(&mut self, _action: Action) -> Result<(), AmmError> {
+        Err(AmmError::SyncError(self.address))
+    }
+
+    fn sync_from_log(&mut self, log: Log) -> Result<(), AmmError> {
+        let event_signature = log.topics()[0];
+
+        if self.center_price_shifting {
+            self.collateral = FluidDexReserves::default();
+            self.debt = FluidDexReserves::default();
+            return Err(AmmError::SyncError(self.address))
+        }
+
+        if event_signature == IFluidDexT1::Swap::SIGNATURE_HASH {
+            let swap = IFluidDexT1::Swap::decode_log_data(&log, false)?;
+            self.apply_swap(swap.swap0to1, swap.amountIn, swap.amountOut);
+        } else if event_signature == IFluidDexT1::LogDepositPerfectColLiquidity::SIGNATURE_HASH {
+            let deposit = IFluidDexT1::LogDepositPerfectColLiquidity::decode_log_data(&log, false)?;
+            let (amount0, amount1) = self.adjust(deposit.token0Amt, deposit.token1Amt);
+            self.collateral.deposit(amount0, amount1);
+        } else if event_signature == IFluidDexT1::LogDepositColLiquidity::SIGNATURE_HASH {
+            let deposit = IFluidDexT1::LogDepositColLiquidity::decode_log_data(&log, false)?;
+            let (amount0, amount1) = self.adjust(deposit.amount0, deposit.amount1);
+            self.collateral.deposit(amount0, amount1);
+        } else if event_signature == IFluidDexT1::LogWithdrawPerfectColLiquidity::SIGNATURE_HASH {
+            let withdraw =
+                IFluidDexT1::LogWithdrawPerfectColLiquidity::decode_log_data(&log, false)?;
+            let (amount0, amount1) = self.adjust(withdraw.token0Amt, withdraw.token1Amt);
+            self.collateral.withdraw(amount0, amount1);
+        } else if event_signature == IFluidDexT1::LogWithdrawColLiquidity::SIGNATURE_HASH {
+            let withdraw = IFluidDexT1::LogWithdrawColLiquidity::decode_log_data(&log, false)?;
+            let (amount0, amount1) = self.adjust(withdraw.amount0, withdraw.amount1);
+            self.collateral.withdraw(amount0, amount1);
+        } else if event_signature == IFluidDexT1::LogWithdrawColInOneToken::SIGNATURE_HASH {
+            let withdraw = IFluidDexT1::LogWithdrawColInOneToken::decode_log_data(&log, false)?;
+            let (amount0, amount1) = self.adjust(withdraw.token0Amt, withdraw.token1Amt);
+            self.collateral.withdraw(amount0, amount1);
+        } else if event_signature == IFluidDexT1::LogBorrowPerfectDebtLiquidity::SIGNATURE_HASH {
+            let borrow = IFluidDexT1::LogBorrowPerfectDebtLiquidity::decode_log_data(&log, false)?;
+            let (amount0, amount1) = self.adjust(borrow.token0Amt, borrow.token1Amt);
+            self.debt.deposit(amount0, amount1);
+        } else if event_signature == IFluidDexT1::LogBorrowDebtLiquidity::SIGNATURE_HASH {
+            let borrow = IFluidDexT1::LogBorrowDebtLiquidity::decode_log_data(&log, false)?;
+            let (amount0, amount1) = self.adjust(borrow.amount0, borrow.amount1);
+            self.debt.deposit(amount0, amount1);
+        } else if event_signature == IFluidDexT1::LogPaybackPerfectDebtLiquidity::SIGNATURE_HASH {
+            let payback =
+                IFluidDexT1::LogPaybackPerfectDebtLiquidity::decode_log_data(&log, false)?;
+            let (amount0, amount1) = self.adjust(payback.token0Amt, payback.token1Amt);
+            self.debt.withdraw(amount0, amount1);
+        } else if event_signature == IFluidDexT1::LogPaybackDebtLiquidity::SIGNATURE_HASH {
+            let payback = IFluidDexT1::LogPaybackDebtLiquidity::decode_log_data(&log, false)?;
+            let (amount0, amount1) = self.adjust(payback.amount0, payback.amount1);
+            self.debt.withdraw(amount0, amount1);
+        } else if event_signature == IFluidDexT1::LogPaybackDebtInOneToken::SIGNATURE_HASH {
+            let payback = IFluidDexT1::LogPaybackDebtInOneToken::decode_log_data(&log, false)?;
+            let (amount0, amount1) = self.adjust(payback.token0Amt, payback.token1Amt);
+            self.debt.withdraw(amount0, amount1);
+        } else {
+            return Err(AmmError::EventLogError(EventLogError::InvalidEventSignature))
+        }
+
+        Ok(())
+    }
+
+    fn calculate_price(
+        &self,
+        base_token: Address,
+        _quote_token: Address,
+    ) -> Result<Rational, ArithmeticError> {
+        let token0 = self.collateral.token0_imaginary + self.debt.token0_imaginary;
+        let token1 = self.collateral.token1_imaginary + self.debt.token1_imaginary;
+        if token0.is_zero() || token1.is_zero() {
+            return Err(ArithmeticError::FluidDexDivZero)
+        }
+
+        // both tokens are in 12 decimals
+        let price = token1.to_scaled_rational(0) / token0.to_scaled_rational(0);
+
+        if base_token == self.token0 {
+            Ok(price)
+        } else if base_token == self.token1 {
+            Ok(Rational::ONE / price)
+        } else {
+            Err(ArithmeticError::FluidDexUnknownToken(base_token))
+        }
+    }
+
+    fn tokens(&self) -> Vec<Address> {
+        vec![self.token0, self.token1]
+    }
+}
+
+impl FluidDexPool {
+    pub async fn new_load_on_block<M: TracingProvider>(
+        address: Address,
+        middleware: Arc<M>,
+        block: u64,
+    ) -> Result<Self, AmmError> {
+        let block = Some(block);
+
+        let (tokens, precisions, collateral, debt, state, configs) = futures::try_join!(
+            make_call_request(
+                IFluidDexResolver::getDexTokensCall::new((address,)),
+                &middleware,
+                FLUID_DEX_RESOLVER_ADDRESS,
+                block
+            ),
+            make_call_request(
+                IFluidDexResolver::getDexConstantsView2Call::new((address,)),
+                &middleware,
+                FLUID_DEX_RESOLVER_ADDRESS,
+                block
+            ),
+            make_call_request(
+                IFluidDexResolver::getDexCollateralReservesCall::new((address,)),
+                &middleware,
+                FLUID_DEX_RESOLVER_ADDRESS,
+                block
+            ),
+            make_call_request(
+                IFluidDexResolver::getDexDebtReservesCall::new((address,)),
+                &middleware,
+                FLUID_DEX_RESOLVER_ADDRESS,
+                block
+            ),
+            make_call_request(
+                IFluidDexResolver::getDexStateCall::new((address,)),
+                &middleware,
+                FLUID_DEX_RESOLVER_ADDRESS,
+                block
+            ),
+            make_call_request(
+                IFluidDexResolver::getDexConfigsCall::new((address,)),
+                &middleware,
+                FLUID_DEX_RESOLVER_ADDRESS,
+                block
+            ),
+        )?;
+        let (precisions, collateral, debt) = (precisions._0, collateral._0, debt._0);
+        let shifts = state._0.shifts;
+
+        let pool = FluidDexPool {
+            address,
+            token0: tokens.token0_,
+            token1: tokens.token1_,
+            token0_numerator_precision: precisions.token0NumeratorPrecision,
+            token0_denominator_precision: precisions.token0DenominatorPrecision,
+            token1_numerator_precision: precisions.token1NumeratorPrecision,
+            token1_denominator_precision: precisions.token1DenominatorPrecision,
+            collateral: FluidDexReserves {
+                token0_real:      collateral.token0RealReserves,
+                token1_real:      collateral.token1RealReserves,
+                token0_imaginary: collateral.token0ImaginaryReserves,
+                token1_imaginary: collateral.token1ImaginaryReserves,
+            },
+            debt: FluidDexReserves {
+                token0_real:      debt.token0RealReserves,
+                token1_real:      debt.token1RealReserves,
+                token0_imaginary: debt.token0ImaginaryReserves,
+                token1_imaginary: debt.token1ImaginaryReserves,
+            },
+            center_price_shifting: shifts.isRangeChangeActive
+                || shifts.isThresholdChangeActive
+                || shifts.isCenterPriceShiftActive
+                || !configs._0.centerPriceAddress.is_zero(),
+        };
+
+        if !pool.data_is_populated() {
+            return Err(AmmError::NoStateError(address))
+        }
+
+        Ok(pool)
+    }
+
+    pub fn data_is_populated(&self) -> bool {
+        let imaginary = |reserves: &FluidDexReserves| {
+            !(reserves.token0_imaginary.is_zero() || reserves.token1_imaginary.is_zero())
+        };
+
+        !(self.token0.is_zero()
+            || self.token1.is_zero()
+            || self.token0_denominator_precision.is_zero()
+            || self.token1_denominator_precision.is_zero())
+            && (imaginary(&self.collateral) || imaginary(&self.debt))
+    }
+
+    /// Liquidity a swap can draw on, the real reserves of both sides
+    pub fn get_tvl(&self, base: Address, quote: Address) -> (Rational, Rational) {
+        let reserve = |token: Address| {
+            if token == self.token0 {
+                (self.collateral.token0_real + self.debt.token0_real).to_scaled_rational(12)
+            } else if token == self.token1 {
+                (self.collateral.token1_real + self.debt.token1_real).to_scaled_rational(12)
+            } else {
+                Rational::ZERO
+            }
+        };
+
+        (reserve(base), reserve(quote))
+    }
+
+    /// The dex splits a swap across both sides so they end on the same price.
+    /// Two constant product curves at the same price stay level when the swap
+    /// is split in proportion to their reserves
+    fn apply_swap(&mut self, swap0to1: bool, amount_in: U256, amount_out: U256) {
+        let (amount_in, amount_out) = if swap0to1 {
+            (self.adjust_token0(amount_in), self.adjust_token1(amount_out))
+        } else {
+            (self.adjust_token1(amount_in), self.adjust_token0(amount_out))
+        };
+        let reserve_in = |reserves: &FluidDexReserves| {
+            if swap0to1 {
+                reserves.token0_imaginary
+            } else {
+                reserves.token1_imaginary
+            }
+        };
+        let reserve_out = |reserves: &FluidDexReserves| {
+            if swap0to1 {
+                reserves.token1_imaginary
+            } else {
+                reserves.token0_imaginary
+            }
+        };
+
+        let (collateral_in, debt_in) =
+            split(amount_in, reserve_in(&self.collateral), reserve_in(&self.debt));
+        let (collateral_out, debt_out) =
+            split(amount_out, reserve_out(&self.collateral), reserve_out(&self.debt));
+
+        self.collateral
+            .apply_swap(swap0to1, collateral_in, collateral_out);
+        self.debt.apply_swap(swap0to1, debt_in, debt_out);
+    }
+
+    fn adjust(&self, amount0: U256, amount1: U256) -> (U256, U256) {
+        (self.adjust_token0(amount0), self.adjust_token1(amount1))
+    }
+
+    fn adjust_token0(&self, amount: U256) -> U256 {
+        amount.saturating_mul(self.token0_numerator_precision) / self.token0_denominator_precision
+    }
+
+    fn adjust_token1(&self, amount: U256) -> U256 {
+        amount.saturating_mul(self.token1_numerator_precision) / self.token1_denominator_precision
+    }
+}
+
+/// Splits an amount in proportion to the collateral and debt reserves
+fn split(amount: U256, collateral: U256, debt: U256) -> (U256, U256) {
+    let total = collateral + debt;
+    if total.is_zero() {
+        return (amount, U256::ZERO)
+    }
+    let collateral = amount.saturating_mul(collateral) / total;
+
+    (collateral, amount - collateral)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{address, LogData};
+
+    use super::*;
+
+    fn reserves(token0: u64, token1: u64) -> FluidDexReserves {
+        let (token0, token1) = (U256::from(token0), U256::from(token1));
+        FluidDexReserves {
+            token0_real:      token0,
+            token1_real:      token1,
+            token0_imaginary: token0 * U256::from(4),
+            token1_imaginary: token1 * U256::from(4),
+        }
+    }
+
+    fn pool() -> FluidDexPool {
+        FluidDexPool {
+            address: Address::ZERO,
+            token0: address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"),
+            token1: address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"),
+            // 18 and 6 decimals into 12
+            token0_numerator_precision: U256::from(1),
+            token0_denominator_precision: U256::from(1_000_000),
+            token1_numerator_precision: U256::from(1_000_000),
+            token1_denominator_precision: U256::from(1),
+            collateral: reserves(1_000_000_000_000, 2_000_000_000_000_000),
+            debt: reserves(3_000_000_000_000, 6_000_000_000_000_000),
+            center_price_shifting: false,
+        }
+    }
+
+    #[test]
+    fn test_price_of_combined_sides() {
+        let pool = pool();
+
+        assert_eq!(pool.calculate_price(pool.token0, pool.token1).unwrap(), Rational::from(2000));
+        assert_eq!(
+            pool.calculate_price(pool.token1, pool.token0).unwrap(),
+            Rational::ONE / Rational::from(2000)
+        );
+    }
+
+    #[test]
+    fn test_swap_keeps_sides_level() {
+        let mut pool = pool();
+
+        // 0.1 of token0 in for 190 of token1 out
+        pool.apply_swap(true, U256::from(100_000_000_000_000_000u64), U256::from(190_000_000u64));
+
+        let price = |reserves: &FluidDexReserves| {
+            reserves.token1_imaginary.to_scaled_rational(0)
+                / reserves.token0_imaginary.to_scaled_rational(0)
+        };
+        assert_eq!(price(&pool.collateral), price(&pool.debt));
+        assert!(pool.calculate_price(pool.token0, pool.token1).unwrap() < Rational::from(2000));
+    }
+
+    fn log(pool: &FluidDexPool, data: LogData) -> Log {
+        Log { address: pool.address, data }
+    }
+
+    #[test]
+    fn test_deposit_and_borrow_keep_the_price() {
+        let mut pool = pool();
+
+        // 1 token0 and 1000 token1 into collateral
+        let deposit = IFluidDexT1::LogDepositColLiquidity {
+            amount0: U256::from(10u64.pow(18)),
+            amount1: U256::from(1_000_000_000u64),
+            shares:  U256::ZERO,
+        };
+        pool.sync_from_log(log(&pool, deposit.encode_log_data()))
+            .unwrap();
+
+        assert_eq!(pool.collateral.token0_real, U256::from(2_000_000_000_000u64));
+        assert_eq!(pool.collateral.token1_real, U256::from(3_000_000_000_000_000u64));
+        // 4000 token1 worth of real reserves grew by 3000
+        assert_eq!(pool.collateral.token0_imaginary, U256::from(7_000_000_000_000u64));
+        assert_eq!(pool.collateral.token1_imaginary, U256::from(14_000_000_000_000_000u64));
+
+        let borrow = IFluidDexT1::LogBorrowPerfectDebtLiquidity {
+            shares:    U256::ZERO,
+            token0Amt: U256::from(3 * 10u64.pow(18)),
+            token1Amt: U256::from(6_000_000_000u64),
+        };
+        pool.sync_from_log(log(&pool, borrow.encode_log_data()))
+            .unwrap();
+
+        assert_eq!(pool.debt, reserves(6_000_000_000_000, 12_000_000_000_000_000));
+        assert_eq!(pool.calculate_price(pool.token0, pool.token1).unwrap(), Rational::from(2000));
+    }
+
+    #[test]
+    fn test_withdraw_and_payback_in_one_token() {
+        let mut pool = pool();
+
+        let withdraw = IFluidDexT1::LogWithdrawColInOneToken {
+            shares:    U256::ZERO,
+            token0Amt: U256::ZERO,
+            token1Amt: U256::from(1_000_000_000u64),
+        };
+        pool.sync_from_log(log(&pool, withdraw.encode_log_data()))
+            .unwrap();
+
+        assert_eq!(pool.collateral.token1_real, U256::from(1_000_000_000_000_000u64));
+        assert_eq!(pool.collateral.token0_imaginary, U256::from(3_000_000_000_000u64));
+
+        let payback = IFluidDexT1::LogPaybackPerfectDebtLiquidity {
+            shares:    U256::ZERO,
+            token0Amt: U256::from(3 * 10u64.pow(18)),
+            token1Amt: U256::from(6_000_000_000u64),
+        };
+        pool.sync_from_log(log(&pool, payback.encode_log_data()))
+            .unwrap();
+
+        assert_eq!(pool.debt, FluidDexReserves::default());
+        assert_eq!(pool.calculate_price(pool.token0, pool.token1).unwrap(), Rational::from(2000));
+    }
+
+    #[test]
+    fn test_shifting_center_price_drops_the_reserves() {
+        let mut pool = FluidDexPool { center_price_shifting: true, ..pool() };
+        let swap = IFluidDexT1::Swap {
+            swap0to1:  true,
+            amountIn:  U256::from(10u64.pow(17)),
+            amountOut: U256::from(190_000_000u64),
+            to:        Address::ZERO,
+        };
+
+        assert!(pool
+            .sync_from_log(log(&pool, swap.encode_log_data()))
+            .is_err());
+        assert!(matches!(
+            pool.calculate_price(pool.token0, pool.token1),
+            Err(ArithmeticError::FluidDexDivZero)
+        ));
+        assert_eq!(pool.get_tvl(pool.token0, pool.token1), (Rational::ZERO, Rational::ZERO));
+    }
+}
@@ -1,6 +1,6 @@
 use std::{collections::hash_map::Entry, pin::Pin, sync::Arc, task::Poll};
 
-use alloy_primitives::Address;
+use alloy_primitives::{Address, B256};
 use brontes_metrics::pricing::DexPricingMetrics;
 use brontes_types::{
     pair::Pair, traits::TracingProvider, unzip_either::IterExt, BrontesTaskExecutor, FastHashMap,
@@ -70,6 +70,9 @@ pub struct LazyExchangeLoader<T: TracingProvider> {
     /// requests we are processing for a given block.
     req_per_block:     FastHashMap<BlockNumber, u64>,
     state_tracking:    LoadingStateTracker,
+    /// uniswap v4 pools are addressed by the first 20 bytes of their id. the
+    /// full id is needed to read their state from the pool manager
+    v4_pool_ids:       FastHashMap<Address, B256>,
     ex:                BrontesTaskExecutor,
 }
 
@@ -81,10 +84,16 @@ impl<T: TracingProvider> LazyExchangeLoader<T> {
             pool_load_futures: MultiBlockPoolFutures::new(),
             provider,
             req_per_block: FastHashMap::default(),
+            v4_pool_ids: FastHashMap::default(),
             ex,
         }
     }
 
+    pub fn add_v4_pool_id(&mut self, pool_id: B256) {
+        self.v4_pool_ids
+            .insert(Address::from_slice(&pool_id[..20]), pool_id);
+    }
+
     pub fn is_loading(&self, k: &Address) -> bool {
         self.pool_buf.contains_key(k)
     }
@@ -165,7 +174,8 @@ impl<T: TracingProvider> LazyExchangeLoader<T> {
         let provider = self.provider.clone();
         self.add_state_trackers(block_number, id, address, pair);
 
-        let fut = ex_type.try_load_state(address, provider, block_number, pool_pair, pair);
+        let pool_id = self.v4_pool_ids.get(&address).copied();
+        let fut = ex_type.try_load_state(address, pool_id, provider, block_number, pool_pair, pair);
         self.pool_load_futures.add_future(
             block_number,
             Box::pin(self.ex.handle().spawn(async move {
@@ -0,0 +1,260 @@
+use std::sync::Arc;
+
+use alloy_primitives::{Address, Log, B256, U256};
+use alloy_sol_macro::sol;
+use alloy_sol_types::SolEvent;
+use async_trait::async_trait;
+use brontes_types::{normalized_actions::Action, traits::TracingProvider, ToScaledRational};
+use malachite::{
+    num::{
+        arithmetic::traits::{Pow, Reciprocal},
+        basic::traits::Zero,
+    },
+    Rational,
+};
+use serde::{Deserialize, Serialize};
+
+use super::make_call_request;
+use crate::{
+    errors::{AmmError, ArithmeticError, EventLogError},
+    Protocol, UpdatableProtocol,
+};
+
+sol!(
+    interface ILBPair {
+        function getTokenX() external view returns (address);
+        function getTokenY() external view returns (address);
+        function getBinStep() external view returns (uint16);
+        function getActiveId() external view returns (uint24);
+        function getReserves() external view returns (uint128 reserveX, uint128 reserveY);
+
+        event Swap(
+            address indexed sender,
+            address indexed to,
+            uint24 id,
+            bytes32 amountsIn,
+            bytes32 amountsOut,
+            uint24 volatilityAccumulator,
+            bytes32 totalFees,
+            bytes32 protocolFees
+        );
+        event DepositedToBins(
+            address indexed sender,
+            address indexed to,
+            uint256[] ids,
+            bytes32[] amounts
+        );
+        event WithdrawnFromBins(
+            address indexed sender,
+            address indexed to,
+            uint256[] ids,
+            bytes32[] amounts
+        );
+        event CollectedProtocolFees(address indexed feeRecipient, bytes32 protocolFees);
+    }
+);
+
+sol!(
+    interface ILBToken {
+        function decimals() external view returns (uint8);
+    }
+);
+
+/// Bin id of a price of one
+const REAL_ID_SHIFT: i32 = 1 << 23;
+const BASIS_POINT_MAX: u64 = 10_000;
+
+/// A Liquidity Book pair (LFJ V2.1 and V2.2). Liquidity sits in discrete bins
+/// that each trade at a constant price, so the spot price is the price of the
+/// active bin. The pair emits a swap log for every bin it crosses, which keeps
+/// the active bin current
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct LFJPool {
+    pub address:          Address,
+    pub protocol:         Protocol,
+    pub token_x:          Address,
+    pub token_x_decimals: u8,
+    pub token_y:          Address,
+    pub token_y_decimals: u8,
+    /// Price increment between bins, in basis points
+    pub bin_step:         u16,
+    pub active_id:        u32,
+    /// Reserves across all bins, excluding protocol fees
+    pub reserve_x:        U256,
+    pub reserve_y:        U256,
+}
+
+#[async_trait]
+impl UpdatableProtocol for LFJPool {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn sync_from_action(&mut self, _action: Action) -> Result<(), AmmError> {
+        Err(AmmError::SyncError(self.address))
+    }
+
+    fn sync_from_log(&mut self, log: Log) -> Result<(), AmmError> {
+        let event_signature = log.topics()[0];
+
+        if event_signature == ILBPair::Swap::SIGNATURE_HASH {
+            let swap = ILBPair::Swap::decode_log_data(&log, false)?;
+            let (in_x, in_y) = decode_amounts(swap.amountsIn);
+            let (out_x, out_y) = decode_amounts(swap.amountsOut);
+            // the lp share of the fee stays in the bin, the protocol's is set aside
+            let (fee_x, fee_y) = decode_amounts(swap.protocolFees);
+
+            self.reserve_x = self
+                .reserve_x
+                .saturating_add(in_x)
+                .saturating_sub(fee_x)
+                .saturating_sub(out_x);
+            self.reserve_y = self
+                .reserve_y
+                .saturating_add(in_y)
+                .saturating_sub(fee_y)
+                .saturating_sub(out_y);
+            self.active_id = swap.id;
+        } else if event_signature == ILBPair::DepositedToBins::SIGNATURE_HASH {
+            let deposit = ILBPair::DepositedToBins::decode_log_data(&log, false)?;
+            for (x, y) in deposit.amounts.into_iter().map(decode_amounts) {
+                self.reserve_x += x;
+                self.reserve_y += y;
+            }
+        } else if event_signature == ILBPair::WithdrawnFromBins::SIGNATURE_HASH {
+            let withdraw = ILBPair::WithdrawnFromBins::decode_log_data(&log, false)?;
+            for (x, y) in withdraw.amounts.into_iter().map(decode_amounts) {
+                self.reserve_x = self.reserve_x.saturating_sub(x);
+                self.reserve_y = self.reserve_y.saturating_sub(y);
+            }
+        } else if event_signature == ILBPair::CollectedProtocolFees::SIGNATURE_HASH {
+            // protocol fees were never part of the reserves
+        } else {
+            return Err(AmmError::EventLogError(EventLogError::InvalidEventSignature))
+        }
+
+        Ok(())
+    }
+
+    fn calculate_price(
+        &self,
+        base_token: Address,
+        _quote_token: Address,
+    ) -> Result<Rational, ArithmeticError> {
+        let shift = self.token_x_decimals as i64 - self.token_y_decimals as i64;
+        let price = bin_price(self.active_id, self.bin_step) * Rational::from(10).pow(shift);
+
+        if base_token == self.token_x {
+            Ok(price)
+        } else if base_token == self.token_y {
+            Ok(price.reciprocal())
+        } else {
+            Err(ArithmeticError::LFJUnknownToken(base_token))
+        }
+    }
+
+    fn tokens(&self) -> Vec<Address> {
+        vec![self.token_x, self.token_y]
+    }
+}
+
+impl LFJPool {
+    pub async fn new_load_on_block<M: TracingProvider>(
+        address: Address,
+        protocol: Protocol,
+        middleware: Arc<M>,
+        block: u64,
+    ) -> Result<Self, AmmError> {
+        let block = Some(block);
+
+        let (token_x, token_y, bin_step, active_id, reserves) = futures::try_join!(
+            make_call_request(ILBPair::getTokenXCall::new(()), &middleware, address, block),
+            make_call_request(ILBPair::getTokenYCall::new(()), &middleware, address, block),
+            make_call_request(ILBPair::getBinStepCall::new(()), &middleware, address, block),
+            make_call_request(ILBPair::getActiveIdCall::new(()), &middleware, address, block),
+            make_call_request(ILBPair::getReservesCall::new(()), &middleware, address, block),
+        )?;
+        let (token_x, token_y) = (token_x._0, token_y._0);
+
+        let (token_x_decimals, token_y_decimals) = futures::try_join!(
+            make_call_request(ILBToken::decimalsCall::new(()), &middleware, token_x, block),
+            make_call_request(ILBToken::decimalsCall::new(()), &middleware, token_y, block),
+        )?;
+
+        let pool = LFJPool {
+            address,
+            protocol,
+            token_x,
+            token_x_decimals: token_x_decimals._0,
+            token_y,
+            token_y_decimals: token_y_decimals._0,
+            bin_step: bin_step._0,
+            active_id: active_id._0,
+            reserve_x: U256::from(reserves.reserveX),
+            reserve_y: U256::from(reserves.reserveY),
+        };
+
+        if !pool.data_is_populated() {
+            return Err(AmmError::NoStateError(address))
+        }
+
+        Ok(pool)
+    }
+
+    pub fn data_is_populated(&self) -> bool {
+        !(self.token_x.is_zero()
+            || self.token_y.is_zero()
+            || self.bin_step == 0
+            || (self.reserve_x.is_zero() && self.reserve_y.is_zero()))
+    }
+
+    pub fn get_tvl(&self, base: Address, quote: Address) -> (Rational, Rational) {
+        let reserve = |token: Address| {
+            if token == self.token_x {
+                self.reserve_x.to_scaled_rational(self.token_x_decimals)
+            } else if token == self.token_y {
+                self.reserve_y.to_scaled_rational(self.token_y_decimals)
+            } else {
+                Rational::ZERO
+            }
+        };
+
+        (reserve(base), reserve(quote))
+    }
+}
+
+/// Bin amounts are packed as `y << 128 | x`
+fn decode_amounts(amounts: B256) -> (U256, U256) {
+    let amounts = U256::from_be_bytes(amounts.0);
+    (amounts & U256::from(u128::MAX), amounts >> 128)
+}
+
+/// Price of token x in token y of a bin, in raw token units
+fn bin_price(id: u32, bin_step: u16) -> Rational {
+    Rational::from_unsigneds(BASIS_POINT_MAX + bin_step as u64, BASIS_POINT_MAX)
+        .pow(id as i64 - REAL_ID_SHIFT as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bin_price() {
+        assert_eq!(bin_price(REAL_ID_SHIFT as u32, 25), Rational::from(1));
+        assert_eq!(
+            bin_price(REAL_ID_SHIFT as u32 + 1, 25),
+            Rational::from_unsigneds(401u64, 400u64)
+        );
+        assert_eq!(
+            bin_price(REAL_ID_SHIFT as u32 - 2, 100),
+            Rational::from_unsigneds(10_000u64, 10_201u64)
+        );
+    }
+
+    #[test]
+    fn test_decode_amounts() {
+        let packed = B256::from((U256::from(7) << 128) | U256::from(3));
+        assert_eq!(decode_amounts(packed), (U256::from(3), U256::from(7)));
+    }
+}
@@ -0,0 +1,584 @@
+mod tick_math;
+
+use std::sync::Arc;
+
+use alloy_primitives::{Address, Log, U256};
+use alloy_sol_macro::sol;
+use alloy_sol_types::SolEvent;
+use async_trait::async_trait;
+use brontes_types::{
+    normalized_actions::Action, traits::TracingProvider, FastHashMap, ToScaledRational,
+};
+use malachite::{num::basic::traits::Zero, Rational};
+use serde::{Deserialize, Serialize};
+
+use super::make_call_request;
+use crate::{
+    errors::{AmmError, ArithmeticError, EventLogError},
+    UpdatableProtocol,
+};
+
+sol!(
+    interface IMaverickV2Pool {
+        struct State {
+            uint128 reserveA;
+            uint128 reserveB;
+            int64 lastTwaD8;
+            int64 lastLogPriceD8;
+            uint40 lastTimestamp;
+            int32 activeTick;
+            bool isLocked;
+            uint32 binCounter;
+            uint8 protocolFeeRatioD3;
+        }
+        struct TickState {
+            uint128 reserveA;
+            uint128 reserveB;
+            uint128 totalSupply;
+            uint32[4] binIdsByTick;
+        }
+        struct SwapParams {
+            uint256 amount;
+            bool tokenAIn;
+            bool exactOutput;
+            int32 tickLimit;
+        }
+        struct AddLiquidityParams {
+            uint8 kind;
+            int32[] ticks;
+            uint128[] amounts;
+        }
+        struct RemoveLiquidityParams {
+            uint32[] binIds;
+            uint128[] amounts;
+        }
+
+        function tokenA() external view returns (address);
+        function tokenB() external view returns (address);
+        function tokenAScale() external view returns (uint256);
+        function tokenBScale() external view returns (uint256);
+        function tickSpacing() external view returns (uint256);
+        function getState() external view returns (State memory);
+        function getTick(int32 tick) external view returns (TickState memory);
+
+        event PoolSwap(
+            address sender,
+            address recipient,
+            SwapParams params,
+            uint256 amountIn,
+            uint256 amountOut
+        );
+        event PoolAddLiquidity(
+            address sender,
+            address recipient,
+            uint256 subaccount,
+            AddLiquidityParams params,
+            uint256 tokenAAmount,
+            uint256 tokenBAmount,
+            uint32[] binIds
+        );
+        event PoolRemoveLiquidity(
+            address sender,
+            address recipient,
+            uint256 subaccount,
+            RemoveLiquidityParams params,
+            uint256 tokenAOut,
+            uint256 tokenBOut
+        );
+    }
+);
+
+/// Ticks loaded on each side of the active tick
+const TICK_WINDOW: i32 = 16;
+
+/// Reserves of a tick, scaled to 18 decimals
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MaverickV2Tick {
+    pub reserve_a: u128,
+    pub reserve_b: u128,
+}
+
+impl MaverickV2Tick {
+    fn is_empty(&self) -> bool {
+        self.reserve_a == 0 && self.reserve_b == 0
+    }
+}
+
+/// A Maverick V2 pool. Bins of every kind add their liquidity to ticks, and
+/// the spot price is that of the active tick's reserves within its price
+/// range. Swap logs don't include the tick a swap ends on, so swaps are walked
+/// across the ticks around the active one
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MaverickV2Pool {
+    pub address:       Address,
+    pub token_a:       Address,
+    pub token_b:       Address,
+    /// Amounts are scaled by `1e18 / scale` into the pool's units
+    pub token_a_scale: U256,
+    pub token_b_scale: U256,
+    pub tick_spacing:  u32,
+    pub active_tick:   i32,
+    /// Reserves across all ticks, scaled to 18 decimals
+    pub reserve_a:     u128,
+    pub reserve_b:     u128,
+    pub ticks:         FastHashMap<i32, MaverickV2Tick>,
+}
+
+#[async_trait]
+impl UpdatableProtocol for MaverickV2Pool {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn sync_from_action(&mut self, _action: Action) -> Result<(), AmmError> {
+        Err(AmmError::SyncError(self.address))
+    }
+
+    fn sync_from_log(&mut self, log: Log) -> Result<(), AmmError> {
+        let event_signature = log.topics()[0];
+
+        if event_signature == IMaverickV2Pool::PoolSwap::SIGNATURE_HASH {
+            let swap = IMaverickV2Pool::PoolSwap::decode_log_data(&log, false)?;
+            if let Err(e) = self.apply_swap(swap.params.tokenAIn, swap.amountIn, swap.amountOut) {
+                // the swap ended on a tick that was never loaded. Drop the ticks so the pool
+                // stops pricing instead of holding the price from before the swap
+                self.ticks.clear();
+                return Err(e)
+            }
+        } else if event_signature == IMaverickV2Pool::PoolAddLiquidity::SIGNATURE_HASH {
+            let add = IMaverickV2Pool::PoolAddLiquidity::decode_log_data(&log, false)?;
+            let amount_a = scale(add.tokenAAmount, self.token_a_scale);
+            let amount_b = scale(add.tokenBAmount, self.token_b_scale);
+            self.add_to_ticks(&add.params.ticks, amount_a, amount_b);
+            self.reserve_a = self.reserve_a.saturating_add(amount_a);
+            self.reserve_b = self.reserve_b.saturating_add(amount_b);
+        } else if event_signature == IMaverickV2Pool::PoolRemoveLiquidity::SIGNATURE_HASH {
+            let remove = IMaverickV2Pool::PoolRemoveLiquidity::decode_log_data(&log, false)?;
+            self.reserve_a = self
+                .reserve_a
+                .saturating_sub(scale(remove.tokenAOut, self.token_a_scale));
+            self.reserve_b = self
+                .reserve_b
+                .saturating_sub(scale(remove.tokenBOut, self.token_b_scale));
+        } else {
+            return Err(AmmError::EventLogError(EventLogError::InvalidEventSignature))
+        }
+
+        Ok(())
+    }
+
+    fn calculate_price(
+        &self,
+        base_token: Address,
+        _quote_token: Address,
+    ) -> Result<Rational, ArithmeticError> {
+        let tick = self
+            .ticks
+            .get(&self.active_tick)
+            .ok_or(ArithmeticError::MaverickV2TickNotLoaded(self.active_tick))?;
+        if tick.is_empty() {
+            return Err(ArithmeticError::MaverickV2EmptyTick(self.active_tick))
+        }
+
+        let sqrt_price = tick_math::sqrt_price(
+            tick.reserve_a as f64,
+            tick.reserve_b as f64,
+            tick_math::tick_sqrt_price(self.tick_spacing, self.active_tick),
+            tick_math::tick_sqrt_price(self.tick_spacing, self.active_tick + 1),
+        );
+        // both tokens are in 18 decimals
+        let price = sqrt_price * sqrt_price;
+
+        let price = if base_token == self.token_a {
+            price
+        } else if base_token == self.token_b {
+            1.0 / price
+        } else {
+            return Err(ArithmeticError::MaverickV2UnknownToken(base_token))
+        };
+
+        Rational::try_from(price)
+            .map_err(|_| ArithmeticError::MaverickV2PriceOverflow(self.active_tick))
+    }
+
+    fn tokens(&self) -> Vec<Address> {
+        vec![self.token_a, self.token_b]
+    }
+}
+
+impl MaverickV2Pool {
+    pub async fn new_load_on_block<M: TracingProvider>(
+        address: Address,
+        middleware: Arc<M>,
+        block: u64,
+    ) -> Result<Self, AmmError> {
+        let block = Some(block);
+
+        let (state, token_a, token_b) = futures::try_join!(
+            make_call_request(IMaverickV2Pool::getStateCall::new(()), &middleware, address, block),
+            make_call_request(IMaverickV2Pool::tokenACall::new(()), &middleware, address, block),
+            make_call_request(IMaverickV2Pool::tokenBCall::new(()), &middleware, address, block),
+        )?;
+        let (token_a_scale, token_b_scale, tick_spacing) = futures::try_join!(
+            make_call_request(
+                IMaverickV2Pool::tokenAScaleCall::new(()),
+                &middleware,
+                address,
+                block
+            ),
+            make_call_request(
+                IMaverickV2Pool::tokenBScaleCall::new(()),
+                &middleware,
+                address,
+                block
+            ),
+            make_call_request(
+                IMaverickV2Pool::tickSpacingCall::new(()),
+                &middleware,
+                address,
+                block
+            ),
+        )?;
+        let state = state._0;
+
+        let tick_range = state.activeTick - TICK_WINDOW..=state.activeTick + TICK_WINDOW;
+        let ticks = futures::future::try_join_all(tick_range.clone().map(|tick| {
+            make_call_request(
+                IMaverickV2Pool::getTickCall::new((tick,)),
+                &middleware,
+                address,
+                block,
+            )
+        }))
+        .await?;
+
+        let pool = MaverickV2Pool {
+            address,
+            token_a: token_a._0,
+            token_b: token_b._0,
+            token_a_scale: token_a_scale._0,
+            token_b_scale: token_b_scale._0,
+            tick_spacing: tick_spacing._0.saturating_to(),
+            active_tick: state.activeTick,
+            reserve_a: state.reserveA,
+            reserve_b: state.reserveB,
+            ticks: tick_range
+                .zip(ticks)
+                .map(|(tick, state)| {
+                    (
+                        tick,
+                        MaverickV2Tick {
+                            reserve_a: state._0.reserveA,
+                            reserve_b: state._0.reserveB,
+                        },
+                    )
+                })
+                .collect(),
+        };
+
+        if !pool.data_is_populated() {
+            return Err(AmmError::NoStateError(address))
+        }
+
+        Ok(pool)
+    }
+
+    pub fn data_is_populated(&self) -> bool {
+        !(self.token_a.is_zero()
+            || self.token_b.is_zero()
+            || self.token_a_scale.is_zero()
+            || self.token_b_scale.is_zero()
+            || self.tick_spacing == 0
+            || self
+                .ticks
+                .get(&self.active_tick)
+                .map_or(true, MaverickV2Tick::is_empty))
+    }
+
+    pub fn get_tvl(&self, base: Address, quote: Address) -> (Rational, Rational) {
+        let reserve = |token: Address| {
+            if token == self.token_a {
+                U256::from(self.reserve_a).to_scaled_rational(18)
+            } else if token == self.token_b {
+                U256::from(self.reserve_b).to_scaled_rational(18)
+            } else {
+                Rational::ZERO
+            }
+        };
+
+        (reserve(base), reserve(quote))
+    }
+
+    /// The split of a deposit across its ticks isn't logged. Ticks above the
+    /// active one only hold token a and those below only token b, so each
+    /// token is spread evenly over the deposit's ticks on its side. The active
+    /// tick takes as much as it can in proportion to its reserves, so its
+    /// price holds. Ticks outside the loaded window are skipped
+    fn add_to_ticks(&mut self, ticks: &[i32], amount_a: u128, amount_b: u128) {
+        let mut ticks = ticks.to_vec();
+        ticks.sort_unstable();
+        ticks.dedup();
+
+        let above = ticks.iter().filter(|t| **t > self.active_tick).count() as u128;
+        let below = ticks.iter().filter(|t| **t < self.active_tick).count() as u128;
+        let (mut rest_a, mut rest_b) = (amount_a, amount_b);
+
+        if ticks.contains(&self.active_tick) {
+            let share_a = amount_a / (above + 1);
+            let share_b = amount_b / (below + 1);
+            if let Some(active) = self.ticks.get_mut(&self.active_tick) {
+                let (a, b) = proportional_deposit(active, share_a, share_b);
+                active.reserve_a = active.reserve_a.saturating_add(a);
+                active.reserve_b = active.reserve_b.saturating_add(b);
+                rest_a -= a;
+                rest_b -= b;
+            }
+        }
+
+        for tick in ticks {
+            let Some(state) = self.ticks.get_mut(&tick) else { continue };
+            if tick > self.active_tick {
+                state.reserve_a = state.reserve_a.saturating_add(rest_a / above);
+            } else if tick < self.active_tick {
+                state.reserve_b = state.reserve_b.saturating_add(rest_b / below);
+            }
+        }
+    }
+
+    /// Drains the output token from the active tick and the ticks past it
+    /// until the swap's output is filled. A swap that runs past the loaded
+    /// ticks leaves the pool untouched
+    fn apply_swap(
+        &mut self,
+        token_a_in: bool,
+        amount_in: U256,
+        amount_out: U256,
+    ) -> Result<(), AmmError> {
+        let (amount_in, amount_out) = if token_a_in {
+            (scale(amount_in, self.token_a_scale), scale(amount_out, self.token_b_scale))
+        } else {
+            (scale(amount_in, self.token_b_scale), scale(amount_out, self.token_a_scale))
+        };
+
+        let mut tick = self.active_tick;
+        let mut remaining = amount_out as f64;
+        let mut crossed = Vec::new();
+        loop {
+            let state = self
+                .ticks
+                .get(&tick)
+                .ok_or(AmmError::SyncError(self.address))?;
+            let (a, b) = (state.reserve_a as f64, state.reserve_b as f64);
+            let lower = tick_math::tick_sqrt_price(self.tick_spacing, tick);
+            let upper = tick_math::tick_sqrt_price(self.tick_spacing, tick + 1);
+            let l = tick_math::tick_liquidity(a, b, lower, upper);
+            let out = if token_a_in { b } else { a };
+
+            if remaining <= out {
+                if l > 0.0 {
+                    let (a, b) = if token_a_in {
+                        (tick_math::reserve_a(b - remaining, l, lower, upper), b - remaining)
+                    } else {
+                        (a - remaining, tick_math::reserve_b(a - remaining, l, lower, upper))
+                    };
+                    crossed.push((tick, a, b));
+                }
+                break
+            }
+
+            // a drained tick holds its whole range in the input token
+            remaining -= out;
+            if token_a_in {
+                crossed.push((tick, l / lower - l / upper, 0.0));
+                tick -= 1;
+            } else {
+                crossed.push((tick, 0.0, l * (upper - lower)));
+                tick += 1;
+            }
+        }
+
+        for (tick, a, b) in crossed {
+            self.ticks
+                .insert(tick, MaverickV2Tick { reserve_a: a as u128, reserve_b: b as u128 });
+        }
+        self.active_tick = tick;
+
+        if token_a_in {
+            self.reserve_a = self.reserve_a.saturating_add(amount_in);
+            self.reserve_b = self.reserve_b.saturating_sub(amount_out);
+        } else {
+            self.reserve_b = self.reserve_b.saturating_add(amount_in);
+            self.reserve_a = self.reserve_a.saturating_sub(amount_out);
+        }
+
+        Ok(())
+    }
+}
+
+/// The largest part of `a` and `b` that can be added to `tick` without moving
+/// its price
+fn proportional_deposit(tick: &MaverickV2Tick, a: u128, b: u128) -> (u128, u128) {
+    if tick.reserve_a == 0 {
+        return (0, b)
+    } else if tick.reserve_b == 0 {
+        return (a, 0)
+    }
+
+    let (a, b) = (U256::from(a), U256::from(b));
+    let (reserve_a, reserve_b) = (U256::from(tick.reserve_a), U256::from(tick.reserve_b));
+    if a * reserve_b <= b * reserve_a {
+        (a.saturating_to(), (a * reserve_b / reserve_a).saturating_to())
+    } else {
+        ((b * reserve_a / reserve_b).saturating_to(), b.saturating_to())
+    }
+}
+
+/// Token amount in the pool's 18 decimal units
+fn scale(amount: U256, token_scale: U256) -> u128 {
+    if token_scale.is_zero() {
+        return 0
+    }
+
+    (amount.saturating_mul(U256::from(10).pow(U256::from(18))) / token_scale).saturating_to()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::address;
+
+    use super::*;
+
+    fn pool() -> MaverickV2Pool {
+        let one = U256::from(10).pow(U256::from(18));
+        MaverickV2Pool {
+            address:       Address::ZERO,
+            token_a:       address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"),
+            token_b:       address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"),
+            token_a_scale: one,
+            token_b_scale: one,
+            tick_spacing:  10,
+            active_tick:   0,
+            reserve_a:     30e18 as u128,
+            reserve_b:     30e18 as u128,
+            ticks:         [
+                (-1, MaverickV2Tick { reserve_a: 0, reserve_b: 10e18 as u128 }),
+                (0, MaverickV2Tick { reserve_a: 10e18 as u128, reserve_b: 10e18 as u128 }),
+                (1, MaverickV2Tick { reserve_a: 10e18 as u128, reserve_b: 0 }),
+            ]
+            .into_iter()
+            .collect(),
+        }
+    }
+
+    #[test]
+    fn test_swap_within_tick() {
+        let mut pool = pool();
+        let before = pool.calculate_price(pool.token_a, pool.token_b).unwrap();
+
+        pool.apply_swap(
+            true,
+            U256::from(10).pow(U256::from(18)),
+            U256::from(10).pow(U256::from(18)),
+        )
+        .unwrap();
+
+        assert_eq!(pool.active_tick, 0);
+        assert!(pool.calculate_price(pool.token_a, pool.token_b).unwrap() < before);
+    }
+
+    #[test]
+    fn test_swap_crosses_into_next_tick() {
+        let mut pool = pool();
+
+        pool.apply_swap(true, U256::from(15e18 as u128), U256::from(15e18 as u128))
+            .unwrap();
+
+        assert_eq!(pool.active_tick, -1);
+        assert_eq!(pool.ticks[&0].reserve_b, 0);
+        assert_eq!(pool.ticks[&-1].reserve_b, 5e18 as u128);
+    }
+
+    #[test]
+    fn test_swap_past_loaded_ticks_is_rejected() {
+        let mut pool = pool();
+
+        assert!(pool
+            .apply_swap(true, U256::from(25e18 as u128), U256::from(25e18 as u128))
+            .is_err());
+        assert_eq!(pool, self::pool());
+    }
+
+    #[test]
+    fn test_swap_past_loaded_ticks_drops_the_ticks() {
+        let mut pool = pool();
+        let swap = IMaverickV2Pool::PoolSwap {
+            sender:    Address::ZERO,
+            recipient: Address::ZERO,
+            params:    IMaverickV2Pool::SwapParams {
+                amount:      U256::from(25e18 as u128),
+                tokenAIn:    true,
+                exactOutput: false,
+                tickLimit:   i32::MIN,
+            },
+            amountIn:  U256::from(25e18 as u128),
+            amountOut: U256::from(25e18 as u128),
+        };
+
+        assert!(pool
+            .sync_from_log(Log { address: pool.address, data: swap.encode_log_data() })
+            .is_err());
+        assert!(pool.ticks.is_empty());
+        assert!(matches!(
+            pool.calculate_price(pool.token_a, pool.token_b),
+            Err(ArithmeticError::MaverickV2TickNotLoaded(0))
+        ));
+    }
+
+    fn add_liquidity(ticks: Vec<i32>, amount_a: u128, amount_b: u128) -> Log {
+        let add = IMaverickV2Pool::PoolAddLiquidity {
+            sender:       Address::ZERO,
+            recipient:    Address::ZERO,
+            subaccount:   U256::ZERO,
+            params:       IMaverickV2Pool::AddLiquidityParams {
+                kind: 0,
+                amounts: vec![0; ticks.len()],
+                ticks,
+            },
+            tokenAAmount: U256::from(amount_a),
+            tokenBAmount: U256::from(amount_b),
+            binIds:       vec![],
+        };
+
+        Log { address: Address::ZERO, data: add.encode_log_data() }
+    }
+
+    #[test]
+    fn test_add_liquidity_to_active_tick_keeps_its_ratio() {
+        let mut pool = pool();
+
+        pool.sync_from_log(add_liquidity(vec![0], 5e18 as u128, 5e18 as u128))
+            .unwrap();
+
+        assert_eq!(
+            pool.ticks[&0],
+            MaverickV2Tick { reserve_a: 15e18 as u128, reserve_b: 15e18 as u128 }
+        );
+        assert_eq!((pool.reserve_a, pool.reserve_b), (35e18 as u128, 35e18 as u128));
+    }
+
+    #[test]
+    fn test_add_liquidity_across_ticks_splits_by_side() {
+        let mut pool = pool();
+
+        pool.sync_from_log(add_liquidity(vec![-1, 0, 1], 6e18 as u128, 4e18 as u128))
+            .unwrap();
+
+        // each side's share is capped to the active tick's 1:1 ratio
+        assert_eq!(
+            pool.ticks[&0],
+            MaverickV2Tick { reserve_a: 12e18 as u128, reserve_b: 12e18 as u128 }
+        );
+        assert_eq!(pool.ticks[&1].reserve_a, 14e18 as u128);
+        assert_eq!(pool.ticks[&-1].reserve_b, 12e18 as u128);
+    }
+}
@@ -0,0 +1,66 @@
+//! A Maverick V2 tick trades on a constant product curve between its lower
+//! and upper price, with virtual reserves `a + L / sqrt_upper` and
+//! `b + L * sqrt_lower`. Prices are of token a in token b.
+
+/// sqrt price at the lower edge of a tick
+pub fn tick_sqrt_price(tick_spacing: u32, tick: i32) -> f64 {
+    1.0001_f64.powf(tick as f64 * tick_spacing as f64 / 2.0)
+}
+
+/// Liquidity of a tick holding `a` and `b`, the `L` that solves
+/// `(a + L / sqrt_upper) * (b + L * sqrt_lower) = L^2`
+pub fn tick_liquidity(a: f64, b: f64, sqrt_lower: f64, sqrt_upper: f64) -> f64 {
+    let k = 1.0 - sqrt_lower / sqrt_upper;
+    let m = a * sqrt_lower + b / sqrt_upper;
+
+    (m + (m * m + 4.0 * k * a * b).sqrt()) / (2.0 * k)
+}
+
+pub fn sqrt_price(a: f64, b: f64, sqrt_lower: f64, sqrt_upper: f64) -> f64 {
+    if a == 0.0 {
+        return sqrt_upper
+    } else if b == 0.0 {
+        return sqrt_lower
+    }
+    let l = tick_liquidity(a, b, sqrt_lower, sqrt_upper);
+
+    ((b + l * sqrt_lower) / (a + l / sqrt_upper))
+        .sqrt()
+        .clamp(sqrt_lower, sqrt_upper)
+}
+
+/// Amount of token a a tick of liquidity `l` holds alongside `b`
+pub fn reserve_a(b: f64, l: f64, sqrt_lower: f64, sqrt_upper: f64) -> f64 {
+    (l * l / (b + l * sqrt_lower) - l / sqrt_upper).max(0.0)
+}
+
+/// Amount of token b a tick of liquidity `l` holds alongside `a`
+pub fn reserve_b(a: f64, l: f64, sqrt_lower: f64, sqrt_upper: f64) -> f64 {
+    (l * l / (a + l / sqrt_upper) - l * sqrt_lower).max(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_one_sided_ticks_price_at_their_edge() {
+        let (lower, upper) = (tick_sqrt_price(10, 5), tick_sqrt_price(10, 6));
+
+        assert_eq!(sqrt_price(0.0, 1e18, lower, upper), upper);
+        assert_eq!(sqrt_price(1e18, 0.0, lower, upper), lower);
+    }
+
+    #[test]
+    fn test_reserves_stay_on_the_curve() {
+        let (lower, upper) = (tick_sqrt_price(1, 0), tick_sqrt_price(1, 1));
+        let (a, b) = (3e18, 2e18);
+        let l = tick_liquidity(a, b, lower, upper);
+
+        assert!((reserve_a(b, l, lower, upper) - a).abs() / a < 1e-9);
+        assert!((reserve_b(a, l, lower, upper) - b).abs() / b < 1e-9);
+        // a drained tick holds the full range of the other token
+        let full = l * (1.0 / lower - 1.0 / upper);
+        assert!((reserve_a(0.0, l, lower, upper) - full).abs() / full < 1e-9);
+    }
+}
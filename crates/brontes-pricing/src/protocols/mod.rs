@@ -1,11 +1,18 @@
+pub mod balancer;
+pub mod curve;
 pub mod erc20;
+pub mod erc4626;
 pub mod errors;
+pub mod fluid_dex;
 pub mod lazy;
+pub mod lfj;
+pub mod maverick_v2;
 pub mod uniswap_v2;
 pub mod uniswap_v3;
+pub mod uniswap_v4;
 use std::{future::Future, sync::Arc};
 
-use alloy_primitives::{Address, Log};
+use alloy_primitives::{Address, Log, B256};
 use async_trait::async_trait;
 use brontes_types::{normalized_actions::Action, pair::Pair, traits::TracingProvider};
 pub use brontes_types::{queries::make_call_request, Protocol};
@@ -13,11 +20,18 @@ use malachite::Rational;
 use tracing::{debug, warn};
 
 use crate::{
+    balancer::BalancerPool,
+    curve::{CurveCryptoSwapPool, CurveStableSwapPool},
+    erc4626::ERC4626Vault,
+    fluid_dex::FluidDexPool,
     lazy::{PoolFetchError, PoolFetchSuccess},
+    lfj::LFJPool,
+    maverick_v2::MaverickV2Pool,
     protocols::errors::{AmmError, ArithmeticError},
     types::PairWithFirstPoolHop,
     uniswap_v2::UniswapV2Pool,
     uniswap_v3::UniswapV3Pool,
+    uniswap_v4::UniswapV4Pool,
     LoadResult, PoolState,
 };
 
@@ -25,16 +39,23 @@ use crate::{
 pub trait UpdatableProtocol {
     fn address(&self) -> Address;
     fn tokens(&self) -> Vec<Address>;
-    fn calculate_price(&self, base_token: Address) -> Result<Rational, ArithmeticError>;
+    fn calculate_price(
+        &self,
+        base_token: Address,
+        quote_token: Address,
+    ) -> Result<Rational, ArithmeticError>;
     fn sync_from_action(&mut self, action: Action) -> Result<(), AmmError>;
     fn sync_from_log(&mut self, log: Log) -> Result<(), AmmError>;
 }
 
 pub trait LoadState {
     fn has_state_updater(&self) -> bool;
+    /// `pool_id` is the full id of pools that live in a singleton and are only
+    /// known by a truncated address
     fn try_load_state<T: TracingProvider>(
         self,
         address: Address,
+        pool_id: Option<B256>,
         provider: Arc<T>,
         block_number: u64,
         pool_pair: Pair,
@@ -53,12 +74,32 @@ impl LoadState for Protocol {
                 | Self::PancakeSwapV2
                 | Self::PancakeSwapV3
                 | Self::CamelotV2
+                | Self::CamelotV3
+                | Self::UniswapV4
+                | Self::ERC4626
+                | Self::CurveBasePool2
+                | Self::CurveBasePool3
+                | Self::CurveBasePool4
+                | Self::CurveV1MetaPool
+                | Self::CurveV2MetaPool
+                | Self::CurveV2PlainPool
+                | Self::CurvecrvUSDMetaPool
+                | Self::CurvecrvUSDPlainPool
+                | Self::CurveCryptoSwapPool
+                | Self::CurveTriCryptoPool
+                | Self::BalancerV2
+                | Self::BalancerV3
+                | Self::LFJV2_1
+                | Self::LFJV2_2
+                | Self::MaverickV2
+                | Self::FluidDEX
         )
     }
 
     async fn try_load_state<T: TracingProvider>(
         self,
         address: Address,
+        pool_id: Option<B256>,
         provider: Arc<T>,
         block_number: u64,
         pool_pair: Pair,
@@ -121,6 +162,303 @@ impl LoadState for Protocol {
                     res,
                 ))
             }
+            Self::CamelotV3 => {
+                let (pool, res) = if let Ok(pool) = UniswapV3Pool::new_from_algebra_address(
+                    address,
+                    block_number - 1,
+                    provider.clone(),
+                )
+                .await
+                {
+                    (pool, LoadResult::Ok)
+                } else {
+                    (
+                        UniswapV3Pool::new_from_algebra_address(address, block_number, provider)
+                            .await
+                            .map_err(|e| {
+                                debug!(?pool_pair, protocol=%self, %block_number, pool_address=?address, err=%e, "lazy load failed");
+                                (address, self, block_number, pool_pair, fp, e)
+                            })?,
+                        LoadResult::PoolInitOnBlock,
+                    )
+                };
+
+                Ok((
+                    block_number,
+                    address,
+                    PoolState::new(
+                        crate::types::PoolVariants::UniswapV3(Box::new(pool)),
+                        block_number,
+                    ),
+                    res,
+                ))
+            }
+            Self::UniswapV4 => {
+                let Some(pool_id) = pool_id else {
+                    debug!(?pool_pair, %block_number, pool_address=?address, "unknown uniswap v4 pool id");
+                    return Err((
+                        address,
+                        self,
+                        block_number,
+                        pool_pair,
+                        fp,
+                        AmmError::NoStateError(address),
+                    ))
+                };
+
+                let (pool, res) = if let Ok(pool) = UniswapV4Pool::new_load_on_block(
+                    pool_id,
+                    pool_pair,
+                    provider.clone(),
+                    block_number - 1,
+                )
+                .await
+                {
+                    (pool, LoadResult::Ok)
+                } else {
+                    (
+                        UniswapV4Pool::new_load_on_block(pool_id, pool_pair, provider, block_number)
+                            .await
+                            .map_err(|e| {
+                                debug!(?pool_pair, protocol=%self, %block_number, pool_address=?address, err=%e, "lazy load failed");
+                                (address, self, block_number, pool_pair, fp, e)
+                            })?,
+                        LoadResult::PoolInitOnBlock,
+                    )
+                };
+
+                Ok((
+                    block_number,
+                    address,
+                    PoolState::new(
+                        crate::types::PoolVariants::UniswapV4(Box::new(pool)),
+                        block_number,
+                    ),
+                    res,
+                ))
+            }
+            Self::ERC4626 => {
+                let (vault, res) = if let Ok(vault) =
+                    ERC4626Vault::new_load_on_block(address, provider.clone(), block_number - 1)
+                        .await
+                {
+                    (vault, LoadResult::Ok)
+                } else {
+                    (
+                        ERC4626Vault::new_load_on_block(address, provider, block_number)
+                            .await
+                            .map_err(|e| {
+                                debug!(?pool_pair, protocol=%self, %block_number, pool_address=?address, err=%e, "lazy load failed");
+                                (address, Protocol::ERC4626, block_number, pool_pair, fp, e)
+                            })?,
+                        LoadResult::PoolInitOnBlock,
+                    )
+                };
+
+                Ok((
+                    block_number,
+                    address,
+                    PoolState::new(
+                        crate::types::PoolVariants::ERC4626(Box::new(vault)),
+                        block_number,
+                    ),
+                    res,
+                ))
+            }
+            Self::CurveBasePool2
+            | Self::CurveBasePool3
+            | Self::CurveBasePool4
+            | Self::CurveV1MetaPool
+            | Self::CurveV2MetaPool
+            | Self::CurveV2PlainPool
+            | Self::CurvecrvUSDMetaPool
+            | Self::CurvecrvUSDPlainPool => {
+                let (pool, res) = if let Ok(pool) = CurveStableSwapPool::new_load_on_block(
+                    address,
+                    self,
+                    provider.clone(),
+                    block_number - 1,
+                )
+                .await
+                {
+                    (pool, LoadResult::Ok)
+                } else {
+                    (
+                        CurveStableSwapPool::new_load_on_block(
+                            address,
+                            self,
+                            provider,
+                            block_number,
+                        )
+                        .await
+                        .map_err(|e| {
+                            debug!(?pool_pair, protocol=%self, %block_number, pool_address=?address, err=%e, "lazy load failed");
+                            (address, self, block_number, pool_pair, fp, e)
+                        })?,
+                        LoadResult::PoolInitOnBlock,
+                    )
+                };
+
+                Ok((
+                    block_number,
+                    address,
+                    PoolState::new(
+                        crate::types::PoolVariants::CurveStableSwap(Box::new(pool)),
+                        block_number,
+                    ),
+                    res,
+                ))
+            }
+            Self::CurveCryptoSwapPool | Self::CurveTriCryptoPool => {
+                let (pool, res) = if let Ok(pool) = CurveCryptoSwapPool::new_load_on_block(
+                    address,
+                    self,
+                    provider.clone(),
+                    block_number - 1,
+                )
+                .await
+                {
+                    (pool, LoadResult::Ok)
+                } else {
+                    (
+                        CurveCryptoSwapPool::new_load_on_block(
+                            address,
+                            self,
+                            provider,
+                            block_number,
+                        )
+                        .await
+                        .map_err(|e| {
+                            debug!(?pool_pair, protocol=%self, %block_number, pool_address=?address, err=%e, "lazy load failed");
+                            (address, self, block_number, pool_pair, fp, e)
+                        })?,
+                        LoadResult::PoolInitOnBlock,
+                    )
+                };
+
+                Ok((
+                    block_number,
+                    address,
+                    PoolState::new(
+                        crate::types::PoolVariants::CurveCryptoSwap(Box::new(pool)),
+                        block_number,
+                    ),
+                    res,
+                ))
+            }
+            Self::BalancerV2 | Self::BalancerV3 => {
+                let (pool, res) = if let Ok(pool) = BalancerPool::new_load_on_block(
+                    address,
+                    self,
+                    provider.clone(),
+                    block_number - 1,
+                )
+                .await
+                {
+                    (pool, LoadResult::Ok)
+                } else {
+                    (
+                        BalancerPool::new_load_on_block(address, self, provider, block_number)
+                            .await
+                            .map_err(|e| {
+                                debug!(?pool_pair, protocol=%self, %block_number, pool_address=?address, err=%e, "lazy load failed");
+                                (address, self, block_number, pool_pair, fp, e)
+                            })?,
+                        LoadResult::PoolInitOnBlock,
+                    )
+                };
+
+                Ok((
+                    block_number,
+                    address,
+                    PoolState::new(
+                        crate::types::PoolVariants::Balancer(Box::new(pool)),
+                        block_number,
+                    ),
+                    res,
+                ))
+            }
+            Self::LFJV2_1 | Self::LFJV2_2 => {
+                let (pool, res) = if let Ok(pool) =
+                    LFJPool::new_load_on_block(address, self, provider.clone(), block_number - 1)
+                        .await
+                {
+                    (pool, LoadResult::Ok)
+                } else {
+                    (
+                        LFJPool::new_load_on_block(address, self, provider, block_number)
+                            .await
+                            .map_err(|e| {
+                                debug!(?pool_pair, protocol=%self, %block_number, pool_address=?address, err=%e, "lazy load failed");
+                                (address, self, block_number, pool_pair, fp, e)
+                            })?,
+                        LoadResult::PoolInitOnBlock,
+                    )
+                };
+
+                Ok((
+                    block_number,
+                    address,
+                    PoolState::new(crate::types::PoolVariants::LFJ(Box::new(pool)), block_number),
+                    res,
+                ))
+            }
+            Self::MaverickV2 => {
+                let (pool, res) = if let Ok(pool) =
+                    MaverickV2Pool::new_load_on_block(address, provider.clone(), block_number - 1)
+                        .await
+                {
+                    (pool, LoadResult::Ok)
+                } else {
+                    (
+                        MaverickV2Pool::new_load_on_block(address, provider, block_number)
+                            .await
+                            .map_err(|e| {
+                                debug!(?pool_pair, protocol=%self, %block_number, pool_address=?address, err=%e, "lazy load failed");
+                                (address, self, block_number, pool_pair, fp, e)
+                            })?,
+                        LoadResult::PoolInitOnBlock,
+                    )
+                };
+
+                Ok((
+                    block_number,
+                    address,
+                    PoolState::new(
+                        crate::types::PoolVariants::MaverickV2(Box::new(pool)),
+                        block_number,
+                    ),
+                    res,
+                ))
+            }
+            Self::FluidDEX => {
+                let (pool, res) = if let Ok(pool) =
+                    FluidDexPool::new_load_on_block(address, provider.clone(), block_number - 1)
+                        .await
+                {
+                    (pool, LoadResult::Ok)
+                } else {
+                    (
+                        FluidDexPool::new_load_on_block(address, provider, block_number)
+                            .await
+                            .map_err(|e| {
+                                debug!(?pool_pair, protocol=%self, %block_number, pool_address=?address, err=%e, "lazy load failed");
+                                (address, self, block_number, pool_pair, fp, e)
+                            })?,
+                        LoadResult::PoolInitOnBlock,
+                    )
+                };
+
+                Ok((
+                    block_number,
+                    address,
+                    PoolState::new(
+                        crate::types::PoolVariants::FluidDex(Box::new(pool)),
+                        block_number,
+                    ),
+                    res,
+                ))
+            }
             rest => {
                 warn!(protocol=?rest, "no state updater is build for");
                 Err((address, self, block_number, pool_pair, fp, AmmError::UnsupportedProtocol))
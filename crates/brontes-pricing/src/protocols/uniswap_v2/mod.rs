@@ -89,7 +89,11 @@ impl UpdatableProtocol for UniswapV2Pool {
 
     //Calculates base/quote, meaning the price of base token per quote (ie.
     // exchange rate is X base per 1 quote)
-    fn calculate_price(&self, base_token: Address) -> Result<Rational, ArithmeticError> {
+    fn calculate_price(
+        &self,
+        base_token: Address,
+        _quote_token: Address,
+    ) -> Result<Rational, ArithmeticError> {
         self.calculate_price_64_x_64(base_token)
     }
 
@@ -0,0 +1,173 @@
+use std::sync::Arc;
+
+use alloy_primitives::Address;
+use alloy_sol_macro::sol;
+use brontes_types::traits::TracingProvider;
+
+use super::{batch_request::get_v3_token_data_batch_request, make_call_request, UniswapV3Pool};
+use crate::errors::AmmError;
+
+sol!(
+    interface IAlgebraPool {
+        function token0() external view returns (address);
+        function token1() external view returns (address);
+        function liquidity() external view returns (uint128);
+        function tickSpacing() external view returns (int24);
+        function globalState() external view returns (
+            uint160 price,
+            int24 tick,
+            uint16 feeZto,
+            uint16 feeOtz,
+            uint16 timepointIndex,
+            uint8 communityFeeToken0,
+            uint8 communityFeeToken1,
+            bool unlocked
+        );
+
+        event Fee(uint16 feeZto, uint16 feeOtz);
+    }
+);
+
+impl UniswapV3Pool {
+    /// Algebra pools (Camelot V3) emit the same swap, mint and burn events as
+    /// v3, but keep their price in `globalState` instead of `slot0` and set
+    /// their fee dynamically for each swap direction
+    pub async fn new_from_algebra_address<M: TracingProvider>(
+        pool_address: Address,
+        block_number: u64,
+        middleware: Arc<M>,
+    ) -> Result<Self, AmmError> {
+        let block = Some(block_number);
+        let global_state = make_call_request(
+            IAlgebraPool::globalStateCall::new(()),
+            &middleware,
+            pool_address,
+            block,
+        )
+        .await?;
+
+        let mut pool = UniswapV3Pool {
+            address: pool_address,
+            token_a: make_call_request(
+                IAlgebraPool::token0Call::new(()),
+                &middleware,
+                pool_address,
+                block,
+            )
+            .await?
+            ._0,
+            token_b: make_call_request(
+                IAlgebraPool::token1Call::new(()),
+                &middleware,
+                pool_address,
+                block,
+            )
+            .await?
+            ._0,
+            liquidity: make_call_request(
+                IAlgebraPool::liquidityCall::new(()),
+                &middleware,
+                pool_address,
+                block,
+            )
+            .await?
+            ._0,
+            tick_spacing: make_call_request(
+                IAlgebraPool::tickSpacingCall::new(()),
+                &middleware,
+                pool_address,
+                block,
+            )
+            .await?
+            ._0,
+            sqrt_price: global_state.price,
+            tick: global_state.tick,
+            fee: global_state.feeZto as u32,
+            ..Default::default()
+        };
+
+        get_v3_token_data_batch_request(&mut pool, block, middleware).await?;
+
+        if !pool.data_is_populated() {
+            return Err(AmmError::NoStateError(pool_address))
+        }
+
+        Ok(pool)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Log, I256, U256};
+    use alloy_sol_types::{SolCall, SolEvent};
+
+    use super::*;
+    use crate::{uniswap_v3::IUniswapV3Pool, UpdatableProtocol};
+
+    fn pool() -> UniswapV3Pool {
+        UniswapV3Pool {
+            address: Address::repeat_byte(0xaa),
+            liquidity: 1_000,
+            sqrt_price: U256::from(1) << 96,
+            fee: 100,
+            reserve_0: U256::from(1_000),
+            reserve_1: U256::from(1_000),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_global_state_decoding() {
+        let words = [
+            U256::from(1) << 96,
+            // tick -5, sign extended
+            U256::MAX - U256::from(4),
+            U256::from(100),
+            U256::from(500),
+            U256::from(7),
+            U256::ZERO,
+            U256::ZERO,
+            U256::from(1),
+        ];
+        let data = words
+            .iter()
+            .flat_map(|word| word.to_be_bytes::<32>())
+            .collect::<Vec<_>>();
+
+        let state = IAlgebraPool::globalStateCall::abi_decode_returns(&data, true).unwrap();
+        assert_eq!(state.price, U256::from(1) << 96);
+        assert_eq!(state.tick, -5);
+        assert_eq!((state.feeZto, state.feeOtz), (100, 500));
+    }
+
+    #[test]
+    fn test_fee_event_keeps_zero_for_one() {
+        let mut pool = pool();
+        let fee = IAlgebraPool::Fee { feeZto: 250, feeOtz: 500 };
+
+        pool.sync_from_log(Log { address: pool.address, data: fee.encode_log_data() })
+            .unwrap();
+        assert_eq!(pool.fee, 250);
+    }
+
+    #[test]
+    fn test_swap_event_moves_the_price() {
+        let mut pool = pool();
+        let swap = IUniswapV3Pool::Swap {
+            sender:       Address::ZERO,
+            recipient:    Address::ZERO,
+            amount0:      I256::try_from(100).unwrap(),
+            amount1:      I256::try_from(-90).unwrap(),
+            sqrtPriceX96: U256::from(3) << 95,
+            liquidity:    900,
+            tick:         -1_000,
+        };
+
+        pool.sync_from_log(Log { address: pool.address, data: swap.encode_log_data() })
+            .unwrap();
+        assert_eq!((pool.reserve_0, pool.reserve_1), (U256::from(1_100), U256::from(910)));
+        assert_eq!(pool.sqrt_price, U256::from(3) << 95);
+        assert_eq!((pool.liquidity, pool.tick), (900, -1_000));
+        assert_eq!(pool.fee, 100);
+    }
+}
@@ -124,6 +124,15 @@ pub async fn get_v3_pool_data_batch_request<M: TracingProvider>(
     pool.fee = return_data._0[0].fee;
     pool.tick_spacing = return_data._0[0].tickSpacing;
 
+    get_v3_token_data_batch_request(pool, block_number, middleware).await
+}
+
+/// Loads the decimals of the pool's tokens and the pool's balance of each
+pub async fn get_v3_token_data_batch_request<M: TracingProvider>(
+    pool: &mut UniswapV3Pool,
+    block_number: Option<u64>,
+    middleware: Arc<M>,
+) -> Result<(), AmmError> {
     let mut bytecode = IGetERC20DataRequest::BYTECODE.to_vec();
     getERC20DataCall::new((pool.token_a, pool.token_b, pool.address)).abi_encode_raw(&mut bytecode);
     let req = TransactionRequest {
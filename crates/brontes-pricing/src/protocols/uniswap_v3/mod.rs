@@ -1,3 +1,4 @@
+mod algebra;
 pub mod batch_request;
 pub mod uniswap_v3_math;
 use std::{cmp::Ordering, sync::Arc};
@@ -12,7 +13,7 @@ use brontes_types::{
 use malachite::Rational;
 use serde::{Deserialize, Serialize};
 
-use self::batch_request::get_v3_pool_data_batch_request;
+use self::{algebra::IAlgebraPool, batch_request::get_v3_pool_data_batch_request};
 use super::make_call_request;
 #[cfg(feature = "uni-v3-ticks")]
 use crate::uniswap_v3::batch_request::get_uniswap_v3_tick_data_batch_request;
@@ -175,6 +176,9 @@ impl UpdatableProtocol for UniswapV3Pool {
             self.sync_from_mint_log(log)?;
         } else if event_signature == SWAP_EVENT_SIGNATURE {
             self.sync_from_swap_log(log)?;
+        } else if event_signature == IAlgebraPool::Fee::SIGNATURE_HASH {
+            // algebra pools charge a fee per swap direction, zero for one is kept
+            self.fee = IAlgebraPool::Fee::decode_log_data(&log, false)?.feeZto as u32;
         } else {
             Err(EventLogError::InvalidEventSignature)?
         }
@@ -186,7 +190,11 @@ impl UpdatableProtocol for UniswapV3Pool {
         vec![self.token_a, self.token_b]
     }
 
-    fn calculate_price(&self, base_token: Address) -> Result<Rational, ArithmeticError> {
+    fn calculate_price(
+        &self,
+        base_token: Address,
+        _quote_token: Address,
+    ) -> Result<Rational, ArithmeticError> {
         if self.liquidity <= 10_000 {
             return Err(ArithmeticError::UniswapV3MathError(
                 uniswap_v3_math::error::UniswapV3MathError::LiquidityTooLow(self.liquidity),
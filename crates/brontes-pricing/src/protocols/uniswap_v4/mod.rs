@@ -0,0 +1,326 @@
+use std::sync::Arc;
+
+use alloy_primitives::{keccak256, Address, Log, B256, U256};
+use alloy_sol_macro::sol;
+use alloy_sol_types::SolEvent;
+use async_trait::async_trait;
+use brontes_types::{
+    chain::chain_config, normalized_actions::Action, pair::Pair, traits::TracingProvider,
+};
+use malachite::Rational;
+use serde::{Deserialize, Serialize};
+
+use super::make_call_request;
+use crate::{
+    errors::{AmmError, ArithmeticError, EventLogError},
+    uniswap_v3::UniswapV3Pool,
+    UpdatableProtocol,
+};
+
+sol!(
+    interface IPoolManager {
+        function extsload(bytes32[] slots) external view returns (bytes32[]);
+
+        event Swap(
+            bytes32 indexed id,
+            address indexed sender,
+            int128 amount0,
+            int128 amount1,
+            uint160 sqrtPriceX96,
+            uint128 liquidity,
+            int24 tick,
+            uint24 fee
+        );
+        event ModifyLiquidity(
+            bytes32 indexed id,
+            address indexed sender,
+            int24 tickLower,
+            int24 tickUpper,
+            int256 liquidityDelta,
+            bytes32 salt
+        );
+    }
+);
+
+sol!(
+    interface IUniswapV4Currency {
+        function decimals() external view returns (uint8);
+    }
+);
+
+/// Storage slot of the `PoolManager`'s `pools` mapping
+const POOLS_SLOT: U256 = U256::from_limbs([6, 0, 0, 0]);
+/// Offset of `liquidity` from the start of a pool's state
+const LIQUIDITY_OFFSET: U256 = U256::from_limbs([3, 0, 0, 0]);
+
+/// A Uniswap V4 pool. All pools live in the `PoolManager` singleton, so the
+/// state is read from its storage with `extsload` and kept current through
+/// the manager's events for this pool's id. V4 pools have no balance of their
+/// own, reserves are the virtual reserves of the in range liquidity
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UniswapV4Pool {
+    pub pool_id: B256,
+    /// The pool's state, keyed by the first 20 bytes of its id
+    pub pool:    UniswapV3Pool,
+}
+
+#[async_trait]
+impl UpdatableProtocol for UniswapV4Pool {
+    fn address(&self) -> Address {
+        self.pool.address
+    }
+
+    fn sync_from_action(&mut self, _action: Action) -> Result<(), AmmError> {
+        todo!("syncing from actions is currently not supported for v4")
+    }
+
+    fn sync_from_log(&mut self, log: Log) -> Result<(), AmmError> {
+        let event_signature = log.topics()[0];
+        // the manager logs for every pool
+        if log.topics().get(1) != Some(&self.pool_id) {
+            return Ok(())
+        }
+
+        if event_signature == IPoolManager::Swap::SIGNATURE_HASH {
+            let swap = IPoolManager::Swap::decode_log_data(&log, false)?;
+            self.pool.sqrt_price = swap.sqrtPriceX96;
+            self.pool.liquidity = swap.liquidity;
+            self.pool.tick = swap.tick;
+            self.pool.fee = swap.fee;
+        } else if event_signature == IPoolManager::ModifyLiquidity::SIGNATURE_HASH {
+            let modify = IPoolManager::ModifyLiquidity::decode_log_data(&log, false)?;
+            let liquidity_delta = i128::try_from(modify.liquidityDelta)
+                .map_err(|_| AmmError::SyncError(self.address()))?;
+            // ticks aren't loaded, only the active liquidity is tracked
+            if modify.tickLower <= self.pool.tick && self.pool.tick < modify.tickUpper {
+                self.pool.liquidity = self.pool.liquidity.saturating_add_signed(liquidity_delta);
+            }
+        } else {
+            return Err(AmmError::EventLogError(EventLogError::InvalidEventSignature))
+        }
+
+        self.set_virtual_reserves();
+
+        Ok(())
+    }
+
+    fn calculate_price(
+        &self,
+        base_token: Address,
+        quote_token: Address,
+    ) -> Result<Rational, ArithmeticError> {
+        self.pool.calculate_price(base_token, quote_token)
+    }
+
+    fn tokens(&self) -> Vec<Address> {
+        self.pool.tokens()
+    }
+}
+
+impl UniswapV4Pool {
+    pub async fn new_load_on_block<M: TracingProvider>(
+        pool_id: B256,
+        pool_pair: Pair,
+        middleware: Arc<M>,
+        block: u64,
+    ) -> Result<Self, AmmError> {
+        let block = Some(block);
+        let address = Address::from_slice(&pool_id[..20]);
+        let Pair(currency0, currency1) = pool_pair.ordered();
+
+        let mut key = [0u8; 64];
+        key[..32].copy_from_slice(pool_id.as_slice());
+        key[32..].copy_from_slice(&POOLS_SLOT.to_be_bytes::<32>());
+        let state_slot = keccak256(key);
+        let state = make_call_request(
+            IPoolManager::extsloadCall::new((vec![
+                state_slot,
+                B256::from(U256::from_be_bytes(state_slot.0) + LIQUIDITY_OFFSET),
+            ],)),
+            &middleware,
+            chain_config().uniswap_v4_pool_manager(),
+            block,
+        )
+        .await?
+        ._0;
+        let [slot0, liquidity] = state[..] else { return Err(AmmError::NoStateError(address)) };
+        let (sqrt_price, tick, fee) = unpack_slot0(U256::from_be_bytes(slot0.0));
+
+        let mut pool = UniswapV4Pool {
+            pool_id,
+            pool: UniswapV3Pool {
+                address,
+                token_a: currency0,
+                token_a_decimals: Self::decimals(currency0, &middleware, block).await?,
+                token_b: currency1,
+                token_b_decimals: Self::decimals(currency1, &middleware, block).await?,
+                liquidity: U256::from_be_bytes(liquidity.0).saturating_to(),
+                sqrt_price,
+                tick,
+                fee,
+                ..Default::default()
+            },
+        };
+
+        if pool.pool.sqrt_price.is_zero() {
+            return Err(AmmError::NoStateError(address))
+        }
+        pool.set_virtual_reserves();
+
+        Ok(pool)
+    }
+
+    /// The native currency is the zero address
+    async fn decimals<M: TracingProvider>(
+        currency: Address,
+        middleware: &Arc<M>,
+        block: Option<u64>,
+    ) -> Result<u8, AmmError> {
+        if currency.is_zero() {
+            return Ok(18)
+        }
+
+        Ok(make_call_request(
+            IUniswapV4Currency::decimalsCall::new(()),
+            middleware,
+            currency,
+            block,
+        )
+        .await?
+        ._0)
+    }
+
+    /// `L / sqrt(P)` of currency0 and `L * sqrt(P)` of currency1
+    fn set_virtual_reserves(&mut self) {
+        let liquidity = U256::from(self.pool.liquidity);
+        let sqrt_price = self.pool.sqrt_price;
+        if sqrt_price.is_zero() {
+            return
+        }
+
+        self.pool.reserve_0 = (liquidity << 96) / sqrt_price;
+        self.pool.reserve_1 = liquidity
+            .checked_mul(sqrt_price)
+            .map(|reserve| reserve >> 96)
+            .unwrap_or_else(|| (liquidity * (sqrt_price >> 64)) >> 32);
+    }
+
+    /// The virtual reserves of the in range liquidity. The manager holds the
+    /// tokens of every pool, so there is no balance of this pool to report, and
+    /// these overstate what a trade can take once it leaves the current range
+    pub fn get_tvl(&self, base: Address) -> (Rational, Rational) {
+        self.pool.get_tvl(base)
+    }
+}
+
+/// `slot0` packs `sqrtPriceX96 | tick | protocolFee | lpFee` from the lowest
+/// bits up
+fn unpack_slot0(slot0: U256) -> (U256, i32, u32) {
+    let sqrt_price = slot0 & ((U256::from(1) << 160) - U256::from(1));
+    // shift the 24 bit tick to the top of an i32 to sign extend it
+    let tick = (((slot0 >> 160).as_limbs()[0] as u32) << 8) as i32 >> 8;
+    let fee = (slot0 >> 208).as_limbs()[0] as u32 & 0xffffff;
+
+    (sqrt_price, tick, fee)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{address, I256};
+
+    use super::*;
+
+    const Q96: U256 = U256::from_limbs([0, 1 << 32, 0, 0]);
+
+    fn pool(tick: i32, liquidity: u128) -> UniswapV4Pool {
+        let pool_id = B256::repeat_byte(0x11);
+        let mut pool = UniswapV4Pool {
+            pool_id,
+            pool: UniswapV3Pool {
+                address: Address::from_slice(&pool_id[..20]),
+                token_a: address!("82af49447d8a07e3bd95bd0d56f35241523fbab1"),
+                token_a_decimals: 18,
+                token_b: address!("912ce59144191c1204e64559fe8253a0e49e6548"),
+                token_b_decimals: 18,
+                liquidity,
+                sqrt_price: Q96,
+                tick,
+                ..Default::default()
+            },
+        };
+        pool.set_virtual_reserves();
+
+        pool
+    }
+
+    fn modify_liquidity(pool_id: B256, tick_lower: i32, tick_upper: i32, delta: i64) -> Log {
+        let event = IPoolManager::ModifyLiquidity {
+            id:             pool_id,
+            sender:         Address::ZERO,
+            tickLower:      tick_lower,
+            tickUpper:      tick_upper,
+            liquidityDelta: I256::try_from(delta).unwrap(),
+            salt:           B256::ZERO,
+        };
+
+        Log { address: chain_config().uniswap_v4_pool_manager(), data: event.encode_log_data() }
+    }
+
+    #[test]
+    fn test_unpack_slot0() {
+        let sqrt_price = U256::from(79228162514264337593543950336u128);
+        // tick -200 as 24 bits, a protocol fee in between and a 0.3% lp fee
+        let slot0 = sqrt_price
+            | (U256::from(0xffff38u32) << 160)
+            | (U256::from(0x000fffu32) << 184)
+            | (U256::from(3000u32) << 208);
+
+        assert_eq!(unpack_slot0(slot0), (sqrt_price, -200, 3000));
+        assert_eq!(unpack_slot0(U256::from(887272u32) << 160), (U256::ZERO, 887272, 0));
+    }
+
+    #[test]
+    fn test_virtual_reserves_at_par() {
+        let pool = pool(0, 10u128.pow(18));
+
+        assert_eq!(pool.pool.reserve_0, U256::from(10u128.pow(18)));
+        assert_eq!(pool.pool.reserve_1, U256::from(10u128.pow(18)));
+    }
+
+    #[test]
+    fn test_virtual_reserves_follow_the_price() {
+        let mut pool = pool(0, 10u128.pow(18));
+        // sqrt price of 2, a price of 4
+        pool.pool.sqrt_price = Q96 * U256::from(2);
+        pool.set_virtual_reserves();
+
+        assert_eq!(pool.pool.reserve_0, U256::from(10u128.pow(18) / 2));
+        assert_eq!(pool.pool.reserve_1, U256::from(2 * 10u128.pow(18)));
+    }
+
+    #[test]
+    fn test_modify_liquidity_only_counts_the_active_range() {
+        let mut pool = pool(100, 1_000);
+        let pool_id = pool.pool_id;
+
+        pool.sync_from_log(modify_liquidity(pool_id, 0, 200, 500))
+            .unwrap();
+        assert_eq!(pool.pool.liquidity, 1_500);
+
+        // the upper tick is exclusive
+        pool.sync_from_log(modify_liquidity(pool_id, 0, 100, 500))
+            .unwrap();
+        pool.sync_from_log(modify_liquidity(pool_id, 101, 200, 500))
+            .unwrap();
+        assert_eq!(pool.pool.liquidity, 1_500);
+
+        pool.sync_from_log(modify_liquidity(pool_id, 100, 101, -1_500))
+            .unwrap();
+        assert_eq!(pool.pool.liquidity, 0);
+
+        // another pool's liquidity
+        pool.sync_from_log(modify_liquidity(B256::repeat_byte(0x22), 0, 200, 500))
+            .unwrap();
+        assert_eq!(pool.pool.liquidity, 0);
+    }
+}
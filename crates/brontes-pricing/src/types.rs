@@ -1,16 +1,25 @@
 use std::fmt::{Debug, Display};
 
-use alloy_primitives::{wrap_fixed_bytes, Address, FixedBytes, Log};
+use alloy_primitives::{wrap_fixed_bytes, Address, FixedBytes, Log, B256};
 use brontes_types::{
-    constants::WETH_ADDRESS,
+    chain::chain_config,
     normalized_actions::{pool::NormalizedPoolConfigUpdate, Action},
     pair::Pair,
 };
 use malachite::Rational;
 
 use crate::{
-    errors::ArithmeticError, uniswap_v2::UniswapV2Pool, uniswap_v3::UniswapV3Pool, LoadState,
-    Protocol, UpdatableProtocol,
+    balancer::BalancerPool,
+    curve::{CurveCryptoSwapPool, CurveStableSwapPool},
+    erc4626::ERC4626Vault,
+    errors::ArithmeticError,
+    fluid_dex::FluidDexPool,
+    lfj::LFJPool,
+    maverick_v2::MaverickV2Pool,
+    uniswap_v2::UniswapV2Pool,
+    uniswap_v3::UniswapV3Pool,
+    uniswap_v4::UniswapV4Pool,
+    LoadState, Protocol, UpdatableProtocol,
 };
 
 wrap_fixed_bytes!(extra_derives:[],
@@ -51,18 +60,20 @@ impl PairWithFirstPoolHop {
     }
 }
 
+/// Pools can hold more than two tokens, so both sides of the edge being priced
+/// are passed
 pub trait ProtocolState: Debug {
-    fn price(&self, base: Address) -> Result<Rational, ArithmeticError>;
-    fn tvl(&self, base: Address) -> (Rational, Rational);
+    fn price(&self, base: Address, quote: Address) -> Result<Rational, ArithmeticError>;
+    fn tvl(&self, base: Address, quote: Address) -> (Rational, Rational);
 }
 
 impl ProtocolState for PoolState {
-    fn tvl(&self, base: Address) -> (Rational, Rational) {
-        self.get_tvl(base)
+    fn tvl(&self, base: Address, quote: Address) -> (Rational, Rational) {
+        self.get_tvl(base, quote)
     }
 
-    fn price(&self, base: Address) -> Result<Rational, ArithmeticError> {
-        self.get_price(base)
+    fn price(&self, base: Address, quote: Address) -> Result<Rational, ArithmeticError> {
+        self.get_price(base, quote)
     }
 }
 
@@ -76,8 +87,8 @@ impl Debug for PoolState {
         f.debug_struct("Pool State")
             .field("addr", &self.address())
             .field("pair", &self.pair())
-            .field("tvl 0", &self.get_tvl(self.pair().0).0)
-            .field("tvl 1", &self.get_tvl(self.pair().0).1)
+            .field("tvl 0", &self.get_tvl(self.pair().0, self.pair().1).0)
+            .field("tvl 1", &self.get_tvl(self.pair().0, self.pair().1).1)
             .field("block", &self.last_update)
             .finish()
     }
@@ -92,6 +103,14 @@ impl PoolState {
         match &self.variant {
             PoolVariants::UniswapV2(v) => Pair(v.token_a, v.token_b),
             PoolVariants::UniswapV3(v) => Pair(v.token_a, v.token_b),
+            PoolVariants::ERC4626(v) => Pair(v.asset, v.address),
+            PoolVariants::CurveStableSwap(v) => Pair(v.coins[0], v.coins[1]),
+            PoolVariants::CurveCryptoSwap(v) => Pair(v.coins[0], v.coins[1]),
+            PoolVariants::Balancer(v) => Pair(v.tokens[0], v.tokens[1]),
+            PoolVariants::UniswapV4(v) => Pair(v.pool.token_a, v.pool.token_b),
+            PoolVariants::LFJ(v) => Pair(v.token_x, v.token_y),
+            PoolVariants::MaverickV2(v) => Pair(v.token_a, v.token_b),
+            PoolVariants::FluidDex(v) => Pair(v.token0, v.token1),
         }
     }
 
@@ -99,6 +118,14 @@ impl PoolState {
         match &self.variant {
             PoolVariants::UniswapV2(_) => Protocol::UniswapV2,
             PoolVariants::UniswapV3(_) => Protocol::UniswapV3,
+            PoolVariants::ERC4626(_) => Protocol::ERC4626,
+            PoolVariants::CurveStableSwap(v) => v.protocol,
+            PoolVariants::CurveCryptoSwap(v) => v.protocol,
+            PoolVariants::Balancer(v) => v.protocol,
+            PoolVariants::UniswapV4(_) => Protocol::UniswapV4,
+            PoolVariants::LFJ(v) => v.protocol,
+            PoolVariants::MaverickV2(_) => Protocol::MaverickV2,
+            PoolVariants::FluidDex(_) => Protocol::FluidDEX,
         }
     }
 
@@ -108,27 +135,51 @@ impl PoolState {
             return
         }
         self.last_update = state.block;
-        self.variant.increment_state(state.logs);
+        self.variant.increment_state(state.action, state.logs);
     }
 
     pub fn address(&self) -> Address {
         match &self.variant {
             PoolVariants::UniswapV2(v) => v.address(),
             PoolVariants::UniswapV3(v) => v.address(),
+            PoolVariants::ERC4626(v) => v.address(),
+            PoolVariants::CurveStableSwap(v) => v.address(),
+            PoolVariants::CurveCryptoSwap(v) => v.address(),
+            PoolVariants::Balancer(v) => v.address(),
+            PoolVariants::UniswapV4(v) => v.address(),
+            PoolVariants::LFJ(v) => v.address(),
+            PoolVariants::MaverickV2(v) => v.address(),
+            PoolVariants::FluidDex(v) => v.address(),
         }
     }
 
-    pub fn get_tvl(&self, base: Address) -> (Rational, Rational) {
+    pub fn get_tvl(&self, base: Address, quote: Address) -> (Rational, Rational) {
         match &self.variant {
             PoolVariants::UniswapV2(v) => v.get_tvl(base),
             PoolVariants::UniswapV3(v) => v.get_tvl(base),
+            PoolVariants::ERC4626(v) => v.get_tvl(base),
+            PoolVariants::CurveStableSwap(v) => v.get_tvl(base, quote),
+            PoolVariants::CurveCryptoSwap(v) => v.get_tvl(base, quote),
+            PoolVariants::Balancer(v) => v.get_tvl(base, quote),
+            PoolVariants::UniswapV4(v) => v.get_tvl(base),
+            PoolVariants::LFJ(v) => v.get_tvl(base, quote),
+            PoolVariants::MaverickV2(v) => v.get_tvl(base, quote),
+            PoolVariants::FluidDex(v) => v.get_tvl(base, quote),
         }
     }
 
-    pub fn get_price(&self, base: Address) -> Result<Rational, ArithmeticError> {
+    pub fn get_price(&self, base: Address, quote: Address) -> Result<Rational, ArithmeticError> {
         match &self.variant {
-            PoolVariants::UniswapV2(v) => v.calculate_price(base),
-            PoolVariants::UniswapV3(v) => v.calculate_price(base),
+            PoolVariants::UniswapV2(v) => v.calculate_price(base, quote),
+            PoolVariants::UniswapV3(v) => v.calculate_price(base, quote),
+            PoolVariants::ERC4626(v) => v.calculate_price(base, quote),
+            PoolVariants::CurveStableSwap(v) => v.calculate_price(base, quote),
+            PoolVariants::CurveCryptoSwap(v) => v.calculate_price(base, quote),
+            PoolVariants::Balancer(v) => v.calculate_price(base, quote),
+            PoolVariants::UniswapV4(v) => v.calculate_price(base, quote),
+            PoolVariants::LFJ(v) => v.calculate_price(base, quote),
+            PoolVariants::MaverickV2(v) => v.calculate_price(base, quote),
+            PoolVariants::FluidDex(v) => v.calculate_price(base, quote),
         }
     }
 }
@@ -137,14 +188,39 @@ impl PoolState {
 pub enum PoolVariants {
     UniswapV2(Box<UniswapV2Pool>),
     UniswapV3(Box<UniswapV3Pool>),
+    ERC4626(Box<ERC4626Vault>),
+    CurveStableSwap(Box<CurveStableSwapPool>),
+    CurveCryptoSwap(Box<CurveCryptoSwapPool>),
+    Balancer(Box<BalancerPool>),
+    UniswapV4(Box<UniswapV4Pool>),
+    LFJ(Box<LFJPool>),
+    MaverickV2(Box<MaverickV2Pool>),
+    FluidDex(Box<FluidDexPool>),
 }
 
 impl PoolVariants {
-    fn increment_state(&mut self, logs: Vec<Log>) {
+    fn increment_state(&mut self, action: Action, logs: Vec<Log>) {
+        // balancer v2 swaps are classified from the pool's hook, so the vault's swap
+        // log isn't part of the update
+        if let PoolVariants::Balancer(a) = self {
+            if action.is_swap_no_fee() && !logs.iter().any(|log| a.is_vault_log(log)) {
+                let _ = a.sync_from_action(action);
+                return
+            }
+        }
+
         for log in logs {
             let _ = match self {
                 PoolVariants::UniswapV3(a) => a.sync_from_log(log),
                 PoolVariants::UniswapV2(a) => a.sync_from_log(log),
+                PoolVariants::ERC4626(a) => a.sync_from_log(log),
+                PoolVariants::CurveStableSwap(a) => a.sync_from_log(log),
+                PoolVariants::CurveCryptoSwap(a) => a.sync_from_log(log),
+                PoolVariants::Balancer(a) => a.sync_from_log(log),
+                PoolVariants::UniswapV4(a) => a.sync_from_log(log),
+                PoolVariants::LFJ(a) => a.sync_from_log(log),
+                PoolVariants::MaverickV2(a) => a.sync_from_log(log),
+                PoolVariants::FluidDex(a) => a.sync_from_log(log),
             };
         }
     }
@@ -202,6 +278,7 @@ impl PoolUpdate {
             || self.action.is_batch()
             || self.action.is_aggregator()
             || self.action.is_eth_transfer()
+            || self.action.is_bridge()
         {
             return None
         }
@@ -212,6 +289,21 @@ impl PoolUpdate {
         self.action.is_transfer()
     }
 
+    /// Uniswap V4 pools are addressed by the first 20 bytes of their id, the
+    /// full id is the first indexed topic of the manager's logs for the pool
+    pub fn uniswap_v4_pool_id(&self) -> Option<B256> {
+        if self.action.get_protocol() != Protocol::UniswapV4 {
+            return None
+        }
+        let pool = self.get_pool_address();
+
+        self.logs
+            .iter()
+            .filter_map(|log| log.topics().get(1))
+            .find(|id| id[..20] == pool[..])
+            .copied()
+    }
+
     pub fn is_supported_protocol(&self) -> bool {
         if let Action::Swap(s) = &self.action {
             return s.protocol.has_state_updater()
@@ -240,9 +332,12 @@ impl PoolUpdate {
                 b.token.get(1).map(|t| t.address).unwrap_or(quote),
             )),
             Action::Transfer(t) => Some(Pair(t.token.address, quote)),
-            Action::EthTransfer(_) => Some(Pair(WETH_ADDRESS, quote)),
+            Action::EthTransfer(_) => Some(Pair(chain_config().weth_address(), quote)),
             Action::Liquidation(l) => Some(Pair(l.collateral_asset.address, l.debt_asset.address)),
             Action::SwapWithFee(s) => Some(Pair(s.token_in.address, s.token_out.address)),
+            Action::Wrap(w) => Some(Pair(w.underlying.address, w.wrapped.address)),
+            Action::Unwrap(u) => Some(Pair(u.underlying.address, u.wrapped.address)),
+            Action::Bridge(b) => Some(Pair(b.token.address, quote)),
             rest => {
                 tracing::debug!(?rest, "tried to get pair for action with no def");
                 None
@@ -1,27 +1,44 @@
-use std::{cmp::Ordering, sync::Arc};
+use std::{cmp::Ordering, collections::BTreeMap, sync::Arc};
 
-use alloy_primitives::{hex, Address, U256};
+use alloy_primitives::{Address, U256};
 use alloy_rpc_types::Filter;
 use alloy_sol_macro::sol;
 use alloy_sol_types::SolEvent;
-use brontes_types::{express_lane::ExpressLaneMetaData, traits::TracingProvider};
+use brontes_types::{
+    chain::chain_config,
+    db::express_lane::{ExpressLaneRound, ExpressLaneTransfer},
+    express_lane::ExpressLaneMetaData,
+    make_call_request,
+    traits::TracingProvider,
+    FastHashMap,
+};
+use parking_lot::RwLock;
+
+use crate::{
+    bids::ExpressLaneBidStore,
+    config::{ExpressLaneAuctionConfig, RoundTimingInfo, RoundTimingSource},
+};
 
 sol!(IExpressLaneAuction, "./src/contracts/IExpressLaneAuction.json");
 
+impl From<&IExpressLaneAuction::SetRoundTimingInfo> for RoundTimingInfo {
+    fn from(event: &IExpressLaneAuction::SetRoundTimingInfo) -> Self {
+        Self {
+            offset_timestamp:           event.offsetTimestamp,
+            round_duration_seconds:     event.roundDurationSeconds,
+            auction_closing_seconds:    event.auctionClosingSeconds,
+            reserve_submission_seconds: event.reserveSubmissionSeconds,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum ExpressLaneAuctionLog {
     SetExpressLaneController(ExpressLaneControllerEvent),
     AuctionResolved(ExpressLaneAuctionEvent),
+    SetRoundTimingInfo(RoundTimingInfo),
 }
 
-// TODO(jinmel): Parametrize this address
-pub const ONE_EXPRESS_LANE_AUCTION_ADDRESS: Address =
-    Address::new(hex!("5fcb496a31b7AE91e7c9078Ec662bd7A55cd3079"));
-
-// 250ms block time, 1min per round
-// NOTE: Nitro full node prunes out blocks behind 128 blocks however longer range works anyway.
-pub const BLOCKS_PER_ROUND: u64 = 4 * 60;
-
 #[derive(Debug)]
 pub struct ExpressLaneControllerEvent {
     pub block_number: u64,
@@ -45,62 +62,320 @@ pub struct ExpressLaneAuctionEvent {
     pub round_end_timestamp: u64,
 }
 
+impl ExpressLaneAuctionLog {
+    /// Folds the log into the per round records. The auction for a round is
+    /// resolved during the previous round, while transfers of control happen
+    /// during the round itself.
+    pub fn apply_to_rounds(self, rounds: &mut BTreeMap<u64, ExpressLaneRound>) {
+        match self {
+            ExpressLaneAuctionLog::AuctionResolved(event) => {
+                let round = rounds.entry(event.round).or_default();
+                round.round = event.round;
+                round.round_start_timestamp = event.round_start_timestamp;
+                round.round_end_timestamp = event.round_end_timestamp;
+                round.resolved_block = event.block_number;
+                round.bidder = Some(event.first_price_bidder);
+                round.controller = event.first_price_express_lane_controller;
+                round.bid_price = Some(event.first_price_amount);
+                round.price = Some(event.price);
+            }
+            // the controller set by the auction resolution itself has no transferor
+            ExpressLaneAuctionLog::SetExpressLaneController(event)
+                if event.transferor == Address::ZERO => {}
+            ExpressLaneAuctionLog::SetExpressLaneController(event) => {
+                let round = rounds.entry(event.round).or_default();
+                round.round = event.round;
+                round.transfers.push(ExpressLaneTransfer {
+                    block_number:        event.block_number,
+                    previous_controller: event.previous_express_lane_controller,
+                    new_controller:      event.new_express_lane_controller,
+                    transferor:          event.transferor,
+                    start_timestamp:     event.start_timestamp,
+                    end_timestamp:       event.end_timestamp,
+                });
+            }
+            // rounds store the timing they were filled with
+            ExpressLaneAuctionLog::SetRoundTimingInfo(_) => {}
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct ExpressLaneAuctionProvider<T: TracingProvider> {
     provider:         Arc<T>,
-    contract_address: Address,
+    config:           ExpressLaneAuctionConfig,
+    bids:             Option<Arc<ExpressLaneBidStore>>,
+    /// `roundTimingInfo` keyed by the block it applies from, filled from the
+    /// `SetRoundTimingInfo` logs
+    round_timing:     Arc<RwLock<BTreeMap<u64, RoundTimingInfo>>>,
+    /// Last block the `SetRoundTimingInfo` logs have been fetched up to
+    timing_synced_to: Arc<RwLock<Option<u64>>>,
+    /// Deployment block of the auction, when it isn't set in the chain config
+    activation_block: Arc<RwLock<Option<u64>>>,
+    /// Reserve price at resolution keyed by round, so that it is only queried
+    /// once per round rather than for every block
+    reserve_prices:   Arc<RwLock<FastHashMap<u64, U256>>>,
 }
 
 impl<T: TracingProvider> Clone for ExpressLaneAuctionProvider<T> {
     fn clone(&self) -> Self {
-        Self { provider: self.provider.clone(), contract_address: self.contract_address }
+        Self {
+            provider:         self.provider.clone(),
+            config:           self.config.clone(),
+            bids:             self.bids.clone(),
+            round_timing:     self.round_timing.clone(),
+            timing_synced_to: self.timing_synced_to.clone(),
+            activation_block: self.activation_block.clone(),
+            reserve_prices:   self.reserve_prices.clone(),
+        }
     }
 }
 
 impl<T: TracingProvider> ExpressLaneAuctionProvider<T> {
-    pub fn new(provider: Arc<T>) -> Self {
-        Self { provider, contract_address: ONE_EXPRESS_LANE_AUCTION_ADDRESS }
+    pub fn new(provider: Arc<T>, config: ExpressLaneAuctionConfig) -> Self {
+        Self {
+            provider,
+            config,
+            bids: None,
+            round_timing: Arc::new(RwLock::new(BTreeMap::new())),
+            timing_synced_to: Arc::new(RwLock::new(None)),
+            activation_block: Arc::new(RwLock::new(None)),
+            reserve_prices: Arc::new(RwLock::new(FastHashMap::default())),
+        }
+    }
+
+    /// Attaches the sealed-bid history loaded from the auctioneer's bid feed
+    /// so that every round's metadata carries its losing bids.
+    pub fn with_bid_store(mut self, bids: Arc<ExpressLaneBidStore>) -> Self {
+        self.bids = Some(bids);
+        self
+    }
+
+    pub fn contract_address(&self) -> Address {
+        self.config.address
     }
 
+    pub fn config(&self) -> &ExpressLaneAuctionConfig {
+        &self.config
+    }
+
+    /// Express lane state at the given block. The active round is derived from
+    /// the block timestamp and the auction's round timing, the auction for it
+    /// having been resolved during the previous round.
     pub async fn get_express_lane_meta_data(
         &self,
         block_number: u64,
+        block_timestamp: u64,
     ) -> eyre::Result<ExpressLaneMetaData> {
-        let start_block = block_number - BLOCKS_PER_ROUND - 20; // Add some buffer until the auction is resolved. 
-        let end_block = block_number;
-        let logs = self
-            .fetch_auction_events_range(start_block, end_block)
+        let Some(activation_block) = self.activation_block(block_number).await? else {
+            return Err(eyre::eyre!("express lane auction isn't active at block {block_number}"))
+        };
+
+        let timing = self.round_timing(block_number).await?;
+        let mut round = timing.round_at(block_timestamp);
+        let (previous_round_start, _) = timing.round_timestamps(round.saturating_sub(1));
+
+        // block timestamps only have second precision, so we estimate the amount of
+        // blocks since the start of the previous round using the minimum block
+        // time.
+        let blocks_back = block_timestamp.saturating_sub(previous_round_start) * 1000
+            / chain_config().block_time_millis() as u64;
+        let start_block = block_number
+            .saturating_sub(blocks_back)
+            .max(activation_block);
+
+        let mut rounds = BTreeMap::new();
+        for log in self
+            .fetch_auction_events_range(start_block, block_number)
+            .await?
+        {
+            // the schedule changed in the meantime
+            if let ExpressLaneAuctionLog::SetRoundTimingInfo(timing) = &log {
+                round = timing.round_at(block_timestamp);
+            }
+            log.apply_to_rounds(&mut rounds);
+        }
+
+        let Some(mut express_lane_round) = rounds
+            .remove(&round)
+            .filter(|round| round.resolved_block != 0)
+        else {
+            return Err(eyre::eyre!(
+                "no resolved auction found for round {round}, start block: {start_block}, end \
+                 block: {block_number}"
+            ))
+        };
+
+        self.fill_round(&mut express_lane_round).await;
+
+        Ok(express_lane_round.into_meta_data(block_timestamp))
+    }
+
+    /// First block the auction is live at, either set in the chain config or
+    /// the block the auction contract was deployed in. The latter is found by
+    /// searching for the first block the contract has code at, and is only
+    /// cached once every probe succeeded. None if the auction isn't live as of
+    /// `block_number`.
+    pub async fn activation_block(&self, block_number: u64) -> eyre::Result<Option<u64>> {
+        if let Some(block) = self
+            .config
+            .activation_block
+            .or(*self.activation_block.read())
+        {
+            return Ok((block <= block_number).then_some(block))
+        }
+
+        if !self.is_deployed(block_number).await? {
+            return Ok(None)
+        }
+
+        let (mut low, mut high) = (0, block_number);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.is_deployed(mid).await? {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        tracing::info!(block = low, "found express lane auction deployment block");
+        *self.activation_block.write() = Some(low);
+
+        Ok(Some(low))
+    }
+
+    async fn is_deployed(&self, block_number: u64) -> eyre::Result<bool> {
+        let code = self
+            .provider
+            .get_bytecode(Some(block_number), self.config.address)
             .await?;
 
-        if logs.is_empty() {
-            return Err(eyre::eyre!("no auction events found for start block: {:?}, end block: {:?}", start_block, end_block));
+        Ok(code.is_some_and(|code| !code.is_empty()))
+    }
+
+    /// Round timing of the auction, either fixed in the chain config or the
+    /// one in effect at the given block. The latter is the last
+    /// `SetRoundTimingInfo` logged at or before the block, falling back to the
+    /// timing the contract was deployed with.
+    pub async fn round_timing(&self, block_number: u64) -> eyre::Result<RoundTimingInfo> {
+        if let RoundTimingSource::Fixed(timing) = self.config.timing {
+            return Ok(timing)
         }
 
-        let mut express_lane_meta_data = ExpressLaneMetaData::default();
-        // apply all the logs to make the latest express lane state.
-        for log in logs {
-            match log {
-                ExpressLaneAuctionLog::SetExpressLaneController(event) => {
-                    express_lane_meta_data.block_number = event.block_number;
-                    express_lane_meta_data.round = event.round;
-                    express_lane_meta_data.controller = event.new_express_lane_controller;
-                    express_lane_meta_data.round_start_timestamp = event.start_timestamp;
-                    express_lane_meta_data.round_end_timestamp = event.end_timestamp;
-                }
-                ExpressLaneAuctionLog::AuctionResolved(event) => {
-                    express_lane_meta_data.block_number = event.block_number;
-                    express_lane_meta_data.round = event.round;
-                    express_lane_meta_data.controller = event.first_price_express_lane_controller;
-                    express_lane_meta_data.round_start_timestamp = event.round_start_timestamp;
-                    express_lane_meta_data.round_end_timestamp = event.round_end_timestamp;
-                    express_lane_meta_data.bidder = Some(event.first_price_bidder);
-                    express_lane_meta_data.bid_price = Some(event.first_price_amount);
-                    express_lane_meta_data.price = Some(event.price);
+        let Some(activation_block) = self.activation_block(block_number).await? else {
+            return Err(eyre::eyre!("express lane auction isn't active at block {block_number}"))
+        };
+
+        let synced_to = *self.timing_synced_to.read();
+        if synced_to.map_or(true, |synced_to| synced_to < block_number) {
+            let start_block = synced_to.map_or(activation_block, |synced_to| synced_to + 1);
+            self.sync_round_timing(start_block, block_number).await?;
+        }
+
+        if let Some((_, timing)) = self.round_timing.read().range(..=block_number).next_back() {
+            return Ok(*timing)
+        }
+
+        // no change logged since the deployment
+        let res = make_call_request(
+            IExpressLaneAuction::roundTimingInfoCall {},
+            &self.provider,
+            self.config.address,
+            Some(activation_block),
+        )
+        .await?;
+
+        let timing = RoundTimingInfo {
+            offset_timestamp:           res.offsetTimestamp,
+            round_duration_seconds:     res.roundDurationSeconds,
+            auction_closing_seconds:    res.auctionClosingSeconds,
+            reserve_submission_seconds: res.reserveSubmissionSeconds,
+        };
+        self.round_timing.write().insert(activation_block, timing);
+
+        Ok(timing)
+    }
+
+    /// Caches every `SetRoundTimingInfo` logged in the given range.
+    async fn sync_round_timing(&self, start_block: u64, end_block: u64) -> eyre::Result<()> {
+        let filter = Filter::new()
+            .address(self.config.address)
+            .event_signature(IExpressLaneAuction::SetRoundTimingInfo::SIGNATURE_HASH)
+            .from_block(start_block)
+            .to_block(end_block);
+
+        for log in self.provider.get_logs(&filter).await? {
+            let block_number = log
+                .block_number
+                .ok_or(eyre::eyre!("block number not found"))?;
+            let event = IExpressLaneAuction::SetRoundTimingInfo::decode_log(&log.inner, true)?;
+            self.round_timing
+                .write()
+                .insert(block_number, RoundTimingInfo::from(&event.data));
+        }
+
+        let mut synced_to = self.timing_synced_to.write();
+        *synced_to = Some(synced_to.map_or(end_block, |synced_to| synced_to.max(end_block)));
+
+        Ok(())
+    }
+
+    /// Attaches the round timing, the reserve price at resolution and the
+    /// sealed bids of the round, if a bid store is loaded. Rounds that only saw
+    /// transfers get their boundaries from the round timing.
+    pub async fn fill_round(&self, round: &mut ExpressLaneRound) {
+        let block = Some(round.resolved_block)
+            .filter(|block| *block != 0)
+            .or_else(|| {
+                round
+                    .transfers
+                    .first()
+                    .map(|transfer| transfer.block_number)
+            });
+        if let Some(block) = block {
+            match self.round_timing(block).await {
+                Ok(timing) => {
+                    round.timing = timing;
+                    if round.round_end_timestamp == 0 {
+                        (round.round_start_timestamp, round.round_end_timestamp) =
+                            timing.round_timestamps(round.round);
+                    }
                 }
+                Err(e) => tracing::debug!(?e, "failed to fetch express lane round timing"),
+            }
+        }
+
+        if round.reserve_price.is_none() && round.resolved_block != 0 {
+            round.reserve_price = self.reserve_prices.read().get(&round.round).copied();
+        }
+
+        if round.reserve_price.is_none() && round.resolved_block != 0 {
+            round.reserve_price = self
+                .fetch_reserve_price(round.resolved_block)
+                .await
+                .inspect_err(|e| tracing::debug!(?e, "failed to fetch express lane reserve price"))
+                .ok();
+            if let Some(price) = round.reserve_price {
+                self.reserve_prices.write().insert(round.round, price);
             }
         }
 
-        Ok(express_lane_meta_data)
+        if let Some(bids) = self.bids.as_ref() {
+            round.bids = bids.bids_for_round(round.round);
+        }
+    }
+
+    /// Reserve price of the auction as of the given block.
+    pub async fn fetch_reserve_price(&self, block_number: u64) -> eyre::Result<U256> {
+        let res = make_call_request(
+            IExpressLaneAuction::reservePriceCall {},
+            &self.provider,
+            self.config.address,
+            Some(block_number),
+        )
+        .await?;
+
+        Ok(res._0)
     }
 
     pub async fn fetch_auction_events(
@@ -122,10 +397,11 @@ impl<T: TracingProvider> ExpressLaneAuctionProvider<T> {
         let topics = vec![
             IExpressLaneAuction::SetExpressLaneController::SIGNATURE_HASH,
             IExpressLaneAuction::AuctionResolved::SIGNATURE_HASH,
+            IExpressLaneAuction::SetRoundTimingInfo::SIGNATURE_HASH,
         ];
 
         let filter = Filter::new()
-            .address(self.contract_address)
+            .address(self.config.address)
             .event_signature(topics)
             .from_block(start_block)
             .to_block(end_block);
@@ -180,6 +456,18 @@ impl<T: TracingProvider> ExpressLaneAuctionProvider<T> {
                         round_start_timestamp: event.roundStartTimestamp,
                         round_end_timestamp: event.roundEndTimestamp,
                     }));
+                } else if *topic0 == IExpressLaneAuction::SetRoundTimingInfo::SIGNATURE_HASH {
+                    let event =
+                        IExpressLaneAuction::SetRoundTimingInfo::decode_log(&log.inner, true)?;
+                    let timing = RoundTimingInfo::from(&event.data);
+                    // a fixed schedule is never read from the contract
+                    if matches!(self.config.timing, RoundTimingSource::Contract) {
+                        let block_number = log
+                            .block_number
+                            .ok_or(eyre::eyre!("block number not found"))?;
+                        self.round_timing.write().insert(block_number, timing);
+                    }
+                    updates.push(ExpressLaneAuctionLog::SetRoundTimingInfo(timing));
                 }
             }
         }
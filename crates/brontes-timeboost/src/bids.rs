@@ -0,0 +1,176 @@
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+    str::FromStr,
+};
+
+use alloy_primitives::{Address, Bytes, U256};
+use brontes_types::{
+    db::express_lane::ExpressLaneRoundBids, express_lane::ExpressLaneBid, FastHashMap,
+};
+use serde::Deserialize;
+
+/// A bid as it appears in the auctioneer's bid feed dump. The same record
+/// layout is used for both the CSV and the JSON exports.
+#[derive(Debug, Deserialize)]
+struct RawBid {
+    #[serde(alias = "Bidder")]
+    bidder:                   Address,
+    #[serde(alias = "ExpressLaneController", alias = "expressLaneController")]
+    express_lane_controller:  Address,
+    #[serde(alias = "AuctionContractAddress", alias = "auctionContractAddress", default)]
+    auction_contract_address: Option<Address>,
+    #[serde(alias = "Round")]
+    round:                    u64,
+    #[serde(alias = "Amount")]
+    amount:                   String,
+    #[serde(alias = "Signature")]
+    signature:                String,
+}
+
+impl RawBid {
+    fn into_bid(self) -> eyre::Result<ExpressLaneBid> {
+        Ok(ExpressLaneBid {
+            round:                   self.round,
+            bidder:                  self.bidder,
+            express_lane_controller: self.express_lane_controller,
+            amount:                  U256::from_str(self.amount.trim())
+                .map_err(|e| eyre::eyre!("invalid bid amount {}: {e}", self.amount))?,
+            signature:               Bytes::from_str(self.signature.trim())
+                .map_err(|e| eyre::eyre!("invalid bid signature {}: {e}", self.signature))?,
+        })
+    }
+}
+
+/// Sealed-bid history of the express lane auction, keyed by round.
+///
+/// Only the winning and second price bids ever land on chain, so the losing
+/// bids have to be loaded from a dump of the auctioneer's bid feed.
+#[derive(Debug, Default, Clone)]
+pub struct ExpressLaneBidStore {
+    bids: FastHashMap<u64, Vec<ExpressLaneBid>>,
+}
+
+impl ExpressLaneBidStore {
+    /// Loads a bid dump, picking the format from the file extension.
+    /// Bids submitted to an auction contract other than `auction_contract`
+    /// are dropped.
+    pub fn load(path: impl AsRef<Path>, auction_contract: Address) -> eyre::Result<Self> {
+        let path = path.as_ref();
+        let reader = BufReader::new(File::open(path)?);
+        let store = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Self::from_json_reader(reader, auction_contract)?,
+            Some("csv") => Self::from_csv_reader(reader, auction_contract)?,
+            _ => {
+                return Err(eyre::eyre!(
+                    "unsupported bid dump format: {}, expected .json or .csv",
+                    path.display()
+                ))
+            }
+        };
+
+        tracing::info!(
+            rounds = store.bids.len(),
+            path = %path.display(),
+            "loaded express lane bid dump"
+        );
+
+        Ok(store)
+    }
+
+    pub fn from_json_reader(reader: impl Read, auction_contract: Address) -> eyre::Result<Self> {
+        let raw: Vec<RawBid> = serde_json::from_reader(reader)?;
+        Self::from_raw(raw, auction_contract)
+    }
+
+    pub fn from_csv_reader(reader: impl Read, auction_contract: Address) -> eyre::Result<Self> {
+        let raw = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(reader)
+            .deserialize()
+            .collect::<Result<Vec<RawBid>, _>>()?;
+        Self::from_raw(raw, auction_contract)
+    }
+
+    fn from_raw(raw: Vec<RawBid>, auction_contract: Address) -> eyre::Result<Self> {
+        let mut store = Self::default();
+        for bid in raw {
+            if bid
+                .auction_contract_address
+                .is_some_and(|addr| addr != auction_contract)
+            {
+                continue
+            }
+            store.insert(bid.into_bid()?);
+        }
+
+        Ok(store)
+    }
+
+    pub fn insert(&mut self, bid: ExpressLaneBid) {
+        let round = self.bids.entry(bid.round).or_default();
+        if !round.contains(&bid) {
+            round.push(bid);
+        }
+    }
+
+    /// All bids of the given round, sorted from the highest to the lowest
+    /// amount.
+    pub fn bids_for_round(&self, round: u64) -> Vec<ExpressLaneBid> {
+        let mut bids = self.bids.get(&round).cloned().unwrap_or_default();
+        bids.sort_unstable_by(|a, b| b.amount.cmp(&a.amount));
+        bids
+    }
+
+    /// The bids of every round in the form they are persisted in libmdbx.
+    pub fn round_bids(&self) -> Vec<ExpressLaneRoundBids> {
+        self.rounds()
+            .map(|&round| ExpressLaneRoundBids { round, bids: self.bids_for_round(round) })
+            .collect()
+    }
+
+    pub fn rounds(&self) -> impl Iterator<Item = &u64> + '_ {
+        self.bids.keys()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bids.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::hex;
+
+    use super::*;
+
+    const AUCTION_ADDRESS: Address = Address::new(hex!("5fcb496a31b7AE91e7c9078Ec662bd7A55cd3079"));
+
+    #[test]
+    fn test_load_csv_bid_dump() {
+        let dump = "\
+ChainID,Bidder,ExpressLaneController,AuctionContractAddress,Round,Amount,Signature
+42161,0x95c0d89b2bd5d4e4a3eac2f3e1bbf4ce3cbd2277,0x95c0d89b2bd5d4e4a3eac2f3e1bbf4ce3cbd2277,\
+                    0x5fcb496a31b7AE91e7c9078Ec662bd7A55cd3079,100,1000000000000000,0x01
+42161,0x2b38a73dd32a2eafe849825a4b515ae5187eda42,0x2b38a73dd32a2eafe849825a4b515ae5187eda42,\
+                    0x5fcb496a31b7AE91e7c9078Ec662bd7A55cd3079,100,3000000000000000,0x02
+42161,0x2b38a73dd32a2eafe849825a4b515ae5187eda42,0x2b38a73dd32a2eafe849825a4b515ae5187eda42,\
+                    0x0000000000000000000000000000000000000001,100,9000000000000000,0x03
+";
+
+        let store = ExpressLaneBidStore::from_csv_reader(dump.as_bytes(), AUCTION_ADDRESS).unwrap();
+
+        let bids = store.bids_for_round(100);
+        assert_eq!(bids.len(), 2);
+        assert_eq!(bids[0].amount, U256::from(3_000_000_000_000_000u64));
+        assert_eq!(bids[0].bidder, Address::new(hex!("2b38a73dd32a2eafe849825a4b515ae5187eda42")));
+        assert_eq!(bids[1].signature, Bytes::from_static(&[0x01]));
+        assert!(store.bids_for_round(101).is_empty());
+
+        let round_bids = store.round_bids();
+        assert_eq!(round_bids.len(), 1);
+        assert_eq!(round_bids[0].round, 100);
+        assert_eq!(round_bids[0].bids, bids);
+    }
+}
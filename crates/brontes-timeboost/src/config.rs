@@ -0,0 +1,107 @@
+use std::{collections::HashMap, path::Path};
+
+use alloy_primitives::Address;
+pub use brontes_types::db::express_lane::RoundTimingInfo;
+use serde::Deserialize;
+
+pub const CHAIN_CONFIG_FILE: &str = "config/chain_config.toml";
+
+/// Deployment of the express lane auction on a given chain.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExpressLaneAuctionConfig {
+    pub address:          Address,
+    /// First block where the auction is live. Blocks before it never have an
+    /// express lane controller. When unset, the block the auction contract
+    /// was deployed in is looked up on chain, which needs `contract` timing.
+    #[serde(default)]
+    pub activation_block: Option<u64>,
+    #[serde(default)]
+    pub timing:           RoundTimingSource,
+}
+
+impl ExpressLaneAuctionConfig {
+    /// Loads the express lane auction config of `chain` from the chain config
+    /// file.
+    pub fn load(path: impl AsRef<Path>, chain: &str) -> eyre::Result<Self> {
+        let path = path.as_ref();
+        let config_str = std::fs::read_to_string(path)
+            .map_err(|e| eyre::eyre!("failed to read chain config {}: {e}", path.display()))?;
+        let mut chains: HashMap<String, ChainEntry> = toml::from_str(&config_str)?;
+
+        chains
+            .remove(chain)
+            .ok_or_else(|| eyre::eyre!("chain {chain} not found in {}", path.display()))?
+            .express_lane_auction
+            .ok_or_else(|| eyre::eyre!("chain {chain} has no express lane auction configured"))
+    }
+
+    /// Config for chains without an express lane auction, never active.
+    pub fn disabled() -> Self {
+        Self {
+            address:          Address::ZERO,
+            activation_block: Some(u64::MAX),
+            timing:           Default::default(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ChainEntry {
+    express_lane_auction: Option<ExpressLaneAuctionConfig>,
+}
+
+/// Where the round schedule of the auction comes from.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundTimingSource {
+    /// Query `roundTimingInfo` on the auction contract
+    #[default]
+    Contract,
+    Fixed(RoundTimingInfo),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_boundaries() {
+        let timing = RoundTimingInfo {
+            offset_timestamp:           1_740_000_000,
+            round_duration_seconds:     60,
+            auction_closing_seconds:    15,
+            reserve_submission_seconds: 15,
+        };
+
+        assert_eq!(timing.round_at(1_739_999_999), 0);
+        assert_eq!(timing.round_at(1_740_000_059), 0);
+        assert_eq!(timing.round_at(1_740_000_060), 1);
+        assert_eq!(timing.round_timestamps(1), (1_740_000_060, 1_740_000_119));
+    }
+
+    #[test]
+    fn test_parse_fixed_timing() {
+        let config: HashMap<String, ChainEntry> = toml::from_str(
+            r#"
+            [orbit]
+            chain_id = 1
+
+            [orbit.express_lane_auction]
+            address = "0x5fcb496a31b7AE91e7c9078Ec662bd7A55cd3079"
+            activation_block = 10
+
+            [orbit.express_lane_auction.timing.fixed]
+            offset_timestamp = 100
+            round_duration_seconds = 30
+            auction_closing_seconds = 5
+            reserve_submission_seconds = 5
+            "#,
+        )
+        .unwrap();
+
+        let auction = config["orbit"].express_lane_auction.as_ref().unwrap();
+        assert_eq!(auction.activation_block, Some(10));
+        let RoundTimingSource::Fixed(timing) = auction.timing else { panic!("expected fixed") };
+        assert_eq!(timing.round_at(160), 2);
+    }
+}
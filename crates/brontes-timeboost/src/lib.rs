@@ -1 +1,3 @@
 pub mod auction;
+pub mod bids;
+pub mod config;
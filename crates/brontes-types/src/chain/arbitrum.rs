@@ -0,0 +1,142 @@
+use alloy_primitives::Address;
+use reth_primitives::NamedChain;
+
+use super::{ChainConfig, SupportedChain};
+use crate::{constants::arbitrum::*, db::cex::CexExchange, mev::MevType, Protocol};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ArbitrumConfig;
+
+impl ChainConfig for ArbitrumConfig {
+    fn chain(&self) -> SupportedChain {
+        SupportedChain::Arbitrum
+    }
+
+    fn named_chain(&self) -> NamedChain {
+        NamedChain::Arbitrum
+    }
+
+    fn chain_config_entry(&self) -> &'static str {
+        "arbitrum-one"
+    }
+
+    fn block_time_millis(&self) -> usize {
+        BLOCK_TIME_MILLIS
+    }
+
+    fn weth_address(&self) -> Address {
+        WETH_ADDRESS
+    }
+
+    fn usdt_address(&self) -> Address {
+        USDT_ADDRESS
+    }
+
+    fn usdc_address(&self) -> Address {
+        USDC_ADDRESS
+    }
+
+    fn uniswap_v4_pool_manager(&self) -> Address {
+        UNISWAP_V4_FACTORY_ADDRESS
+    }
+
+    fn mempool_data_start_block(&self) -> u64 {
+        START_OF_CHAINBOUND_MEMPOOL_DATA
+    }
+
+    fn eth_addresses(&self) -> &'static [Address] {
+        &ETH_ADDRESSES
+    }
+
+    fn usd_stables(&self) -> &'static [&'static str] {
+        &USD_STABLES
+    }
+
+    fn euro_stables(&self) -> &'static [&'static str] {
+        &EURO_STABLES
+    }
+
+    fn gold_stables(&self) -> &'static [&'static str] {
+        &GOLD_STABLES
+    }
+
+    fn usd_stables_by_address(&self) -> &'static [Address] {
+        &USD_STABLES_BY_ADDRESS
+    }
+
+    fn filter_transfer_addresses(&self) -> &'static [Address] {
+        &FILTER_TRANSFER_ADDRESSES
+    }
+
+    fn cex_quote_assets(&self, exchange: &CexExchange) -> Vec<Address> {
+        match exchange {
+            CexExchange::Binance => {
+                vec![USDT_ADDRESS, WBTC_ADDRESS, USDC_ADDRESS, WETH_ADDRESS]
+            }
+            CexExchange::Bitmex => vec![USDT_ADDRESS, USDC_ADDRESS, WETH_ADDRESS],
+            CexExchange::Bitstamp => {
+                vec![WBTC_ADDRESS, USDC_ADDRESS, USDT_ADDRESS]
+            }
+            CexExchange::BybitSpot => {
+                vec![USDT_ADDRESS, USDC_ADDRESS, WBTC_ADDRESS, DAI_ADDRESS, WETH_ADDRESS]
+            }
+            CexExchange::Coinbase => {
+                vec![USDC_ADDRESS, USDT_ADDRESS, WBTC_ADDRESS, DAI_ADDRESS, WETH_ADDRESS]
+            }
+            CexExchange::Deribit => vec![USDT_ADDRESS, USDC_ADDRESS, WBTC_ADDRESS],
+            CexExchange::GateIo => vec![USDT_ADDRESS, WETH_ADDRESS, WBTC_ADDRESS, USDC_ADDRESS],
+            CexExchange::Gemini => {
+                vec![WBTC_ADDRESS, WETH_ADDRESS, DAI_ADDRESS, USDT_ADDRESS]
+            }
+            CexExchange::Huobi => {
+                vec![USDT_ADDRESS, WBTC_ADDRESS, WETH_ADDRESS, USDC_ADDRESS, DAI_ADDRESS]
+            }
+            CexExchange::Kraken => {
+                vec![WBTC_ADDRESS, WETH_ADDRESS, USDT_ADDRESS, USDC_ADDRESS, DAI_ADDRESS]
+            }
+            CexExchange::Kucoin => {
+                vec![USDT_ADDRESS, WBTC_ADDRESS, WETH_ADDRESS, USDC_ADDRESS, DAI_ADDRESS]
+            }
+            CexExchange::Okex => {
+                vec![USDT_ADDRESS, USDC_ADDRESS, WBTC_ADDRESS, WETH_ADDRESS, DAI_ADDRESS]
+            }
+            CexExchange::Upbit => {
+                vec![WETH_ADDRESS, WBTC_ADDRESS, LINK_ADDRESS]
+            }
+            _ => vec![],
+        }
+    }
+
+    fn explorer_url(&self) -> &'static str {
+        "https://arbiscan.io"
+    }
+
+    fn supports_protocol(&self, protocol: Protocol) -> bool {
+        !matches!(
+            protocol,
+            Protocol::MakerPSM
+                | Protocol::MakerDssFlash
+                | Protocol::BalancerV1
+                | Protocol::BalancerV1CRP
+                | Protocol::CompoundV2
+        )
+    }
+
+    fn supports_mev_type(&self, mev_type: MevType) -> bool {
+        // the sequencer orders transactions first come first served and never
+        // exposes them before they are sequenced, so nothing can be placed
+        // around a victim
+        !matches!(
+            mev_type,
+            MevType::Sandwich | MevType::Jit | MevType::JitSandwich | MevType::JitCexDex
+        )
+    }
+
+    fn has_sequencer(&self) -> bool {
+        true
+    }
+
+    fn min_base_fee(&self) -> u128 {
+        MIN_BASE_FEE_WEI
+    }
+}
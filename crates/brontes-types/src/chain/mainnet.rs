@@ -0,0 +1,180 @@
+use alloy_primitives::Address;
+use reth_primitives::NamedChain;
+
+use super::{ChainConfig, SupportedChain};
+use crate::{constants::mainnet::*, db::cex::CexExchange, mev::MevType, Protocol};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MainnetConfig;
+
+impl ChainConfig for MainnetConfig {
+    fn chain(&self) -> SupportedChain {
+        SupportedChain::Mainnet
+    }
+
+    fn named_chain(&self) -> NamedChain {
+        NamedChain::Mainnet
+    }
+
+    fn chain_config_entry(&self) -> &'static str {
+        "mainnet"
+    }
+
+    fn block_time_millis(&self) -> usize {
+        BLOCK_TIME_MILLIS
+    }
+
+    fn weth_address(&self) -> Address {
+        WETH_ADDRESS
+    }
+
+    fn usdt_address(&self) -> Address {
+        USDT_ADDRESS
+    }
+
+    fn usdc_address(&self) -> Address {
+        USDC_ADDRESS
+    }
+
+    fn uniswap_v4_pool_manager(&self) -> Address {
+        UNISWAP_V4_FACTORY_ADDRESS
+    }
+
+    fn mempool_data_start_block(&self) -> u64 {
+        START_OF_CHAINBOUND_MEMPOOL_DATA
+    }
+
+    fn eth_addresses(&self) -> &'static [Address] {
+        &ETH_ADDRESSES
+    }
+
+    fn usd_stables(&self) -> &'static [&'static str] {
+        &USD_STABLES
+    }
+
+    fn euro_stables(&self) -> &'static [&'static str] {
+        &EURO_STABLES
+    }
+
+    fn gold_stables(&self) -> &'static [&'static str] {
+        &GOLD_STABLES
+    }
+
+    fn usd_stables_by_address(&self) -> &'static [Address] {
+        &USD_STABLES_BY_ADDRESS
+    }
+
+    fn filter_transfer_addresses(&self) -> &'static [Address] {
+        &[]
+    }
+
+    fn cex_quote_assets(&self, exchange: &CexExchange) -> Vec<Address> {
+        match exchange {
+            CexExchange::Binance => {
+                vec![
+                    USDT_ADDRESS,
+                    WBTC_ADDRESS,
+                    BUSD_ADDRESS,
+                    USDC_ADDRESS,
+                    BNB_ADDRESS,
+                    WETH_ADDRESS,
+                    FDUSD_ADDRESS,
+                    PAX_DOLLAR_ADDRESS,
+                ]
+            }
+            CexExchange::Bitmex => vec![USDT_ADDRESS, USDC_ADDRESS, WETH_ADDRESS],
+            CexExchange::Bitstamp => {
+                vec![WBTC_ADDRESS, USDC_ADDRESS, USDT_ADDRESS, PAX_DOLLAR_ADDRESS]
+            }
+            CexExchange::BybitSpot => {
+                vec![USDT_ADDRESS, USDC_ADDRESS, WBTC_ADDRESS, DAI_ADDRESS, WETH_ADDRESS]
+            }
+            CexExchange::Coinbase => {
+                vec![
+                    USDC_ADDRESS,
+                    USDT_ADDRESS,
+                    WBTC_ADDRESS,
+                    DAI_ADDRESS,
+                    WETH_ADDRESS,
+                    DAI_ADDRESS,
+                ]
+            }
+            CexExchange::Deribit => vec![USDT_ADDRESS, USDC_ADDRESS, WBTC_ADDRESS],
+            CexExchange::GateIo => vec![USDT_ADDRESS, WETH_ADDRESS, WBTC_ADDRESS, USDC_ADDRESS],
+            CexExchange::Gemini => {
+                vec![WBTC_ADDRESS, WETH_ADDRESS, GUSD_ADDRESS, DAI_ADDRESS, USDT_ADDRESS]
+            }
+            CexExchange::Huobi => {
+                vec![
+                    USDT_ADDRESS,
+                    WBTC_ADDRESS,
+                    WETH_ADDRESS,
+                    HT_ADDRESS,
+                    HUSD_ADDRESS,
+                    USDC_ADDRESS,
+                    USDD_ADDRESS,
+                    TUSD_ADDRESS,
+                    DAI_ADDRESS,
+                    PYUSD_ADDRESS,
+                ]
+            }
+            CexExchange::Kraken => {
+                vec![WBTC_ADDRESS, WETH_ADDRESS, USDT_ADDRESS, USDC_ADDRESS, DAI_ADDRESS]
+            }
+            CexExchange::Kucoin => {
+                vec![
+                    USDT_ADDRESS,
+                    WBTC_ADDRESS,
+                    WETH_ADDRESS,
+                    USDC_ADDRESS,
+                    TUSD_ADDRESS,
+                    DAI_ADDRESS,
+                ]
+            }
+            CexExchange::Okex => {
+                vec![
+                    USDT_ADDRESS,
+                    USDC_ADDRESS,
+                    WBTC_ADDRESS,
+                    WETH_ADDRESS,
+                    DAI_ADDRESS,
+                    EURT_ADDRESS,
+                ]
+            }
+            CexExchange::Upbit => {
+                vec![WETH_ADDRESS, WBTC_ADDRESS, LINK_ADDRESS, EURT_ADDRESS, UNI_TOKEN]
+            }
+            _ => vec![],
+        }
+    }
+
+    fn explorer_url(&self) -> &'static str {
+        "https://etherscan.io"
+    }
+
+    fn supports_protocol(&self, protocol: Protocol) -> bool {
+        !matches!(
+            protocol,
+            Protocol::CamelotV2
+                | Protocol::CamelotV3
+                | Protocol::Dexalot
+                | Protocol::Dolomite
+                | Protocol::FluidDEX
+                | Protocol::FluidLending
+                | Protocol::GMXV1
+                | Protocol::GMXV2
+                | Protocol::LFJV2_1
+                | Protocol::LFJV2_2
+                | Protocol::RamsesV1
+                | Protocol::RamsesV2
+        )
+    }
+
+    fn supports_mev_type(&self, mev_type: MevType) -> bool {
+        mev_type != MevType::Timeboost
+    }
+
+    fn has_sequencer(&self) -> bool {
+        false
+    }
+}
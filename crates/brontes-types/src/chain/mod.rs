@@ -0,0 +1,161 @@
+//! Runtime selection of the chain brontes runs against.
+//!
+//! Everything that differs between networks and is needed outside of the
+//! per chain constants goes through the [`ChainConfig`] returned by
+//! [`chain_config`], which is set once from the `--chain` argument.
+use std::{fmt::Debug, sync::OnceLock};
+
+use alloy_primitives::Address;
+use clap::ValueEnum;
+use reth_primitives::NamedChain;
+use strum::Display;
+
+use crate::{db::cex::CexExchange, mev::MevType, Protocol};
+
+mod arbitrum;
+mod mainnet;
+pub use arbitrum::ArbitrumConfig;
+pub use mainnet::MainnetConfig;
+
+static CHAIN_CONFIG: OnceLock<&'static dyn ChainConfig> = OnceLock::new();
+
+/// Sets the chain for the rest of the process. Errors if a different chain
+/// was already set or read.
+pub fn init_chain_config(chain: SupportedChain) -> eyre::Result<()> {
+    let config = *CHAIN_CONFIG.get_or_init(|| chain.config());
+    if config.chain() != chain {
+        eyre::bail!("chain is already set to {}, can't switch to {}", config.chain(), chain)
+    }
+
+    Ok(())
+}
+
+/// The config of the chain selected with `--chain`, arbitrum if it was never
+/// set.
+pub fn chain_config() -> &'static dyn ChainConfig {
+    *CHAIN_CONFIG.get_or_init(|| SupportedChain::default().config())
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Display, ValueEnum)]
+#[strum(serialize_all = "lowercase")]
+pub enum SupportedChain {
+    Mainnet,
+    #[default]
+    Arbitrum,
+}
+
+impl SupportedChain {
+    pub fn config(&self) -> &'static dyn ChainConfig {
+        match self {
+            SupportedChain::Mainnet => &MainnetConfig,
+            SupportedChain::Arbitrum => &ArbitrumConfig,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StableType {
+    USD,
+    EURO,
+    GOLD,
+}
+
+pub trait ChainConfig: Debug + Send + Sync {
+    fn chain(&self) -> SupportedChain;
+
+    /// Used to pick the reth chainspec of the network
+    fn named_chain(&self) -> NamedChain;
+
+    /// Name of the chain's entry in the chain config file
+    fn chain_config_entry(&self) -> &'static str;
+
+    fn block_time_millis(&self) -> usize;
+
+    fn weth_address(&self) -> Address;
+
+    fn usdt_address(&self) -> Address;
+
+    fn usdc_address(&self) -> Address;
+
+    /// The uniswap v4 singleton holding every v4 pool
+    fn uniswap_v4_pool_manager(&self) -> Address;
+
+    /// The first block with mempool data from chainbound
+    fn mempool_data_start_block(&self) -> u64;
+
+    /// Addresses that stand in for native eth in actions
+    fn eth_addresses(&self) -> &'static [Address];
+
+    fn usd_stables(&self) -> &'static [&'static str];
+
+    fn euro_stables(&self) -> &'static [&'static str];
+
+    fn gold_stables(&self) -> &'static [&'static str];
+
+    fn usd_stables_by_address(&self) -> &'static [Address];
+
+    /// Routers and bridges whose transfers are ignored when accounting for
+    /// atomic arbs
+    fn filter_transfer_addresses(&self) -> &'static [Address];
+
+    /// Quote assets with the most liquid markets on the given exchange
+    fn cex_quote_assets(&self, exchange: &CexExchange) -> Vec<Address>;
+
+    fn explorer_url(&self) -> &'static str;
+
+    /// Whether the protocol is deployed on the chain. Pools of other protocols
+    /// are not discovered.
+    fn supports_protocol(&self, protocol: Protocol) -> bool;
+
+    /// Whether the inspector for the mev type applies to the chain
+    fn supports_mev_type(&self, mev_type: MevType) -> bool;
+
+    /// Blocks are ordered by a single sequencer instead of a builder paying a
+    /// proposer
+    fn has_sequencer(&self) -> bool;
+
+    /// Lowest base fee the chain charges, zero when there is no floor
+    fn min_base_fee(&self) -> u128 {
+        0
+    }
+
+    fn stable_type(&self, symbol: &str) -> Option<StableType> {
+        if self.usd_stables().contains(&symbol) {
+            Some(StableType::USD)
+        } else if self.euro_stables().contains(&symbol) {
+            Some(StableType::EURO)
+        } else if self.gold_stables().contains(&symbol) {
+            Some(StableType::GOLD)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mev_types_are_gated_by_chain() {
+        let arbitrum = SupportedChain::Arbitrum.config();
+        assert!(arbitrum.supports_mev_type(MevType::Timeboost));
+        assert!(arbitrum.supports_mev_type(MevType::AtomicArb));
+        assert!(!arbitrum.supports_mev_type(MevType::Sandwich));
+        assert!(!arbitrum.supports_mev_type(MevType::JitSandwich));
+
+        let mainnet = SupportedChain::Mainnet.config();
+        assert!(mainnet.supports_mev_type(MevType::Sandwich));
+        assert!(!mainnet.supports_mev_type(MevType::Timeboost));
+    }
+
+    #[test]
+    fn test_chain_values_differ() {
+        let arbitrum = SupportedChain::Arbitrum.config();
+        let mainnet = SupportedChain::Mainnet.config();
+        assert_ne!(arbitrum.weth_address(), mainnet.weth_address());
+        assert_ne!(arbitrum.usdt_address(), mainnet.usdt_address());
+        assert_ne!(arbitrum.block_time_millis(), mainnet.block_time_millis());
+        assert_ne!(arbitrum.uniswap_v4_pool_manager(), mainnet.uniswap_v4_pool_manager());
+    }
+}
@@ -2,6 +2,21 @@ use alloy_primitives::{hex, Address};
 
 pub const BLOCK_TIME_MILLIS: usize = 250;
 
+/// ArbOS minimum L2 base fee (0.01 gwei). Execution fees up to this price go to
+/// the infra fee account, anything above it to the network fee account.
+pub const MIN_BASE_FEE_WEI: u128 = 10_000_000;
+
+/// Arbitrum specific EIP-2718 transaction types
+pub const ARBITRUM_DEPOSIT_TX_TYPE: u8 = 0x64;
+pub const ARBITRUM_RETRY_TX_TYPE: u8 = 0x68;
+pub const ARBITRUM_SUBMIT_RETRYABLE_TX_TYPE: u8 = 0x69;
+pub const ARBITRUM_INTERNAL_TX_TYPE: u8 = 0x6a;
+
+/// ArbOS precompiles
+pub const ARB_SYS_ADDRESS: Address = Address::new(hex!("0000000000000000000000000000000000000064"));
+pub const ARB_RETRYABLE_TX_ADDRESS: Address =
+    Address::new(hex!("000000000000000000000000000000000000006e"));
+
 pub const USDT_ADDRESS_STRING: &str = "0xFd086bC7CD5C481DCC9C85ebE478A1C0b69FCbb9";
 // USD Stablecoins
 pub const USDT_ADDRESS: Address = Address::new(hex!("Fd086bC7CD5C481DCC9C85ebE478A1C0b69FCbb9"));
@@ -73,35 +88,6 @@ pub const USD_STABLES: [&str; 15] = [
     "XUSD",  // Overnight Finance
 ];
 
-pub fn is_usd_stable(symbol: &str) -> bool {
-    USD_STABLES.contains(&symbol)
-}
-pub fn is_euro_stable(symbol: &str) -> bool {
-    EURO_STABLES.contains(&symbol)
-}
-
-pub fn is_gold_stable(symbol: &str) -> bool {
-    GOLD_STABLES.contains(&symbol)
-}
-
-pub fn get_stable_type(symbol: &str) -> Option<StableType> {
-    if USD_STABLES.contains(&symbol) {
-        Some(StableType::USD)
-    } else if EURO_STABLES.contains(&symbol) {
-        Some(StableType::EURO)
-    } else if GOLD_STABLES.contains(&symbol) {
-        Some(StableType::GOLD)
-    } else {
-        None
-    }
-}
-
-pub enum StableType {
-    USD,
-    EURO,
-    GOLD,
-}
-
 pub const USD_STABLES_BY_ADDRESS: [Address; 13] = [
     USDT_ADDRESS,
     USDC_ADDRESS,
@@ -121,9 +107,6 @@ pub const USD_STABLES_BY_ADDRESS: [Address; 13] = [
 pub const EURO_STABLES_BY_ADDRESS: [Address; 2] = [AGEUR_ADDRESS, VEUR_ADDRESS];
 
 pub const GOLD_STABLES_BY_ADDRESS: [Address; 0] = [];
-// Pendle V2 api url
-pub const PENDLE_V2_SY_ASSETS_API_URL: &str =
-    "https://api-v2.pendle.finance/core/v3/42161/assets/all";
 
 pub const LIFI_CONTRACT_ADDRESS: Address =
     Address::new(hex!("1231DEB6f5749EF6cE6943a275A1D3E7486F4EaE"));
@@ -159,6 +142,8 @@ pub const FILTER_TRANSFER_ADDRESSES: [Address; 9] = [
 // DEX Factory Addresses
 pub const BALANCER_V2_VAULT_ADDRESS: Address =
     Address::new(hex!("ba12222222228d8ba445958a75a0704d566bf2c8"));
+pub const BALANCER_V3_VAULT_ADDRESS: Address =
+    Address::new(hex!("ba1333333333a1ba1108e8412f11850a5c319ba9"));
 pub const UNISWAP_V2_FACTORY_ADDRESS: Address =
     Address::new(hex!("f1D7CC64Fb4452F05c498126312eBE29f30Fbcf9"));
 pub const SUSHISWAP_V2_FACTORY_ADDRESS: Address =
@@ -175,6 +160,10 @@ pub const CAMELOT_V3_FACTORY_ADDRESS: Address =
     Address::new(hex!("1a3c9B1d2F0529D97f2afC5136Cc23e58f1FD35B"));
 pub const UNISWAP_V3_FACTORY_ADDRESS: Address =
     Address::new(hex!("1F98431c8aD98523631AE4a59f267346ea31F984"));
+pub const RAMSES_V1_FACTORY_ADDRESS: Address =
+    Address::new(hex!("AAA20D08e59F6561f242b08513D36266C5A29415"));
+pub const RAMSES_V2_FACTORY_ADDRESS: Address =
+    Address::new(hex!("AA2cd7477c451E703f3B9Ba5663334914763edF8"));
 pub const UNISWAP_V4_FACTORY_ADDRESS: Address =
     Address::new(hex!("360E68faCcca8cA495c1B759Fd9EEe466db9FB32"));
 pub const FLUID_DEX_RESOLVER_ADDRESS: Address =
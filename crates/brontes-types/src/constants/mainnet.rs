@@ -54,6 +54,13 @@ pub const SCP_MAIN_CEX_DEX_BOT: Address =
 
 pub const EXECUTE_FFS_YO: [u8; 4] = [0x78, 0xe1, 0x11, 0xf6];
 
+pub const BALANCER_V2_VAULT_ADDRESS: Address =
+    Address::new(hex!("ba12222222228d8ba445958a75a0704d566bf2c8"));
+pub const BALANCER_V3_VAULT_ADDRESS: Address =
+    Address::new(hex!("ba1333333333a1ba1108e8412f11850a5c319ba9"));
+pub const UNISWAP_V4_FACTORY_ADDRESS: Address =
+    Address::new(hex!("000000000004444c5dc75cB358380D2e3dE08A90"));
+
 pub const EURO_STABLES: [&str; 2] = [
     "EURT", // Tether Euro
     "EURS", // STASIS EURO
@@ -92,35 +99,6 @@ pub const USD_STABLES: [&str; 25] = [
     "MIMATIC", // MAI (Mimatic)
 ];
 
-pub fn is_usd_stable(symbol: &str) -> bool {
-    USD_STABLES.contains(&symbol)
-}
-pub fn is_euro_stable(symbol: &str) -> bool {
-    EURO_STABLES.contains(&symbol)
-}
-
-pub fn is_gold_stable(symbol: &str) -> bool {
-    GOLD_STABLES.contains(&symbol)
-}
-
-pub fn get_stable_type(symbol: &str) -> Option<StableType> {
-    if USD_STABLES.contains(&symbol) {
-        Some(StableType::USD)
-    } else if EURO_STABLES.contains(&symbol) {
-        Some(StableType::EURO)
-    } else if GOLD_STABLES.contains(&symbol) {
-        Some(StableType::GOLD)
-    } else {
-        None
-    }
-}
-
-pub enum StableType {
-    USD,
-    EURO,
-    GOLD,
-}
-
 pub const USD_STABLES_BY_ADDRESS: [Address; 24] = [
     USDT_ADDRESS,
     USDC_ADDRESS,
@@ -1,9 +1,28 @@
-#[cfg(not(feature = "arbitrum"))]
+//! Per chain constants. Values that differ between chains are read through
+//! [`crate::chain::chain_config`] or imported from the module of the only
+//! chain they exist on. Only the values that are the same on every supported
+//! chain are re-exported here.
+pub mod arbitrum;
 pub mod mainnet;
-#[cfg(not(feature = "arbitrum"))]
-pub use mainnet::*;
 
-#[cfg(feature = "arbitrum")]
-pub mod arbitrum;
-#[cfg(feature = "arbitrum")]
-pub use arbitrum::*;
+pub use mainnet::{
+    BALANCER_V2_VAULT_ADDRESS, BALANCER_V3_VAULT_ADDRESS, ETH_ADDRESS, EXECUTE_FFS_YO,
+    SCP_MAIN_CEX_DEX_BOT,
+};
+
+/// Pendle V2 api, the chain id follows as the first path segment
+pub const PENDLE_V2_API_URL: &str = "https://api-v2.pendle.finance/core/v3";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shared_constants_match_on_every_chain() {
+        assert_eq!(ETH_ADDRESS, arbitrum::ETH_ADDRESS);
+        assert_eq!(BALANCER_V2_VAULT_ADDRESS, arbitrum::BALANCER_V2_VAULT_ADDRESS);
+        assert_eq!(BALANCER_V3_VAULT_ADDRESS, arbitrum::BALANCER_V3_VAULT_ADDRESS);
+        assert_eq!(EXECUTE_FFS_YO, arbitrum::EXECUTE_FFS_YO);
+        assert_eq!(SCP_MAIN_CEX_DEX_BOT, arbitrum::SCP_MAIN_CEX_DEX_BOT);
+    }
+}
@@ -1,13 +1,13 @@
 use std::str::FromStr;
 
-use alloy_primitives::Address;
+use alloy_primitives::{Address, B256};
 use clickhouse::{fixed_string::FixedString, Row};
 use redefined::Redefined;
 use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    db::redefined_types::primitives::AddressRedefined,
+    db::redefined_types::primitives::{AddressRedefined, B256Redefined},
     implement_table_value_codecs_with_zc,
     serde_utils::{addresss, option_addresss, protocol},
     Protocol,
@@ -27,20 +27,22 @@ use crate::{
 pub struct ProtocolInfo {
     #[serde(with = "protocol")]
     #[redefined(same_fields)]
-    pub protocol:       Protocol,
+    pub protocol:            Protocol,
     #[serde(with = "addresss")]
-    pub token0:         Address,
+    pub token0:              Address,
     #[serde(with = "addresss")]
-    pub token1:         Address,
+    pub token1:              Address,
     #[serde(with = "option_addresss")]
-    pub token2:         Option<Address>,
+    pub token2:              Option<Address>,
     #[serde(with = "option_addresss")]
-    pub token3:         Option<Address>,
+    pub token3:              Option<Address>,
     #[serde(with = "option_addresss")]
-    pub token4:         Option<Address>,
+    pub token4:              Option<Address>,
     #[serde(with = "option_addresss")]
-    pub curve_lp_token: Option<Address>,
-    pub init_block:     u64,
+    pub curve_lp_token:      Option<Address>,
+    pub init_block:          u64,
+    #[serde(default)]
+    pub uniswap_v4_pool_key: Option<UniswapV4PoolKey>,
 }
 
 impl ProtocolInfo {
@@ -90,12 +92,83 @@ impl From<(Vec<String>, u64, String, Option<String>)> for ProtocolInfo {
             token4: iter.next().and_then(|a| Address::from_str(&a).ok()),
             curve_lp_token,
             init_block,
+            uniswap_v4_pool_key: None,
         }
     }
 }
 
 implement_table_value_codecs_with_zc!(ProtocolInfoRedefined);
 
+/// Archived layout of [`ProtocolInfo`] before the uniswap v4 pool key was
+/// added, only read to migrate the rows of databases written with it.
+#[derive(Debug, Clone, Serialize, rSerialize, rDeserialize, Archive)]
+pub struct LegacyProtocolInfoRedefined {
+    pub protocol:       Protocol,
+    pub token0:         AddressRedefined,
+    pub token1:         AddressRedefined,
+    pub token2:         Option<AddressRedefined>,
+    pub token3:         Option<AddressRedefined>,
+    pub token4:         Option<AddressRedefined>,
+    pub curve_lp_token: Option<AddressRedefined>,
+    pub init_block:     u64,
+}
+
+implement_table_value_codecs_with_zc!(LegacyProtocolInfoRedefined);
+
+impl From<LegacyProtocolInfoRedefined> for ProtocolInfoRedefined {
+    fn from(info: LegacyProtocolInfoRedefined) -> Self {
+        Self {
+            protocol:            info.protocol,
+            token0:              info.token0,
+            token1:              info.token1,
+            token2:              info.token2,
+            token3:              info.token3,
+            token4:              info.token4,
+            curve_lp_token:      info.curve_lp_token,
+            init_block:          info.init_block,
+            uniswap_v4_pool_key: None,
+        }
+    }
+}
+
+/// Uniswap V4 pools all live in the `PoolManager` and are identified by the
+/// hash of their `PoolKey`. They are stored under a pseudo address made of the
+/// first 20 bytes of that id, so the rest of the key is kept here.
+#[derive(Debug, Default, PartialEq, Clone, Eq, Serialize, Deserialize, Redefined, Hash)]
+#[redefined_attr(derive(
+    Debug,
+    PartialEq,
+    Clone,
+    Serialize,
+    rSerialize,
+    rDeserialize,
+    Archive,
+    Hash
+))]
+pub struct UniswapV4PoolKey {
+    pub pool_id:      B256,
+    pub fee:          u32,
+    pub tick_spacing: i32,
+    pub hooks:        Address,
+}
+
+impl UniswapV4PoolKey {
+    /// Set on `fee` when the pool's fee is set by its hook
+    pub const DYNAMIC_FEE_FLAG: u32 = 0x800000;
+
+    pub fn pool_address(&self) -> Address {
+        Address::from_slice(&self.pool_id[..20])
+    }
+
+    pub fn has_hooks(&self) -> bool {
+        self.hooks != Address::ZERO
+    }
+
+    pub fn has_dynamic_fee(&self) -> bool {
+        self.fee == Self::DYNAMIC_FEE_FLAG
+    }
+}
+
 #[derive(Debug, Default, Row, PartialEq, Clone, Eq, Serialize, Deserialize)]
 pub struct ProtocolInfoClickhouse {
     pub protocol:         String,
@@ -8,7 +8,7 @@ use serde::Deserialize;
 
 use super::{CexPriceMap, CexQuote};
 use crate::{
-    constants::USDC_ADDRESS,
+    chain::chain_config,
     db::{
         block_times::{BlockTimes, CexBlockTimes},
         cex::{BestCexPerPair, CexExchange, CexSymbols},
@@ -267,9 +267,9 @@ const QUOTE_TIME_BOUNDARY: [u64; 6] = [0, 2, 12, 30, 60, 300];
 pub fn correct_usdc_address(pair: &Pair) -> Pair {
     let mut corrected_pair = *pair;
     if corrected_pair.0 == hex!("2f6081e3552b1c86ce4479b80062a1dda8ef23e3") {
-        corrected_pair.0 = USDC_ADDRESS;
+        corrected_pair.0 = chain_config().usdc_address();
     } else if corrected_pair.1 == hex!("2f6081e3552b1c86ce4479b80062a1dda8ef23e3") {
-        corrected_pair.1 = USDC_ADDRESS;
+        corrected_pair.1 = chain_config().usdc_address();
     }
     corrected_pair
 }
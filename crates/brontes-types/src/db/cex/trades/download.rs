@@ -6,7 +6,7 @@ use serde::Deserialize;
 use strum::Display;
 
 use crate::{
-    constants::USDC_ADDRESS,
+    chain::chain_config,
     db::{
         block_times::{BlockTimes, CexBlockTimes},
         cex::{cex_symbols::CexSymbols, trades::CexTradeMap, CexExchange},
@@ -134,7 +134,7 @@ impl CexTradesConverter {
                                     if symbol.address_pair.1
                                         == hex!("2f6081e3552b1c86ce4479b80062a1dda8ef23e3")
                                     {
-                                        symbol.address_pair.1 = USDC_ADDRESS;
+                                        symbol.address_pair.1 = chain_config().usdc_address();
                                     }
 
                                     if symbol.address_pair.0
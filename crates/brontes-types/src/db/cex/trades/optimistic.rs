@@ -17,7 +17,7 @@ const R2: Rational = Rational::TWO;
 
 use super::{config::CexDexTradeConfig, time_window_vwam::ExchangePath};
 use crate::{
-    constants::{USDC_ADDRESS, USDT_ADDRESS},
+    chain::chain_config,
     db::cex::{
         trades::{
             utils::{log_insufficient_trade_volume, log_missing_trade_data, TimeBasketQueue},
@@ -176,11 +176,12 @@ impl<'a> SortedTrades<'a> {
                 tracing::debug!(target: "brontes_types::db::cex::trades::optimistic", ?pair, ?intermediary, "trying via intermediary");
 
                 let mut bypass_intermediary_vol = false;
+                let (usdc, usdt) = (chain_config().usdc_address(), chain_config().usdt_address());
 
                 // bypass volume requirements for stable pairs as we can assume that 
                 // some arbitrageurs consider the USDC & USDT to be equal on a longer time frame
-                if pair0.0 == USDC_ADDRESS && pair0.1 == USDT_ADDRESS
-                || pair0.0 == USDT_ADDRESS && pair0.1 == USDC_ADDRESS {
+                if pair0.0 == usdc && pair0.1 == usdt
+                || pair0.0 == usdt && pair0.1 == usdc {
                     bypass_intermediary_vol = true;
                 }
 
@@ -200,8 +201,8 @@ impl<'a> SortedTrades<'a> {
                     * ((&first_leg.global.price_maker + &first_leg.global.price_taker) / R2);
 
                 bypass_intermediary_vol = false;
-                if pair1.0 == USDT_ADDRESS && pair1.1 == USDC_ADDRESS
-                || pair1.0 == USDC_ADDRESS && pair1.1 == USDT_ADDRESS{
+                if pair1.0 == usdt && pair1.1 == usdc
+                || pair1.0 == usdc && pair1.1 == usdt{
                     bypass_intermediary_vol = true;
                 }
 
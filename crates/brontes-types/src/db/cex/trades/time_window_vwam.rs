@@ -19,12 +19,8 @@ use super::{
     CexTrades,
 };
 use crate::{
-    constants::{USDC_ADDRESS, USDT_ADDRESS},
-    db::cex::CexExchange,
-    display::utils::format_etherscan_url,
-    normalized_actions::NormalizedSwap,
-    pair::Pair,
-    FastHashMap, FastHashSet,
+    chain::chain_config, db::cex::CexExchange, display::utils::format_etherscan_url,
+    normalized_actions::NormalizedSwap, pair::Pair, FastHashMap, FastHashSet,
 };
 
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -184,10 +180,11 @@ impl<'a> TimeWindowTrades<'a> {
                 let pair1 = Pair(*intermediary, pair.1);
 
                 let mut bypass_intermediary_vol = false;
+                let (usdc, usdt) = (chain_config().usdc_address(), chain_config().usdt_address());
 
                 // bypass volume requirements for stable pairs
-                if pair0.0 == USDC_ADDRESS && pair0.1 == USDT_ADDRESS
-                || pair0.0 == USDT_ADDRESS && pair0.1 == USDC_ADDRESS {
+                if pair0.0 == usdc && pair0.1 == usdt
+                || pair0.0 == usdt && pair0.1 == usdc {
                     bypass_intermediary_vol = true;
                 }
 
@@ -208,8 +205,8 @@ impl<'a> TimeWindowTrades<'a> {
                 let second_leg_volume = &first_leg.global.price_maker * volume;
 
                 bypass_intermediary_vol = false;
-                if pair1.0 == USDT_ADDRESS && pair1.1 == USDC_ADDRESS
-                || pair1.0 == USDC_ADDRESS && pair1.1 == USDT_ADDRESS{
+                if pair1.0 == usdt && pair1.1 == usdc
+                || pair1.0 == usdc && pair1.1 == usdt{
                     bypass_intermediary_vol = true;
                 }
 
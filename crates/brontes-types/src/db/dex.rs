@@ -21,7 +21,7 @@ use serde::{Deserialize, Serialize};
 use tracing::debug;
 
 use crate::{
-    constants::{ETH_ADDRESSES, WETH_ADDRESS},
+    chain::chain_config,
     db::{clickhouse_serde::dex::dex_quote, redefined_types::malachite::RationalRedefined},
     implement_table_value_codecs_with_zc,
     pair::{Pair, PairRedefined},
@@ -113,11 +113,11 @@ impl DexQuotes {
     /// the index to zero
     #[cfg(feature = "test_pricing")]
     pub fn price_at(&self, mut pair: Pair, mut tx: usize) -> Option<DexPrices> {
-        if ETH_ADDRESSES.contains(&pair.0) {
-            pair.0 = WETH_ADDRESS;
+        if chain_config().eth_addresses().contains(&pair.0) {
+            pair.0 = chain_config().weth_address();
         }
-        if ETH_ADDRESSES.contains(&pair.1) {
-            pair.1 = WETH_ADDRESS;
+        if chain_config().eth_addresses().contains(&pair.1) {
+            pair.1 = chain_config().weth_address();
         }
         let s_idx = tx;
 
@@ -152,11 +152,11 @@ impl DexQuotes {
     /// the price at all previous indexes in the block
     #[cfg(not(feature = "test_pricing"))]
     pub fn price_at(&self, mut pair: Pair, tx: usize) -> Option<DexPrices> {
-        if ETH_ADDRESSES.contains(&pair.0) {
-            pair.0 = WETH_ADDRESS;
+        if chain_config().eth_addresses().contains(&pair.0) {
+            pair.0 = chain_config().weth_address();
         }
-        if ETH_ADDRESSES.contains(&pair.1) {
-            pair.1 = WETH_ADDRESS;
+        if chain_config().eth_addresses().contains(&pair.1) {
+            pair.1 = chain_config().weth_address();
         }
         let s_idx = tx;
 
@@ -181,11 +181,11 @@ impl DexQuotes {
     }
 
     pub fn price_at_or_before(&self, mut pair: Pair, mut tx: usize) -> Option<DexPrices> {
-        if ETH_ADDRESSES.contains(&pair.0) {
-            pair.0 = WETH_ADDRESS;
+        if chain_config().eth_addresses().contains(&pair.0) {
+            pair.0 = chain_config().weth_address();
         }
-        if ETH_ADDRESSES.contains(&pair.1) {
-            pair.1 = WETH_ADDRESS;
+        if chain_config().eth_addresses().contains(&pair.1) {
+            pair.1 = chain_config().weth_address();
         }
         let s_idx = tx;
 
@@ -217,11 +217,11 @@ impl DexQuotes {
     }
 
     pub fn price_for_block(&self, mut pair: Pair, price_at: BlockPrice) -> Option<Rational> {
-        if ETH_ADDRESSES.contains(&pair.0) {
-            pair.0 = WETH_ADDRESS;
+        if chain_config().eth_addresses().contains(&pair.0) {
+            pair.0 = chain_config().weth_address();
         }
-        if ETH_ADDRESSES.contains(&pair.1) {
-            pair.1 = WETH_ADDRESS;
+        if chain_config().eth_addresses().contains(&pair.1) {
+            pair.1 = chain_config().weth_address();
         }
 
         match price_at {
@@ -272,11 +272,11 @@ impl DexQuotes {
     }
 
     fn get_price(&self, mut pair: Pair, tx: usize) -> Option<&DexPrices> {
-        if ETH_ADDRESSES.contains(&pair.0) {
-            pair.0 = WETH_ADDRESS;
+        if chain_config().eth_addresses().contains(&pair.0) {
+            pair.0 = chain_config().weth_address();
         }
-        if ETH_ADDRESSES.contains(&pair.1) {
-            pair.1 = WETH_ADDRESS;
+        if chain_config().eth_addresses().contains(&pair.1) {
+            pair.1 = chain_config().weth_address();
         }
         self.0.get(tx)?.as_ref()?.get(&pair)
     }
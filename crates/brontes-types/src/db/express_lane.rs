@@ -0,0 +1,422 @@
+use alloy_primitives::{Address, U256};
+use clickhouse::Row;
+use redefined::Redefined;
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    db::{mev_block::MevBlockWithClassified, redefined_types::primitives::*},
+    express_lane::{ExpressLaneBid, ExpressLaneBidRedefined, ExpressLaneMetaData},
+    implement_table_value_codecs_with_zc,
+    serde_utils::{addresss, option_addresss, option_u256},
+};
+
+/// A resolved express lane auction round along with every transfer of
+/// control that happened during it.
+#[derive(Debug, Default, Row, PartialEq, Eq, Clone, Serialize, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct ExpressLaneRound {
+    pub round:                 u64,
+    pub round_start_timestamp: u64,
+    pub round_end_timestamp:   u64,
+    /// Block the auction for this round was resolved in
+    pub resolved_block:        u64,
+    pub bidder:                Option<Address>,
+    /// Controller that won the auction
+    pub controller:            Address,
+    pub bid_price:             Option<U256>, // first price
+    pub price:                 Option<U256>, // second price
+    pub reserve_price:         Option<U256>,
+    /// Transfers of control during the round, in log order
+    pub transfers:             Vec<ExpressLaneTransfer>,
+    pub bids:                  Vec<ExpressLaneBid>,
+    /// Round schedule of the auction when the round was stored
+    pub timing:                RoundTimingInfo,
+}
+
+implement_table_value_codecs_with_zc!(ExpressLaneRoundRedefined);
+
+impl ExpressLaneRound {
+    /// Whether the given timestamp falls inside of this round
+    pub fn contains_timestamp(&self, timestamp: u64) -> bool {
+        self.round_start_timestamp <= timestamp && timestamp <= self.round_end_timestamp
+    }
+
+    /// The controller of the express lane as of the given timestamp, taking
+    /// into account any transfers of control made during the round.
+    pub fn controller_at(&self, timestamp: u64) -> Address {
+        self.transfers
+            .iter()
+            .rev()
+            .find(|transfer| transfer.start_timestamp <= timestamp)
+            .map(|transfer| transfer.new_controller)
+            .unwrap_or(self.controller)
+    }
+
+    /// Chain of custody of the express lane over the round, starting with the
+    /// controller that won the auction. Periods are contiguous and ordered.
+    pub fn control_periods(&self) -> Vec<ExpressLaneControlPeriod> {
+        let mut periods = vec![ExpressLaneControlPeriod {
+            controller:      self.controller,
+            start_timestamp: self.round_start_timestamp,
+            end_timestamp:   self.round_end_timestamp,
+            transfer:        None,
+        }];
+
+        for transfer in &self.transfers {
+            let last = periods.last_mut().unwrap();
+            // a transfer that takes effect immediately replaces the current holder
+            if transfer.start_timestamp <= last.start_timestamp {
+                periods.pop();
+            } else {
+                last.end_timestamp = transfer.start_timestamp - 1;
+            }
+
+            periods.push(ExpressLaneControlPeriod {
+                controller:      transfer.new_controller,
+                start_timestamp: transfer.start_timestamp.max(self.round_start_timestamp),
+                end_timestamp:   self.round_end_timestamp,
+                transfer:        Some(transfer.clone()),
+            });
+        }
+
+        periods
+    }
+
+    /// Whether control changed hands during the round, even if it ended up
+    /// back with the winner of the auction
+    pub fn was_transferred(&self) -> bool {
+        !self.transfers.is_empty()
+    }
+
+    pub fn into_meta_data(self, timestamp: u64) -> ExpressLaneMetaData {
+        ExpressLaneMetaData {
+            round:                 self.round,
+            bidder:                self.bidder,
+            controller:            self.controller_at(timestamp),
+            auction_controller:    self.controller,
+            bid_price:             self.bid_price,
+            price:                 self.price,
+            round_start_timestamp: self.round_start_timestamp,
+            round_end_timestamp:   self.round_end_timestamp,
+            block_number:          self.resolved_block,
+            reserve_price:         self.reserve_price,
+            bids:                  self.bids,
+            transfers:             self
+                .transfers
+                .into_iter()
+                .filter(|transfer| transfer.start_timestamp <= timestamp)
+                .collect(),
+        }
+    }
+}
+
+/// Mirrors the `RoundTimingInfo` struct of the auction contract.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct RoundTimingInfo {
+    pub offset_timestamp:           i64,
+    pub round_duration_seconds:     u64,
+    pub auction_closing_seconds:    u64,
+    pub reserve_submission_seconds: u64,
+}
+
+impl RoundTimingInfo {
+    /// The round that is active at the given timestamp, zero before the
+    /// offset like the contract does.
+    pub fn round_at(&self, timestamp: u64) -> u64 {
+        self.checked_round_at(timestamp).unwrap_or_default()
+    }
+
+    /// The round that is active at the given timestamp, none before the
+    /// offset or if the timing isn't set.
+    pub fn checked_round_at(&self, timestamp: u64) -> Option<u64> {
+        let elapsed = i64::try_from(timestamp)
+            .ok()?
+            .checked_sub(self.offset_timestamp)?;
+
+        u64::try_from(elapsed)
+            .ok()?
+            .checked_div(self.round_duration_seconds)
+    }
+
+    /// Inclusive start and end timestamp of the round.
+    pub fn round_timestamps(&self, round: u64) -> (u64, u64) {
+        let start = (self.offset_timestamp + (round * self.round_duration_seconds) as i64) as u64;
+        (start, start + self.round_duration_seconds - 1)
+    }
+}
+
+/// Sealed bids submitted for a round, as loaded from a dump of the
+/// auctioneer's bid feed. Stored on their own so they survive independently of
+/// when the round itself is ingested.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct ExpressLaneRoundBids {
+    pub round: u64,
+    pub bids:  Vec<ExpressLaneBid>,
+}
+
+implement_table_value_codecs_with_zc!(ExpressLaneRoundBidsRedefined);
+
+/// A `SetExpressLaneController` event emitted after the auction resolved,
+/// i.e a resale or transfer of the express lane rights.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct ExpressLaneTransfer {
+    pub block_number:        u64,
+    pub previous_controller: Address,
+    pub new_controller:      Address,
+    pub transferor:          Address,
+    pub start_timestamp:     u64,
+    pub end_timestamp:       u64,
+}
+
+/// A stretch of a round during which a single address controlled the express
+/// lane.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpressLaneControlPeriod {
+    pub controller:      Address,
+    pub start_timestamp: u64,
+    pub end_timestamp:   u64,
+    /// The transfer that handed control to `controller`, none for the auction
+    /// winner
+    pub transfer:        Option<ExpressLaneTransfer>,
+}
+
+/// Profitability of the express lane over a single auction round, rolled up
+/// from the timeboosted bundles found in the blocks of that round.
+#[derive(Debug, Default, Row, PartialEq, Clone, Serialize, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct ExpressLaneRoundReport {
+    pub round:                   u64,
+    pub round_start_timestamp:   u64,
+    pub round_end_timestamp:     u64,
+    /// First and last block of the round that we have mev data for
+    pub start_block:             u64,
+    pub end_block:               u64,
+    #[serde(with = "option_addresss")]
+    pub bidder:                  Option<Address>,
+    #[serde(with = "addresss")]
+    pub controller:              Address,
+    /// Price paid for the round, in wei
+    #[serde(with = "option_u256")]
+    pub price:                   Option<U256>,
+    pub price_usd:               f64,
+    /// Profit of the timeboosted bundles captured while the auction winner's
+    /// controller held the express lane
+    pub extracted_usd:           f64,
+    /// Profit of the timeboosted bundles captured by buyers of the express
+    /// lane on the secondary market, not counted towards the winner's profit
+    pub secondary_extracted_usd: f64,
+    /// `extracted_usd` net of the price paid for the round
+    pub net_profit_usd:          f64,
+    /// `net_profit_usd / price_usd`, none if nothing was paid for the round
+    pub roi:                     Option<f64>,
+    pub timeboosted_tx_count:    u64,
+    /// Number of timeboosted transactions that were classified as mev
+    pub timeboosted_mev_count:   u64,
+    /// Number of times control was transferred during the round
+    pub transfer_count:          u64,
+}
+
+implement_table_value_codecs_with_zc!(ExpressLaneRoundReportRedefined);
+
+impl ExpressLaneRoundReport {
+    /// Builds the report of `round` from the mev blocks that were built while
+    /// it was active. Blocks from other rounds are ignored.
+    pub fn new(round: &ExpressLaneRound, blocks: &[MevBlockWithClassified]) -> Self {
+        let mut report = Self {
+            round: round.round,
+            round_start_timestamp: round.round_start_timestamp,
+            round_end_timestamp: round.round_end_timestamp,
+            bidder: round.bidder,
+            controller: round.controller,
+            price: round.price,
+            transfer_count: round.transfers.len() as u64,
+            ..Default::default()
+        };
+
+        let mut eth_price = None;
+        for mev_block in blocks
+            .iter()
+            .filter(|mev_block| mev_block.block.express_lane_round == Some(round.round))
+        {
+            let block = &mev_block.block;
+            if report.start_block == 0 || block.block_number < report.start_block {
+                report.start_block = block.block_number;
+            }
+            report.end_block = report.end_block.max(block.block_number);
+            report.timeboosted_tx_count += block.timeboosted_tx_count;
+            report.timeboosted_mev_count += block.timeboosted_tx_mev_count;
+            for bundle in mev_block
+                .mev
+                .iter()
+                .filter(|bundle| bundle.header.timeboosted)
+            {
+                // bundles carry the controller that was active at their block
+                if bundle.header.express_lane_controller == Some(round.controller) {
+                    report.extracted_usd += bundle.header.profit_usd;
+                } else {
+                    report.secondary_extracted_usd += bundle.header.profit_usd;
+                }
+            }
+
+            if eth_price.is_none() && block.eth_price != 0.0 {
+                eth_price = Some(block.eth_price);
+            }
+        }
+
+        report.price_usd = report
+            .price
+            .zip(eth_price)
+            .map(|(price, eth_price)| price.saturating_to::<u128>() as f64 * 1e-18 * eth_price)
+            .unwrap_or_default();
+        report.net_profit_usd = report.extracted_usd - report.price_usd;
+        report.roi = (report.price_usd != 0.0).then(|| report.net_profit_usd / report.price_usd);
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mev::{Bundle, BundleData, BundleHeader, MevBlock};
+
+    fn timeboosted_bundle(controller: Address, profit_usd: f64) -> Bundle {
+        Bundle {
+            header: BundleHeader {
+                timeboosted: true,
+                express_lane_controller: Some(controller),
+                profit_usd,
+                ..Default::default()
+            },
+            data:   BundleData::default(),
+        }
+    }
+
+    #[test]
+    fn test_round_report() {
+        let winner = Address::with_last_byte(1);
+        let buyer = Address::with_last_byte(2);
+        let round = ExpressLaneRound {
+            round: 10,
+            controller: winner,
+            // 0.01 eth
+            price: Some(U256::from(10_000_000_000_000_000u64)),
+            ..Default::default()
+        };
+
+        let block = |block_number, express_lane_round| MevBlock {
+            block_number,
+            express_lane_round,
+            eth_price: 2000.0,
+            timeboosted_tx_count: 2,
+            timeboosted_tx_mev_count: 1,
+            ..Default::default()
+        };
+        let blocks = vec![
+            MevBlockWithClassified {
+                block: block(100, Some(10)),
+                mev:   vec![
+                    timeboosted_bundle(winner, 50.0),
+                    timeboosted_bundle(buyer, 7.0),
+                    Bundle {
+                        header: BundleHeader { profit_usd: 1000.0, ..Default::default() },
+                        data:   BundleData::default(),
+                    },
+                ],
+            },
+            MevBlockWithClassified {
+                block: block(101, Some(10)),
+                mev:   vec![timeboosted_bundle(winner, 10.0)],
+            },
+            // another round
+            MevBlockWithClassified {
+                block: block(102, Some(11)),
+                mev:   vec![timeboosted_bundle(winner, 100.0)],
+            },
+        ];
+
+        let report = ExpressLaneRoundReport::new(&round, &blocks);
+        assert_eq!((report.start_block, report.end_block), (100, 101));
+        assert_eq!(report.extracted_usd, 60.0);
+        assert_eq!(report.secondary_extracted_usd, 7.0);
+        assert_eq!(report.price_usd, 20.0);
+        assert_eq!(report.net_profit_usd, 40.0);
+        assert_eq!(report.roi, Some(2.0));
+        assert_eq!((report.timeboosted_tx_count, report.timeboosted_mev_count), (4, 2));
+
+        // a price that doesn't fit in a u128 doesn't panic
+        let round = ExpressLaneRound { price: Some(U256::MAX), ..round };
+        assert!(ExpressLaneRoundReport::new(&round, &blocks).price_usd > 0.0);
+    }
+
+    #[test]
+    fn test_checked_round_at() {
+        // rounds stored without their timing
+        let timing = RoundTimingInfo::default();
+        assert_eq!(timing.checked_round_at(1_740_000_000), None);
+        assert_eq!(timing.round_at(1_740_000_000), 0);
+
+        let timing = RoundTimingInfo {
+            offset_timestamp: 1_740_000_000,
+            round_duration_seconds: 60,
+            ..Default::default()
+        };
+        assert_eq!(timing.checked_round_at(1_739_999_999), None);
+        assert_eq!(timing.checked_round_at(1_740_000_059), Some(0));
+        assert_eq!(timing.checked_round_at(1_740_000_060), Some(1));
+        assert_eq!(timing.checked_round_at(u64::MAX), None);
+    }
+
+    #[test]
+    fn test_control_periods() {
+        let winner = Address::with_last_byte(1);
+        let buyer = Address::with_last_byte(2);
+        let round = ExpressLaneRound {
+            round: 10,
+            round_start_timestamp: 600,
+            round_end_timestamp: 659,
+            controller: winner,
+            transfers: vec![ExpressLaneTransfer {
+                previous_controller: winner,
+                new_controller: buyer,
+                transferor: winner,
+                start_timestamp: 630,
+                end_timestamp: 659,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let periods = round.control_periods();
+        assert_eq!(periods.len(), 2);
+        assert_eq!((periods[0].controller, periods[0].end_timestamp), (winner, 629));
+        assert_eq!((periods[1].controller, periods[1].start_timestamp), (buyer, 630));
+
+        assert_eq!(round.controller_at(629), winner);
+        assert_eq!(round.controller_at(630), buyer);
+
+        let meta = round.clone().into_meta_data(620);
+        assert!(!meta.is_transferred());
+        assert!(meta.transfers.is_empty());
+        assert!(round.clone().into_meta_data(640).is_transferred());
+
+        // control handed back to the winner is still a transfer
+        let mut round = round;
+        round.transfers.push(ExpressLaneTransfer {
+            previous_controller: buyer,
+            new_controller: winner,
+            transferor: buyer,
+            start_timestamp: 645,
+            end_timestamp: 659,
+            ..Default::default()
+        });
+        assert!(round.was_transferred());
+        let meta = round.into_meta_data(650);
+        assert_eq!(meta.controller, meta.auction_controller);
+        assert!(meta.is_transferred());
+    }
+}
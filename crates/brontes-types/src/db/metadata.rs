@@ -15,7 +15,7 @@ use super::{
 };
 use crate::{
     block_metadata::RelayBlockMetadata,
-    constants::WETH_ADDRESS,
+    chain::chain_config,
     db::{dex::BlockPrice, redefined_types::primitives::*},
     express_lane::ExpressLaneMetaData,
     implement_table_value_codecs_with_zc,
@@ -125,7 +125,10 @@ impl Metadata {
         self.dex_quotes
             .as_ref()
             .and_then(|dex_quotes| {
-                dex_quotes.price_for_block(Pair(WETH_ADDRESS, quote_token), BlockPrice::Average)
+                dex_quotes.price_for_block(
+                    Pair(chain_config().weth_address(), quote_token),
+                    BlockPrice::Average,
+                )
             })
             .unwrap_or(Rational::ZERO)
     }
@@ -14,6 +14,7 @@ pub mod clickhouse;
 pub mod clickhouse_serde;
 pub mod codecs;
 pub mod dex;
+pub mod express_lane;
 pub mod initialized_state;
 pub mod metadata;
 pub mod mev_block;
@@ -58,6 +58,7 @@ impl Serialize for TransactionRoot {
                 self.gas_details.priority_fee,
                 self.gas_details.gas_used,
                 self.gas_details.effective_gas_price,
+                self.gas_details.l1_gas_used,
             ),
         )?;
 
@@ -161,6 +162,20 @@ pub enum ActionKind {
     NewPool,
     PoolConfigUpdate,
     Aggregator,
+    PerpOrder,
+    PositionChange,
+    L1Deposit,
+    RetryableTicket,
+    RetryableRedeem,
+    RetryTx,
+    L2ToL1Tx,
+    Loan,
+    Repayment,
+    Supply,
+    Withdraw,
+    Wrap,
+    Unwrap,
+    Bridge,
     Revert,
 }
 
@@ -182,6 +197,20 @@ impl From<&Action> for ActionKind {
             Action::NewPool(_) => ActionKind::NewPool,
             Action::PoolConfigUpdate(_) => ActionKind::PoolConfigUpdate,
             Action::Aggregator(_) => ActionKind::Aggregator,
+            Action::PerpOrder(_) => ActionKind::PerpOrder,
+            Action::PositionChange(_) => ActionKind::PositionChange,
+            Action::L1Deposit(_) => ActionKind::L1Deposit,
+            Action::RetryableTicket(_) => ActionKind::RetryableTicket,
+            Action::RetryableRedeem(_) => ActionKind::RetryableRedeem,
+            Action::RetryTx(_) => ActionKind::RetryTx,
+            Action::L2ToL1Tx(_) => ActionKind::L2ToL1Tx,
+            Action::Loan(_) => ActionKind::Loan,
+            Action::Repayment(_) => ActionKind::Repayment,
+            Action::Supply(_) => ActionKind::Supply,
+            Action::Withdraw(_) => ActionKind::Withdraw,
+            Action::Wrap(_) => ActionKind::Wrap,
+            Action::Unwrap(_) => ActionKind::Unwrap,
+            Action::Bridge(_) => ActionKind::Bridge,
             Action::Revert => ActionKind::Revert,
         }
     }
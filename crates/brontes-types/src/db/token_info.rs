@@ -11,10 +11,8 @@ use serde::{ser::SerializeStruct, Deserialize, Serialize};
 
 use super::clickhouse_serde::token_info::token_info_des;
 use crate::{
-    constants::{USDC_ADDRESS, USDT_ADDRESS, WETH_ADDRESS},
-    db::redefined_types::primitives::AddressRedefined,
-    implement_table_value_codecs_with_zc,
-    serde_utils::addresss,
+    chain::chain_config, db::redefined_types::primitives::AddressRedefined,
+    implement_table_value_codecs_with_zc, serde_utils::addresss,
 };
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Deserialize, Redefined)]
@@ -31,28 +29,28 @@ impl TokenInfoWithAddress {
     pub fn native_eth() -> Self {
         Self {
             inner:   TokenInfo { decimals: 18, symbol: "ETH".to_string() },
-            address: WETH_ADDRESS,
+            address: chain_config().weth_address(),
         }
     }
 
     pub fn weth() -> Self {
         Self {
             inner:   TokenInfo { decimals: 18, symbol: "WETH".to_string() },
-            address: WETH_ADDRESS,
+            address: chain_config().weth_address(),
         }
     }
 
     pub fn usdt() -> Self {
         Self {
             inner:   TokenInfo { decimals: 6, symbol: "USDT".to_string() },
-            address: USDT_ADDRESS,
+            address: chain_config().usdt_address(),
         }
     }
 
     pub fn usdc() -> Self {
         Self {
             inner:   TokenInfo { decimals: 6, symbol: "USDC".to_string() },
-            address: USDC_ADDRESS,
+            address: chain_config().usdc_address(),
         }
     }
 
@@ -50,6 +50,9 @@ pub struct TxTraceRedefined {
     pub timeboosted:     bool,
     // False if the transaction reverted
     pub is_success:      bool,
+    pub gas_used_for_l1: u128,
+    pub l1_block_number: u64,
+    pub tx_type:         u8,
 }
 
 #[derive(
@@ -2,9 +2,15 @@ use alloy_primitives::Address;
 
 use crate::{
     db::{
-        address_metadata::AddressMetadata, address_to_protocol_info::ProtocolInfo,
-        builder::BuilderInfo, cex::trades::CexTradeMap, dex::DexQuotes, metadata::Metadata,
-        mev_block::MevBlockWithClassified, searcher::SearcherInfo,
+        address_metadata::AddressMetadata,
+        address_to_protocol_info::ProtocolInfo,
+        builder::BuilderInfo,
+        cex::trades::CexTradeMap,
+        dex::DexQuotes,
+        express_lane::{ExpressLaneRound, ExpressLaneRoundBids, ExpressLaneRoundReport},
+        metadata::Metadata,
+        mev_block::MevBlockWithClassified,
+        searcher::SearcherInfo,
         token_info::TokenInfoWithAddress,
     },
     pair::Pair,
@@ -155,4 +161,18 @@ pub trait LibmdbxReader: Send + Sync + Unpin + 'static {
     }
 
     fn load_trace(&self, block_num: u64) -> eyre::Result<Vec<TxTrace>>;
+
+    fn try_fetch_express_lane_round(&self, round: u64) -> eyre::Result<Option<ExpressLaneRound>>;
+
+    /// returns the express lane round that was active at the given block
+    /// timestamp
+    fn try_fetch_express_lane_round_at(
+        &self,
+        timestamp: u64,
+    ) -> eyre::Result<Option<ExpressLaneRound>>;
+
+    fn try_fetch_express_lane_bids(&self, round: u64)
+        -> eyre::Result<Option<ExpressLaneRoundBids>>;
+
+    fn fetch_all_express_lane_reports(&self) -> eyre::Result<Vec<ExpressLaneRoundReport>>;
 }
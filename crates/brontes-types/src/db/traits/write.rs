@@ -3,8 +3,13 @@ use futures::Future;
 
 use crate::{
     db::{
-        address_metadata::AddressMetadata, block_analysis::BlockAnalysis, builder::BuilderInfo,
-        dex::DexQuotes, searcher::SearcherInfo,
+        address_metadata::AddressMetadata,
+        address_to_protocol_info::UniswapV4PoolKey,
+        block_analysis::BlockAnalysis,
+        builder::BuilderInfo,
+        dex::DexQuotes,
+        express_lane::{ExpressLaneRound, ExpressLaneRoundBids, ExpressLaneRoundReport},
+        searcher::SearcherInfo,
     },
     mev::{Bundle, MevBlock},
     normalized_actions::Action,
@@ -110,6 +115,16 @@ pub trait DBWriter: Send + Unpin + 'static {
             .insert_pool(block, address, tokens, curve_lp_token, classifier_name)
     }
 
+    /// attaches the `PoolKey` of a Uniswap V4 pool to its already inserted
+    /// protocol info
+    fn insert_uniswap_v4_pool_key(
+        &self,
+        address: Address,
+        pool_key: UniswapV4PoolKey,
+    ) -> impl Future<Output = eyre::Result<()>> + Send {
+        self.inner().insert_uniswap_v4_pool_key(address, pool_key)
+    }
+
     fn insert_tree(
         &self,
         tree: BlockTree<Action>,
@@ -124,4 +139,25 @@ pub trait DBWriter: Send + Unpin + 'static {
     ) -> impl Future<Output = eyre::Result<()>> + Send {
         self.inner().save_traces(block, traces)
     }
+
+    fn write_express_lane_rounds(
+        &self,
+        rounds: Vec<ExpressLaneRound>,
+    ) -> impl Future<Output = eyre::Result<()>> + Send {
+        self.inner().write_express_lane_rounds(rounds)
+    }
+
+    fn write_express_lane_bids(
+        &self,
+        bids: Vec<ExpressLaneRoundBids>,
+    ) -> impl Future<Output = eyre::Result<()>> + Send {
+        self.inner().write_express_lane_bids(bids)
+    }
+
+    fn write_express_lane_reports(
+        &self,
+        reports: Vec<ExpressLaneRoundReport>,
+    ) -> impl Future<Output = eyre::Result<()>> + Send {
+        self.inner().write_express_lane_reports(reports)
+    }
 }
@@ -8,6 +8,7 @@ use prettytable::{Cell, Row, Table};
 use reth_primitives::B256;
 
 use crate::{
+    chain::chain_config,
     mev::{ArbDetails, AtomicArbType, Bundle, BundleData, CexDex, OptimisticTrade},
     utils::ToFloatNearest,
 };
@@ -1029,6 +1030,86 @@ pub fn display_searcher_tx(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Resu
     Ok(())
 }
 
+pub fn display_timeboost(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Result {
+    let ascii_header = indoc! {r#"
+
+         _______ _                _                     _
+        |__   __(_)              | |                   | |
+           | |   _ _ __ ___   ___| |__   ___   ___  ___| |_
+           | |  | | '_ ` _ \ / _ \ '_ \ / _ \ / _ \/ __| __|
+           | |  | | | | | | |  __/ |_) | (_) | (_) \__ \ |_
+           |_|  |_|_| |_| |_|\___|_.__/ \___/ \___/|___/\__|
+
+    "#};
+
+    let timeboost_data = match &bundle.data {
+        BundleData::Timeboost(data) => data,
+        _ => panic!("Wrong bundle type"),
+    };
+
+    for line in ascii_header.lines() {
+        writeln!(f, "{}", line.bright_magenta())?;
+    }
+
+    // Tx details
+    writeln!(f, "\n{}: \n", "Transaction Details".bold().underline().bright_yellow())?;
+    writeln!(f, "   - Tx Index: {}", bundle.header.tx_index.to_string().bold())?;
+    writeln!(f, "   - EOA: {}", bundle.header.eoa)?;
+
+    match bundle.header.mev_contract {
+        Some(contract) => {
+            writeln!(f, "   - Mev Contract: {}", format_etherscan_address_url(&contract))?;
+        }
+        None => {
+            writeln!(f, "   - Mev Contract: None")?;
+        }
+    }
+
+    writeln!(f, "   - Etherscan: {}", format_etherscan_url(&bundle.header.tx_hash))?;
+    writeln!(f, "   - Strategy: {}", timeboost_data.strategy.to_string().bold())?;
+
+    // Express lane
+    writeln!(f, "\n{}: \n", "Express Lane".bold().underline().bright_yellow())?;
+    writeln!(f, "   - Round: {}", timeboost_data.express_lane_round)?;
+    if let Some(controller) = bundle.header.express_lane_controller {
+        writeln!(f, "   - Controller: {}", format_etherscan_address_url(&controller))?;
+    }
+    if let Some(price_usd) = bundle.header.express_lane_price_usd {
+        writeln!(f, "   - Auction Price (USD): {}", format_bribe(price_usd))?;
+    }
+    if let Some(move_bps) = timeboost_data.cex_price_move_bps {
+        writeln!(f, "   - CEX Price Move: {:.2} bps", move_bps)?;
+    }
+
+    for (victim, swaps) in timeboost_data
+        .victim_tx_hashes
+        .iter()
+        .zip(&timeboost_data.victim_swaps)
+    {
+        writeln!(f, "\n   - Victim: {}", format_etherscan_url(victim))?;
+        for swap in swaps {
+            writeln!(f, "      - {}", swap)?;
+        }
+    }
+
+    writeln!(f, "\n  - {}:", "Swaps".bright_blue())?;
+    for swap in &timeboost_data.swaps {
+        writeln!(f, "      - {}", swap)?;
+    }
+
+    writeln!(f, "  - {}:", "PnL".bright_blue())?;
+
+    writeln!(f, "   - Transaction Profit (USD): {}", format_profit(bundle.header.profit_usd))?;
+    writeln!(f, "   - Bribe (USD): {}", (format_bribe(bundle.header.bribe_usd)).to_string().red())?;
+
+    // Gas Details
+    writeln!(f, "\n{}: \n", "Gas Details".underline().bright_yellow())?;
+
+    timeboost_data.gas_details.pretty_print_with_spaces(f, 8)?;
+
+    Ok(())
+}
+
 // Helper function to format profit values
 fn format_profit(value: f64) -> ColoredString {
     if value < 0.0 {
@@ -1044,30 +1125,14 @@ fn format_bribe(value: f64) -> ColoredString {
     format!("${:.2}", value).red()
 }
 
-#[cfg(not(feature = "arbitrum"))]
 pub fn format_etherscan_url(tx_hash: &FixedBytes<32>) -> String {
-    format!("https://etherscan.io/tx/{:?}", tx_hash)
-        .underline()
-        .to_string()
-}
-
-#[cfg(feature = "arbitrum")]
-pub fn format_etherscan_url(tx_hash: &FixedBytes<32>) -> String {
-    format!("https://arbiscan.io/tx/{:?}", tx_hash)
-        .underline()
-        .to_string()
-}
-
-#[cfg(not(feature = "arbitrum"))]
-pub fn format_etherscan_address_url(tx_hash: &Address) -> String {
-    format!("https://etherscan.io/address/{:?}", tx_hash)
+    format!("{}/tx/{:?}", chain_config().explorer_url(), tx_hash)
         .underline()
         .to_string()
 }
 
-#[cfg(feature = "arbitrum")]
 pub fn format_etherscan_address_url(tx_hash: &Address) -> String {
-    format!("https://arbiscan.io/address/{:?}", tx_hash)
+    format!("{}/address/{:?}", chain_config().explorer_url(), tx_hash)
         .underline()
         .to_string()
 }
@@ -1,13 +1,75 @@
-use alloy_primitives::{Address, U256};
+use alloy_primitives::{Address, Bytes, U256};
+use redefined::Redefined;
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+
+use crate::db::{express_lane::ExpressLaneTransfer, redefined_types::primitives::*};
 
 #[derive(Debug, Clone, Default)]
 pub struct ExpressLaneMetaData {
-  pub round: u64,
-  pub bidder: Option<Address>,
-  pub controller: Address,
-  pub bid_price: Option<U256>, // first price
-  pub price: Option<U256>, // second price
-  pub round_start_timestamp: u64,
-  pub round_end_timestamp: u64,
-  pub block_number: u64,
-}
\ No newline at end of file
+    pub round:                 u64,
+    pub bidder:                Option<Address>,
+    /// Controller of the express lane at the block, after any transfers
+    pub controller:            Address,
+    /// Controller chosen by the winner of the auction
+    pub auction_controller:    Address,
+    pub bid_price:             Option<U256>, // first price
+    pub price:                 Option<U256>, // second price
+    pub round_start_timestamp: u64,
+    pub round_end_timestamp:   u64,
+    pub block_number:          u64,
+    /// Reserve price of the auction at the time the round was resolved
+    pub reserve_price:         Option<U256>,
+    /// All sealed bids submitted to the auctioneer for this round, including
+    /// the losing ones. Empty when no bid dump was loaded.
+    pub bids:                  Vec<ExpressLaneBid>,
+    /// Transfers of control made during the round up to the block
+    pub transfers:             Vec<ExpressLaneTransfer>,
+}
+
+impl ExpressLaneMetaData {
+    /// Whether control of the express lane changed hands during the round
+    /// before this block, even if it was handed back to the auction winner
+    /// since.
+    pub fn is_transferred(&self) -> bool {
+        !self.transfers.is_empty()
+    }
+
+    /// The bid that won the auction for this round, if it is part of the
+    /// loaded bid set.
+    pub fn winning_bid(&self) -> Option<&ExpressLaneBid> {
+        let bidder = self.bidder?;
+        let amount = self.bid_price?;
+
+        self.bids
+            .iter()
+            .find(|bid| bid.bidder == bidder && bid.amount == amount)
+    }
+
+    /// All bids for this round except the winning one.
+    pub fn losing_bids(&self) -> impl Iterator<Item = &ExpressLaneBid> + '_ {
+        let winner = self.winning_bid();
+        self.bids
+            .iter()
+            .filter(move |bid| winner.map(|w| w != *bid).unwrap_or(true))
+    }
+
+    /// Number of distinct bidders that competed for this round.
+    pub fn unique_bidders(&self) -> usize {
+        let mut bidders = self.bids.iter().map(|bid| bid.bidder).collect::<Vec<_>>();
+        bidders.sort_unstable();
+        bidders.dedup();
+        bidders.len()
+    }
+}
+
+/// A sealed bid submitted to the auctioneer for the express lane of a round.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct ExpressLaneBid {
+    pub round:                   u64,
+    pub bidder:                  Address,
+    pub express_lane_controller: Address,
+    pub amount:                  U256,
+    pub signature:               Bytes,
+}
@@ -44,6 +44,7 @@ pub mod utils;
 pub use utils::*;
 pub mod protocol;
 pub use protocol::*;
+pub mod chain;
 pub mod channel_alerts;
 pub use channel_alerts::*;
 pub mod block_metadata;
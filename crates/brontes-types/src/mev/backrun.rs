@@ -130,6 +130,7 @@ impl Serialize for AtomicArb {
             self.gas_details.priority_fee,
             self.gas_details.gas_used,
             self.gas_details.effective_gas_price,
+            self.gas_details.l1_gas_used,
         );
         ser_struct.serialize_field("gas_details", &gas_details)?;
         ser_struct.serialize_field("arb_type", &self.arb_type.to_string())?;
@@ -158,3 +159,24 @@ impl DbRow for AtomicArb {
         "protocols",
     ];
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gas_details_row_has_l1_gas_used() {
+        let arb = AtomicArb {
+            gas_details: GasDetails {
+                gas_used: 100,
+                effective_gas_price: 10,
+                l1_gas_used: 20,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let row = serde_json::to_value(&arb).unwrap();
+        assert_eq!(row["gas_details"], serde_json::json!([null, 0, 100, 10, 20]));
+    }
+}
@@ -63,6 +63,12 @@ pub struct MevBlock {
     pub timeboosted_tx_count:        u64,
     // Number of timeboosted tx that are MEV
     pub timeboosted_tx_mev_count:    u64,
+    // Express lane auction round the block was built in
+    pub express_lane_round:          Option<u64>,
+    // Fee breakdown of the sequencer, set in place of the builder & proposer
+    // PnL on Arbitrum
+    #[redefined(same_fields)]
+    pub sequencer_revenue:           Option<SequencerRevenue>,
     pub possible_mev:                PossibleMevCollection,
 }
 
@@ -118,6 +124,14 @@ impl fmt::Display for MevBlock {
                 * 100.0
         )?;
 
+        if let Some(sequencer_revenue) = &self.sequencer_revenue {
+            writeln!(f, "\n{}", "Sequencer Revenue:".bold().red().underline())?;
+            write!(f, "{}", sequencer_revenue)?;
+            writeln!(f, "\n{}: {}", "Missed Mev".bold().red().underline(), self.possible_mev)?;
+
+            return Ok(())
+        }
+
         // Builder PnL
         writeln!(f, "\n{}", "Builder PnL:".bold().red().underline())?;
 
@@ -182,6 +196,90 @@ impl fmt::Display for MevBlock {
     }
 }
 
+/// Where the fees of an Arbitrum block went.
+///
+/// Arbitrum has no builder or proposer, and none of the base fee is burned: the
+/// part of the execution fee up to the minimum base fee goes to the infra fee
+/// account and the rest is collected by the network fee account.
+#[derive(Debug, Deserialize, PartialEq, Serialize, Row, Clone, Default, rDeser, rSer, Archive)]
+pub struct SequencerRevenue {
+    /// Base fee paid for L2 execution gas, the amount mainnet would burn
+    pub base_fee_paid:         u128,
+    /// Execution fees collected by the network fee account
+    pub network_fee_collected: u128,
+    /// Execution fees collected by the infra fee account
+    pub infra_fee_collected:   u128,
+    /// L1 data fees collected in the block. The batch poster is reimbursed out
+    /// of these once the batch lands on L1, which isn't visible at the block
+    /// level, so this is the most the block can add to the L1 surplus.
+    pub l1_surplus_fee:        u128,
+    /// Express lane auction price attributed to the block, pro rata over the
+    /// round
+    pub express_lane_proceeds: u128,
+    /// Fees paid by transactions that are part of a MEV bundle
+    pub mev_fees_paid:         u128,
+    /// Share of the block's fees paid by MEV transactions
+    pub mev_fee_share:         f64,
+    /// Fees and express lane proceeds in USD
+    pub total_revenue_usd:     f64,
+}
+
+self_convert_redefined!(SequencerRevenue);
+
+impl SequencerRevenue {
+    pub fn total_fees(&self) -> u128 {
+        self.network_fee_collected + self.infra_fee_collected + self.l1_surplus_fee
+    }
+}
+
+impl fmt::Display for SequencerRevenue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "  - {} {:.6} ETH (none burned)",
+            "Base Fee Paid:".bold(),
+            self.base_fee_paid as f64 * 1e-18
+        )?;
+        writeln!(
+            f,
+            "  - {} {:.6} ETH",
+            "Network Fee Account:".bold(),
+            self.network_fee_collected as f64 * 1e-18
+        )?;
+        writeln!(
+            f,
+            "  - {} {:.6} ETH",
+            "Infra Fee Account:".bold(),
+            self.infra_fee_collected as f64 * 1e-18
+        )?;
+        writeln!(
+            f,
+            "  - {} {:.6} ETH",
+            "L1 Surplus Fee:".bold(),
+            self.l1_surplus_fee as f64 * 1e-18
+        )?;
+        writeln!(
+            f,
+            "  - {} {:.6} ETH",
+            "Express Lane Proceeds:".bold(),
+            self.express_lane_proceeds as f64 * 1e-18
+        )?;
+        writeln!(
+            f,
+            "  - {} {:.6} ETH ({:.2}% of fees)",
+            "MEV Fees Paid:".bold(),
+            self.mev_fees_paid as f64 * 1e-18,
+            self.mev_fee_share * 100.0
+        )?;
+        writeln!(
+            f,
+            "  - {} {}",
+            "Total Revenue (USD):".purple().bold(),
+            format_profit(self.total_revenue_usd)
+        )
+    }
+}
+
 // Helper function to format profit values
 fn format_profit(value: f64) -> String {
     if value < 0.0 {
@@ -205,6 +303,7 @@ pub struct MevCount {
     pub atomic_backrun_count: Option<u64>,
     pub liquidation_count:    Option<u64>,
     pub searcher_tx_count:    Option<u64>,
+    pub timeboost_count:      Option<u64>,
 }
 
 impl MevCount {
@@ -234,6 +333,9 @@ impl MevCount {
             MevType::SearcherTx => {
                 self.searcher_tx_count = Some(self.searcher_tx_count.unwrap_or_default().add(1))
             }
+            MevType::Timeboost => {
+                self.timeboost_count = Some(self.timeboost_count.unwrap_or_default().add(1))
+            }
             MevType::JitCexDex => {
                 self.jit_cex_dex_count = Some(self.jit_cex_dex_count.unwrap_or_default().add(1))
             }
@@ -274,6 +376,9 @@ impl fmt::Display for MevCount {
         if let Some(count) = self.searcher_tx_count {
             writeln!(f, "    - Searcher TXs: {}", count.to_string().bold())?;
         }
+        if let Some(count) = self.timeboost_count {
+            writeln!(f, "    - Timeboost: {}", count.to_string().bold())?;
+        }
 
         Ok(())
     }
@@ -364,7 +469,7 @@ impl Serialize for MevBlock {
     where
         S: serde::Serializer,
     {
-        let mut ser_struct = serializer.serialize_struct("MevBlock", 33)?;
+        let mut ser_struct = serializer.serialize_struct("MevBlock", 34)?;
 
         ser_struct.serialize_field("block_hash", &format!("{:?}", self.block_hash))?;
         ser_struct.serialize_field("block_number", &self.block_number)?;
@@ -435,12 +540,81 @@ impl Serialize for MevBlock {
         ser_struct.serialize_field("timeboosted_profit_usd", &self.timeboosted_profit_usd)?;
         ser_struct.serialize_field("timeboosted_tx_count", &self.timeboosted_tx_count)?;
         ser_struct.serialize_field("timeboosted_tx_mev_count", &self.timeboosted_tx_mev_count)?;
+        ser_struct.serialize_field("express_lane_round", &self.express_lane_round)?;
+
+        // nested so that blocks without a sequencer summary store no rows
+        ser_struct.serialize_field(
+            "sequencer_revenue.base_fee_paid",
+            &self
+                .sequencer_revenue
+                .iter()
+                .map(|r| r.base_fee_paid)
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "sequencer_revenue.network_fee_collected",
+            &self
+                .sequencer_revenue
+                .iter()
+                .map(|r| r.network_fee_collected)
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "sequencer_revenue.infra_fee_collected",
+            &self
+                .sequencer_revenue
+                .iter()
+                .map(|r| r.infra_fee_collected)
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "sequencer_revenue.l1_surplus_fee",
+            &self
+                .sequencer_revenue
+                .iter()
+                .map(|r| r.l1_surplus_fee)
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "sequencer_revenue.express_lane_proceeds",
+            &self
+                .sequencer_revenue
+                .iter()
+                .map(|r| r.express_lane_proceeds)
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "sequencer_revenue.mev_fees_paid",
+            &self
+                .sequencer_revenue
+                .iter()
+                .map(|r| r.mev_fees_paid)
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "sequencer_revenue.mev_fee_share",
+            &self
+                .sequencer_revenue
+                .iter()
+                .map(|r| r.mev_fee_share)
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "sequencer_revenue.total_revenue_usd",
+            &self
+                .sequencer_revenue
+                .iter()
+                .map(|r| r.total_revenue_usd)
+                .collect::<Vec<_>>(),
+        )?;
+
         let mut possible_tx_hashes = Vec::new();
         let mut possible_tx_idxes = Vec::new();
         let mut possible_gas_coinbases = Vec::new();
         let mut possible_priority_fees = Vec::new();
         let mut possible_gas_useds = Vec::new();
         let mut possible_effective_gas_prices = Vec::new();
+        let mut possible_l1_gas_useds = Vec::new();
         let mut possible_is_privates = Vec::new();
         let mut possible_trigger_coinbases = Vec::new();
         let mut possible_high_priority_fee = Vec::new();
@@ -456,6 +630,7 @@ impl Serialize for MevBlock {
                         tx.gas_details.priority_fee,
                         tx.gas_details.gas_used,
                         tx.gas_details.effective_gas_price,
+                        tx.gas_details.l1_gas_used,
                     ),
                     (
                         tx.triggers.is_private,
@@ -468,7 +643,7 @@ impl Serialize for MevBlock {
                 |(
                     hash,
                     idx,
-                    (gas_coinbase, priority_fee, gas_used, effective_gas_price),
+                    (gas_coinbase, priority_fee, gas_used, effective_gas_price, l1_gas_used),
                     (is_private, trigger_coinbase, high_priority_fee),
                 )| {
                     possible_tx_hashes.push(hash);
@@ -477,6 +652,7 @@ impl Serialize for MevBlock {
                     possible_priority_fees.push(priority_fee);
                     possible_gas_useds.push(gas_used);
                     possible_effective_gas_prices.push(effective_gas_price);
+                    possible_l1_gas_useds.push(l1_gas_used);
                     possible_is_privates.push(is_private);
                     possible_trigger_coinbases.push(trigger_coinbase);
                     possible_high_priority_fee.push(high_priority_fee);
@@ -496,6 +672,8 @@ impl Serialize for MevBlock {
             "possible_mev.gas_details.effective_gas_price",
             &possible_effective_gas_prices,
         )?;
+        ser_struct
+            .serialize_field("possible_mev.gas_details.l1_gas_used", &possible_l1_gas_useds)?;
         ser_struct.serialize_field("possible_mev.triggers.is_private", &possible_is_privates)?;
         ser_struct.serialize_field(
             "possible_mev.triggers.coinbase_transfer",
@@ -545,12 +723,22 @@ impl DbRow for MevBlock {
         "timeboosted_profit_usd",
         "timeboosted_tx_count",
         "timeboosted_tx_mev_count",
+        "express_lane_round",
+        "sequencer_revenue.base_fee_paid",
+        "sequencer_revenue.network_fee_collected",
+        "sequencer_revenue.infra_fee_collected",
+        "sequencer_revenue.l1_surplus_fee",
+        "sequencer_revenue.express_lane_proceeds",
+        "sequencer_revenue.mev_fees_paid",
+        "sequencer_revenue.mev_fee_share",
+        "sequencer_revenue.total_revenue_usd",
         "possible_mev.tx_hash",
         "possible_mev.tx_idx",
         "possible_mev.gas_details.coinbase_transfer",
         "possible_mev.gas_details.priority_fee",
         "possible_mev.gas_details.gas_used",
         "possible_mev.gas_details.effective_gas_price",
+        "possible_mev.gas_details.l1_gas_used",
         "possible_mev.triggers.is_private",
         "possible_mev.triggers.coinbase_transfer",
         "possible_mev.triggers.high_priority_fee",
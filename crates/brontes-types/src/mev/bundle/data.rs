@@ -31,6 +31,7 @@ pub enum BundleData {
     CexDexQuote(CexDexQuote),
     CexDex(CexDex),
     Liquidation(Liquidation),
+    Timeboost(Timeboost),
     Unknown(SearcherTx),
 }
 
@@ -50,6 +51,7 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.mev_type(),
             BundleData::CexDexQuote(m) => m.mev_type(),
             BundleData::Liquidation(m) => m.mev_type(),
+            BundleData::Timeboost(m) => m.mev_type(),
             BundleData::Unknown(m) => m.mev_type(),
         }
     }
@@ -63,6 +65,7 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.total_gas_paid(),
             BundleData::CexDexQuote(m) => m.total_gas_paid(),
             BundleData::Liquidation(m) => m.total_gas_paid(),
+            BundleData::Timeboost(m) => m.total_gas_paid(),
             BundleData::Unknown(s) => s.total_gas_paid(),
         }
     }
@@ -76,6 +79,7 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.total_priority_fee_paid(base_fee),
             BundleData::CexDexQuote(m) => m.total_priority_fee_paid(base_fee),
             BundleData::Liquidation(m) => m.total_priority_fee_paid(base_fee),
+            BundleData::Timeboost(m) => m.total_priority_fee_paid(base_fee),
             BundleData::Unknown(s) => s.total_priority_fee_paid(base_fee),
         }
     }
@@ -89,6 +93,7 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.bribe(),
             BundleData::CexDexQuote(m) => m.bribe(),
             BundleData::Liquidation(m) => m.bribe(),
+            BundleData::Timeboost(m) => m.bribe(),
             BundleData::Unknown(s) => s.bribe(),
         }
     }
@@ -102,6 +107,7 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.mev_transaction_hashes(),
             BundleData::CexDexQuote(m) => m.mev_transaction_hashes(),
             BundleData::Liquidation(m) => m.mev_transaction_hashes(),
+            BundleData::Timeboost(m) => m.mev_transaction_hashes(),
             BundleData::Unknown(s) => s.mev_transaction_hashes(),
         }
     }
@@ -115,6 +121,7 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.protocols(),
             BundleData::CexDexQuote(m) => m.protocols(),
             BundleData::Liquidation(m) => m.protocols(),
+            BundleData::Timeboost(m) => m.protocols(),
             BundleData::Unknown(s) => s.protocols(),
         }
     }
@@ -162,6 +169,12 @@ impl From<Liquidation> for BundleData {
     }
 }
 
+impl From<Timeboost> for BundleData {
+    fn from(value: Timeboost) -> Self {
+        Self::Timeboost(value)
+    }
+}
+
 impl Serialize for BundleData {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -175,6 +188,7 @@ impl Serialize for BundleData {
             BundleData::CexDex(cex_dex) => cex_dex.serialize(serializer),
             BundleData::CexDexQuote(cex_dex) => cex_dex.serialize(serializer),
             BundleData::Liquidation(liquidation) => liquidation.serialize(serializer),
+            BundleData::Timeboost(timeboost) => timeboost.serialize(serializer),
             BundleData::Unknown(s) => s.serialize(serializer),
         }
     }
@@ -190,6 +204,7 @@ impl InsertRow for BundleData {
             BundleData::CexDex(cex_dex) => cex_dex.get_column_names(),
             BundleData::CexDexQuote(cex_dex) => cex_dex.get_column_names(),
             BundleData::Liquidation(liquidation) => liquidation.get_column_names(),
+            BundleData::Timeboost(timeboost) => timeboost.get_column_names(),
             BundleData::Unknown(s) => s.get_column_names(),
         }
     }
@@ -68,6 +68,7 @@ impl fmt::Display for Bundle {
             MevType::Liquidation => display_liquidation(self, f)?,
             MevType::JitSandwich => display_jit_liquidity_sandwich(self, f)?,
             MevType::SearcherTx => display_searcher_tx(self, f)?,
+            MevType::Timeboost => display_timeboost(self, f)?,
             MevType::Unknown => (),
         }
 
@@ -102,6 +103,7 @@ pub enum MevType {
     Liquidation,
     AtomicArb,
     SearcherTx,
+    Timeboost,
     #[default]
     Unknown,
 }
@@ -115,6 +117,7 @@ impl MevType {
             | MevType::AtomicArb
             | MevType::Liquidation
             | MevType::SearcherTx
+            | MevType::Timeboost
             | MevType::Unknown => false,
             MevType::CexDexRfq
             | MevType::CexDexTrades
@@ -134,6 +137,7 @@ impl MevType {
             MevType::Sandwich => "sandwich",
             MevType::JitSandwich => "jit-sandwich",
             MevType::SearcherTx => "searcher-tx",
+            MevType::Timeboost => "timeboost",
             MevType::Liquidation => "liquidation",
             MevType::Unknown => "header",
         }
@@ -154,6 +158,7 @@ impl From<String> for MevType {
             "JitSandwich" => MevType::JitSandwich,
             "AtomicArb" => MevType::AtomicArb,
             "SearcherTx" => MevType::SearcherTx,
+            "Timeboost" => MevType::Timeboost,
             _ => MevType::Unknown,
         }
     }
@@ -613,6 +613,7 @@ impl Serialize for CexDex {
             self.gas_details.priority_fee,
             self.gas_details.gas_used,
             self.gas_details.effective_gas_price,
+            self.gas_details.l1_gas_used,
         );
 
         ser_struct.serialize_field("gas_details", &gas_details)?;
@@ -104,6 +104,7 @@ impl Serialize for CexDexQuote {
                 self.gas_details.priority_fee,
                 self.gas_details.gas_used,
                 self.gas_details.effective_gas_price,
+                self.gas_details.l1_gas_used,
             ),
         )?;
         ser_struct.serialize_field("profit_usd", &self.profit_usd)?;
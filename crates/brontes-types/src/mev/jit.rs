@@ -83,7 +83,7 @@ impl Serialize for JitLiquidity {
     where
         S: Serializer,
     {
-        let mut ser_struct = serializer.serialize_struct("JitLiquidity", 31)?;
+        let mut ser_struct = serializer.serialize_struct("JitLiquidity", 32)?;
 
         // frontrun mint
         ser_struct.serialize_field(
@@ -110,6 +110,7 @@ impl Serialize for JitLiquidity {
             self.frontrun_mint_gas_details.priority_fee,
             self.frontrun_mint_gas_details.gas_used,
             self.frontrun_mint_gas_details.effective_gas_price,
+            self.frontrun_mint_gas_details.l1_gas_used,
         );
 
         ser_struct.serialize_field("frontrun_mint_gas_details", &(frontrun_mint_gas_details))?;
@@ -146,6 +147,8 @@ impl Serialize for JitLiquidity {
             "victim_gas_details.effective_gas_price",
             &victim_gas_details.effective_gas_price,
         )?;
+        ser_struct
+            .serialize_field("victim_gas_details.l1_gas_used", &victim_gas_details.l1_gas_used)?;
 
         // backrun burn
         ser_struct
@@ -169,6 +172,7 @@ impl Serialize for JitLiquidity {
             self.backrun_burn_gas_details.priority_fee,
             self.backrun_burn_gas_details.gas_used,
             self.backrun_burn_gas_details.effective_gas_price,
+            self.backrun_burn_gas_details.l1_gas_used,
         );
 
         ser_struct.serialize_field("backrun_burn_gas_details", &(backrun_burn_gas_details))?;
@@ -202,6 +206,7 @@ impl DbRow for JitLiquidity {
         "victim_gas_details.priority_fee",
         "victim_gas_details.gas_used",
         "victim_gas_details.effective_gas_price",
+        "victim_gas_details.l1_gas_used",
         "backrun_burn_tx_hash",
         "backrun_burns.trace_idx",
         "backrun_burns.from",
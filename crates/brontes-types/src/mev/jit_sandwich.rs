@@ -174,18 +174,18 @@ pub fn compose_sandwich_jit(mev: Vec<Bundle>) -> Option<Bundle> {
 
     // Create new classified MEV data
     let new_classified = BundleHeader {
-        tx_index:              classified_sandwich.tx_index,
-        tx_hash:               *sandwich.frontrun_tx_hash.first().unwrap_or_default(),
-        mev_type:              MevType::JitSandwich,
-        fund:                  classified_sandwich.fund,
-        block_number:          classified_sandwich.block_number,
-        eoa:                   jit_classified.eoa,
-        mev_contract:          classified_sandwich.mev_contract,
-        profit_usd:            classified_sandwich.profit_usd,
-        balance_deltas:        classified_sandwich.balance_deltas,
-        bribe_usd:             classified_sandwich.bribe_usd,
-        no_pricing_calculated: classified_sandwich.no_pricing_calculated,
-        timeboosted:           classified_sandwich.timeboosted,
+        tx_index:                classified_sandwich.tx_index,
+        tx_hash:                 *sandwich.frontrun_tx_hash.first().unwrap_or_default(),
+        mev_type:                MevType::JitSandwich,
+        fund:                    classified_sandwich.fund,
+        block_number:            classified_sandwich.block_number,
+        eoa:                     jit_classified.eoa,
+        mev_contract:            classified_sandwich.mev_contract,
+        profit_usd:              classified_sandwich.profit_usd,
+        balance_deltas:          classified_sandwich.balance_deltas,
+        bribe_usd:               classified_sandwich.bribe_usd,
+        no_pricing_calculated:   classified_sandwich.no_pricing_calculated,
+        timeboosted:             classified_sandwich.timeboosted,
         express_lane_controller: classified_sandwich.express_lane_controller,
         express_lane_price:      classified_sandwich.express_lane_price,
         express_lane_round:      classified_sandwich.express_lane_round,
@@ -200,7 +200,7 @@ impl Serialize for JitLiquiditySandwich {
     where
         S: Serializer,
     {
-        let mut ser_struct = serializer.serialize_struct("JitLiquiditySandwich", 35)?;
+        let mut ser_struct = serializer.serialize_struct("JitLiquiditySandwich", 38)?;
         ser_struct.serialize_field("block_number", &self.block_number)?;
 
         // frontruns
@@ -254,6 +254,10 @@ impl Serialize for JitLiquiditySandwich {
             "frontrun_gas_details.effective_gas_price",
             &frontrun_gas_details.effective_gas_price,
         )?;
+        ser_struct.serialize_field(
+            "frontrun_gas_details.l1_gas_used",
+            &frontrun_gas_details.l1_gas_used,
+        )?;
 
         // victims
         let victim_swaps: ClickhouseDoubleVecNormalizedSwap =
@@ -284,6 +288,8 @@ impl Serialize for JitLiquiditySandwich {
             "victim_gas_details.effective_gas_price",
             &victim_gas_details.effective_gas_price,
         )?;
+        ser_struct
+            .serialize_field("victim_gas_details.l1_gas_used", &victim_gas_details.l1_gas_used)?;
 
         // backrun
         let fixed_str_backrun_tx_hash = format!("{:?}", &self.backrun_tx_hash);
@@ -348,6 +354,10 @@ impl Serialize for JitLiquiditySandwich {
             "backrun_gas_details.effective_gas_price",
             &vec![self.backrun_gas_details.effective_gas_price],
         )?;
+        ser_struct.serialize_field(
+            "backrun_gas_details.l1_gas_used",
+            &vec![self.backrun_gas_details.l1_gas_used],
+        )?;
 
         ser_struct.end()
     }
@@ -378,6 +388,7 @@ impl DbRow for JitLiquiditySandwich {
         "frontrun_gas_details.priority_fee",
         "frontrun_gas_details.gas_used",
         "frontrun_gas_details.effective_gas_price",
+        "frontrun_gas_details.l1_gas_used",
         "victim_swaps.tx_hash",
         "victim_swaps.trace_idx",
         "victim_swaps.from",
@@ -392,6 +403,7 @@ impl DbRow for JitLiquiditySandwich {
         "victim_gas_details.priority_fee",
         "victim_gas_details.gas_used",
         "victim_gas_details.effective_gas_price",
+        "victim_gas_details.l1_gas_used",
         "backrun_tx_hash",
         "backrun_swaps.tx_hash",
         "backrun_swaps.trace_idx",
@@ -414,5 +426,6 @@ impl DbRow for JitLiquiditySandwich {
         "backrun_gas_details.priority_fee",
         "backrun_gas_details.gas_used",
         "backrun_gas_details.effective_gas_price",
+        "backrun_gas_details.l1_gas_used",
     ];
 }
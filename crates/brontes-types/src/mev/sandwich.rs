@@ -166,7 +166,7 @@ impl Serialize for Sandwich {
     where
         S: Serializer,
     {
-        let mut ser_struct = serializer.serialize_struct("Sandwich", 35)?;
+        let mut ser_struct = serializer.serialize_struct("Sandwich", 38)?;
         ser_struct.serialize_field("block_number", &self.block_number)?;
 
         // frontrun
@@ -207,6 +207,10 @@ impl Serialize for Sandwich {
             "frontrun_gas_details.effective_gas_price",
             &frontrun_gas_details.effective_gas_price,
         )?;
+        ser_struct.serialize_field(
+            "frontrun_gas_details.l1_gas_used",
+            &frontrun_gas_details.l1_gas_used,
+        )?;
 
         // victims
         let victim_swaps: ClickhouseDoubleVecNormalizedSwap =
@@ -237,6 +241,8 @@ impl Serialize for Sandwich {
             "victim_gas_details.effective_gas_price",
             &victim_gas_details.effective_gas_price,
         )?;
+        ser_struct
+            .serialize_field("victim_gas_details.l1_gas_used", &victim_gas_details.l1_gas_used)?;
 
         // backrun
         let fixed_str_backrun_tx_hash = format!("{:?}", &self.backrun_tx_hash);
@@ -281,6 +287,10 @@ impl Serialize for Sandwich {
             "backrun_gas_details.effective_gas_price",
             &vec![self.backrun_gas_details.effective_gas_price],
         )?;
+        ser_struct.serialize_field(
+            "backrun_gas_details.l1_gas_used",
+            &vec![self.backrun_gas_details.l1_gas_used],
+        )?;
 
         ser_struct.end()
     }
@@ -304,6 +314,7 @@ impl DbRow for Sandwich {
         "frontrun_gas_details.priority_fee",
         "frontrun_gas_details.gas_used",
         "frontrun_gas_details.effective_gas_price",
+        "frontrun_gas_details.l1_gas_used",
         "victim_swaps.tx_hash",
         "victim_swaps.trace_idx",
         "victim_swaps.from",
@@ -318,6 +329,7 @@ impl DbRow for Sandwich {
         "victim_gas_details.priority_fee",
         "victim_gas_details.gas_used",
         "victim_gas_details.effective_gas_price",
+        "victim_gas_details.l1_gas_used",
         "backrun_tx_hash",
         "backrun_swaps.tx_hash",
         "backrun_swaps.trace_idx",
@@ -333,5 +345,6 @@ impl DbRow for Sandwich {
         "backrun_gas_details.priority_fee",
         "backrun_gas_details.gas_used",
         "backrun_gas_details.effective_gas_price",
+        "backrun_gas_details.l1_gas_used",
     ];
 }
@@ -82,6 +82,7 @@ impl Serialize for SearcherTx {
             self.gas_details.priority_fee,
             self.gas_details.gas_used,
             self.gas_details.effective_gas_price,
+            self.gas_details.l1_gas_used,
         );
 
         ser_struct.serialize_field("gas_details", &(gas_details))?;
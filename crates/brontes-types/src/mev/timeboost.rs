@@ -0,0 +1,197 @@
+use std::{
+    fmt,
+    fmt::{Debug, Display},
+};
+
+use ::clickhouse::DbRow;
+use ::serde::ser::{SerializeStruct, Serializer};
+use ahash::HashSet;
+use redefined::{self_convert_redefined, Redefined};
+use reth_primitives::B256;
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use super::{Mev, MevType};
+use crate::{
+    db::redefined_types::primitives::*,
+    normalized_actions::{
+        ClickhouseDoubleVecNormalizedSwap, ClickhouseVecNormalizedSwap, NormalizedSwap,
+        NormalizedSwapRedefined,
+    },
+    GasDetails, Protocol,
+};
+
+/// A timeboosted transaction whose profit depends on the express lane's head
+/// start over the regular sequencer queue.
+///
+/// The header of the bundle carries the express lane round, controller and
+/// the second price paid at auction, so the profit of the strategy can be
+/// compared against the cost of the lane.
+#[serde_as]
+#[derive(Debug, Deserialize, PartialEq, Clone, Default, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct Timeboost {
+    pub tx_hash:            B256,
+    pub block_number:       u64,
+    pub express_lane_round: u64,
+    #[redefined(same_fields)]
+    pub strategy:           TimeboostStrategy,
+    pub swaps:              Vec<NormalizedSwap>,
+    /// Non boosted transactions that traded on the same pools earlier in the
+    /// block. Only set for [`TimeboostStrategy::Backrun`].
+    pub victim_tx_hashes:   Vec<B256>,
+    pub victim_swaps:       Vec<Vec<NormalizedSwap>>,
+    /// Largest move of the CEX mid price, in basis points, over the pairs
+    /// traded ahead of the block. Only set for
+    /// [`TimeboostStrategy::CexPriceMove`].
+    pub cex_price_move_bps: Option<f64>,
+    #[redefined(same_fields)]
+    pub gas_details:        GasDetails,
+    pub profit_usd:         f64,
+}
+
+/// The express lane strategies the timeboost inspector looks for
+#[derive(
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    Serialize,
+    Deserialize,
+    rSerialize,
+    rDeserialize,
+    Archive,
+    Copy,
+)]
+pub enum TimeboostStrategy {
+    /// Boosted transaction trading against pools that a non boosted swap
+    /// moved earlier in the same block
+    #[default]
+    Backrun,
+    /// Boosted transaction inserted right after the CEX price of the traded
+    /// pair moved
+    CexPriceMove,
+}
+
+impl Display for TimeboostStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeboostStrategy::Backrun => write!(f, "Backrun"),
+            TimeboostStrategy::CexPriceMove => write!(f, "CexPriceMove"),
+        }
+    }
+}
+
+self_convert_redefined!(TimeboostStrategy);
+
+impl Mev for Timeboost {
+    fn mev_type(&self) -> MevType {
+        MevType::Timeboost
+    }
+
+    fn total_gas_paid(&self) -> u128 {
+        self.gas_details.gas_paid()
+    }
+
+    fn total_priority_fee_paid(&self, base_fee: u128) -> u128 {
+        self.gas_details.priority_fee_paid(base_fee)
+    }
+
+    fn bribe(&self) -> u128 {
+        self.gas_details.coinbase_transfer.unwrap_or(0)
+    }
+
+    fn mev_transaction_hashes(&self) -> Vec<B256> {
+        vec![self.tx_hash]
+    }
+
+    fn protocols(&self) -> HashSet<Protocol> {
+        self.swaps.iter().map(|swap| swap.protocol).collect()
+    }
+}
+
+impl Serialize for Timeboost {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut ser_struct = serializer.serialize_struct("Timeboost", 24)?;
+        ser_struct.serialize_field("tx_hash", &format!("{:?}", self.tx_hash))?;
+        ser_struct.serialize_field("block_number", &self.block_number)?;
+        ser_struct.serialize_field("express_lane_round", &self.express_lane_round)?;
+        ser_struct.serialize_field("strategy", &self.strategy.to_string())?;
+
+        let swaps: ClickhouseVecNormalizedSwap = self
+            .swaps
+            .clone()
+            .try_into()
+            .map_err(serde::ser::Error::custom)?;
+        ser_struct.serialize_field("swaps.trace_idx", &swaps.trace_index)?;
+        ser_struct.serialize_field("swaps.from", &swaps.from)?;
+        ser_struct.serialize_field("swaps.recipient", &swaps.recipient)?;
+        ser_struct.serialize_field("swaps.pool", &swaps.pool)?;
+        ser_struct.serialize_field("swaps.token_in", &swaps.token_in)?;
+        ser_struct.serialize_field("swaps.token_out", &swaps.token_out)?;
+        ser_struct.serialize_field("swaps.amount_in", &swaps.amount_in)?;
+        ser_struct.serialize_field("swaps.amount_out", &swaps.amount_out)?;
+
+        let victim_swaps: ClickhouseDoubleVecNormalizedSwap =
+            (self.victim_tx_hashes.clone(), self.victim_swaps.clone())
+                .try_into()
+                .map_err(serde::ser::Error::custom)?;
+        ser_struct.serialize_field("victim_swaps.tx_hash", &victim_swaps.tx_hash)?;
+        ser_struct.serialize_field("victim_swaps.trace_idx", &victim_swaps.trace_index)?;
+        ser_struct.serialize_field("victim_swaps.from", &victim_swaps.from)?;
+        ser_struct.serialize_field("victim_swaps.recipient", &victim_swaps.recipient)?;
+        ser_struct.serialize_field("victim_swaps.pool", &victim_swaps.pool)?;
+        ser_struct.serialize_field("victim_swaps.token_in", &victim_swaps.token_in)?;
+        ser_struct.serialize_field("victim_swaps.token_out", &victim_swaps.token_out)?;
+        ser_struct.serialize_field("victim_swaps.amount_in", &victim_swaps.amount_in)?;
+        ser_struct.serialize_field("victim_swaps.amount_out", &victim_swaps.amount_out)?;
+
+        ser_struct.serialize_field("cex_price_move_bps", &self.cex_price_move_bps)?;
+
+        let gas_details = (
+            self.gas_details.coinbase_transfer,
+            self.gas_details.priority_fee,
+            self.gas_details.gas_used,
+            self.gas_details.effective_gas_price,
+            self.gas_details.l1_gas_used,
+        );
+        ser_struct.serialize_field("gas_details", &gas_details)?;
+        ser_struct.serialize_field("profit_usd", &self.profit_usd)?;
+
+        ser_struct.end()
+    }
+}
+
+impl DbRow for Timeboost {
+    const COLUMN_NAMES: &'static [&'static str] = &[
+        "tx_hash",
+        "block_number",
+        "express_lane_round",
+        "strategy",
+        "swaps.trace_idx",
+        "swaps.from",
+        "swaps.recipient",
+        "swaps.pool",
+        "swaps.token_in",
+        "swaps.token_out",
+        "swaps.amount_in",
+        "swaps.amount_out",
+        "victim_swaps.tx_hash",
+        "victim_swaps.trace_idx",
+        "victim_swaps.from",
+        "victim_swaps.recipient",
+        "victim_swaps.pool",
+        "victim_swaps.token_in",
+        "victim_swaps.token_out",
+        "victim_swaps.amount_in",
+        "victim_swaps.amount_out",
+        "cex_price_move_bps",
+        "gas_details",
+        "profit_usd",
+    ];
+}
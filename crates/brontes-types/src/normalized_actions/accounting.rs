@@ -91,5 +91,49 @@ pub fn apply_delta<K: PartialEq + Hash + Eq>(
 
 #[cfg(test)]
 pub mod test {
-    // todo: add tests
+    use alloy_primitives::{Address, U256};
+    use malachite::{num::basic::traits::Zero, Rational};
+
+    use super::*;
+    use crate::{
+        db::token_info::{TokenInfo, TokenInfoWithAddress},
+        normalized_actions::{NormalizedRepayment, NormalizedTransfer},
+        FastHashMap, Protocol,
+    };
+
+    #[test]
+    fn test_lending_action_replaces_its_transfer() {
+        let payer = Address::new([0x01; 20]);
+        let comet = Address::new([0xc3; 20]);
+        let usdc = TokenInfoWithAddress {
+            address: Address::new([0xa0; 20]),
+            inner:   TokenInfo { decimals: 6, symbol: "USDC".to_string() },
+        };
+
+        let repayment = Action::Repayment(NormalizedRepayment {
+            protocol: Protocol::CompoundV3,
+            trace_index: 1,
+            lender: comet,
+            borrower: payer,
+            payer,
+            repayed_token: usdc.clone(),
+            repayment_amount: Rational::from(100),
+            collateral: FastHashMap::default(),
+            msg_value: U256::ZERO,
+        });
+        let transfer = Action::Transfer(NormalizedTransfer {
+            trace_index: 2,
+            from:        payer,
+            to:          comet,
+            token:       usdc.clone(),
+            amount:      Rational::from(100),
+            fee:         Rational::ZERO,
+            msg_value:   U256::ZERO,
+        });
+
+        let deltas = [transfer, repayment].into_iter().account_for_actions();
+
+        assert_eq!(deltas[&payer][&usdc.address], Rational::from(-100));
+        assert!(!deltas.contains_key(&comet));
+    }
 }
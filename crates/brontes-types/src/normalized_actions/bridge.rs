@@ -0,0 +1,44 @@
+use std::fmt::Debug;
+
+use alloy_primitives::{Address, U256};
+use clickhouse::Row;
+use malachite::Rational;
+use serde::{Deserialize, Serialize};
+
+use super::accounting::{apply_delta, AddressDeltas, TokenAccounting};
+use crate::{db::token_info::TokenInfoWithAddress, Protocol};
+
+/// Tokens sent to or received from another chain through a bridge
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedBridge {
+    pub protocol:          Protocol,
+    pub trace_index:       u64,
+    /// Pays the tokens on this chain, the depositor for outbound transfers and
+    /// the relayer or bridge contract for fills
+    pub from:              Address,
+    /// Receives the tokens on the destination chain
+    pub recipient:         Address,
+    pub bridge:            Address,
+    /// Relayer or filler of the transfer, zero if any relayer can fill it
+    pub relayer:           Address,
+    pub token:             TokenInfoWithAddress,
+    pub amount:            Rational,
+    /// Evm chain ids, zero if the bridge's chain identifier has no known
+    /// mapping or the bridge doesn't expose it on this chain
+    pub origin_chain:      u64,
+    pub destination_chain: u64,
+    /// The tokens are paid out on this chain for a transfer from
+    /// `origin_chain`
+    pub is_fill:           bool,
+    pub msg_value:         U256,
+}
+
+impl TokenAccounting for NormalizedBridge {
+    fn apply_token_deltas(&self, delta_map: &mut AddressDeltas) {
+        apply_delta(self.from, self.token.address, -self.amount.clone(), delta_map);
+        // outbound transfers are paid out on the destination chain
+        if self.is_fill {
+            apply_delta(self.recipient, self.token.address, self.amount.clone(), delta_map);
+        }
+    }
+}
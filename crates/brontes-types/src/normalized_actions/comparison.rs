@@ -1,6 +1,13 @@
 use std::fmt::Debug;
 
-use super::{Action, NormalizedCollect, NormalizedMint, NormalizedSwap, NormalizedTransfer};
+use malachite::{num::basic::traits::Zero, Rational};
+
+use super::{
+    Action, NormalizedBridge, NormalizedCollect, NormalizedEthTransfer, NormalizedLoan,
+    NormalizedMint, NormalizedRepayment, NormalizedSupply, NormalizedSwap, NormalizedTransfer,
+    NormalizedUnwrap, NormalizedWithdraw, NormalizedWrap,
+};
+use crate::{constants::ETH_ADDRESS, db::token_info::TokenInfoWithAddress, ToScaledRational};
 
 impl<T: Sized + SubordinateAction<O>, O: ActionCmp<T>> ActionComparison<O> for T {}
 
@@ -21,6 +28,13 @@ impl ActionCmp<Action> for Action {
             Action::Mint(m) => m.is_superior_action(other),
             Action::Collect(c) => c.is_superior_action(other),
             Action::SwapWithFee(s) => s.swap.is_superior_action(other),
+            Action::Loan(l) => l.is_superior_action(other),
+            Action::Repayment(r) => r.is_superior_action(other),
+            Action::Supply(s) => s.is_superior_action(other),
+            Action::Withdraw(w) => w.is_superior_action(other),
+            Action::Wrap(w) => w.is_superior_action(other),
+            Action::Unwrap(u) => u.is_superior_action(other),
+            Action::Bridge(b) => b.is_superior_action(other),
             Action::FlashLoan(f) => f.child_actions.iter().any(|a| a.is_superior_action(other)),
             Action::Batch(b) => {
                 let user = b.user_swaps.iter().any(|b| b.is_superior_action(other));
@@ -83,7 +97,16 @@ impl ActionCmp<NormalizedTransfer> for NormalizedMint {
             }
         }
 
-        false
+        // liquidity spread over several ranges lists a token once per range, while
+        // the deposit is a single transfer of the total
+        let token_total = self
+            .amount
+            .iter()
+            .zip(&self.token)
+            .filter(|(_, token)| transfer.token.eq(token))
+            .fold(Rational::ZERO, |total, (amount, _)| total + amount);
+
+        token_total != Rational::ZERO && transfer.amount.eq(&token_total)
     }
 }
 
@@ -116,3 +139,198 @@ impl ActionCmp<Action> for NormalizedCollect {
         }
     }
 }
+
+/// The underlying token of a lending or wrapping action can move through a
+/// different contract than the pool (e.g. an aToken), so the transfer is
+/// matched on the user's side only
+fn is_lending_transfer(
+    transfer: &NormalizedTransfer,
+    token: &TokenInfoWithAddress,
+    amount: &Rational,
+) -> bool {
+    transfer.token.eq(token) && (&transfer.amount + &transfer.fee).eq(amount)
+}
+
+impl ActionCmp<Action> for NormalizedLoan {
+    fn is_superior_action(&self, other: &Action) -> bool {
+        match other {
+            Action::Transfer(t) => {
+                t.to == self.recipient
+                    && is_lending_transfer(t, &self.loaned_token, &self.loan_amount)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl ActionCmp<Action> for NormalizedRepayment {
+    fn is_superior_action(&self, other: &Action) -> bool {
+        match other {
+            Action::Transfer(t) => {
+                t.from == self.payer
+                    && is_lending_transfer(t, &self.repayed_token, &self.repayment_amount)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl ActionCmp<Action> for NormalizedSupply {
+    fn is_superior_action(&self, other: &Action) -> bool {
+        match other {
+            Action::Transfer(t) => {
+                t.from == self.supplier && is_lending_transfer(t, &self.token, &self.amount)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl ActionCmp<Action> for NormalizedWithdraw {
+    fn is_superior_action(&self, other: &Action) -> bool {
+        match other {
+            Action::Transfer(t) => {
+                t.to == self.recipient && is_lending_transfer(t, &self.token, &self.amount)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl ActionCmp<NormalizedTransfer> for NormalizedWrap {
+    fn is_superior_action(&self, transfer: &NormalizedTransfer) -> bool {
+        (transfer.from == self.from
+            && is_lending_transfer(transfer, &self.underlying, &self.underlying_amount))
+            || (transfer.to == self.recipient
+                && is_lending_transfer(transfer, &self.wrapped, &self.wrapped_amount))
+    }
+}
+
+impl ActionCmp<NormalizedEthTransfer> for NormalizedWrap {
+    fn is_superior_action(&self, transfer: &NormalizedEthTransfer) -> bool {
+        self.underlying.address == ETH_ADDRESS
+            && transfer.from == self.from
+            && transfer.to == self.wrapper
+            && transfer.value.to_scaled_rational(18) == self.underlying_amount
+    }
+}
+
+impl ActionCmp<Action> for NormalizedWrap {
+    fn is_superior_action(&self, other: &Action) -> bool {
+        match other {
+            Action::Transfer(t) => self.is_superior_action(t),
+            Action::EthTransfer(t) => self.is_superior_action(t),
+            _ => false,
+        }
+    }
+}
+
+impl ActionCmp<NormalizedTransfer> for NormalizedUnwrap {
+    fn is_superior_action(&self, transfer: &NormalizedTransfer) -> bool {
+        (transfer.from == self.from
+            && is_lending_transfer(transfer, &self.wrapped, &self.wrapped_amount))
+            || (transfer.to == self.recipient
+                && is_lending_transfer(transfer, &self.underlying, &self.underlying_amount))
+    }
+}
+
+// the wrapper's eth payout of a native unwrap isn't classified, so there is no
+// eth transfer to cover
+impl ActionCmp<Action> for NormalizedUnwrap {
+    fn is_superior_action(&self, other: &Action) -> bool {
+        match other {
+            Action::Transfer(t) => self.is_superior_action(t),
+            _ => false,
+        }
+    }
+}
+
+impl ActionCmp<NormalizedTransfer> for NormalizedBridge {
+    fn is_superior_action(&self, transfer: &NormalizedTransfer) -> bool {
+        if self.is_fill {
+            transfer.to == self.recipient
+                && is_lending_transfer(transfer, &self.token, &self.amount)
+        } else {
+            transfer.from == self.from && is_lending_transfer(transfer, &self.token, &self.amount)
+        }
+    }
+}
+
+/// Native deposits send the value to the bridge, native fills pay it out to the
+/// recipient
+impl ActionCmp<NormalizedEthTransfer> for NormalizedBridge {
+    fn is_superior_action(&self, transfer: &NormalizedEthTransfer) -> bool {
+        if self.is_fill {
+            transfer.from == self.bridge
+                && transfer.to == self.recipient
+                && transfer.value.to_scaled_rational(18) == self.amount
+        } else {
+            !self.msg_value.is_zero()
+                && transfer.from == self.from
+                && transfer.to == self.bridge
+                && transfer.value == self.msg_value
+        }
+    }
+}
+
+impl ActionCmp<Action> for NormalizedBridge {
+    fn is_superior_action(&self, other: &Action) -> bool {
+        match other {
+            Action::Transfer(t) => self.is_superior_action(t),
+            Action::EthTransfer(t) => self.is_superior_action(t),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Address, U256};
+
+    use super::*;
+    use crate::{db::token_info::TokenInfo, Protocol};
+
+    fn token(byte: u8) -> TokenInfoWithAddress {
+        TokenInfoWithAddress {
+            address: Address::repeat_byte(byte),
+            inner:   TokenInfo { decimals: 18, symbol: format!("T{byte}") },
+        }
+    }
+
+    fn transfer(token: TokenInfoWithAddress, amount: u64) -> NormalizedTransfer {
+        NormalizedTransfer {
+            trace_index: 1,
+            from: Address::repeat_byte(0xaa),
+            to: Address::repeat_byte(0xbb),
+            token,
+            amount: Rational::from(amount),
+            fee: Rational::ZERO,
+            msg_value: U256::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_mint_over_several_bins_covers_the_total_transfer() {
+        let mint = NormalizedMint {
+            protocol:    Protocol::LFJV2_2,
+            trace_index: 2,
+            from:        Address::repeat_byte(0xaa),
+            recipient:   Address::repeat_byte(0xaa),
+            pool:        Address::repeat_byte(0xbb),
+            token:       vec![token(2), token(1), token(2), token(1)],
+            amount:      vec![
+                Rational::from(4),
+                Rational::from(1),
+                Rational::from(6),
+                Rational::from(3),
+            ],
+        };
+
+        assert!(mint.is_superior_action(&transfer(token(1), 4)));
+        assert!(mint.is_superior_action(&transfer(token(2), 10)));
+        // a single bin's amount is still covered
+        assert!(mint.is_superior_action(&transfer(token(2), 6)));
+        assert!(!mint.is_superior_action(&transfer(token(1), 5)));
+        assert!(!mint.is_superior_action(&transfer(token(3), 0)));
+    }
+}
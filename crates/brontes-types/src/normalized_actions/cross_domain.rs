@@ -0,0 +1,109 @@
+use std::fmt::Debug;
+
+use alloy_primitives::{Address, B256, U256};
+use clickhouse::Row;
+use serde::{Deserialize, Serialize};
+
+use super::accounting::{apply_delta, AddressDeltas, TokenAccounting};
+use crate::{constants::ETH_ADDRESS, ToScaledRational};
+
+/// Eth bridged from L1 through the inbox, minted to `to` by ArbOS
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedL1Deposit {
+    pub trace_index: u64,
+    pub from:        Address,
+    pub to:          Address,
+    pub value:       U256,
+}
+
+impl TokenAccounting for NormalizedL1Deposit {
+    fn apply_token_deltas(&self, delta_map: &mut AddressDeltas) {
+        let am = self.value.to_scaled_rational(18);
+        apply_delta(self.to, ETH_ADDRESS, am, delta_map);
+    }
+}
+
+/// A retryable ticket created by a submit retryable transaction
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedRetryableTicket {
+    pub trace_index:        u64,
+    pub ticket_id:          B256,
+    pub from:               Address,
+    pub to:                 Address,
+    pub deposit:            U256,
+    pub call_value:         U256,
+    pub max_submission_fee: U256,
+    pub fee_refund_address: Address,
+    pub beneficiary:        Address,
+    pub auto_redeemed:      bool,
+}
+
+impl TokenAccounting for NormalizedRetryableTicket {
+    // ArbOS mints the deposit and moves it without any eth transfer in the
+    // trace. The call value is escrowed until the ticket is redeemed (see
+    // [`NormalizedRetryTx`]), the submission fee is paid to the network and
+    // whatever is left is refunded. Only the max submission fee is known here,
+    // so the refund is a lower bound.
+    fn apply_token_deltas(&self, delta_map: &mut AddressDeltas) {
+        let refund = self
+            .deposit
+            .saturating_sub(self.call_value)
+            .saturating_sub(self.max_submission_fee);
+        if refund.is_zero() {
+            return
+        }
+
+        apply_delta(self.fee_refund_address, ETH_ADDRESS, refund.to_scaled_rational(18), delta_map);
+    }
+}
+
+/// A manual redeem of a retryable ticket through `ArbRetryableTx.redeem`
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedRetryableRedeem {
+    pub trace_index:   u64,
+    pub from:          Address,
+    pub ticket_id:     B256,
+    pub retry_tx_hash: B256,
+}
+
+impl TokenAccounting for NormalizedRetryableRedeem {
+    // a redeem only schedules the retry tx, the escrowed value moves when
+    // that one executes
+    fn apply_token_deltas(&self, _delta_map: &mut AddressDeltas) {}
+}
+
+/// The execution of a retryable ticket, either auto-redeemed right after its
+/// submission or scheduled by a manual redeem. ArbOS releases the escrowed call
+/// value to `from` before the root call spends it.
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedRetryTx {
+    pub trace_index: u64,
+    pub from:        Address,
+    pub to:          Address,
+    pub value:       U256,
+}
+
+impl TokenAccounting for NormalizedRetryTx {
+    fn apply_token_deltas(&self, delta_map: &mut AddressDeltas) {
+        let am = self.value.to_scaled_rational(18);
+        apply_delta(self.from, ETH_ADDRESS, am, delta_map);
+    }
+}
+
+/// An L2 to L1 message sent through `ArbSys`, `value` is the eth burnt on L2
+/// to be withdrawn on L1
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedL2ToL1Tx {
+    pub trace_index: u64,
+    pub from:        Address,
+    pub destination: Address,
+    pub value:       U256,
+    pub position:    U256,
+}
+
+impl TokenAccounting for NormalizedL2ToL1Tx {
+    fn apply_token_deltas(&self, delta_map: &mut AddressDeltas) {
+        let am = self.value.to_scaled_rational(18);
+        apply_delta(self.from, ETH_ADDRESS, -am, delta_map);
+    }
+}
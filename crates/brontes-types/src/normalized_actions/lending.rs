@@ -6,6 +6,7 @@ use malachite::Rational;
 use reth_primitives::Address;
 use serde::{Deserialize, Serialize};
 
+use super::accounting::{apply_delta, AddressDeltas, TokenAccounting};
 use crate::{db::token_info::TokenInfoWithAddress, FastHashMap, Protocol};
 
 #[derive(Debug, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
@@ -14,20 +15,82 @@ pub struct NormalizedLoan {
     pub trace_index:  u64,
     pub lender:       Address,
     pub borrower:     Address,
+    /// Receives the loaned tokens, this is the borrower unless the borrow
+    /// was made with delegated credit
+    pub recipient:    Address,
     pub loaned_token: TokenInfoWithAddress,
     pub loan_amount:  Rational,
     pub collateral:   FastHashMap<TokenInfoWithAddress, Rational>,
     pub msg_value:    U256,
 }
 
+impl TokenAccounting for NormalizedLoan {
+    fn apply_token_deltas(&self, delta_map: &mut AddressDeltas) {
+        apply_delta(self.recipient, self.loaned_token.address, self.loan_amount.clone(), delta_map);
+    }
+}
+
 #[derive(Debug, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
 pub struct NormalizedRepayment {
     pub protocol:         Protocol,
     pub trace_index:      u64,
     pub lender:           Address,
     pub borrower:         Address,
+    /// Pays back the debt, not necessarily the borrower
+    pub payer:            Address,
     pub repayed_token:    TokenInfoWithAddress,
     pub repayment_amount: Rational,
     pub collateral:       FastHashMap<TokenInfoWithAddress, Rational>,
     pub msg_value:        U256,
 }
+
+impl TokenAccounting for NormalizedRepayment {
+    fn apply_token_deltas(&self, delta_map: &mut AddressDeltas) {
+        apply_delta(
+            self.payer,
+            self.repayed_token.address,
+            -self.repayment_amount.clone(),
+            delta_map,
+        );
+    }
+}
+
+/// Tokens deposited into a lending market, either as collateral or to earn
+/// interest
+#[derive(Debug, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedSupply {
+    pub protocol:     Protocol,
+    pub trace_index:  u64,
+    pub pool:         Address,
+    pub supplier:     Address,
+    /// Credited with the supplied position
+    pub on_behalf_of: Address,
+    pub token:        TokenInfoWithAddress,
+    pub amount:       Rational,
+    pub msg_value:    U256,
+}
+
+impl TokenAccounting for NormalizedSupply {
+    fn apply_token_deltas(&self, delta_map: &mut AddressDeltas) {
+        apply_delta(self.supplier, self.token.address, -self.amount.clone(), delta_map);
+    }
+}
+
+/// Tokens taken out of a supplied lending position
+#[derive(Debug, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedWithdraw {
+    pub protocol:    Protocol,
+    pub trace_index: u64,
+    pub pool:        Address,
+    pub owner:       Address,
+    pub recipient:   Address,
+    pub token:       TokenInfoWithAddress,
+    pub amount:      Rational,
+    pub msg_value:   U256,
+}
+
+impl TokenAccounting for NormalizedWithdraw {
+    fn apply_token_deltas(&self, delta_map: &mut AddressDeltas) {
+        apply_delta(self.recipient, self.token.address, self.amount.clone(), delta_map);
+    }
+}
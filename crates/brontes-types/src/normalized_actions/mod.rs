@@ -1,17 +1,21 @@
 pub mod accounting;
 pub mod aggregator;
 pub mod batch;
+pub mod bridge;
 pub mod comparison;
+pub mod cross_domain;
 pub mod eth_transfer;
 pub mod flashloan;
 pub mod lending;
 pub mod liquidation;
 pub mod liquidity;
 pub mod multi_callframe;
+pub mod perpetual;
 pub mod pool;
 pub mod self_destruct;
 pub mod swaps;
 pub mod transfer;
+pub mod wrap;
 use std::fmt::Debug;
 
 use ::clickhouse::DbRow;
@@ -19,20 +23,25 @@ use accounting::{AddressDeltas, TokenAccounting};
 pub use aggregator::*;
 use alloy_primitives::{Address, Bytes, Log};
 pub use batch::*;
+pub use bridge::*;
 use clickhouse::InsertRow;
+pub use cross_domain::*;
 pub use eth_transfer::*;
 pub use flashloan::*;
 pub use lending::*;
 pub use liquidation::*;
 pub use liquidity::*;
 pub use multi_callframe::*;
+pub use perpetual::*;
 pub use pool::*;
 use reth_rpc_types::trace::parity::Action as TraceAction;
 pub use self_destruct::*;
 pub use swaps::*;
 pub use transfer::*;
+pub use wrap::*;
 
 use crate::{
+    constants::arbitrum::ARB_RETRYABLE_TX_ADDRESS,
     structured_trace::{TraceActions, TransactionTraceWithLogs},
     Protocol,
 };
@@ -97,6 +106,20 @@ impl NormalizedAction for Action {
             Self::NewPool(p) => p.trace_index,
             Self::PoolConfigUpdate(p) => p.trace_index,
             Self::Aggregator(a) => a.trace_index,
+            Self::PerpOrder(o) => o.trace_index,
+            Self::PositionChange(p) => p.trace_index,
+            Self::L1Deposit(d) => d.trace_index,
+            Self::RetryableTicket(t) => t.trace_index,
+            Self::RetryableRedeem(r) => r.trace_index,
+            Self::RetryTx(r) => r.trace_index,
+            Self::L2ToL1Tx(t) => t.trace_index,
+            Self::Loan(l) => l.trace_index,
+            Self::Repayment(r) => r.trace_index,
+            Self::Supply(s) => s.trace_index,
+            Self::Withdraw(w) => w.trace_index,
+            Self::Wrap(w) => w.trace_index,
+            Self::Unwrap(u) => u.trace_index,
+            Self::Bridge(b) => b.trace_index,
             Self::Revert => unreachable!("no trace index for revert"),
         }
     }
@@ -119,6 +142,20 @@ pub enum Action {
     NewPool(NormalizedNewPool),
     PoolConfigUpdate(NormalizedPoolConfigUpdate),
     Aggregator(NormalizedAggregator),
+    PerpOrder(NormalizedPerpOrder),
+    PositionChange(NormalizedPositionChange),
+    L1Deposit(NormalizedL1Deposit),
+    RetryableTicket(NormalizedRetryableTicket),
+    RetryableRedeem(NormalizedRetryableRedeem),
+    RetryTx(NormalizedRetryTx),
+    L2ToL1Tx(NormalizedL2ToL1Tx),
+    Loan(NormalizedLoan),
+    Repayment(NormalizedRepayment),
+    Supply(NormalizedSupply),
+    Withdraw(NormalizedWithdraw),
+    Wrap(NormalizedWrap),
+    Unwrap(NormalizedUnwrap),
+    Bridge(NormalizedBridge),
     Unclassified(TransactionTraceWithLogs),
     Revert,
 }
@@ -139,6 +176,20 @@ impl InsertRow for Action {
             Action::EthTransfer(_) => todo!("joe pls dome this"),
             Action::NewPool(_) => todo!(),
             Action::PoolConfigUpdate(_) => todo!(),
+            Action::PerpOrder(_) => NormalizedPerpOrder::COLUMN_NAMES,
+            Action::PositionChange(_) => NormalizedPositionChange::COLUMN_NAMES,
+            Action::L1Deposit(_) => NormalizedL1Deposit::COLUMN_NAMES,
+            Action::RetryableTicket(_) => NormalizedRetryableTicket::COLUMN_NAMES,
+            Action::RetryableRedeem(_) => NormalizedRetryableRedeem::COLUMN_NAMES,
+            Action::RetryTx(_) => NormalizedRetryTx::COLUMN_NAMES,
+            Action::L2ToL1Tx(_) => NormalizedL2ToL1Tx::COLUMN_NAMES,
+            Action::Loan(_) => NormalizedLoan::COLUMN_NAMES,
+            Action::Repayment(_) => NormalizedRepayment::COLUMN_NAMES,
+            Action::Supply(_) => NormalizedSupply::COLUMN_NAMES,
+            Action::Withdraw(_) => NormalizedWithdraw::COLUMN_NAMES,
+            Action::Wrap(_) => NormalizedWrap::COLUMN_NAMES,
+            Action::Unwrap(_) => NormalizedUnwrap::COLUMN_NAMES,
+            Action::Bridge(_) => NormalizedBridge::COLUMN_NAMES,
             Action::Unclassified(..) | Action::Revert => panic!(),
             Action::Aggregator(_) => NormalizedAggregator::COLUMN_NAMES,
         }
@@ -163,6 +214,20 @@ impl serde::Serialize for Action {
             Action::Liquidation(c) => c.serialize(serializer),
             Action::SelfDestruct(sd) => sd.serialize(serializer),
             Action::EthTransfer(et) => et.serialize(serializer),
+            Action::PerpOrder(o) => o.serialize(serializer),
+            Action::PositionChange(p) => p.serialize(serializer),
+            Action::L1Deposit(d) => d.serialize(serializer),
+            Action::RetryableTicket(t) => t.serialize(serializer),
+            Action::RetryableRedeem(r) => r.serialize(serializer),
+            Action::RetryTx(r) => r.serialize(serializer),
+            Action::L2ToL1Tx(t) => t.serialize(serializer),
+            Action::Loan(l) => l.serialize(serializer),
+            Action::Repayment(r) => r.serialize(serializer),
+            Action::Supply(s) => s.serialize(serializer),
+            Action::Withdraw(w) => w.serialize(serializer),
+            Action::Wrap(w) => w.serialize(serializer),
+            Action::Unwrap(u) => u.serialize(serializer),
+            Action::Bridge(b) => b.serialize(serializer),
             Action::Unclassified(trace) => (trace).serialize(serializer),
             action => format!("{:?}", action).serialize(serializer),
             //action => unreachable!("no action serialization for {action:?}"),
@@ -218,6 +283,8 @@ impl Action {
                     from: a.from,
                     ..Default::default()
                 }),
+                Self::PerpOrder(_) => None,
+                Self::PositionChange(_) => None,
                 Self::Mint(_) => None,
                 Self::Burn(_) => None,
                 Self::Transfer(_) => None,
@@ -226,6 +293,19 @@ impl Action {
                 Self::EthTransfer(_) => None,
                 Self::NewPool(_) => None,
                 Self::PoolConfigUpdate(_) => None,
+                Self::L1Deposit(_) => None,
+                Self::RetryableTicket(_) => None,
+                Self::RetryableRedeem(_) => None,
+                Self::RetryTx(_) => None,
+                Self::L2ToL1Tx(_) => None,
+                // native supplies and repayments are accounted for by the action
+                Self::Loan(_) => None,
+                Self::Repayment(_) => None,
+                Self::Supply(_) => None,
+                Self::Withdraw(_) => None,
+                Self::Wrap(_) => None,
+                Self::Unwrap(_) => None,
+                Self::Bridge(_) => None,
                 Self::Revert => None,
             };
         if res.is_some() {
@@ -258,6 +338,20 @@ impl Action {
             Self::NewPool(p) => p.trace_index,
             Self::PoolConfigUpdate(p) => p.trace_index,
             Self::Aggregator(a) => a.trace_index,
+            Self::PerpOrder(o) => o.trace_index,
+            Self::PositionChange(p) => p.trace_index,
+            Self::L1Deposit(d) => d.trace_index,
+            Self::RetryableTicket(t) => t.trace_index,
+            Self::RetryableRedeem(r) => r.trace_index,
+            Self::RetryTx(r) => r.trace_index,
+            Self::L2ToL1Tx(t) => t.trace_index,
+            Self::Loan(l) => l.trace_index,
+            Self::Repayment(r) => r.trace_index,
+            Self::Supply(s) => s.trace_index,
+            Self::Withdraw(w) => w.trace_index,
+            Self::Wrap(w) => w.trace_index,
+            Self::Unwrap(u) => u.trace_index,
+            Self::Bridge(b) => b.trace_index,
             Self::Revert => return None,
         })
     }
@@ -335,6 +429,20 @@ impl Action {
             Action::EthTransfer(t) => t.to,
             Action::NewPool(p) => p.pool_address,
             Action::PoolConfigUpdate(p) => p.pool_address,
+            Action::PerpOrder(o) => o.market,
+            Action::PositionChange(p) => p.market,
+            Action::L1Deposit(d) => d.to,
+            Action::RetryableTicket(t) => t.to,
+            Action::RetryableRedeem(_) => ARB_RETRYABLE_TX_ADDRESS,
+            Action::RetryTx(r) => r.to,
+            Action::L2ToL1Tx(t) => t.destination,
+            Action::Loan(l) => l.lender,
+            Action::Repayment(r) => r.lender,
+            Action::Supply(s) => s.pool,
+            Action::Withdraw(w) => w.pool,
+            Action::Wrap(w) => w.wrapper,
+            Action::Unwrap(u) => u.wrapper,
+            Action::Bridge(b) => b.bridge,
             Action::Revert => Address::ZERO,
         }
     }
@@ -362,6 +470,20 @@ impl Action {
             Action::Revert => unreachable!(),
             Action::NewPool(_) => Address::ZERO,
             Action::PoolConfigUpdate(_) => Address::ZERO,
+            Action::PerpOrder(o) => o.from,
+            Action::PositionChange(p) => p.from,
+            Action::L1Deposit(d) => d.from,
+            Action::RetryableTicket(t) => t.from,
+            Action::RetryableRedeem(r) => r.from,
+            Action::RetryTx(r) => r.from,
+            Action::L2ToL1Tx(t) => t.from,
+            Action::Loan(l) => l.recipient,
+            Action::Repayment(r) => r.payer,
+            Action::Supply(s) => s.supplier,
+            Action::Withdraw(w) => w.owner,
+            Action::Wrap(w) => w.from,
+            Action::Unwrap(u) => u.from,
+            Action::Bridge(b) => b.from,
         }
     }
 
@@ -431,6 +553,62 @@ impl Action {
         matches!(self, Action::PoolConfigUpdate(_))
     }
 
+    pub const fn is_perp_order(&self) -> bool {
+        matches!(self, Action::PerpOrder(_))
+    }
+
+    pub const fn is_position_change(&self) -> bool {
+        matches!(self, Action::PositionChange(_))
+    }
+
+    pub const fn is_l1_deposit(&self) -> bool {
+        matches!(self, Action::L1Deposit(_))
+    }
+
+    pub const fn is_retryable_ticket(&self) -> bool {
+        matches!(self, Action::RetryableTicket(_))
+    }
+
+    pub const fn is_retryable_redeem(&self) -> bool {
+        matches!(self, Action::RetryableRedeem(_))
+    }
+
+    pub const fn is_retry_tx(&self) -> bool {
+        matches!(self, Action::RetryTx(_))
+    }
+
+    pub const fn is_l2_to_l1_tx(&self) -> bool {
+        matches!(self, Action::L2ToL1Tx(_))
+    }
+
+    pub const fn is_loan(&self) -> bool {
+        matches!(self, Action::Loan(_))
+    }
+
+    pub const fn is_repayment(&self) -> bool {
+        matches!(self, Action::Repayment(_))
+    }
+
+    pub const fn is_supply(&self) -> bool {
+        matches!(self, Action::Supply(_))
+    }
+
+    pub const fn is_withdraw(&self) -> bool {
+        matches!(self, Action::Withdraw(_))
+    }
+
+    pub const fn is_wrap(&self) -> bool {
+        matches!(self, Action::Wrap(_))
+    }
+
+    pub const fn is_unwrap(&self) -> bool {
+        matches!(self, Action::Unwrap(_))
+    }
+
+    pub const fn is_bridge(&self) -> bool {
+        matches!(self, Action::Bridge(_))
+    }
+
     pub const fn is_unclassified(&self) -> bool {
         matches!(self, Action::Unclassified(_))
     }
@@ -448,6 +626,15 @@ impl Action {
             Action::NewPool(p) => p.protocol,
             Action::PoolConfigUpdate(p) => p.protocol,
             Action::Aggregator(a) => a.protocol,
+            Action::PerpOrder(o) => o.protocol,
+            Action::PositionChange(p) => p.protocol,
+            Action::Loan(l) => l.protocol,
+            Action::Repayment(r) => r.protocol,
+            Action::Supply(s) => s.protocol,
+            Action::Withdraw(w) => w.protocol,
+            Action::Wrap(w) => w.protocol,
+            Action::Unwrap(u) => u.protocol,
+            Action::Bridge(b) => b.protocol,
             _ => Protocol::Unknown,
         }
     }
@@ -526,7 +713,21 @@ extra_impls!(
     (FlashLoan, NormalizedFlashLoan),
     (Aggregator, NormalizedAggregator),
     (Batch, NormalizedBatch),
-    (NewPool, NormalizedNewPool)
+    (NewPool, NormalizedNewPool),
+    (PerpOrder, NormalizedPerpOrder),
+    (PositionChange, NormalizedPositionChange),
+    (L1Deposit, NormalizedL1Deposit),
+    (RetryableTicket, NormalizedRetryableTicket),
+    (RetryableRedeem, NormalizedRetryableRedeem),
+    (RetryTx, NormalizedRetryTx),
+    (L2ToL1Tx, NormalizedL2ToL1Tx),
+    (Loan, NormalizedLoan),
+    (Repayment, NormalizedRepayment),
+    (Supply, NormalizedSupply),
+    (Withdraw, NormalizedWithdraw),
+    (Wrap, NormalizedWrap),
+    (Unwrap, NormalizedUnwrap),
+    (Bridge, NormalizedBridge)
 );
 
 /// Custom impl for itering over swaps and swap with fee
@@ -578,6 +779,20 @@ impl TokenAccounting for Action {
             Action::SwapWithFee(swap_with_fee) => swap_with_fee.swap.apply_token_deltas(delta_map),
             Action::Collect(collect) => collect.apply_token_deltas(delta_map),
             Action::EthTransfer(eth_transfer) => eth_transfer.apply_token_deltas(delta_map),
+            Action::PerpOrder(order) => order.apply_token_deltas(delta_map),
+            Action::PositionChange(position) => position.apply_token_deltas(delta_map),
+            Action::L1Deposit(deposit) => deposit.apply_token_deltas(delta_map),
+            Action::RetryableTicket(ticket) => ticket.apply_token_deltas(delta_map),
+            Action::RetryableRedeem(redeem) => redeem.apply_token_deltas(delta_map),
+            Action::RetryTx(retry) => retry.apply_token_deltas(delta_map),
+            Action::L2ToL1Tx(l2_to_l1) => l2_to_l1.apply_token_deltas(delta_map),
+            Action::Loan(loan) => loan.apply_token_deltas(delta_map),
+            Action::Repayment(repayment) => repayment.apply_token_deltas(delta_map),
+            Action::Supply(supply) => supply.apply_token_deltas(delta_map),
+            Action::Withdraw(withdraw) => withdraw.apply_token_deltas(delta_map),
+            Action::Wrap(wrap) => wrap.apply_token_deltas(delta_map),
+            Action::Unwrap(unwrap) => unwrap.apply_token_deltas(delta_map),
+            Action::Bridge(bridge) => bridge.apply_token_deltas(delta_map),
             Action::Unclassified(_) => (), /* Potentially no token deltas to apply, adjust as */
             // necessary
             Action::SelfDestruct(_self_destruct) => (),
@@ -0,0 +1,51 @@
+use clickhouse::Row;
+use malachite::Rational;
+use reth_primitives::{Address, U256};
+use serde::{Deserialize, Serialize};
+
+use super::accounting::{AddressDeltas, TokenAccounting};
+use crate::{db::token_info::TokenInfoWithAddress, Protocol};
+
+/// An order submitted to a perp exchange that is executed later by a keeper
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedPerpOrder {
+    pub protocol:          Protocol,
+    pub trace_index:       u64,
+    pub from:              Address,
+    pub receiver:          Address,
+    pub market:            Address,
+    pub collateral_token:  TokenInfoWithAddress,
+    pub collateral_amount: Rational,
+    /// Change in position size, denominated in usd
+    pub size_delta_usd:    Rational,
+    pub is_long:           bool,
+    pub order_type:        u8,
+    pub execution_fee:     U256,
+    pub msg_value:         U256,
+}
+
+impl TokenAccounting for NormalizedPerpOrder {
+    // collateral is moved into the exchange with a separate transfer
+    fn apply_token_deltas(&self, _delta_map: &mut AddressDeltas) {}
+}
+
+/// A leveraged position being increased or decreased
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedPositionChange {
+    pub protocol:         Protocol,
+    pub trace_index:      u64,
+    pub from:             Address,
+    pub account:          Address,
+    pub market:           Address,
+    pub collateral_token: TokenInfoWithAddress,
+    pub collateral_delta: Rational,
+    /// Change in position size, denominated in usd
+    pub size_delta_usd:   Rational,
+    pub is_long:          bool,
+    pub is_increase:      bool,
+}
+
+impl TokenAccounting for NormalizedPositionChange {
+    // collateral moves in and out of the market with separate transfers
+    fn apply_token_deltas(&self, _delta_map: &mut AddressDeltas) {}
+}
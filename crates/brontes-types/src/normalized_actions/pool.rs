@@ -1,7 +1,7 @@
 use alloy_primitives::Address;
 use serde::Deserialize;
 
-use crate::Protocol;
+use crate::{db::address_to_protocol_info::UniswapV4PoolKey, Protocol};
 
 #[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
 pub struct NormalizedNewPool {
@@ -20,18 +20,21 @@ impl TryFrom<NormalizedNewPool> for NormalizedPoolConfigUpdate {
         }
 
         Ok(NormalizedPoolConfigUpdate {
-            pool_address: value.pool_address,
-            trace_index:  value.trace_index,
-            protocol:     value.protocol,
-            tokens:       value.tokens,
+            pool_address:        value.pool_address,
+            trace_index:         value.trace_index,
+            protocol:            value.protocol,
+            tokens:              value.tokens,
+            uniswap_v4_pool_key: None,
         })
     }
 }
 
 #[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
 pub struct NormalizedPoolConfigUpdate {
-    pub trace_index:  u64,
-    pub protocol:     Protocol,
-    pub pool_address: Address,
-    pub tokens:       Vec<Address>,
+    pub trace_index:         u64,
+    pub protocol:            Protocol,
+    pub pool_address:        Address,
+    pub tokens:              Vec<Address>,
+    #[serde(default)]
+    pub uniswap_v4_pool_key: Option<UniswapV4PoolKey>,
 }
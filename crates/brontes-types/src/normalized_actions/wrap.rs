@@ -0,0 +1,61 @@
+use std::fmt::Debug;
+
+use alloy_primitives::{Address, U256};
+use clickhouse::Row;
+use malachite::Rational;
+use serde::{Deserialize, Serialize};
+
+use super::accounting::{apply_delta, AddressDeltas, TokenAccounting};
+use crate::{db::token_info::TokenInfoWithAddress, Protocol};
+
+/// Underlying tokens deposited into a wrapper, such as an ERC-4626 vault or
+/// the wrapped native token, in exchange for its shares
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedWrap {
+    pub protocol:          Protocol,
+    pub trace_index:       u64,
+    pub from:              Address,
+    /// Receives the wrapped tokens
+    pub recipient:         Address,
+    pub wrapper:           Address,
+    pub underlying:        TokenInfoWithAddress,
+    pub wrapped:           TokenInfoWithAddress,
+    pub underlying_amount: Rational,
+    pub wrapped_amount:    Rational,
+    pub msg_value:         U256,
+}
+
+impl TokenAccounting for NormalizedWrap {
+    fn apply_token_deltas(&self, delta_map: &mut AddressDeltas) {
+        apply_delta(self.from, self.underlying.address, -self.underlying_amount.clone(), delta_map);
+        apply_delta(self.recipient, self.wrapped.address, self.wrapped_amount.clone(), delta_map);
+    }
+}
+
+/// Wrapped tokens burnt for the underlying they are redeemable for
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedUnwrap {
+    pub protocol:          Protocol,
+    pub trace_index:       u64,
+    /// Owner of the burnt wrapped tokens
+    pub from:              Address,
+    pub recipient:         Address,
+    pub wrapper:           Address,
+    pub underlying:        TokenInfoWithAddress,
+    pub wrapped:           TokenInfoWithAddress,
+    pub underlying_amount: Rational,
+    pub wrapped_amount:    Rational,
+    pub msg_value:         U256,
+}
+
+impl TokenAccounting for NormalizedUnwrap {
+    fn apply_token_deltas(&self, delta_map: &mut AddressDeltas) {
+        apply_delta(self.from, self.wrapped.address, -self.wrapped_amount.clone(), delta_map);
+        apply_delta(
+            self.recipient,
+            self.underlying.address,
+            self.underlying_amount.clone(),
+            delta_map,
+        );
+    }
+}
@@ -7,7 +7,7 @@ use reth_db::table::{Decode, Encode};
 use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
 use serde::{Deserialize, Serialize};
 
-use crate::{constants::USD_STABLES_BY_ADDRESS, db::redefined_types::primitives::AddressRedefined};
+use crate::{chain::chain_config, db::redefined_types::primitives::AddressRedefined};
 
 #[derive(
     Debug,
@@ -91,7 +91,8 @@ impl Pair {
     }
 
     pub fn is_usd_stable_pair(&self) -> bool {
-        USD_STABLES_BY_ADDRESS.contains(&self.0) && USD_STABLES_BY_ADDRESS.contains(&self.1)
+        let stables = chain_config().usd_stables_by_address();
+        stables.contains(&self.0) && stables.contains(&self.1)
     }
 }
 
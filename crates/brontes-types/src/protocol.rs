@@ -61,6 +61,7 @@ utils!(
         PartialOrd,
         Ord,
         strum::EnumString,
+        strum::EnumIter,
     )]
     #[repr(u8)]
     pub enum Protocol {
@@ -116,6 +117,18 @@ utils!(
         FluidLending,
         LFJV2_1,
         LFJV2_2,
+        ERC4626,
+        WETH,
+        RamsesV1,
+        RamsesV2,
+        OneInchV6,
+        OdosV2,
+        ParaSwapV6,
+        KyberSwap,
+        AcrossV3,
+        StargateV1,
+        Hop,
+        CCTP,
         #[default]
         Unknown,
     }
@@ -140,9 +153,9 @@ impl Protocol {
             Protocol::UniswapX => ("Uniswap", "X"),
             Protocol::ZeroX => ("ZeroX", ""),
             Protocol::Cowswap => ("Cowswap", ""),
-            Protocol::CurveBasePool2 => ("Curve.fi", "Base"),
-            Protocol::CurveBasePool3 => ("Curve.fi", "Base"),
-            Protocol::CurveBasePool4 => ("Curve.fi", "Base"),
+            Protocol::CurveBasePool2 => ("Curve.fi", "Base2"),
+            Protocol::CurveBasePool3 => ("Curve.fi", "Base3"),
+            Protocol::CurveBasePool4 => ("Curve.fi", "Base4"),
             Protocol::CurveV1MetaPool => ("Curve.fi", "V1 Metapool"),
             Protocol::CurveV1MetapoolImpl => ("Curve.fi", "V1 Metapool Impl"),
             Protocol::CurveV2MetaPool => ("Curve.fi", "V2 Metapool"),
@@ -176,6 +189,18 @@ impl Protocol {
             Protocol::PendleV2 => ("Pendle", "V2"),
             Protocol::FluidDEX => ("Fluid", "DEX"),
             Protocol::FluidLending => ("Fluid", "Lending"),
+            Protocol::ERC4626 => ("ERC4626", "Vault"),
+            Protocol::WETH => ("WETH", ""),
+            Protocol::RamsesV1 => ("Ramses", "V1"),
+            Protocol::RamsesV2 => ("Ramses", "V2"),
+            Protocol::OneInchV6 => ("OneInch", "V6"),
+            Protocol::OdosV2 => ("Odos", "V2"),
+            Protocol::ParaSwapV6 => ("ParaSwap", "V6"),
+            Protocol::KyberSwap => ("KyberSwap", ""),
+            Protocol::AcrossV3 => ("Across", "V3"),
+            Protocol::StargateV1 => ("Stargate", "V1"),
+            Protocol::Hop => ("Hop", ""),
+            Protocol::CCTP => ("Circle", "CCTP"),
             Protocol::Unknown => ("Unknown", "Unknown"),
         }
     }
@@ -187,17 +212,34 @@ impl Protocol {
             "uniswapv3" => Protocol::UniswapV3,
             "uniswapv4" => Protocol::UniswapV4,
             "sushiswapv3" => Protocol::SushiSwapV3,
+            "aavev2" => Protocol::AaveV2,
+            "aavev3" => Protocol::AaveV3,
+            "uniswapx" => Protocol::UniswapX,
+            "zerox" => Protocol::ZeroX,
+            "cowswap" => Protocol::Cowswap,
             "curve.fibase2" => Protocol::CurveBasePool2,
             "curve.fibase3" => Protocol::CurveBasePool3,
             "curve.fibase4" => Protocol::CurveBasePool4,
             "curve.fiv1 metapool" => Protocol::CurveV1MetaPool,
+            "curve.fiv1 metapool impl" => Protocol::CurveV1MetapoolImpl,
             "curve.fiv2 metapool" => Protocol::CurveV2MetaPool,
+            "curve.fiv2 metapool impl" => Protocol::CurveV2MetapoolImpl,
             "curve.fiv2 plain" => Protocol::CurveV2PlainPool,
+            "curve.fiv2 plain impl" => Protocol::CurveV2PlainPoolImpl,
             "curve.ficrvusd metapool" => Protocol::CurvecrvUSDMetaPool,
+            "curve.ficrvusd metapool impl" => Protocol::CurvecrvUSDMetapoolImpl,
             "curve.ficrvusd plain" => Protocol::CurvecrvUSDPlainPool,
+            "curve.ficrvusd plain impl" => Protocol::CurvecrvUSDPlainPoolImpl,
             "curve.ficryptoswap" => Protocol::CurveCryptoSwapPool,
             "curve.fitricrypto" => Protocol::CurveTriCryptoPool,
-            "propellerlabssolver" => Protocol::PropellerLabsSolver,
+            "compoundv2" => Protocol::CompoundV2,
+            "compoundv3" => Protocol::CompoundV3,
+            "makerpsm" => Protocol::MakerPSM,
+            "makerdssflash" => Protocol::MakerDssFlash,
+            "oneinchv5" => Protocol::OneInchV5,
+            "oneinchfusion" => Protocol::OneInchFusion,
+            "clipperexchange" => Protocol::ClipperExchange,
+            "propellerlabssolver" | "propeller labs solver" => Protocol::PropellerLabsSolver,
             "balancerv1" => Protocol::BalancerV1,
             "balancerv1smartpool" => Protocol::BalancerV1CRP,
             "balancerv2" => Protocol::BalancerV2,
@@ -209,14 +251,26 @@ impl Protocol {
             "camelotv2" => Protocol::CamelotV2,
             "camelotv3" => Protocol::CamelotV3,
             "dexalot" => Protocol::Dexalot,
-            "dolomite" => Protocol::Dolomite,
+            "dolomite" | "dolomitev3" => Protocol::Dolomite,
             "gmxv1" => Protocol::GMXV1,
             "gmxv2" => Protocol::GMXV2,
             "pendlev2" => Protocol::PendleV2,
             "fluiddex" => Protocol::FluidDEX,
-            "fluidelending" => Protocol::FluidLending,
+            "fluidlending" => Protocol::FluidLending,
             "lfjv2.1" => Protocol::LFJV2_1,
             "lfjv2.2" => Protocol::LFJV2_2,
+            "erc4626vault" => Protocol::ERC4626,
+            "weth" => Protocol::WETH,
+            "ramsesv1" => Protocol::RamsesV1,
+            "ramsesv2" => Protocol::RamsesV2,
+            "oneinchv6" => Protocol::OneInchV6,
+            "odosv2" => Protocol::OdosV2,
+            "paraswapv6" => Protocol::ParaSwapV6,
+            "kyberswap" => Protocol::KyberSwap,
+            "acrossv3" => Protocol::AcrossV3,
+            "stargatev1" => Protocol::StargateV1,
+            "hop" => Protocol::Hop,
+            "circlecctp" => Protocol::CCTP,
             _ => Protocol::Unknown,
         }
     }
@@ -289,6 +343,18 @@ impl fmt::Display for Protocol {
                 Protocol::PendleV2 => "Pendle V2",
                 Protocol::FluidDEX => "Fluid DEX",
                 Protocol::FluidLending => "Fluid Lending",
+                Protocol::ERC4626 => "ERC-4626 Vault",
+                Protocol::WETH => "WETH",
+                Protocol::RamsesV1 => "Ramses V1",
+                Protocol::RamsesV2 => "Ramses V2",
+                Protocol::OneInchV6 => "1inch V6",
+                Protocol::OdosV2 => "Odos V2",
+                Protocol::ParaSwapV6 => "ParaSwap V6",
+                Protocol::KyberSwap => "KyberSwap",
+                Protocol::AcrossV3 => "Across V3",
+                Protocol::StargateV1 => "Stargate V1",
+                Protocol::Hop => "Hop",
+                Protocol::CCTP => "Circle CCTP",
                 Protocol::Unknown => "Unknown",
             }
         )
@@ -297,3 +363,18 @@ impl fmt::Display for Protocol {
 
 self_convert_redefined!(Protocol);
 implement_table_value_codecs_with_zc!(Protocol);
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn test_db_string_round_trip() {
+        for protocol in Protocol::iter() {
+            let (name, subtype) = protocol.into_clickhouse_protocol();
+            assert_eq!(Protocol::from_db_string(&format!("{name}{subtype}")), protocol);
+        }
+    }
+}
@@ -230,6 +230,15 @@ pub struct TxTrace {
     pub timeboosted:     bool,
     // False if the transaction reverted
     pub is_success:      bool,
+    // Gas charged for posting the calldata to L1, not included in `gas_used`
+    #[serde(default)]
+    pub gas_used_for_l1: u128,
+    #[serde(default)]
+    pub l1_block_number: u64,
+    /// EIP-2718 type of the transaction. Arbitrum adds its own types for
+    /// deposits, retryables and ArbOS internal transactions
+    #[serde(default)]
+    pub tx_type:         u8,
 }
 
 impl TxTrace {
@@ -242,6 +251,9 @@ impl TxTrace {
         effective_price: u128,
         is_success: bool,
         timeboosted: bool,
+        gas_used_for_l1: u128,
+        l1_block_number: u64,
+        tx_type: u8,
     ) -> Self {
         Self {
             block_number,
@@ -252,6 +264,9 @@ impl TxTrace {
             gas_used,
             is_success,
             timeboosted,
+            gas_used_for_l1,
+            l1_block_number,
+            tx_type,
         }
     }
 }
@@ -1,5 +1,5 @@
 use alloy_consensus::{AnyReceiptEnvelope, TxType};
-use alloy_primitives::Address;
+use alloy_primitives::{Address, U128, U64};
 use reth_rpc_types::{AnyTransactionReceipt, Log, ReceiptEnvelope, TransactionReceipt};
 use serde::{Deserialize, Serialize};
 
@@ -7,8 +7,14 @@ use serde::{Deserialize, Serialize};
 #[serde(rename_all = "camelCase")]
 pub struct TimeboostTransactionReceipt<T = TransactionReceipt<AnyReceiptEnvelope<Log>>> {
     #[serde(flatten)]
-    pub inner:       T,
-    pub timeboosted: bool,
+    pub inner:           T,
+    pub timeboosted:     bool,
+    /// Part of `gas_used` charged for posting the calldata to L1
+    #[serde(default)]
+    pub gas_used_for_l1: u128,
+    /// L1 block number the sequencer saw when the transaction was included
+    #[serde(default)]
+    pub l1_block_number: u64,
 }
 
 impl AsRef<TransactionReceipt<AnyReceiptEnvelope<Log>>>
@@ -48,8 +54,20 @@ impl From<AnyTransactionReceipt>
             .get_deserialized::<bool>("timeboosted")
             .unwrap_or(Ok(false))
             .unwrap_or(false);
+        let gas_used_for_l1 = receipt
+            .other
+            .get_deserialized::<U128>("gasUsedForL1")
+            .and_then(Result::ok)
+            .map(|gas| gas.to::<u128>())
+            .unwrap_or_default();
+        let l1_block_number = receipt
+            .other
+            .get_deserialized::<U64>("l1BlockNumber")
+            .and_then(Result::ok)
+            .map(|block| block.to::<u64>())
+            .unwrap_or_default();
 
-        Self { inner: receipt.inner, timeboosted }
+        Self { inner: receipt.inner, timeboosted, gas_used_for_l1, l1_block_number }
     }
 }
 
@@ -59,6 +77,11 @@ impl<T> TimeboostTransactionReceipt<T> {
     where
         F: FnOnce(T) -> U,
     {
-        TimeboostTransactionReceipt { inner: f(self.inner), timeboosted: self.timeboosted }
+        TimeboostTransactionReceipt {
+            inner:           f(self.inner),
+            timeboosted:     self.timeboosted,
+            gas_used_for_l1: self.gas_used_for_l1,
+            l1_block_number: self.l1_block_number,
+        }
     }
 }
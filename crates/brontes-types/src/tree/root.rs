@@ -344,8 +344,13 @@ impl<V: NormalizedAction> Root<V> {
 pub struct GasDetails {
     pub coinbase_transfer:   Option<u128>,
     pub priority_fee:        u128,
+    /// Gas used executing the transaction on L2. On Arbitrum this excludes the
+    /// gas charged for posting the calldata to L1, see `l1_gas_used`.
     pub gas_used:            u128,
     pub effective_gas_price: u128,
+    /// Arbitrum `gasUsedForL1`, the L1 data fee denominated in L2 gas
+    #[serde(default)]
+    pub l1_gas_used:         u128,
 }
 //TODO: Fix this
 impl Display for GasDetails {
@@ -353,8 +358,12 @@ impl Display for GasDetails {
         write!(
             f,
             "GasDetails {{ coinbase_transfer: {:?}, priority_fee: {}, gas_used: {}, \
-             effective_gas_price: {} }}",
-            self.coinbase_transfer, self.priority_fee, self.gas_used, self.effective_gas_price
+             effective_gas_price: {}, l1_gas_used: {} }}",
+            self.coinbase_transfer,
+            self.priority_fee,
+            self.gas_used,
+            self.effective_gas_price,
+            self.l1_gas_used
         )
     }
 }
@@ -362,8 +371,10 @@ impl Display for GasDetails {
 self_convert_redefined!(GasDetails);
 
 impl GasDetails {
+    /// Total paid by the transaction: the L2 execution fee, the L1 data fee
+    /// and any coinbase transfer.
     pub fn gas_paid(&self) -> u128 {
-        let mut gas = self.gas_used * self.effective_gas_price;
+        let mut gas = self.execution_fee_paid() + self.l1_fee_paid();
 
         if let Some(coinbase) = self.coinbase_transfer {
             gas += coinbase
@@ -372,6 +383,15 @@ impl GasDetails {
         gas
     }
 
+    pub fn execution_fee_paid(&self) -> u128 {
+        self.gas_used * self.effective_gas_price
+    }
+
+    /// Fee paid for posting the transaction's calldata to L1
+    pub fn l1_fee_paid(&self) -> u128 {
+        self.l1_gas_used * self.effective_gas_price
+    }
+
     pub fn priority_fee(&self, base_fee: u128) -> u128 {
         self.effective_gas_price - base_fee
     }
@@ -394,6 +414,7 @@ impl GasDetails {
         self.priority_fee += other.priority_fee;
         self.gas_used += other.gas_used;
         self.effective_gas_price += other.effective_gas_price;
+        self.l1_gas_used += other.l1_gas_used;
     }
 
     // Pretty print after 'spaces' spaces
@@ -408,7 +429,9 @@ impl GasDetails {
             ),
             ("Priority Fee", format!("{} Wei", self.priority_fee)),
             ("Gas Used", self.gas_used.to_string()),
+            ("L1 Gas Used", self.l1_gas_used.to_string()),
             ("Effective Gas Price", format!("{} Wei", self.effective_gas_price)),
+            ("L1 Data Fee in ETH", format!("{:.7} ETH", self.l1_fee_paid() as f64 / 1e18)),
             ("Total Gas Paid in ETH", format!("{:.7} ETH", self.gas_paid() as f64 / 1e18)),
         ];
 
@@ -448,6 +471,7 @@ pub struct ClickhouseVecGasDetails {
     pub priority_fee:        Vec<u128>,
     pub gas_used:            Vec<u128>,
     pub effective_gas_price: Vec<u128>,
+    pub l1_gas_used:         Vec<u128>,
 }
 
 impl From<(Vec<TxHash>, Vec<GasDetails>)> for ClickhouseVecGasDetails {
@@ -463,6 +487,7 @@ impl From<(Vec<TxHash>, Vec<GasDetails>)> for ClickhouseVecGasDetails {
                     gas.priority_fee,
                     gas.gas_used,
                     gas.effective_gas_price,
+                    gas.l1_gas_used,
                 )
             })
             .collect::<Vec<_>>();
@@ -473,6 +498,7 @@ impl From<(Vec<TxHash>, Vec<GasDetails>)> for ClickhouseVecGasDetails {
             priority_fee:        vec_vals.iter().map(|val| val.2.to_owned()).collect_vec(),
             gas_used:            vec_vals.iter().map(|val| val.3.to_owned()).collect_vec(),
             effective_gas_price: vec_vals.iter().map(|val| val.4.to_owned()).collect_vec(),
+            l1_gas_used:         vec_vals.iter().map(|val| val.5.to_owned()).collect_vec(),
         }
     }
 }
@@ -518,3 +544,33 @@ pub mod test {
         assert_eq!(info.mev_contract, None)
     }
 }*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gas_paid_includes_l1_data_fee() {
+        let gas = GasDetails {
+            coinbase_transfer:   Some(5),
+            priority_fee:        1,
+            gas_used:            100,
+            effective_gas_price: 10,
+            l1_gas_used:         20,
+        };
+
+        assert_eq!(gas.execution_fee_paid(), 1_000);
+        assert_eq!(gas.l1_fee_paid(), 200);
+        assert_eq!(gas.gas_paid(), 1_205);
+    }
+
+    #[test]
+    fn test_clickhouse_gas_details_keep_l1_gas_used() {
+        let gas = |l1_gas_used| GasDetails { gas_used: 100, l1_gas_used, ..Default::default() };
+        let details: ClickhouseVecGasDetails =
+            (vec![TxHash::ZERO, TxHash::with_last_byte(1)], vec![gas(20), gas(30)]).into();
+
+        assert_eq!(details.gas_used, vec![100, 100]);
+        assert_eq!(details.l1_gas_used, vec![20, 30]);
+    }
+}
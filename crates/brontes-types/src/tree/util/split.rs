@@ -122,3 +122,13 @@ action_split!(
     (F, RETF, FF),
     (G, RETG, FG)
 );
+action_split!(
+    (A, RETA, FA),
+    (B, RETB, FB),
+    (C, RETC, FC),
+    (D, RETD, FD),
+    (E, RETE, FE),
+    (F, RETF, FF),
+    (G, RETG, FG),
+    (H, RETH, FH)
+);
@@ -2,9 +2,17 @@ use std::{collections::BTreeMap, sync::Arc};
 
 use once_cell::sync::Lazy;
 use reth_primitives::{
-    b256, Chain, ChainSpec, ForkCondition, ForkTimestamps, Hardfork, NamedChain, U256,
+    b256, Chain, ChainSpec, ForkCondition, ForkTimestamps, Hardfork, NamedChain, MAINNET, U256,
 };
 
+/// Chainspec the tracer is started with for the given chain
+pub fn chain_spec(chain: NamedChain) -> Arc<ChainSpec> {
+    match chain {
+        NamedChain::Arbitrum => ARBITRUM_ONE.clone(),
+        _ => MAINNET.clone(),
+    }
+}
+
 // Lets just put some junk here for now, we only need to get the chain id
 // correct (hopefully)
 pub static ARBITRUM_ONE: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
@@ -4,8 +4,8 @@ use std::{
     sync::Arc,
 };
 
-use brontes_types::{structured_trace::TxTrace, BrontesTaskExecutor};
-use chainspec::ARBITRUM_ONE;
+use brontes_types::{chain::chain_config, structured_trace::TxTrace, BrontesTaskExecutor};
+use chainspec::chain_spec;
 use reth_beacon_consensus::BeaconConsensus;
 use reth_blockchain_tree::{
     externals::TreeExternals, BlockchainTree, BlockchainTreeConfig, ShareableBlockchainTree,
@@ -36,7 +36,7 @@ use reth_transaction_pool::{
     EthPooledTransaction, EthTransactionValidator, Pool, TransactionValidationTaskExecutor,
 };
 
-mod chainspec;
+pub mod chainspec;
 mod provider;
 pub mod reth_tracer;
 
@@ -66,7 +66,7 @@ impl TracingClient {
         task_executor: BrontesTaskExecutor,
         static_files_path: PathBuf,
     ) -> Self {
-        let chain = ARBITRUM_ONE.clone();
+        let chain = chain_spec(chain_config().named_chain());
         let provider_factory =
             ProviderFactory::new(Arc::clone(&db), Arc::clone(&chain), static_files_path)
                 .expect("failed to start provider factory");
@@ -394,6 +394,9 @@ impl BrontesTracingInspector {
             timeboosted,
             tx_index: info.index.unwrap(),
             is_success: res.is_success(),
+            gas_used_for_l1: 0,
+            l1_block_number: 0,
+            tx_type: 0,
         }
     }
 
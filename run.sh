@@ -1,6 +1,7 @@
 #!/bin/sh
 
-RUST_LOG="brontes=warn,brontes_inspect::mev_inspectors::cex_dex::markout=info" cargo run --features arbitrum --release -- \
+RUST_LOG="brontes=warn,brontes_inspect::mev_inspectors::cex_dex::markout=info" cargo run --release -- \
+--chain arbitrum \
 run \
 --with-metrics \
 --behind-tip 50 \